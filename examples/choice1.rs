@@ -6,12 +6,14 @@ use rat_menu::event::MenuOutcome;
 use rat_menu::menuline::{MenuLine, MenuLineState};
 use rat_widget::choice::{Choice, ChoiceState};
 use rat_widget::event::Outcome;
+use rat_widget::focus::FocusFollowsMouse;
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, BorderType, StatefulWidget};
 use ratatui::Frame;
 use std::cmp::max;
+use std::time::Duration;
 
 mod mini_salsa;
 
@@ -25,6 +27,7 @@ fn main() -> Result<(), anyhow::Error> {
         c2: ChoiceState::named("c2"),
         c3: ChoiceState::named("c3"),
         menu: MenuLineState::named("menu"),
+        follow_mouse: None,
     };
 
     run_ui(
@@ -43,6 +46,7 @@ struct State {
     c2: ChoiceState,
     c3: ChoiceState,
     menu: MenuLineState,
+    follow_mouse: Option<FocusFollowsMouse>,
 }
 
 fn repaint_input(
@@ -121,6 +125,11 @@ fn repaint_input(
 
     let menu1 = MenuLine::new()
         .title("a|b|c")
+        .item_parsed(if state.follow_mouse.is_some() {
+            "_Click to focus"
+        } else {
+            "_Follow mouse"
+        })
         .item_parsed("_Quit")
         .styles(THEME.menu_style());
     frame.render_stateful_widget(menu1, l1[1], &mut state.menu);
@@ -147,6 +156,17 @@ fn handle_input(
 ) -> Result<Outcome, anyhow::Error> {
     let mut focus = focus(state);
     let f = focus.handle(event, Regular);
+    // There is no timer here, so the focus switches with the
+    // first mouse move after the delay.
+    let f = if let Some(follow_mouse) = &mut state.follow_mouse {
+        if follow_mouse.handle(&focus, event, Some(Duration::from_millis(200))) {
+            Outcome::Changed
+        } else {
+            f
+        }
+    } else {
+        f
+    };
 
     // popup handling first
     let r = state.c1.handle(event, Popup);
@@ -161,6 +181,13 @@ fn handle_input(
         MenuOutcome::Activated(v) => {
             match v {
                 0 => {
+                    state.follow_mouse = if state.follow_mouse.is_some() {
+                        None
+                    } else {
+                        Some(FocusFollowsMouse::new())
+                    };
+                }
+                1 => {
                     istate.quit = true;
                     return Outcome::Changed;
                 }
@@ -106,6 +106,8 @@ fn repaint_input(
     let mut clip_buf = clipper.into_buffer(l2[1], &mut state.clipper);
 
     // render the input fields.
+    // the closure is only called for visible widgets,
+    // everything else is skipped.
     for i in 0..state.hundred.len() {
         clip_buf.render(
             state.hundred[i].focus.clone(),
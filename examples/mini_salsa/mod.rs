@@ -12,6 +12,11 @@ use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use crossterm::ExecutableCommand;
+#[cfg(not(windows))]
+use crossterm::{
+    event::{KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags},
+    terminal::supports_keyboard_enhancement,
+};
 use log::error;
 use rat_event::util::set_have_keyboard_enhancement;
 use rat_event::Outcome;
@@ -13,7 +13,7 @@ use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
 use rat_widget::pager::{SinglePager, SinglePagerState};
 use ratatui::layout::{Constraint, Flex, Layout, Rect};
 use ratatui::text::Span;
-use ratatui::widgets::Padding;
+use ratatui::widgets::{Block, Padding};
 use ratatui::Frame;
 use std::array;
 use std::cmp::max;
@@ -85,7 +85,15 @@ fn repaint_input(
             .spacing(1)
             .line_spacing(1)
             .flex(Flex::Legacy);
+        let mut tag = None;
         for i in 0..state.hundred.len() {
+            // group every 10 fields.
+            if i % 10 == 0 {
+                if let Some(tag) = tag.take() {
+                    form.end(tag);
+                }
+                tag = Some(form.start(Some(Block::bordered().title(format!("{}..{}", i, i + 9)))));
+            }
             let h = if i % 3 == 0 {
                 2
             } else if i % 5 == 0 {
@@ -103,6 +111,9 @@ fn repaint_input(
                 form.page_break();
             }
         }
+        if let Some(tag) = tag.take() {
+            form.end(tag);
+        }
 
         state.pager.layout = Rc::new(form.paged(layout_size, Padding::new(2, 2, 1, 1)));
     }
@@ -110,6 +121,9 @@ fn repaint_input(
     // set current layout and prepare rendering.
     let mut pager = pager.into_buffer(l2[1], frame.buffer_mut(), &mut state.pager);
 
+    // render the group blocks first.
+    pager.render_block();
+
     // render the input fields.
     for i in 0..state.hundred.len() {
         // render manual label
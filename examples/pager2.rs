@@ -10,7 +10,7 @@ use rat_menu::menuline::{MenuLine, MenuLineState};
 use rat_text::HasScreenCursor;
 use rat_widget::event::{Outcome, PagerOutcome};
 use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
-use rat_widget::pager::{DualPager, DualPagerState};
+use rat_widget::pager::{handle_page_focus, DualPager, DualPagerState};
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::widgets::Padding;
 use ratatui::Frame;
@@ -74,7 +74,8 @@ fn repaint_input(
 
     // set up pager
     let pager = DualPager::new() //
-        .styles(THEME.pager_style());
+        .styles(THEME.pager_style())
+        .column_ratio_from(&state.pager);
 
     // maybe rebuild layout
     let layout_size = pager.layout_size(l2[1]);
@@ -156,7 +157,11 @@ fn handle_input(
     state: &mut State,
 ) -> Result<Outcome, anyhow::Error> {
     let mut focus = focus(state);
-    let f = focus.handle(event, Regular);
+    // Tab switches the page before it focuses a widget on another page.
+    let f = handle_page_focus(event, &focus, &state.pager.layout(), |w| {
+        state.pager.show(w)
+    })
+    .or_else(|| focus.handle(event, Regular));
 
     if f == Outcome::Changed {
         if let Some(ff) = focus.focused() {
@@ -175,6 +180,8 @@ fn handle_input(
     };
 
     r = r.or_else(|| match event {
+        ct_event!(keycode press CONTROL_ALT-Left) => state.pager.move_split(-1).into(),
+        ct_event!(keycode press CONTROL_ALT-Right) => state.pager.move_split(1).into(),
         ct_event!(keycode press F(4)) => {
             if state.pager.prev_page() {
                 if let Some(first) = state.pager.first(state.pager.page()) {
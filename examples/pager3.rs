@@ -5,12 +5,13 @@ use crate::mini_salsa::theme::THEME;
 use crate::mini_salsa::{run_ui, setup_logging, MiniSalsaState};
 use log::debug;
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, Regular};
-use rat_focus::{Focus, FocusBuilder, FocusFlag, HasFocus};
+use rat_focus::{ContainerFlag, Focus, FocusBuilder, FocusFlag, HasFocus};
 use rat_menu::event::MenuOutcome;
 use rat_menu::menuline::{MenuLine, MenuLineState};
 use rat_reloc::RelocatableState;
 use rat_text::HasScreenCursor;
 use rat_widget::event::{Outcome, PagerOutcome};
+use rat_widget::focus::{next_container, prev_container};
 use rat_widget::layout::{FormLabel, FormWidget, GenericLayout, LayoutForm};
 use rat_widget::pager::{PageNavigation, PageNavigationState, Pager};
 use ratatui::layout::{Alignment, Constraint, Flex, Layout, Rect};
@@ -69,7 +70,8 @@ fn repaint_input(
     state: &mut State,
 ) -> Result<(), anyhow::Error> {
     if istate.status[0] == "Ctrl-Q to quit." {
-        istate.status[0] = "Ctrl-Q to quit. F2 flex. F4/F5 navigate page.".into();
+        istate.status[0] =
+            "Ctrl-Q to quit. F2 flex. F4/F5 navigate page. Ctrl-Tab next group.".into();
     }
 
     let l1 = Layout::vertical([
@@ -225,7 +227,8 @@ fn render_page(
         let idx = pager.widget_idx(state.hundred[i].focus()).expect("fine");
         if pager.is_visible(idx) {
             if let Some(idx) = pager.widget_idx(state.hundred[i].focus.clone()) {
-                pager.render_auto_label(idx);
+                // lazy label
+                pager.render_label_with(idx, || Line::from(format!("{}", i)));
                 pager.render(
                     idx,
                     || {
@@ -251,10 +254,21 @@ fn focus(state: &mut State) -> Focus {
     fb.widget(&state.menu);
 
     let tag = fb.start(Some(state.page_nav.container.clone()), Rect::default(), 0);
+    // same groups as the blocks of the layout, for Ctrl-Tab.
+    let mut tag8 = None;
     for i in 0..state.hundred.len() {
+        if i >= 8 {
+            if i % 8 == 0 {
+                tag8 = fb.start(Some(ContainerFlag::default()), Rect::default(), 0);
+            }
+            if (i - 4) % 8 == 0 {
+                fb.end(tag8.take());
+            }
+        }
         // Focus wants __all__ areas.
         fb.widget(&state.hundred[i]);
     }
+    fb.end(tag8);
     fb.end(tag);
 
     fb.build()
@@ -278,7 +292,12 @@ fn handle_input(
         state.t_focus / state.n_focus * 1e6f64
     );
 
-    let f = focus.handle(event, Regular);
+    // jump between the groups. Needs a terminal that reports Ctrl-Tab.
+    let f = match event {
+        ct_event!(keycode press CONTROL-Tab) => next_container(&focus).into(),
+        ct_event!(keycode press CONTROL_SHIFT-BackTab) => prev_container(&focus).into(),
+        _ => focus.handle(event, Regular),
+    };
 
     // set the page from focus.
     if f == Outcome::Changed {
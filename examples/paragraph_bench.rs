@@ -0,0 +1,63 @@
+//!
+//! Compares the first render of a 10k line Paragraph with
+//! renders that only change the scroll offset.
+//!
+//! The line breaks are cached in the ParagraphState, so only
+//! the first render has to wrap the whole text.
+//!
+
+use rat_widget::paragraph::{Paragraph, ParagraphState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{StatefulWidget, Wrap};
+use std::time::Instant;
+
+fn main() {
+    let text = (0..10_000)
+        .map(|v| {
+            format!(
+                "{} lorem ipsum dolor sit amet, consectetur adipiscing elit",
+                v
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let area = Rect::new(0, 0, 40, 30);
+    let mut buf = Buffer::empty(area);
+    let mut state = ParagraphState::new();
+
+    let t = Instant::now();
+    Paragraph::new(text.as_str())
+        .wrap(Wrap { trim: true })
+        .text_id(1)
+        .render(area, &mut buf, &mut state);
+    println!(
+        "first render {:?} ({} rows)",
+        t.elapsed(),
+        state.line_count()
+    );
+
+    let t = Instant::now();
+    let n = 100;
+    for i in 0..n {
+        state.set_line_offset(i * 200);
+        Paragraph::new(text.as_str())
+            .wrap(Wrap { trim: true })
+            .text_id(1)
+            .render(area, &mut buf, &mut state);
+    }
+    println!("scroll render {:?} per frame", t.elapsed() / n as u32);
+
+    let t = Instant::now();
+    for i in 0..n {
+        state.set_line_offset(i * 200);
+        Paragraph::new(text.as_str())
+            .wrap(Wrap { trim: true })
+            .render(area, &mut buf, &mut state);
+    }
+    println!(
+        "scroll render without text_id {:?} per frame",
+        t.elapsed() / n as u32
+    );
+}
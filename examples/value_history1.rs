@@ -0,0 +1,194 @@
+use crate::mini_salsa::theme::THEME;
+use crate::mini_salsa::{layout_grid, run_ui, setup_logging, MiniSalsaState};
+use rat_event::{ConsumedEvent, HandleEvent, Popup, Regular};
+use rat_focus::{Focus, FocusBuilder, FocusFlag};
+use rat_text::HasScreenCursor;
+use rat_widget::choice::{Choice, ChoiceState};
+use rat_widget::date_input::{DateInput, DateInputState};
+use rat_widget::event::Outcome;
+use rat_widget::number_input::{NumberInput, NumberInputState};
+use rat_widget::text_input::{TextInput, TextInputState};
+use rat_widget::value_history;
+use rat_widget::value_history::{HistoryOutcome, ValueHistory, ValueStr};
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+use ratatui::text::Span;
+use ratatui::widgets::{StatefulWidget, Widget};
+use ratatui::Frame;
+use std::cmp::max;
+
+mod mini_salsa;
+
+fn main() -> Result<(), anyhow::Error> {
+    setup_logging()?;
+
+    let mut data = Data {};
+
+    let mut state = State {
+        name: TextInputState::named("name"),
+        date: DateInputState::named("date").with_pattern("%d.%m.%Y")?,
+        amount: NumberInputState::named("amount").with_pattern("###,##0.00")?,
+        fruit: ChoiceState::named("fruit"),
+        history: ValueHistory::new(),
+    };
+
+    run_ui(
+        "value_history1",
+        handle_input,
+        repaint_input,
+        &mut data,
+        &mut state,
+    )
+}
+
+struct Data {}
+
+struct State {
+    name: TextInputState,
+    date: DateInputState,
+    amount: NumberInputState,
+    fruit: ChoiceState,
+    history: ValueHistory,
+}
+
+fn repaint_input(
+    frame: &mut Frame<'_>,
+    area: Rect,
+    _data: &mut Data,
+    _istate: &mut MiniSalsaState,
+    state: &mut State,
+) -> Result<(), anyhow::Error> {
+    let lg = layout_grid::<2, 6>(
+        area,
+        Layout::horizontal([
+            Constraint::Length(10), //
+            Constraint::Length(25),
+        ])
+        .spacing(1)
+        .flex(Flex::Start),
+        Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .spacing(1),
+    );
+
+    Span::from("Name").render(lg[0][0], frame.buffer_mut());
+    TextInput::new().styles(THEME.input_style()).render(
+        lg[1][0],
+        frame.buffer_mut(),
+        &mut state.name,
+    );
+
+    Span::from("Date").render(lg[0][1], frame.buffer_mut());
+    DateInput::new().styles(THEME.input_style()).render(
+        lg[1][1],
+        frame.buffer_mut(),
+        &mut state.date,
+    );
+
+    Span::from("Amount").render(lg[0][2], frame.buffer_mut());
+    NumberInput::new().styles(THEME.input_style()).render(
+        lg[1][2],
+        frame.buffer_mut(),
+        &mut state.amount,
+    );
+
+    Span::from("Fruit").render(lg[0][3], frame.buffer_mut());
+    let (w, p) = Choice::new()
+        .styles(THEME.choice_style())
+        .auto_item("Apple")
+        .auto_item("Banana")
+        .auto_item("Cherry")
+        .popup_boundary(area)
+        .into_widgets();
+    w.render(lg[1][3], frame.buffer_mut(), &mut state.fruit);
+
+    Span::from(format!(
+        "Ctrl+Z undo{} | Ctrl+Shift+Z redo{}",
+        if state.history.can_undo() { "" } else { " -" },
+        if state.history.can_redo() { "" } else { " -" },
+    ))
+    .render(lg[1][5], frame.buffer_mut());
+
+    p.render(lg[1][3], frame.buffer_mut(), &mut state.fruit);
+
+    if let Some((x, y)) = state
+        .name
+        .screen_cursor()
+        .or(state.date.screen_cursor())
+        .or(state.amount.screen_cursor())
+    {
+        frame.set_cursor_position((x, y));
+    }
+
+    Ok(())
+}
+
+fn focus(state: &mut State) -> Focus {
+    let mut fb = FocusBuilder::new(None);
+    fb.widget(&state.name);
+    fb.widget(&state.date);
+    fb.widget(&state.amount);
+    fb.widget(&state.fruit);
+    fb.build()
+}
+
+/// Record the committed changes.
+fn track(state: &mut State) {
+    state.history.track(&state.name.focus, &state.name);
+    state.history.track(&state.date.widget.focus, &state.date);
+    state
+        .history
+        .track(&state.amount.widget.focus, &state.amount);
+    state.history.track(&state.fruit.focus, &state.fruit);
+}
+
+/// Apply an undo/redo.
+fn restore(state: &mut State, focus: &Focus, flag: FocusFlag, value: String) {
+    if flag == state.name.focus {
+        state.name.set_value_str(&value);
+        focus.focus(&state.name);
+    } else if flag == state.date.widget.focus {
+        state.date.set_value_str(&value);
+        focus.focus(&state.date);
+    } else if flag == state.amount.widget.focus {
+        state.amount.set_value_str(&value);
+        focus.focus(&state.amount);
+    } else if flag == state.fruit.focus {
+        state.fruit.set_value_str(&value);
+        focus.focus(&state.fruit);
+    }
+}
+
+fn handle_input(
+    event: &crossterm::event::Event,
+    _data: &mut Data,
+    _istate: &mut MiniSalsaState,
+    state: &mut State,
+) -> Result<Outcome, anyhow::Error> {
+    let mut focus = focus(state);
+    let f = focus.handle(event, Regular);
+    track(state);
+
+    // before the widgets, otherwise the text inputs use
+    // Ctrl+Z for their own undo.
+    let r = match value_history::handle_events(&mut state.history, event) {
+        HistoryOutcome::Restore(flag, value) => {
+            restore(state, &focus, flag, value);
+            Outcome::Changed
+        }
+        r => r.into(),
+    };
+
+    let r = r.or_else(|| state.fruit.handle(event, Popup));
+    let r = r.or_else(|| state.name.handle(event, Regular).into());
+    let r = r.or_else(|| state.date.handle(event, Regular).into());
+    let r = r.or_else(|| state.amount.handle(event, Regular).into());
+    let r = r.or_else(|| state.fruit.handle(event, Regular));
+
+    Ok(max(f, r))
+}
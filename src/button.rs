@@ -3,7 +3,7 @@
 //!
 
 use crate::_private::NonExhaustive;
-use crate::util::{block_size, revert_style};
+use crate::util::{block_size, fallback, revert_style};
 use rat_event::util::have_keyboard_enhancement;
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Outcome, Regular};
 use rat_focus::{FocusFlag, HasFocus};
@@ -11,7 +11,7 @@ use rat_reloc::{relocate_area, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::BlockExt;
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Text;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
@@ -19,6 +19,12 @@ use ratatui::widgets::{Block, StatefulWidget, Widget};
 use std::thread;
 use std::time::Duration;
 
+/// Default spinner for a pending button.
+pub const SPINNER_BRAILLE: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Ascii spinner for a pending button.
+pub const SPINNER_ASCII: &[&str] = &["|", "/", "-", "\\"];
+
 /// Button widget.
 #[derive(Debug, Default, Clone)]
 pub struct Button<'a> {
@@ -27,6 +33,10 @@ pub struct Button<'a> {
     focus_style: Option<Style>,
     armed_style: Option<Style>,
     armed_delay: Option<Duration>,
+    flash_style: Option<Style>,
+    flash_frames: Option<u16>,
+    pending_style: Option<Style>,
+    spinner: Option<&'a [&'a str]>,
     block: Option<Block<'a>>,
 }
 
@@ -45,6 +55,14 @@ pub struct ButtonStyle {
     /// This adds some delay when the button state goes from
     /// armed to clicked.
     pub armed_delay: Option<Duration>,
+    /// Style for a short flash after the button has been pressed.
+    pub flash: Option<Style>,
+    /// Number of ticks the flash is shown.
+    pub flash_frames: Option<u16>,
+    /// Style while an action is pending.
+    pub pending: Option<Style>,
+    /// Spinner glyphs while an action is pending.
+    pub spinner: Option<&'static [&'static str]>,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -69,6 +87,23 @@ pub struct ButtonState {
     /// Default is 50ms.
     pub armed_delay: Option<Duration>,
 
+    /// Remaining ticks for the flash after the button has been pressed.
+    /// Counted down by [tick](ButtonState::tick).
+    /// __read+write__
+    pub flash: u16,
+    /// Number of ticks the flash is shown. 0 if there is no flash.
+    /// __readonly__. renewed for each render.
+    pub flash_frames: u16,
+
+    /// An action triggered by the button is still running.
+    /// The button shows a spinner and can't be pressed.
+    /// __read+write__ use [set_pending](ButtonState::set_pending)
+    pub pending: bool,
+    /// Spinner frame. Counted up by [tick](ButtonState::tick)
+    /// while pending.
+    /// __read+write__
+    pub pending_frame: usize,
+
     /// Current focus state.
     /// __read+write__
     pub focus: FocusFlag,
@@ -84,6 +119,10 @@ impl Default for ButtonStyle {
             armed: None,
             block: None,
             armed_delay: None,
+            flash: None,
+            flash_frames: None,
+            pending: None,
+            spinner: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -117,10 +156,21 @@ impl<'a> Button<'a> {
         if styles.armed_delay.is_some() {
             self.armed_delay = styles.armed_delay;
         }
+        if styles.flash.is_some() {
+            self.flash_style = styles.flash;
+        }
+        if styles.flash_frames.is_some() {
+            self.flash_frames = styles.flash_frames;
+        }
+        if styles.pending.is_some() {
+            self.pending_style = styles.pending;
+        }
+        if styles.spinner.is_some() {
+            self.spinner = styles.spinner;
+        }
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
-        self.block = self.block.map(|v| v.style(self.style));
         self
     }
 
@@ -153,6 +203,50 @@ impl<'a> Button<'a> {
         self
     }
 
+    /// Style for a short flash after the button has been pressed.
+    ///
+    /// This works for mouse and keyboard. The app must call
+    /// [ButtonState::tick] regularly to end the flash.
+    #[inline]
+    pub fn flash_style(mut self, style: impl Into<Style>) -> Self {
+        self.flash_style = Some(style.into());
+        self
+    }
+
+    /// Number of ticks the flash is shown.
+    ///
+    /// __Default__
+    /// Default is 3.
+    #[inline]
+    pub fn flash_frames(mut self, frames: u16) -> Self {
+        self.flash_frames = Some(frames);
+        self
+    }
+
+    /// Style while an action is pending.
+    ///
+    /// __Default__
+    /// The base style dimmed.
+    #[inline]
+    pub fn pending_style(mut self, style: impl Into<Style>) -> Self {
+        self.pending_style = Some(style.into());
+        self
+    }
+
+    /// Spinner glyphs while an action is pending.
+    ///
+    /// The frame is advanced by [ButtonState::tick].
+    /// The spinner takes the first column of the button,
+    /// the text moves one column to the right.
+    ///
+    /// __Default__
+    /// [SPINNER_BRAILLE]
+    #[inline]
+    pub fn spinner(mut self, spinner: &'a [&'a str]) -> Self {
+        self.spinner = Some(spinner);
+        self
+    }
+
     /// Button text.
     #[inline]
     pub fn text(mut self, text: impl Into<Text<'a>>) -> Self {
@@ -176,7 +270,6 @@ impl<'a> Button<'a> {
     #[inline]
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.block = Some(block);
-        self.block = self.block.map(|v| v.style(self.style));
         self
     }
 
@@ -212,12 +305,18 @@ fn render_ref(widget: &Button<'_>, area: Rect, buf: &mut Buffer, state: &mut But
     state.area = area;
     state.inner = widget.block.inner_if_some(area);
     state.armed_delay = widget.armed_delay;
-
-    let focus_style = if let Some(focus_style) = widget.focus_style {
-        focus_style
+    state.flash_frames = if widget.flash_style.is_some() {
+        widget.flash_frames.unwrap_or(3)
     } else {
-        revert_style(widget.style)
+        0
     };
+
+    let focus_style = fallback(
+        &[Some(
+            widget.focus_style.unwrap_or(revert_style(widget.style)),
+        )],
+        widget.style,
+    );
     let armed_style = if let Some(armed_style) = widget.armed_style {
         armed_style
     } else {
@@ -228,30 +327,66 @@ fn render_ref(widget: &Button<'_>, area: Rect, buf: &mut Buffer, state: &mut But
         }
     };
 
-    if widget.block.is_some() {
-        widget.block.render(area, buf);
-    } else {
-        buf.set_style(area, widget.style);
-    }
+    buf.set_style(area, widget.style);
+    widget.block.render(area, buf);
 
     if state.focus.get() {
         buf.set_style(state.inner, focus_style);
     }
 
+    let armed_area = Rect::new(
+        state.inner.x + 1,
+        state.inner.y,
+        state.inner.width.saturating_sub(2),
+        state.inner.height,
+    );
     if state.armed {
-        let armed_area = Rect::new(
-            state.inner.x + 1,
-            state.inner.y,
-            state.inner.width.saturating_sub(2),
-            state.inner.height,
-        );
         buf.set_style(armed_area, armed_style);
+    } else if state.flash > 0 {
+        if let Some(flash_style) = widget.flash_style {
+            buf.set_style(armed_area, flash_style);
+        }
     }
 
+    // the spinner gets the first column, the label moves right.
+    let spinner = if state.pending {
+        widget.spinner.unwrap_or(SPINNER_BRAILLE)
+    } else {
+        &[]
+    };
+    let text_area = if !spinner.is_empty() {
+        Rect::new(
+            state.inner.x.saturating_add(1),
+            state.inner.y,
+            state.inner.width.saturating_sub(1),
+            state.inner.height,
+        )
+    } else {
+        state.inner
+    };
+
     let h = widget.text.height() as u16;
-    let r = state.inner.height.saturating_sub(h) / 2;
-    let area = Rect::new(state.inner.x, state.inner.y + r, state.inner.width, h);
+    let r = text_area.height.saturating_sub(h) / 2;
+    let area = Rect::new(text_area.x, text_area.y + r, text_area.width, h);
     (&widget.text).render(area, buf);
+
+    if state.pending {
+        let pending_style = widget
+            .pending_style
+            .unwrap_or(widget.style.add_modifier(Modifier::DIM));
+        buf.set_style(state.inner, pending_style);
+
+        if !spinner.is_empty() && !state.inner.is_empty() {
+            let glyph = spinner[state.pending_frame % spinner.len()];
+            buf.set_stringn(
+                state.inner.x,
+                state.inner.y + state.inner.height.saturating_sub(1) / 2,
+                glyph,
+                1,
+                pending_style,
+            );
+        }
+    }
 }
 
 impl Clone for ButtonState {
@@ -261,6 +396,10 @@ impl Clone for ButtonState {
             inner: self.inner,
             armed: self.armed,
             armed_delay: self.armed_delay,
+            flash: self.flash,
+            flash_frames: self.flash_frames,
+            pending: self.pending,
+            pending_frame: self.pending_frame,
             focus: FocusFlag::named(self.focus.name()),
             non_exhaustive: NonExhaustive,
         }
@@ -274,6 +413,10 @@ impl Default for ButtonState {
             inner: Default::default(),
             armed: false,
             armed_delay: None,
+            flash: 0,
+            flash_frames: 0,
+            pending: false,
+            pending_frame: 0,
             focus: Default::default(),
             non_exhaustive: NonExhaustive,
         }
@@ -291,6 +434,75 @@ impl ButtonState {
             ..Default::default()
         }
     }
+
+    /// Count down the flash after the button has been pressed.
+    /// Advances the spinner while pending.
+    ///
+    /// Returns true if the button needs to be rendered again.
+    pub fn tick(&mut self) -> bool {
+        let mut r = false;
+        if self.flash > 0 {
+            self.flash -= 1;
+            r = true;
+        }
+        if self.pending {
+            self.pending_frame = self.pending_frame.wrapping_add(1);
+            r = true;
+        }
+        r
+    }
+
+    /// Mark an action triggered by the button as running.
+    ///
+    /// While pending the button shows a spinner and doesn't
+    /// emit Pressed. Focus stays with the button, all other
+    /// keys are passed on.
+    ///
+    /// Returns true if the state changed.
+    pub fn set_pending(&mut self, pending: bool) -> bool {
+        let old_pending = self.pending;
+        self.pending = pending;
+        if pending {
+            self.armed = false;
+        } else {
+            self.pending_frame = 0;
+        }
+        old_pending != pending
+    }
+
+    /// Action still running?
+    pub fn is_pending(&self) -> bool {
+        self.pending
+    }
+
+    /// Swallow any activation while pending.
+    fn handle_pending(&self, event: &crossterm::event::Event, keys: bool) -> ButtonOutcome {
+        match event {
+            ct_event!(keycode press Enter)
+            | ct_event!(key press ' ')
+            | ct_event!(keycode release Enter)
+            | ct_event!(key release ' ')
+                if keys =>
+            {
+                ButtonOutcome::Unchanged
+            }
+            ct_event!(mouse down Left for column, row)
+            | ct_event!(mouse up Left for column, row)
+                if self.area.contains((*column, *row).into()) =>
+            {
+                ButtonOutcome::Unchanged
+            }
+            _ => ButtonOutcome::Continue,
+        }
+    }
+
+    /// Start the flash if the button has been pressed.
+    fn flash_on_press(&mut self, r: ButtonOutcome) -> ButtonOutcome {
+        if r == ButtonOutcome::Pressed {
+            self.flash = self.flash_frames;
+        }
+        r
+    }
 }
 
 impl HasFocus for ButtonState {
@@ -315,7 +527,7 @@ impl RelocatableState for ButtonState {
 /// Result value for event-handling.
 ///
 /// Adds `Pressed` to the general Outcome.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub enum ButtonOutcome {
     /// The given event was not handled at all.
     Continue,
@@ -333,6 +545,27 @@ impl ConsumedEvent for ButtonOutcome {
     }
 }
 
+// Useful for converting most navigation/edit results.
+impl From<bool> for ButtonOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            ButtonOutcome::Changed
+        } else {
+            ButtonOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for ButtonOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => ButtonOutcome::Continue,
+            Outcome::Unchanged => ButtonOutcome::Unchanged,
+            Outcome::Changed => ButtonOutcome::Changed,
+        }
+    }
+}
+
 impl From<ButtonOutcome> for Outcome {
     fn from(value: ButtonOutcome) -> Self {
         match value {
@@ -346,6 +579,10 @@ impl From<ButtonOutcome> for Outcome {
 
 impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: Regular) -> ButtonOutcome {
+        if self.pending {
+            return self.handle_pending(event, self.is_focused());
+        }
+
         let r = if self.is_focused() {
             // Release keys may not be available.
             if have_keyboard_enhancement() {
@@ -383,14 +620,18 @@ impl HandleEvent<crossterm::event::Event, Regular, ButtonOutcome> for ButtonStat
         if r == ButtonOutcome::Continue {
             HandleEvent::handle(self, event, MouseOnly)
         } else {
-            r
+            self.flash_on_press(r)
         }
     }
 }
 
 impl HandleEvent<crossterm::event::Event, MouseOnly, ButtonOutcome> for ButtonState {
     fn handle(&mut self, event: &crossterm::event::Event, _keymap: MouseOnly) -> ButtonOutcome {
-        match event {
+        if self.pending {
+            return self.handle_pending(event, false);
+        }
+
+        let r = match event {
             ct_event!(mouse down Left for column, row) => {
                 if self.area.contains((*column, *row).into()) {
                     self.armed = true;
@@ -417,7 +658,8 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, ButtonOutcome> for ButtonSt
                 }
             }
             _ => ButtonOutcome::Continue,
-        }
+        };
+        self.flash_on_press(r)
     }
 }
 
@@ -460,6 +702,17 @@ impl HandleEvent<crossterm::event::Event, CTHotKey, ButtonOutcome> for ButtonSta
     fn handle(&mut self, event: &crossterm::event::Event, hotkey: CTHotKey) -> ButtonOutcome {
         use crossterm::event::Event;
 
+        if self.pending {
+            return match event {
+                Event::Key(key)
+                    if hotkey.0.code == key.code && hotkey.0.modifiers == key.modifiers =>
+                {
+                    ButtonOutcome::Unchanged
+                }
+                _ => self.handle(event, Regular),
+            };
+        }
+
         let r = match event {
             Event::Key(key) => {
                 // Release keys may not be available.
@@ -500,6 +753,7 @@ impl HandleEvent<crossterm::event::Event, CTHotKey, ButtonOutcome> for ButtonSta
             _ => ButtonOutcome::Continue,
         };
 
+        let r = self.flash_on_press(r);
         r.or_else(|| self.handle(event, Regular))
     }
 }
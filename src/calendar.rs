@@ -13,14 +13,22 @@ use rat_focus::{FocusFlag, HasFocus};
 use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Rect};
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Span;
 use ratatui::widgets::block::Title;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::rc::Rc;
+
+pub mod agenda;
+pub mod quick_select;
+
+pub use agenda::{Agenda, AgendaState, AgendaStyle};
+pub use quick_select::{PickerView, QuickSelect, QuickSelectState};
 
 /// Renders a month.
 #[derive(Debug, Default, Clone)]
@@ -46,10 +54,17 @@ pub struct Month<'a> {
     select_style: Option<Style>,
     /// Focus
     focus_style: Option<Style>,
+    /// Tentative range while dragging.
+    preview_style: Option<Style>,
+    /// Days of the previous/next month.
+    adjacent_style: Option<Style>,
     /// Selection
     day_selection: bool,
     week_selection: bool,
+    range_selection: bool,
+    adjacent_selection: bool,
     show_weekdays: bool,
+    show_adjacent_days: bool,
 
     /// Block
     block: Option<Block<'a>>,
@@ -68,10 +83,52 @@ pub struct MonthStyle {
     pub day: Option<Style>,
     pub select: Option<Style>,
     pub focus: Option<Style>,
+    /// Tentative range while dragging.
+    pub preview: Option<Style>,
+    /// Days of the previous/next month.
+    pub adjacent: Option<Style>,
     pub block: Option<Block<'static>>,
     pub non_exhaustive: NonExhaustive,
 }
 
+/// Movement of the selection for [MonthState::move_selection].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectionMove {
+    /// n days back.
+    PrevDay(usize),
+    /// n days forward.
+    NextDay(usize),
+    /// n weeks back.
+    PrevWeek(usize),
+    /// n weeks forward.
+    NextWeek(usize),
+}
+
+/// Selected date shared between calendar widgets.
+///
+/// Give clones of the same handle to [MonthState] and [AgendaState]
+/// and both show the same selection.
+#[derive(Debug, Default, Clone)]
+pub struct SelectedDate(Rc<Cell<Option<NaiveDate>>>);
+
+impl SelectedDate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Selected date.
+    #[inline]
+    pub fn get(&self) -> Option<NaiveDate> {
+        self.0.get()
+    }
+
+    /// Set the selected date.
+    #[inline]
+    pub fn set(&self, date: Option<NaiveDate>) {
+        self.0.set(date);
+    }
+}
+
 /// State & event-handling.
 #[derive(Debug)]
 pub struct MonthState {
@@ -87,6 +144,10 @@ pub struct MonthState {
     /// Area for the week numbers.
     /// __readonly__. renewed for each render.
     pub area_weeks: [Rect; 6],
+    /// Area for the days of the previous/next month,
+    /// if they are shown.
+    /// __readonly__. renewed for each render.
+    pub area_adjacent: Vec<(NaiveDate, Rect)>,
     /// Startdate
     /// __readonly__. renewed for each render.
     pub start_date: NaiveDate,
@@ -97,11 +158,37 @@ pub struct MonthState {
     /// Week selection enabled
     /// __readonly__. renewed for each render.
     week_selection: bool,
+    /// Range selection enabled
+    /// __readonly__. renewed for each render.
+    range_selection: bool,
+    /// Days of the previous/next month can be clicked.
+    /// __readonly__. renewed for each render.
+    adjacent_selection: bool,
 
     /// Selected week
     pub selected_week: Option<usize>,
     /// Selected day
     pub selected_day: Option<usize>,
+    /// Selected range. Start and end are inclusive.
+    /// The range can extend beyond this month.
+    /// __read+write__
+    pub selected_range: Option<(NaiveDate, NaiveDate)>,
+    /// Tentative range while dragging. This is the
+    /// (anchor, current) date, not ordered.
+    /// __read+write__
+    pub drag_range: Option<(NaiveDate, NaiveDate)>,
+    /// Date under the mouse cursor.
+    /// __read only__
+    pub hover_date: Option<NaiveDate>,
+    /// Week where dragging across the week numbers started.
+    week_anchor: Option<usize>,
+    /// Selected date shared with other widgets.
+    /// A changed date is picked up with the next render,
+    /// selecting a day writes it back.
+    /// __read+write__
+    pub shared_date: SelectedDate,
+    /// Last value seen from shared_date.
+    shared_seen: Option<NaiveDate>,
 
     /// Focus
     /// __read+write__
@@ -123,6 +210,8 @@ impl Default for MonthStyle {
             day: None,
             select: None,
             focus: None,
+            preview: None,
+            adjacent: None,
             block: None,
             non_exhaustive: NonExhaustive,
         }
@@ -157,12 +246,43 @@ impl<'a> Month<'a> {
     }
 
     /// Week selection enabled
+    ///
+    /// Clicking a week number selects the week, dragging
+    /// across the week numbers selects a range of weeks.
     #[inline]
     pub fn week_selection(mut self) -> Self {
         self.week_selection = true;
         self
     }
 
+    /// Range selection with the mouse enabled.
+    ///
+    /// Mouse down on a day starts the range, dragging
+    /// shows a tentative range and releasing the mouse
+    /// selects it.
+    #[inline]
+    pub fn range_selection(mut self) -> Self {
+        self.range_selection = true;
+        self
+    }
+
+    /// Show the days of the previous/next month in the
+    /// first and last week.
+    #[inline]
+    pub fn show_adjacent_days(mut self, show: bool) -> Self {
+        self.show_adjacent_days = show;
+        self
+    }
+
+    /// Clicking a day of the previous/next month reports
+    /// [CalOutcome::Adjacent](crate::event::CalOutcome::Adjacent).
+    /// Needs [show_adjacent_days](Self::show_adjacent_days).
+    #[inline]
+    pub fn adjacent_selection(mut self) -> Self {
+        self.adjacent_selection = true;
+        self
+    }
+
     /// Show weekday titles
     #[inline]
     pub fn show_weekdays(mut self) -> Self {
@@ -170,6 +290,14 @@ impl<'a> Month<'a> {
         self
     }
 
+    /// Base style.
+    #[inline]
+    pub fn style(mut self, s: impl Into<Style>) -> Self {
+        self.style = s.into();
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
     /// Set the composite style.
     #[inline]
     pub fn styles(mut self, s: MonthStyle) -> Self {
@@ -192,6 +320,12 @@ impl<'a> Month<'a> {
         if s.focus.is_some() {
             self.focus_style = s.focus;
         }
+        if s.preview.is_some() {
+            self.preview_style = s.preview;
+        }
+        if s.adjacent.is_some() {
+            self.adjacent_style = s.adjacent;
+        }
         if s.block.is_some() {
             self.block = s.block;
         }
@@ -211,6 +345,24 @@ impl<'a> Month<'a> {
         self
     }
 
+    /// Style for the tentative range while dragging.
+    ///
+    /// __Default__
+    /// Underlined.
+    pub fn preview_style(mut self, style: Style) -> Self {
+        self.preview_style = Some(style);
+        self
+    }
+
+    /// Style for the days of the previous/next month.
+    ///
+    /// __Default__
+    /// The day style dimmed.
+    pub fn adjacent_style(mut self, style: Style) -> Self {
+        self.adjacent_style = Some(style);
+        self
+    }
+
     /// Sets the default day-style.
     #[inline]
     pub fn day_style(mut self, s: impl Into<Style>) -> Self {
@@ -299,6 +451,10 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
     state.start_date = widget.start_date;
     state.day_selection = widget.day_selection;
     state.week_selection = widget.week_selection;
+    state.range_selection = widget.range_selection;
+    state.adjacent_selection = widget.show_adjacent_days && widget.adjacent_selection;
+    state.area_adjacent.clear();
+    state.sync_shared();
 
     let mut day = widget.start_date;
 
@@ -316,7 +472,13 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
             revert_style(widget.style)
         }
     };
+    let preview_style = widget
+        .preview_style
+        .unwrap_or(Style::new().add_modifier(Modifier::UNDERLINED));
     let day_style = widget.day_style.unwrap_or(widget.style);
+    let adjacent_style = widget
+        .adjacent_style
+        .unwrap_or(day_style.add_modifier(Modifier::DIM));
     let week_style = widget.week_style.unwrap_or(widget.style);
     let weekday_style = widget.weekday_style.unwrap_or(widget.style);
 
@@ -407,6 +569,11 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
         Weekday::Sun,
     ] {
         if day.weekday() != wd {
+            if widget.show_adjacent_days {
+                let back = day.weekday().num_days_from_monday() - wd.num_days_from_monday();
+                let adjacent = day - chrono::Duration::try_days(back as i64).expect("days");
+                render_adjacent(widget, adjacent, x, y, adjacent_style, buf, state);
+            }
             x += 3;
         } else {
             let day_style = if let Some(day_styles) = widget.day_styles {
@@ -420,6 +587,10 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
             };
             let day_style = if week_sel || state.selected_day == Some(day.day0() as usize) {
                 day_style.patch(select_style)
+            } else if in_range(state.drag_range, day) {
+                day_style.patch(preview_style)
+            } else if in_range(state.selected_range, day) {
+                day_style.patch(select_style)
             } else {
                 day_style
             };
@@ -439,6 +610,11 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
     x = state.inner.x;
     y += 1;
 
+    // first day of the next month, once we get there.
+    let mut trailing = widget
+        .start_date
+        .checked_add_months(chrono::Months::new(1))
+        .expect("date");
     while month == day.month() {
         state.area_weeks[w] = Rect::new(x, y, 2, 1).intersection(state.inner);
         Span::from(day.format_localized("%W", widget.loc).to_string())
@@ -468,6 +644,10 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
                 };
                 let day_style = if week_sel || state.selected_day == Some(day.day0() as usize) {
                     day_style.patch(select_style)
+                } else if in_range(state.drag_range, day) {
+                    day_style.patch(preview_style)
+                } else if in_range(state.selected_range, day) {
+                    day_style.patch(select_style)
                 } else {
                     day_style
                 };
@@ -482,6 +662,10 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
                 x += 3;
                 day += chrono::Duration::try_days(1).expect("days");
             } else {
+                if widget.show_adjacent_days {
+                    render_adjacent(widget, trailing, x, y, adjacent_style, buf, state);
+                    trailing += chrono::Duration::try_days(1).expect("days");
+                }
                 x += 3;
             }
         }
@@ -492,6 +676,32 @@ fn render_ref(widget: &Month<'_>, area: Rect, buf: &mut Buffer, state: &mut Mont
     }
 }
 
+/// Day of the previous/next month.
+fn render_adjacent(
+    widget: &Month<'_>,
+    day: NaiveDate,
+    x: u16,
+    y: u16,
+    style: Style,
+    buf: &mut Buffer,
+    state: &mut MonthState,
+) {
+    let area = Rect::new(x, y, 2, 1).intersection(state.inner);
+    Span::from(day.format_localized("%e", widget.loc).to_string())
+        .style(style)
+        .render(area, buf);
+    state.area_adjacent.push((day, area));
+}
+
+/// Date is in the range. The range may not be ordered.
+fn in_range(range: Option<(NaiveDate, NaiveDate)>, day: NaiveDate) -> bool {
+    if let Some((a, b)) = range {
+        a.min(b) <= day && day <= a.max(b)
+    } else {
+        false
+    }
+}
+
 impl HasFocus for MonthState {
     #[inline]
     fn focus(&self) -> FocusFlag {
@@ -510,6 +720,9 @@ impl RelocatableState for MonthState {
         self.inner = relocate_area(self.inner, shift, clip);
         relocate_areas(&mut self.area_days, shift, clip);
         relocate_areas(&mut self.area_weeks, shift, clip);
+        for (_, area) in self.area_adjacent.iter_mut() {
+            *area = relocate_area(*area, shift, clip);
+        }
     }
 }
 
@@ -520,11 +733,20 @@ impl Clone for MonthState {
             inner: self.inner,
             area_days: self.area_days.clone(),
             area_weeks: self.area_weeks.clone(),
+            area_adjacent: self.area_adjacent.clone(),
             start_date: self.start_date,
             day_selection: self.day_selection,
             week_selection: self.week_selection,
+            range_selection: self.range_selection,
+            adjacent_selection: self.adjacent_selection,
             selected_week: self.selected_week,
             selected_day: self.selected_day,
+            selected_range: self.selected_range,
+            drag_range: None,
+            hover_date: None,
+            week_anchor: None,
+            shared_date: self.shared_date.clone(),
+            shared_seen: self.shared_seen,
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -539,11 +761,20 @@ impl Default for MonthState {
             inner: Default::default(),
             area_days: [Rect::default(); 31],
             area_weeks: [Rect::default(); 6],
+            area_adjacent: Default::default(),
             start_date: Default::default(),
             day_selection: false,
             week_selection: false,
+            range_selection: false,
+            adjacent_selection: false,
             selected_week: Default::default(),
             selected_day: Default::default(),
+            selected_range: Default::default(),
+            drag_range: Default::default(),
+            hover_date: Default::default(),
+            week_anchor: Default::default(),
+            shared_date: Default::default(),
+            shared_seen: Default::default(),
             focus: Default::default(),
             mouse: Default::default(),
             non_exhaustive: NonExhaustive,
@@ -567,6 +798,105 @@ impl MonthState {
     pub fn clear_selection(&mut self) {
         self.selected_week = None;
         self.selected_day = None;
+        self.selected_range = None;
+        self.drag_range = None;
+        self.week_anchor = None;
+    }
+
+    /// Select a range of dates. Start and end are inclusive
+    /// and can be given in any order.
+    pub fn select_range(&mut self, range: Option<(NaiveDate, NaiveDate)>) {
+        self.selected_week = None;
+        self.selected_day = None;
+        self.selected_range = range.map(|(a, b)| (a.min(b), a.max(b)));
+    }
+
+    /// Selected range.
+    pub fn selected_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.selected_range
+    }
+
+    /// Tentative range while dragging, ordered.
+    pub fn preview_range(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.drag_range.map(|(a, b)| (a.min(b), a.max(b)))
+    }
+
+    /// Date at the given screen position.
+    pub fn date_at(&self, x: u16, y: u16) -> Option<NaiveDate> {
+        let month = self.start_date.month();
+        self.area_days
+            .iter()
+            .enumerate()
+            .find(|(_, v)| v.contains((x, y).into()))
+            .map(|(i, _)| self.month_day(i))
+            .filter(|v| v.month() == month)
+    }
+
+    /// Day of the previous/next month at the given screen position.
+    /// Only if these can be selected.
+    pub fn adjacent_at(&self, x: u16, y: u16) -> Option<NaiveDate> {
+        if !self.adjacent_selection {
+            return None;
+        }
+        self.area_adjacent
+            .iter()
+            .find(|(_, area)| area.contains((x, y).into()))
+            .map(|(date, _)| *date)
+    }
+
+    /// Week number at the given screen position.
+    pub fn week_at(&self, x: u16, y: u16) -> Option<usize> {
+        self.area_weeks[..self.week_len()]
+            .iter()
+            .position(|v| v.contains((x, y).into()))
+    }
+
+    /// Update the date under the mouse cursor.
+    /// Returns true if it changed.
+    fn set_hover(&mut self, x: u16, y: u16) -> bool {
+        let hover = self.date_at(x, y);
+        let old = self.hover_date;
+        self.hover_date = hover;
+        old != hover
+    }
+
+    /// Selected date. This is the selected day or
+    /// Monday of the selected week.
+    pub fn selected_date(&self) -> Option<NaiveDate> {
+        if let Some(day) = self.selected_day {
+            Some(self.month_day(day))
+        } else {
+            self.selected_week.map(|v| self.week_day(v))
+        }
+    }
+
+    /// Move the selection.
+    ///
+    /// Day movements select a day, week movements select a week.
+    /// Returns [CalOutcome::Day] or [CalOutcome::Week] with the new
+    /// selection, or [CalOutcome::Continue] if the selection would
+    /// leave the month.
+    ///
+    /// This is what the key bindings use.
+    pub fn move_selection(&mut self, mv: SelectionMove) -> CalOutcome {
+        let r = match mv {
+            SelectionMove::PrevDay(n) => self.prev_day(n),
+            SelectionMove::NextDay(n) => self.next_day(n),
+            SelectionMove::PrevWeek(n) => self.prev_week(n),
+            SelectionMove::NextWeek(n) => self.next_week(n),
+        };
+        if !r {
+            return CalOutcome::Continue;
+        }
+        match mv {
+            SelectionMove::PrevDay(_) | SelectionMove::NextDay(_) => {
+                self.share_selected();
+                CalOutcome::Day(self.selected_day_as_date().expect("day"))
+            }
+            SelectionMove::PrevWeek(_) | SelectionMove::NextWeek(_) => {
+                CalOutcome::Week(self.selected_week_as_date().expect("week"))
+            }
+        }
     }
 
     /// Select a week.
@@ -600,12 +930,12 @@ impl MonthState {
     }
 
     /// Selected week
-    pub fn selected_week(&mut self) -> Option<usize> {
+    pub fn selected_week(&self) -> Option<usize> {
         self.selected_week
     }
 
     /// Selected week
-    pub fn selected_week_as_date(&mut self) -> Option<NaiveDate> {
+    pub fn selected_week_as_date(&self) -> Option<NaiveDate> {
         self.selected_week.map(|v| self.week_day(v))
     }
 
@@ -613,6 +943,7 @@ impl MonthState {
     pub fn select_day(&mut self, n: Option<usize>) {
         self.selected_day = n;
         self.selected_week = None;
+        self.share_selected();
     }
 
     /// Select by date.
@@ -623,6 +954,7 @@ impl MonthState {
         if let Some(d) = d {
             if d.year() == self.start_date.year() && d.month() == self.start_date.month() {
                 self.selected_day = Some(d.day0() as usize);
+                self.share_selected();
                 true
             } else {
                 self.selected_day = None;
@@ -634,13 +966,37 @@ impl MonthState {
         }
     }
 
+    // Write the selected day to the shared date.
+    fn share_selected(&mut self) {
+        if let Some(date) = self.selected_day_as_date() {
+            self.shared_date.set(Some(date));
+            self.shared_seen = Some(date);
+        }
+    }
+
+    // Pick up changes of the shared date, or publish
+    // a selection that was set otherwise.
+    fn sync_shared(&mut self) {
+        let shared = self.shared_date.get();
+        if shared != self.shared_seen {
+            if self.day_selection {
+                self.select_date(shared);
+            }
+        } else if let Some(date) = self.selected_day_as_date() {
+            if Some(date) != shared {
+                self.shared_date.set(Some(date));
+            }
+        }
+        self.shared_seen = self.shared_date.get();
+    }
+
     /// Selected day
-    pub fn selected_day(&mut self) -> Option<usize> {
+    pub fn selected_day(&self) -> Option<usize> {
         self.selected_day
     }
 
     /// Selected day
-    pub fn selected_day_as_date(&mut self) -> Option<NaiveDate> {
+    pub fn selected_day_as_date(&self) -> Option<NaiveDate> {
         self.selected_day.map(|v| self.month_day(v))
     }
 
@@ -847,6 +1203,13 @@ pub(crate) mod event {
         Day(NaiveDate),
         /// Month in a list of months selected.
         Month(usize),
+        /// Range selected with the mouse.
+        /// Start and end are inclusive and ordered.
+        Range(NaiveDate, NaiveDate),
+        /// A day of the previous/next month has been clicked.
+        /// The month doesn't change by itself, that's up to
+        /// the application.
+        Adjacent(NaiveDate),
     }
 
     impl ConsumedEvent for CalOutcome {
@@ -885,6 +1248,8 @@ pub(crate) mod event {
                 CalOutcome::Week(_) => Outcome::Changed,
                 CalOutcome::Day(_) => Outcome::Changed,
                 CalOutcome::Month(_) => Outcome::Changed,
+                CalOutcome::Range(_, _) => Outcome::Changed,
+                CalOutcome::Adjacent(_) => Outcome::Changed,
             }
         }
     }
@@ -894,77 +1259,86 @@ impl HandleEvent<crossterm::event::Event, Regular, CalOutcome> for MonthState {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> CalOutcome {
         if self.is_focused() {
             flow!(match event {
-                ct_event!(keycode press Up) => {
-                    if !self.day_selection {
-                        return CalOutcome::Continue;
-                    }
-                    if self.prev_day(7) {
-                        CalOutcome::Day(self.selected_day_as_date().expect("day"))
-                    } else {
-                        CalOutcome::Continue
-                    }
+                ct_event!(keycode press Up) if self.day_selection => {
+                    self.move_selection(SelectionMove::PrevDay(7))
                 }
-                ct_event!(keycode press Down) => {
-                    if !self.day_selection {
-                        return CalOutcome::Continue;
-                    }
-                    if self.next_day(7) {
-                        CalOutcome::Day(self.selected_day_as_date().expect("day"))
-                    } else {
-                        CalOutcome::Continue
-                    }
+                ct_event!(keycode press Down) if self.day_selection => {
+                    self.move_selection(SelectionMove::NextDay(7))
                 }
-                ct_event!(keycode press Left) => {
-                    if !self.day_selection {
-                        return CalOutcome::Continue;
-                    }
-                    if self.prev_day(1) {
-                        CalOutcome::Day(self.selected_day_as_date().expect("day"))
-                    } else {
-                        CalOutcome::Continue
-                    }
+                ct_event!(keycode press Left) if self.day_selection => {
+                    self.move_selection(SelectionMove::PrevDay(1))
                 }
-                ct_event!(keycode press Right) => {
-                    if !self.day_selection {
-                        return CalOutcome::Continue;
-                    }
-                    if self.next_day(1) {
-                        CalOutcome::Day(self.selected_day_as_date().expect("day"))
+                ct_event!(keycode press Right) if self.day_selection => {
+                    self.move_selection(SelectionMove::NextDay(1))
+                }
+                ct_event!(keycode press ALT-Up) if self.week_selection => {
+                    self.move_selection(SelectionMove::PrevWeek(1))
+                }
+                ct_event!(keycode press ALT-Down) if self.week_selection => {
+                    self.move_selection(SelectionMove::NextWeek(1))
+                }
+                _ => CalOutcome::Continue,
+            })
+        }
+
+        self.handle(event, MouseOnly)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, CalOutcome> for MonthState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> CalOutcome {
+        if self.week_selection {
+            flow!(self.handle_week_drag(event));
+        }
+
+        if self.range_selection {
+            flow!(match event {
+                ct_event!(mouse down Left for x, y) => {
+                    if let Some(date) = self.date_at(*x, *y) {
+                        self.selected_range = None;
+                        self.drag_range = Some((date, date));
+                        CalOutcome::Changed
                     } else {
                         CalOutcome::Continue
                     }
                 }
-                ct_event!(keycode press ALT-Up) => {
-                    if !self.week_selection {
-                        return CalOutcome::Continue;
-                    }
-                    if self.prev_week(1) {
-                        CalOutcome::Week(self.selected_week_as_date().expect("week"))
+                ct_event!(mouse drag Left for x, y) => {
+                    if let Some((anchor, current)) = self.drag_range {
+                        match self.date_at(*x, *y) {
+                            Some(date) if date != current => {
+                                self.drag_range = Some((anchor, date));
+                                CalOutcome::Changed
+                            }
+                            _ => CalOutcome::Unchanged,
+                        }
                     } else {
                         CalOutcome::Continue
                     }
                 }
-                ct_event!(keycode press ALT-Down) => {
-                    if !self.week_selection {
-                        return CalOutcome::Continue;
-                    }
-                    if self.next_week(1) {
-                        CalOutcome::Week(self.selected_week_as_date().expect("week"))
+                ct_event!(mouse up Left for _x, _y) => {
+                    if let Some(range) = self.preview_range() {
+                        self.drag_range = None;
+                        self.select_range(Some(range));
+                        CalOutcome::Range(range.0, range.1)
                     } else {
                         CalOutcome::Continue
                     }
                 }
                 _ => CalOutcome::Continue,
-            })
+            });
         }
 
-        self.handle(event, MouseOnly)
-    }
-}
-
-impl HandleEvent<crossterm::event::Event, MouseOnly, CalOutcome> for MonthState {
-    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> CalOutcome {
         match event {
+            ct_event!(mouse moved for x, y) => {
+                if self.set_hover(*x, *y) {
+                    CalOutcome::Changed
+                } else {
+                    CalOutcome::Continue
+                }
+            }
+            ct_event!(mouse down Left for x, y) if self.adjacent_at(*x, *y).is_some() => {
+                CalOutcome::Adjacent(self.adjacent_at(*x, *y).expect("date"))
+            }
             ct_event!(mouse drag Left for x, y) | ct_event!(mouse down Left for x, y) => {
                 if let Some(sel) = self.mouse.item_at(&self.area_weeks, *x, *y) {
                     if !self.week_selection {
@@ -988,8 +1362,122 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, CalOutcome> for MonthState
     }
 }
 
+impl MonthState {
+    /// Click and drag on the week numbers.
+    fn handle_week_drag(&mut self, event: &crossterm::event::Event) -> CalOutcome {
+        match event {
+            ct_event!(mouse down Left for x, y) => {
+                if let Some(week) = self.week_at(*x, *y) {
+                    self.selected_range = None;
+                    self.drag_range = None;
+                    self.week_anchor = Some(week);
+                    self.select_week(Some(week));
+                    CalOutcome::Week(self.week_day(week))
+                } else {
+                    CalOutcome::Continue
+                }
+            }
+            ct_event!(mouse drag Left for x, y) => {
+                let Some(anchor) = self.week_anchor else {
+                    return CalOutcome::Continue;
+                };
+                let Some(week) = self.week_at(*x, *y) else {
+                    return CalOutcome::Unchanged;
+                };
+                let old = (self.selected_week, self.drag_range);
+                if week == anchor {
+                    self.drag_range = None;
+                    self.select_week(Some(week));
+                } else {
+                    let first = self.week_day(anchor.min(week));
+                    let last = self.week_day(anchor.max(week))
+                        + chrono::Duration::try_days(6).expect("days");
+                    self.selected_week = None;
+                    self.drag_range = Some((first, last));
+                }
+                if old != (self.selected_week, self.drag_range) {
+                    CalOutcome::Changed
+                } else {
+                    CalOutcome::Unchanged
+                }
+            }
+            ct_event!(mouse up Left for _x, _y) => {
+                if self.week_anchor.take().is_none() {
+                    return CalOutcome::Continue;
+                }
+                if let Some(range) = self.preview_range() {
+                    self.drag_range = None;
+                    self.select_range(Some(range));
+                    CalOutcome::Range(range.0, range.1)
+                } else {
+                    CalOutcome::Unchanged
+                }
+            }
+            _ => CalOutcome::Continue,
+        }
+    }
+}
+
+/// Hover and range selection across all months of the slice.
+fn handle_slice_mouse(months: &mut [MonthState], event: &crossterm::event::Event) -> CalOutcome {
+    match event {
+        ct_event!(mouse moved for x, y) => {
+            let mut changed = false;
+            for month in months.iter_mut() {
+                changed |= month.set_hover(*x, *y);
+            }
+            if changed {
+                CalOutcome::Changed
+            } else {
+                CalOutcome::Continue
+            }
+        }
+        ct_event!(mouse down Left for x, y) => {
+            let Some(date) = months
+                .iter()
+                .filter(|v| v.range_selection)
+                .find_map(|v| v.date_at(*x, *y))
+            else {
+                return CalOutcome::Continue;
+            };
+            for month in months.iter_mut() {
+                month.clear_selection();
+                month.drag_range = Some((date, date));
+            }
+            CalOutcome::Changed
+        }
+        ct_event!(mouse drag Left for x, y) => {
+            let Some((anchor, current)) = months.iter().find_map(|v| v.drag_range) else {
+                return CalOutcome::Continue;
+            };
+            match months.iter().find_map(|v| v.date_at(*x, *y)) {
+                Some(date) if date != current => {
+                    for month in months.iter_mut() {
+                        month.drag_range = Some((anchor, date));
+                    }
+                    CalOutcome::Changed
+                }
+                _ => CalOutcome::Unchanged,
+            }
+        }
+        ct_event!(mouse up Left for _x, _y) => {
+            let Some(range) = months.iter().find_map(|v| v.preview_range()) else {
+                return CalOutcome::Continue;
+            };
+            for month in months.iter_mut() {
+                month.drag_range = None;
+                month.select_range(Some(range));
+            }
+            CalOutcome::Range(range.0, range.1)
+        }
+        _ => CalOutcome::Continue,
+    }
+}
+
 impl HandleEvent<crossterm::event::Event, Regular, CalOutcome> for &mut [MonthState] {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> CalOutcome {
+        flow!(handle_slice_mouse(self, event));
+
         for i in 0..self.len() {
             let month = &mut self[i];
             if month.is_focused() {
@@ -1158,3 +1646,20 @@ impl HandleEvent<crossterm::event::Event, Regular, CalOutcome> for &mut [MonthSt
         CalOutcome::Continue
     }
 }
+
+/// Handle all events.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut MonthState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> CalOutcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(state: &mut MonthState, event: &crossterm::event::Event) -> CalOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
@@ -0,0 +1,690 @@
+//!
+//! Agenda view. One row per day, starting from a top date.
+//!
+//! There is no end in either direction, the dates are
+//! generated on demand. Restrict the rows to the days
+//! with entries with [Agenda::entries].
+//!
+//! The selection is a [SelectedDate], give the same handle
+//! to the [MonthState](crate::calendar::MonthState) to keep
+//! the selection when switching views.
+//!
+//! ```rust ignore
+//! Agenda::new()
+//!     .day(|date| Line::from(appointments.summary(date)))
+//!     .render(area, buf, &mut state.agenda);
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::calendar::event::CalOutcome;
+use crate::calendar::SelectedDate;
+use crate::util::{fallback_select_style, revert_style};
+use chrono::{Local, NaiveDate};
+use rat_event::util::{item_at, MouseFlags};
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
+use rat_focus::{FocusFlag, HasFocus};
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::prelude::BlockExt;
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Search limit for the next day with entries.
+const MAX_SKIP: usize = 3660;
+
+/// Filter for the days shown.
+type EntriesFn = Rc<dyn Fn(NaiveDate) -> bool>;
+
+/// Renders a list of days.
+#[derive(Default)]
+pub struct Agenda<'a> {
+    style: Style,
+    date_style: Option<Style>,
+    today_style: Option<Style>,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+    block: Option<Block<'a>>,
+
+    /// Date format.
+    format: Option<&'a str>,
+    /// Locale
+    loc: chrono::Locale,
+
+    /// Text for a day.
+    day: Option<Box<dyn Fn(NaiveDate) -> Line<'a> + 'a>>,
+    /// Only days with entries.
+    entries: Option<EntriesFn>,
+}
+
+/// Composite style for the agenda.
+#[derive(Debug, Clone)]
+pub struct AgendaStyle {
+    pub style: Style,
+    /// Date column.
+    pub date: Option<Style>,
+    /// Today.
+    pub today: Option<Style>,
+    pub select: Option<Style>,
+    pub focus: Option<Style>,
+    pub block: Option<Block<'static>>,
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State & event-handling.
+pub struct AgendaState {
+    /// Total area.
+    /// __read only__. renewed for each render.
+    pub area: Rect,
+    /// Area inside the block.
+    /// __read only__. renewed for each render.
+    pub inner: Rect,
+    /// Area of the rows.
+    /// __read only__. renewed for each render.
+    pub row_areas: Vec<Rect>,
+    /// Dates of the rows.
+    /// __read only__. renewed for each render.
+    pub dates: Vec<NaiveDate>,
+
+    /// First date shown.
+    /// __read+write__
+    pub top: NaiveDate,
+    /// Today. Initialized with the local date.
+    /// __read+write__
+    pub today: NaiveDate,
+    /// Selected date. Can be shared with a MonthState.
+    /// __read+write__
+    pub selection: SelectedDate,
+    /// Last value seen from the selection.
+    selection_seen: Option<NaiveDate>,
+    /// Only days with entries.
+    /// __read only__. renewed for each render.
+    entries: Option<EntriesFn>,
+
+    /// Focus
+    /// __read+write__
+    pub focus: FocusFlag,
+    /// Mouse flags
+    /// __read+write__
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Debug for Agenda<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Agenda")
+            .field("style", &self.style)
+            .field("date_style", &self.date_style)
+            .field("today_style", &self.today_style)
+            .field("select_style", &self.select_style)
+            .field("focus_style", &self.focus_style)
+            .field("block", &self.block)
+            .field("format", &self.format)
+            .field("loc", &self.loc)
+            .field("day", &self.day.is_some())
+            .field("entries", &self.entries.is_some())
+            .finish()
+    }
+}
+
+impl Default for AgendaStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            date: None,
+            today: None,
+            select: None,
+            focus: None,
+            block: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a> Agenda<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Date format for the date column.
+    /// Default is `%a %x`.
+    #[inline]
+    pub fn format(mut self, format: &'a str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Locale for the date column.
+    #[inline]
+    pub fn locale(mut self, loc: chrono::Locale) -> Self {
+        self.loc = loc;
+        self
+    }
+
+    /// Text shown after the date.
+    #[inline]
+    pub fn day(mut self, day: impl Fn(NaiveDate) -> Line<'a> + 'a) -> Self {
+        self.day = Some(Box::new(day));
+        self
+    }
+
+    /// Show only the days with entries.
+    ///
+    /// Days without an entry are skipped when navigating.
+    /// This searches up to 10 years in each direction.
+    #[inline]
+    pub fn entries(mut self, entries: impl Fn(NaiveDate) -> bool + 'static) -> Self {
+        self.entries = Some(Rc::new(entries));
+        self
+    }
+
+    /// Set the composite style.
+    #[inline]
+    pub fn styles(mut self, styles: AgendaStyle) -> Self {
+        self.style = styles.style;
+        if styles.date.is_some() {
+            self.date_style = styles.date;
+        }
+        if styles.today.is_some() {
+            self.today_style = styles.today;
+        }
+        if styles.select.is_some() {
+            self.select_style = styles.select;
+        }
+        if styles.focus.is_some() {
+            self.focus_style = styles.focus;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self.block = self.block.map(|v| v.style(style));
+        self
+    }
+
+    /// Style for the date column.
+    #[inline]
+    pub fn date_style(mut self, style: Style) -> Self {
+        self.date_style = Some(style);
+        self
+    }
+
+    /// Style for today.
+    #[inline]
+    pub fn today_style(mut self, style: Style) -> Self {
+        self.today_style = Some(style);
+        self
+    }
+
+    /// Selection style.
+    #[inline]
+    pub fn select_style(mut self, style: Style) -> Self {
+        self.select_style = Some(style);
+        self
+    }
+
+    /// Selection style when focused.
+    #[inline]
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Block.
+    #[inline]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block.style(self.style));
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl<'a> StatefulWidgetRef for Agenda<'a> {
+    type State = AgendaState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for Agenda<'_> {
+    type State = AgendaState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self, area, buf, state);
+    }
+}
+
+fn render_ref(widget: &Agenda<'_>, area: Rect, buf: &mut Buffer, state: &mut AgendaState) {
+    state.area = area;
+    state.inner = widget.block.inner_if_some(area);
+    state.entries = widget.entries.clone();
+
+    // selection changed elsewhere.
+    let selected = state.selection.get();
+    if selected != state.selection_seen {
+        state.selection_seen = selected;
+        state.scroll_to_selected();
+    }
+    if !state.has_entries(state.top) {
+        if let Some(top) = state.next_date(state.top) {
+            state.top = top;
+        }
+    }
+
+    state.dates.clear();
+    state.row_areas.clear();
+    let mut date = Some(state.top);
+    for _ in 0..state.inner.height {
+        let Some(d) = date else {
+            break;
+        };
+        state.dates.push(d);
+        date = state.next_date(d);
+    }
+
+    let format = widget.format.unwrap_or("%a %x");
+    let labels = state
+        .dates
+        .iter()
+        .map(|v| v.format_localized(format, widget.loc).to_string())
+        .collect::<Vec<_>>();
+    let date_width = labels
+        .iter()
+        .map(|v| Span::from(v.as_str()).width())
+        .max()
+        .unwrap_or_default() as u16;
+
+    let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
+    let select_style = if state.focus.get() {
+        focus_style
+    } else if let Some(select_style) = widget.select_style {
+        fallback_select_style(select_style)
+    } else {
+        revert_style(widget.style)
+    };
+    let date_style = widget.date_style.unwrap_or(widget.style);
+
+    if let Some(block) = &widget.block {
+        block.render(area, buf);
+    } else {
+        buf.set_style(area, widget.style);
+    }
+
+    for (i, (date, label)) in state.dates.iter().zip(labels.iter()).enumerate() {
+        let row_area = Rect::new(
+            state.inner.x,
+            state.inner.y + i as u16,
+            state.inner.width,
+            1,
+        );
+        state.row_areas.push(row_area);
+
+        let mut row_style = widget.style;
+        if *date == state.today {
+            if let Some(today_style) = widget.today_style {
+                row_style = row_style.patch(today_style);
+            }
+        }
+        if Some(*date) == selected {
+            row_style = row_style.patch(select_style);
+        }
+        buf.set_style(row_area, row_style);
+
+        let mut date_area = row_area;
+        date_area.width = date_width.min(row_area.width);
+        Span::from(label.as_str())
+            .style(date_style)
+            .render(date_area, buf);
+
+        if let Some(day) = &widget.day {
+            let text_area = Rect::new(
+                date_area.right() + 1,
+                row_area.y,
+                row_area.width.saturating_sub(date_width + 1),
+                1,
+            );
+            day(*date).render(text_area, buf);
+        }
+        // selection/today wins over the date column style.
+        if row_style != widget.style {
+            buf.set_style(row_area, row_style);
+        }
+    }
+}
+
+impl Clone for AgendaState {
+    fn clone(&self) -> Self {
+        Self {
+            area: self.area,
+            inner: self.inner,
+            row_areas: self.row_areas.clone(),
+            dates: self.dates.clone(),
+            top: self.top,
+            today: self.today,
+            selection: self.selection.clone(),
+            selection_seen: self.selection_seen,
+            entries: self.entries.clone(),
+            focus: FocusFlag::named(self.focus.name()),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Debug for AgendaState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AgendaState")
+            .field("area", &self.area)
+            .field("inner", &self.inner)
+            .field("row_areas", &self.row_areas)
+            .field("dates", &self.dates)
+            .field("top", &self.top)
+            .field("today", &self.today)
+            .field("selection", &self.selection)
+            .field("entries", &self.entries.is_some())
+            .field("focus", &self.focus)
+            .field("mouse", &self.mouse)
+            .finish()
+    }
+}
+
+impl Default for AgendaState {
+    fn default() -> Self {
+        let today = Local::now().date_naive();
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            row_areas: Default::default(),
+            dates: Default::default(),
+            top: today,
+            today,
+            selection: Default::default(),
+            selection_seen: Default::default(),
+            entries: Default::default(),
+            focus: Default::default(),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl HasFocus for AgendaState {
+    #[inline]
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    #[inline]
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+impl RelocatableState for AgendaState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        relocate_areas(&mut self.row_areas, shift, clip);
+    }
+}
+
+impl AgendaState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn named(name: &str) -> Self {
+        Self {
+            focus: FocusFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Number of rows shown.
+    #[inline]
+    pub fn page_len(&self) -> usize {
+        self.inner.height as usize
+    }
+
+    /// Selected date.
+    #[inline]
+    pub fn selected(&self) -> Option<NaiveDate> {
+        self.selection.get()
+    }
+
+    /// Select a date and scroll it into view.
+    /// Returns true if the selection changed.
+    pub fn select(&mut self, date: Option<NaiveDate>) -> bool {
+        let old = self.selection.get();
+        self.selection.set(date);
+        self.selection_seen = date;
+        self.scroll_to_selected();
+        old != date
+    }
+
+    /// Date at the given screen position.
+    pub fn date_at(&self, x: u16, y: u16) -> Option<NaiveDate> {
+        item_at(&self.row_areas, x, y).and_then(|v| self.dates.get(v).copied())
+    }
+
+    /// The date is shown. Always true without an entries filter.
+    pub fn has_entries(&self, date: NaiveDate) -> bool {
+        match &self.entries {
+            None => true,
+            Some(entries) => entries(date),
+        }
+    }
+
+    /// Next date shown after the given date.
+    pub fn next_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut date = date;
+        for _ in 0..MAX_SKIP {
+            date = date.succ_opt()?;
+            if self.has_entries(date) {
+                return Some(date);
+            }
+        }
+        None
+    }
+
+    /// Previous date shown before the given date.
+    pub fn prev_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let mut date = date;
+        for _ in 0..MAX_SKIP {
+            date = date.pred_opt()?;
+            if self.has_entries(date) {
+                return Some(date);
+            }
+        }
+        None
+    }
+
+    /// Scroll so the selected date is visible.
+    pub fn scroll_to_selected(&mut self) {
+        let Some(selected) = self.selection.get() else {
+            return;
+        };
+        if selected < self.top {
+            self.top = selected;
+            return;
+        }
+
+        let mut date = self.top;
+        for _ in 1..self.page_len() {
+            if date >= selected {
+                return;
+            }
+            match self.next_date(date) {
+                Some(d) => date = d,
+                None => return,
+            }
+        }
+        if date >= selected {
+            return;
+        }
+
+        // selected is the last row.
+        let mut top = selected;
+        for _ in 1..self.page_len() {
+            match self.prev_date(top) {
+                Some(d) => top = d,
+                None => break,
+            }
+        }
+        self.top = top;
+    }
+
+    /// Scroll down by n rows.
+    pub fn scroll_down(&mut self, n: usize) -> bool {
+        let old = self.top;
+        for _ in 0..n {
+            match self.next_date(self.top) {
+                Some(d) => self.top = d,
+                None => break,
+            }
+        }
+        old != self.top
+    }
+
+    /// Scroll up by n rows.
+    pub fn scroll_up(&mut self, n: usize) -> bool {
+        let old = self.top;
+        for _ in 0..n {
+            match self.prev_date(self.top) {
+                Some(d) => self.top = d,
+                None => break,
+            }
+        }
+        old != self.top
+    }
+
+    /// Move the selection n rows down.
+    /// Selects the first row if nothing is selected.
+    pub fn move_down(&mut self, n: usize) -> CalOutcome {
+        let mut date = match self.selection.get() {
+            Some(date) => date,
+            None => return self.select_outcome(self.top),
+        };
+        for _ in 0..n {
+            match self.next_date(date) {
+                Some(d) => date = d,
+                None => break,
+            }
+        }
+        self.select_outcome(date)
+    }
+
+    /// Move the selection n rows up.
+    /// Selects the first row if nothing is selected.
+    pub fn move_up(&mut self, n: usize) -> CalOutcome {
+        let mut date = match self.selection.get() {
+            Some(date) => date,
+            None => return self.select_outcome(self.top),
+        };
+        for _ in 0..n {
+            match self.prev_date(date) {
+                Some(d) => date = d,
+                None => break,
+            }
+        }
+        self.select_outcome(date)
+    }
+
+    /// Select today, or the next day with entries.
+    pub fn move_to_today(&mut self) -> CalOutcome {
+        let date = if self.has_entries(self.today) {
+            self.today
+        } else if let Some(date) = self.next_date(self.today) {
+            date
+        } else {
+            return CalOutcome::Unchanged;
+        };
+        self.top = date;
+        self.select_outcome(date)
+    }
+
+    fn select_outcome(&mut self, date: NaiveDate) -> CalOutcome {
+        if self.select(Some(date)) {
+            CalOutcome::Day(date)
+        } else {
+            CalOutcome::Unchanged
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, CalOutcome> for AgendaState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> CalOutcome {
+        if self.is_focused() {
+            flow!(match event {
+                ct_event!(keycode press Up) => self.move_up(1),
+                ct_event!(keycode press Down) => self.move_down(1),
+                ct_event!(keycode press PageUp) => {
+                    self.move_up(self.page_len().saturating_sub(1).max(1))
+                }
+                ct_event!(keycode press PageDown) => {
+                    self.move_down(self.page_len().saturating_sub(1).max(1))
+                }
+                ct_event!(keycode press Home) => self.move_to_today(),
+                _ => CalOutcome::Continue,
+            });
+        }
+
+        self.handle(event, MouseOnly)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, CalOutcome> for AgendaState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> CalOutcome {
+        match event {
+            ct_event!(mouse down Left for x, y) => {
+                if let Some(date) = self.date_at(*x, *y) {
+                    if self.select(Some(date)) {
+                        CalOutcome::Day(date)
+                    } else {
+                        CalOutcome::Unchanged
+                    }
+                } else {
+                    CalOutcome::Continue
+                }
+            }
+            ct_event!(scroll down for x, y) if self.inner.contains((*x, *y).into()) => {
+                self.scroll_down(self.page_len() / 10 + 1).into()
+            }
+            ct_event!(scroll up for x, y) if self.inner.contains((*x, *y).into()) => {
+                self.scroll_up(self.page_len() / 10 + 1).into()
+            }
+            _ => CalOutcome::Continue,
+        }
+    }
+}
+
+/// Handle all events.
+/// Key events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut AgendaState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> CalOutcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(state: &mut AgendaState, event: &crossterm::event::Event) -> CalOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
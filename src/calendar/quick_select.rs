@@ -0,0 +1,579 @@
+//!
+//! Month/year quick-select header for a [Month](crate::calendar::Month).
+//!
+//! Renders a header line "‹ March 2024 ›" and below it either
+//! nothing (Day view, render the Month there), a grid of the 12
+//! months (Month view) or a grid of 12 years (Year view).
+//!
+//! * Clicking the month name opens the month grid, clicking
+//!   the year the year grid. ‹/› and PageUp/PageDown page by
+//!   month, year or 12 years.
+//! * Ctrl+Up goes up one level, Esc goes back one level
+//!   (Year -> Month -> Day).
+//! * The arrow keys, Home/End and Enter work in the grids.
+//!   Picking a year continues with the month grid, picking
+//!   a month goes back to the days.
+//!
+//! The result is [QuickSelectState::date], the first of the
+//! chosen month. Give it to the Month for rendering.
+//!
+//! ```rust ignore
+//! QuickSelect::new().render(area, buf, &mut state.quick);
+//! if state.quick.view() == PickerView::Day {
+//!     Month::new()
+//!         .date(state.quick.date())
+//!         .day_selection()
+//!         .render(state.quick.inner, buf, &mut state.month);
+//! }
+//!
+//! // events
+//! let r = quick_select::handle_events(&mut state.quick, focus, event);
+//! let r = r.or_else(|| {
+//!     if state.quick.view() == PickerView::Day {
+//!         calendar::handle_events(&mut state.month, focus, event)
+//!     } else {
+//!         CalOutcome::Continue
+//!     }
+//! });
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::calendar::event::CalOutcome;
+use crate::calendar::MonthStyle;
+use crate::util::revert_style;
+use chrono::{Datelike, Local, Months, NaiveDate};
+use rat_event::util::MouseFlags;
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Regular};
+use rat_focus::{FocusFlag, HasFocus};
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Span;
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{StatefulWidget, Widget};
+
+/// Columns of the month/year grid.
+const COLUMNS: usize = 3;
+
+/// What the picker shows below the header.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PickerView {
+    /// The days. Render the Month below the header.
+    #[default]
+    Day,
+    /// Grid of the 12 months.
+    Month,
+    /// Grid of 12 years.
+    Year,
+}
+
+/// Renders the header and the month/year grid.
+#[derive(Debug, Default, Clone)]
+pub struct QuickSelect {
+    style: Style,
+    title_style: Option<Style>,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+
+    /// Locale
+    loc: chrono::Locale,
+}
+
+/// State & event-handling.
+#[derive(Debug, Clone)]
+pub struct QuickSelectState {
+    /// Total area.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// Area below the header. Render the Month here in Day view.
+    /// __readonly__. renewed for each render.
+    pub inner: Rect,
+    /// Previous page.
+    /// __readonly__. renewed for each render.
+    pub area_prev: Rect,
+    /// Next page.
+    /// __readonly__. renewed for each render.
+    pub area_next: Rect,
+    /// Month name in the header.
+    /// __readonly__. renewed for each render.
+    pub area_month: Rect,
+    /// Year in the header.
+    /// __readonly__. renewed for each render.
+    pub area_year: Rect,
+    /// Grid items in Month/Year view.
+    /// __readonly__. renewed for each render.
+    pub area_items: [Rect; 12],
+
+    /// Current view.
+    /// __read+write__
+    pub view: PickerView,
+    /// First of the chosen month.
+    /// __read+write__ use set_date()
+    pub date: NaiveDate,
+    /// First year of the year grid.
+    /// __read+write__
+    pub year_start: i32,
+    /// Cursor in the grid.
+    /// __read+write__
+    pub cursor: usize,
+
+    /// Current focus state.
+    /// __read+write__
+    pub focus: FocusFlag,
+    /// Mouse util.
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl QuickSelect {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Locale for the month names.
+    #[inline]
+    pub fn locale(mut self, loc: chrono::Locale) -> Self {
+        self.loc = loc;
+        self
+    }
+
+    /// Same styles as the Month.
+    #[inline]
+    pub fn styles(mut self, s: MonthStyle) -> Self {
+        self.style = s.style;
+        if s.title.is_some() {
+            self.title_style = s.title;
+        }
+        if s.select.is_some() {
+            self.select_style = s.select;
+        }
+        if s.focus.is_some() {
+            self.focus_style = s.focus;
+        }
+        self
+    }
+
+    /// Base style.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Header style.
+    #[inline]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = Some(style);
+        self
+    }
+
+    /// Grid cursor and the active header part.
+    #[inline]
+    pub fn select_style(mut self, style: Style) -> Self {
+        self.select_style = Some(style);
+        self
+    }
+
+    /// Grid cursor with focus.
+    #[inline]
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Height of header and grid.
+    pub fn height(&self) -> u16 {
+        1 + (12 / COLUMNS) as u16
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl StatefulWidgetRef for QuickSelect {
+    type State = QuickSelectState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for QuickSelect {
+    type State = QuickSelectState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self, area, buf, state);
+    }
+}
+
+fn render_ref(widget: &QuickSelect, area: Rect, buf: &mut Buffer, state: &mut QuickSelectState) {
+    state.area = area;
+    state.inner = Rect::new(
+        area.x,
+        area.y.saturating_add(1),
+        area.width,
+        area.height.saturating_sub(1),
+    )
+    .intersection(area);
+    state.area_items = Default::default();
+
+    let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
+    let select_style = if state.is_focused() {
+        focus_style
+    } else {
+        widget.select_style.unwrap_or(revert_style(widget.style))
+    };
+    let title_style = widget.title_style.unwrap_or(widget.style);
+
+    buf.set_style(area, widget.style);
+    if area.is_empty() {
+        return;
+    }
+
+    // header
+    let header = Rect::new(area.x, area.y, area.width, 1);
+    buf.set_style(header, title_style);
+
+    state.area_prev = Rect::new(header.x, header.y, 1, 1).intersection(header);
+    state.area_next = Rect::new(header.right().saturating_sub(1), header.y, 1, 1);
+    Span::from("‹").render(state.area_prev, buf);
+    Span::from("›").render(state.area_next, buf);
+
+    let month = state.date.format_localized("%B", widget.loc).to_string();
+    let year = state.date.year().to_string();
+    let month_width = Span::from(month.as_str()).width() as u16;
+    let year_width = Span::from(year.as_str()).width() as u16;
+    let title_width = month_width + 1 + year_width;
+    let x = header.x + header.width.saturating_sub(title_width) / 2;
+    state.area_month = Rect::new(x, header.y, month_width, 1).intersection(header);
+    state.area_year = Rect::new(x + month_width + 1, header.y, year_width, 1).intersection(header);
+
+    let (month_style, year_style) = match state.view {
+        PickerView::Day => (title_style, title_style),
+        PickerView::Month => (title_style.patch(select_style), title_style),
+        PickerView::Year => (title_style, title_style.patch(select_style)),
+    };
+    Span::from(month)
+        .style(month_style)
+        .render(state.area_month, buf);
+    Span::from(year)
+        .style(year_style)
+        .render(state.area_year, buf);
+
+    // grid
+    let labels: Vec<String> = match state.view {
+        PickerView::Day => return,
+        PickerView::Month => (1..=12)
+            .map(|m| {
+                NaiveDate::from_ymd_opt(2024, m, 1)
+                    .expect("date")
+                    .format_localized("%b", widget.loc)
+                    .to_string()
+            })
+            .collect(),
+        PickerView::Year => (0..12)
+            .map(|n| (state.year_start + n).to_string())
+            .collect(),
+    };
+
+    let item_width = state.inner.width / COLUMNS as u16;
+    for (n, label) in labels.into_iter().enumerate() {
+        let item_area = Rect::new(
+            state.inner.x + (n % COLUMNS) as u16 * item_width,
+            state.inner.y + (n / COLUMNS) as u16,
+            item_width,
+            1,
+        )
+        .intersection(state.inner);
+        state.area_items[n] = item_area;
+
+        let style = if state.cursor == n {
+            widget.style.patch(select_style)
+        } else {
+            widget.style
+        };
+        buf.set_style(item_area, style);
+        Span::from(format!(" {}", label)).render(item_area, buf);
+    }
+}
+
+impl Default for QuickSelectState {
+    fn default() -> Self {
+        let today = Local::now().date_naive();
+        let date = today.with_day(1).expect("date");
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            area_prev: Default::default(),
+            area_next: Default::default(),
+            area_month: Default::default(),
+            area_year: Default::default(),
+            area_items: Default::default(),
+            view: Default::default(),
+            date,
+            year_start: year_page(date.year()),
+            cursor: 0,
+            focus: Default::default(),
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl HasFocus for QuickSelectState {
+    #[inline]
+    fn focus(&self) -> FocusFlag {
+        self.focus.clone()
+    }
+
+    #[inline]
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+impl RelocatableState for QuickSelectState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        self.area_prev = relocate_area(self.area_prev, shift, clip);
+        self.area_next = relocate_area(self.area_next, shift, clip);
+        self.area_month = relocate_area(self.area_month, shift, clip);
+        self.area_year = relocate_area(self.area_year, shift, clip);
+        relocate_areas(&mut self.area_items, shift, clip);
+    }
+}
+
+/// First year of the 12-year page containing the year.
+fn year_page(year: i32) -> i32 {
+    year - year.rem_euclid(12)
+}
+
+impl QuickSelectState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn named(name: &str) -> Self {
+        Self {
+            focus: FocusFlag::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// First of the chosen month.
+    #[inline]
+    pub fn date(&self) -> NaiveDate {
+        self.date
+    }
+
+    /// Set the month. Any day of the month will do.
+    pub fn set_date(&mut self, date: NaiveDate) -> bool {
+        let old = self.date;
+        self.date = date.with_day(1).expect("date");
+        old != self.date
+    }
+
+    /// Current view.
+    #[inline]
+    pub fn view(&self) -> PickerView {
+        self.view
+    }
+
+    /// Change the view. The grid cursor starts at the
+    /// current month/year.
+    pub fn set_view(&mut self, view: PickerView) -> bool {
+        let old = self.view;
+        self.view = view;
+        match view {
+            PickerView::Day => {}
+            PickerView::Month => {
+                self.cursor = self.date.month0() as usize;
+            }
+            PickerView::Year => {
+                self.year_start = year_page(self.date.year());
+                self.cursor = (self.date.year() - self.year_start) as usize;
+            }
+        }
+        old != self.view
+    }
+
+    /// Go up one level. Day -> Month -> Year.
+    pub fn up(&mut self) -> bool {
+        match self.view {
+            PickerView::Day => self.set_view(PickerView::Month),
+            PickerView::Month => self.set_view(PickerView::Year),
+            PickerView::Year => false,
+        }
+    }
+
+    /// Go back one level. Year -> Month -> Day.
+    pub fn back(&mut self) -> bool {
+        match self.view {
+            PickerView::Day => false,
+            PickerView::Month => self.set_view(PickerView::Day),
+            PickerView::Year => self.set_view(PickerView::Month),
+        }
+    }
+
+    /// Previous month, year or 12 years, depending on the view.
+    pub fn prev_page(&mut self) -> bool {
+        match self.view {
+            PickerView::Day => self.add_months(-1),
+            PickerView::Month => self.add_months(-12),
+            PickerView::Year => {
+                self.year_start -= 12;
+                true
+            }
+        }
+    }
+
+    /// Next month, year or 12 years, depending on the view.
+    pub fn next_page(&mut self) -> bool {
+        match self.view {
+            PickerView::Day => self.add_months(1),
+            PickerView::Month => self.add_months(12),
+            PickerView::Year => {
+                self.year_start += 12;
+                true
+            }
+        }
+    }
+
+    fn add_months(&mut self, n: i32) -> bool {
+        let months = Months::new(n.unsigned_abs());
+        let date = if n >= 0 {
+            self.date.checked_add_months(months)
+        } else {
+            self.date.checked_sub_months(months)
+        };
+        match date {
+            Some(date) => self.set_date(date),
+            None => false,
+        }
+    }
+
+    /// Move the grid cursor. Stays within the grid.
+    pub fn move_cursor(&mut self, delta: isize) -> bool {
+        if self.view == PickerView::Day {
+            return false;
+        }
+        let old = self.cursor;
+        self.cursor = (self.cursor as isize + delta).clamp(0, 11) as usize;
+        old != self.cursor
+    }
+
+    /// Pick the grid item n.
+    ///
+    /// A year continues with the month grid, a month
+    /// goes back to the days.
+    pub fn pick(&mut self, n: usize) -> bool {
+        match self.view {
+            PickerView::Day => false,
+            PickerView::Month => {
+                if let Some(date) = self.date.with_month0(n as u32) {
+                    self.date = date;
+                }
+                self.set_view(PickerView::Day);
+                true
+            }
+            PickerView::Year => {
+                if let Some(date) = self.date.with_year(self.year_start + n as i32) {
+                    self.date = date;
+                }
+                self.set_view(PickerView::Month);
+                true
+            }
+        }
+    }
+
+    /// Grid item at the position.
+    pub fn item_at(&self, x: u16, y: u16) -> Option<usize> {
+        if self.view == PickerView::Day {
+            return None;
+        }
+        self.mouse.item_at(&self.area_items, x, y)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, CalOutcome> for QuickSelectState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> CalOutcome {
+        if self.is_focused() {
+            flow!(match event {
+                ct_event!(keycode press PageUp) => self.prev_page().into(),
+                ct_event!(keycode press PageDown) => self.next_page().into(),
+                ct_event!(keycode press CONTROL-Up) => self.up().into(),
+                _ => CalOutcome::Continue,
+            });
+            if self.view != PickerView::Day {
+                flow!(match event {
+                    ct_event!(keycode press Left) => self.move_cursor(-1).into(),
+                    ct_event!(keycode press Right) => self.move_cursor(1).into(),
+                    ct_event!(keycode press Up) => self.move_cursor(-(COLUMNS as isize)).into(),
+                    ct_event!(keycode press Down) => self.move_cursor(COLUMNS as isize).into(),
+                    ct_event!(keycode press Home) => self.move_cursor(-12).into(),
+                    ct_event!(keycode press End) => self.move_cursor(12).into(),
+                    ct_event!(keycode press Enter) => self.pick(self.cursor).into(),
+                    ct_event!(keycode press Esc) => self.back().into(),
+                    _ => CalOutcome::Continue,
+                });
+            }
+        }
+
+        self.handle(event, MouseOnly)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, CalOutcome> for QuickSelectState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> CalOutcome {
+        match event {
+            ct_event!(mouse down Left for x, y) => {
+                let pos = (*x, *y).into();
+                if self.area_prev.contains(pos) {
+                    self.prev_page().into()
+                } else if self.area_next.contains(pos) {
+                    self.next_page().into()
+                } else if self.area_month.contains(pos) {
+                    if self.view == PickerView::Month {
+                        self.set_view(PickerView::Day).into()
+                    } else {
+                        self.set_view(PickerView::Month).into()
+                    }
+                } else if self.area_year.contains(pos) {
+                    if self.view == PickerView::Year {
+                        self.set_view(PickerView::Day).into()
+                    } else {
+                        self.set_view(PickerView::Year).into()
+                    }
+                } else if let Some(n) = self.item_at(*x, *y) {
+                    self.cursor = n;
+                    self.pick(n).into()
+                } else {
+                    CalOutcome::Continue
+                }
+            }
+            _ => CalOutcome::Continue,
+        }
+    }
+}
+
+/// Handle all events.
+/// Key events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut QuickSelectState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> CalOutcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut QuickSelectState,
+    event: &crossterm::event::Event,
+) -> CalOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
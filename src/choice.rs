@@ -31,8 +31,11 @@
 //! ```
 //!
 use crate::_private::NonExhaustive;
-use crate::util::{block_size, revert_style};
-use rat_event::util::{item_at, mouse_trap, MouseFlags};
+use crate::popup::PlacementStrategy;
+use crate::scroll_indicator::MoreIndicator;
+use crate::scroll_to::ScrollTo;
+use crate::util::{block_size, fallback, render_mode, revert_style, RenderMode};
+use rat_event::util::{item_at, mouse_trap, MouseFlags, MouseFlagsN};
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Outcome, Popup, Regular};
 use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
 use rat_popup::event::PopupOutcome;
@@ -43,15 +46,18 @@ use rat_scrolled::{Scroll, ScrollAreaState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::prelude::BlockExt;
-use ratatui::style::Style;
-use ratatui::text::{Line, Span};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, StatefulWidget, Widget};
 use std::cell::RefCell;
 use std::cmp::{max, min};
+use std::fmt;
+use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
 use std::rc::Rc;
+use unicode_width::UnicodeWidthStr;
 
 /// Choice.
 ///
@@ -71,16 +77,44 @@ where
 
     // Can return to default with a user interaction.
     default_key: Option<T>,
+    // Navigation char overrides by item index.
+    nav: Vec<(usize, char)>,
+    nav_any: bool,
 
     style: Style,
     button_style: Option<Style>,
     select_style: Option<Style>,
+    hover_style: Option<Style>,
     focus_style: Option<Style>,
     block: Option<Block<'a>>,
+    closed_glyph: Option<&'a str>,
+    open_glyph: Option<&'a str>,
+    display: Option<DisplayFn<'a, T>>,
+    placeholder: Option<Line<'a>>,
 
     popup_placement: Placement,
+    popup_strategy: PlacementStrategy,
+    popup_anchor: Option<Rect>,
     popup_len: Option<u16>,
     popup: PopupCore<'a>,
+    more_indicator: bool,
+    more_style: Option<Style>,
+}
+
+/// Display function for the closed widget.
+#[allow(clippy::type_complexity)]
+struct DisplayFn<'a, T>(Rc<dyn Fn(&T, &Line<'a>) -> Line<'a> + 'a>);
+
+impl<T> Clone for DisplayFn<'_, T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T> Debug for DisplayFn<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("DisplayFn")
+    }
 }
 
 /// Renders the main widget.
@@ -94,11 +128,17 @@ where
 
     // Can return to default with a user interaction.
     default_key: Option<T>,
+    nav: Vec<(usize, char)>,
+    nav_any: bool,
 
     style: Style,
     button_style: Option<Style>,
     focus_style: Option<Style>,
     block: Option<Block<'a>>,
+    closed_glyph: &'a str,
+    open_glyph: &'a str,
+    display: Option<DisplayFn<'a, T>>,
+    placeholder: Option<Line<'a>>,
     len: Option<u16>,
 
     _phantom: PhantomData<T>,
@@ -115,10 +155,14 @@ where
 
     style: Style,
     select_style: Option<Style>,
+    hover_style: Option<Style>,
 
     popup_placement: Placement,
+    popup_strategy: PlacementStrategy,
+    popup_anchor: Option<Rect>,
     popup_len: Option<u16>,
     popup: PopupCore<'a>,
+    more: Option<MoreIndicator>,
 
     _phantom: PhantomData<T>,
 }
@@ -129,11 +173,18 @@ pub struct ChoiceStyle {
     pub style: Style,
     pub button: Option<Style>,
     pub select: Option<Style>,
+    pub hover: Option<Style>,
     pub focus: Option<Style>,
     pub block: Option<Block<'static>>,
+    /// Button glyph while the popup is closed.
+    pub closed_glyph: Option<&'static str>,
+    /// Button glyph while the popup is open.
+    pub open_glyph: Option<&'static str>,
 
     pub popup: PopupStyle,
     pub popup_len: Option<u16>,
+    /// Style for the more above/below marks.
+    pub more: Option<Style>,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -147,9 +198,14 @@ where
     /// Total area.
     /// __read only__. renewed with each render.
     pub area: Rect,
-    /// First char of each item for navigation.
+    /// Chars of each item for navigation. The first char of the
+    /// item, the [item_nav](Choice::item_nav) char or all chars
+    /// with [nav_any_char](Choice::nav_any_char). Lowercase.
     /// __read only__. renewed with each render.
     pub nav_char: Vec<Vec<char>>,
+    /// Typeahead matches any of the nav_char.
+    /// __read only__. renewed with each render.
+    pub nav_any: bool,
     /// Key for each item.
     /// __read only__. renewed with each render.
     pub keys: Vec<T>,
@@ -176,6 +232,12 @@ where
     pub focus: FocusFlag,
     /// Mouse util.
     pub mouse: MouseFlags,
+    /// Mouse util for the popup items. Tracks the
+    /// hovered item as an index into item_areas.
+    pub mouse_items: MouseFlagsN,
+
+    /// Center the selection on the first render of the popup.
+    recenter: bool,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -186,10 +248,14 @@ impl Default for ChoiceStyle {
             style: Default::default(),
             button: None,
             select: None,
+            hover: None,
             focus: None,
             block: None,
+            closed_glyph: None,
+            open_glyph: None,
             popup: Default::default(),
             popup_len: None,
+            more: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -204,14 +270,25 @@ where
             keys: Default::default(),
             items: Default::default(),
             default_key: None,
+            nav: Default::default(),
+            nav_any: false,
             style: Default::default(),
             button_style: None,
             select_style: None,
+            hover_style: None,
             focus_style: None,
             block: None,
+            closed_glyph: None,
+            open_glyph: None,
+            display: None,
+            placeholder: None,
             popup_len: None,
             popup_placement: Placement::BelowOrAbove,
+            popup_strategy: Default::default(),
+            popup_anchor: None,
             popup: Default::default(),
+            more_indicator: false,
+            more_style: None,
         }
     }
 }
@@ -285,6 +362,24 @@ where
         self
     }
 
+    /// Typeahead char for the item with this key.
+    /// Replaces the first char of the item.
+    ///
+    /// Call after adding the item.
+    pub fn item_nav(mut self, key: T, nav: char) -> Self {
+        if let Some(idx) = self.keys.borrow().iter().position(|v| *v == key) {
+            self.nav.push((idx, nav));
+        }
+        self
+    }
+
+    /// Typeahead matches any char of the item,
+    /// not only the first one.
+    pub fn nav_any_char(mut self, any: bool) -> Self {
+        self.nav_any = any;
+        self
+    }
+
     /// Combined styles.
     pub fn styles(mut self, styles: ChoiceStyle) -> Self {
         self.style = styles.style;
@@ -294,19 +389,30 @@ where
         if styles.select.is_some() {
             self.select_style = styles.select;
         }
+        if styles.hover.is_some() {
+            self.hover_style = styles.hover;
+        }
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
         if styles.block.is_some() {
             self.block = styles.block;
         }
-        self.block = self.block.map(|v| v.style(self.style));
+        if styles.closed_glyph.is_some() {
+            self.closed_glyph = styles.closed_glyph;
+        }
+        if styles.open_glyph.is_some() {
+            self.open_glyph = styles.open_glyph;
+        }
         if let Some(placement) = styles.popup.placement {
             self.popup_placement = placement;
         }
         if styles.popup_len.is_some() {
             self.popup_len = styles.popup_len;
         }
+        if styles.more.is_some() {
+            self.more_style = styles.more;
+        }
         self.popup = self.popup.styles(styles.popup);
         self
     }
@@ -314,7 +420,6 @@ where
     /// Base style.
     pub fn style(mut self, style: Style) -> Self {
         self.style = style;
-        self.block = self.block.map(|v| v.style(self.style));
         self
     }
 
@@ -330,6 +435,15 @@ where
         self
     }
 
+    /// Highlight for the item under the mouse cursor.
+    ///
+    /// __Default__
+    /// The popup style with an underline.
+    pub fn hover_style(mut self, style: Style) -> Self {
+        self.hover_style = Some(style);
+        self
+    }
+
     /// Focused style.
     pub fn focus_style(mut self, style: Style) -> Self {
         self.focus_style = Some(style);
@@ -339,7 +453,34 @@ where
     /// Block for the main widget.
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.block = Some(block);
-        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Glyphs for the button while the popup is closed/open.
+    ///
+    /// The button area adapts to the wider of both.
+    ///
+    /// __Default__
+    /// "▼" and "◆".
+    pub fn button_glyphs(mut self, closed: &'a str, open: &'a str) -> Self {
+        self.closed_glyph = Some(closed);
+        self.open_glyph = Some(open);
+        self
+    }
+
+    /// Display text for the closed widget.
+    ///
+    /// Gets the selected key and its item and returns
+    /// the Line that is shown instead of the item.
+    /// The popup still shows the items.
+    pub fn display(mut self, display: impl Fn(&T, &Line<'a>) -> Line<'a> + 'a) -> Self {
+        self.display = Some(DisplayFn(Rc::new(display)));
+        self
+    }
+
+    /// Shown in the closed widget if there is no selection.
+    pub fn placeholder(mut self, placeholder: impl Into<Line<'a>>) -> Self {
+        self.placeholder = Some(placeholder.into());
         self
     }
 
@@ -352,6 +493,24 @@ where
         self
     }
 
+    /// How the popup adapts if there is not enough space
+    /// for the placement.
+    ///
+    /// __Default__
+    /// PlacementStrategy::Fixed
+    pub fn popup_strategy(mut self, strategy: PlacementStrategy) -> Self {
+        self.popup_strategy = strategy;
+        self
+    }
+
+    /// Align the popup with this area instead of the
+    /// whole widget. The area is relative to the widget area,
+    /// the popup gets its width.
+    pub fn popup_anchor(mut self, anchor: Rect) -> Self {
+        self.popup_anchor = Some(anchor);
+        self
+    }
+
     /// Outer boundary for the popup.
     pub fn popup_boundary(mut self, boundary: Rect) -> Self {
         self.popup = self.popup.boundary(boundary);
@@ -360,6 +519,9 @@ where
 
     /// Override the popup length.
     ///
+    /// The popup is shortened if there is not enough
+    /// space within the popup_boundary.
+    ///
     /// __Default__
     /// Defaults to the number of items or 5.
     pub fn popup_len(mut self, len: u16) -> Self {
@@ -379,6 +541,23 @@ where
         self
     }
 
+    /// Mark the top/bottom edge of the popup with ▲/▼ if there
+    /// are more items above/below. A lightweight alternative
+    /// to a scrollbar.
+    pub fn more_indicator(mut self, show: bool) -> Self {
+        self.more_indicator = show;
+        self
+    }
+
+    /// Style for the more above/below marks.
+    ///
+    /// __Default__
+    /// The popup style.
+    pub fn more_style(mut self, style: Style) -> Self {
+        self.more_style = Some(style);
+        self
+    }
+
     /// Scroll for the popup.
     pub fn popup_scroll(mut self, scroll: Scroll<'a>) -> Self {
         self.popup = self.popup.v_scroll(scroll);
@@ -432,15 +611,24 @@ where
     ///
     /// This builds the widgets from the parameters set for Choice.
     pub fn into_widgets(self) -> (ChoiceWidget<'a, T>, ChoicePopup<'a, T>) {
+        let more = self
+            .more_indicator
+            .then(|| MoreIndicator::new().style(self.more_style.unwrap_or(self.popup.style)));
         (
             ChoiceWidget {
                 keys: self.keys,
                 items: self.items.clone(),
                 default_key: self.default_key,
+                nav: self.nav,
+                nav_any: self.nav_any,
                 style: self.style,
                 button_style: self.button_style,
                 focus_style: self.focus_style,
                 block: self.block,
+                closed_glyph: self.closed_glyph.unwrap_or(default_glyph(false)),
+                open_glyph: self.open_glyph.unwrap_or(default_glyph(true)),
+                display: self.display,
+                placeholder: self.placeholder,
                 len: self.popup_len,
                 _phantom: Default::default(),
             },
@@ -448,9 +636,13 @@ where
                 items: self.items.clone(),
                 style: self.style,
                 select_style: self.select_style,
+                hover_style: self.hover_style,
                 popup: self.popup,
                 popup_placement: self.popup_placement,
+                popup_strategy: self.popup_strategy,
+                popup_anchor: self.popup_anchor,
                 popup_len: self.popup_len,
+                more,
                 _phantom: Default::default(),
             },
         )
@@ -483,6 +675,16 @@ where
     }
 }
 
+/// Default button glyph. Ascii with [RenderMode::NoColor].
+fn default_glyph(open: bool) -> &'static str {
+    match (render_mode(), open) {
+        (RenderMode::NoColor, false) => "[v]",
+        (RenderMode::NoColor, true) => "[^]",
+        (_, false) => "▼",
+        (_, true) => "◆",
+    }
+}
+
 fn render_choice<T: PartialEq>(
     widget: &ChoiceWidget<'_, T>,
     area: Rect,
@@ -497,56 +699,84 @@ fn render_choice<T: PartialEq>(
             .unwrap_or_else(|| min(5, widget.items.borrow().len()) as u16);
         state.popup.v_scroll.max_offset = widget.items.borrow().len().saturating_sub(len as usize);
         state.popup.v_scroll.page_len = len as usize;
-        state
-            .popup
-            .v_scroll
-            .scroll_to_pos(state.selected.unwrap_or_default());
+        // opening the popup shows the selection in the middle.
+        state.center_selected();
+        state.recenter = true;
     }
 
+    state.nav_any = widget.nav_any;
     state.nav_char.clear();
-    state.nav_char.extend(widget.items.borrow().iter().map(|v| {
-        v.spans
-            .first()
-            .and_then(|v| v.content.as_ref().chars().next())
-            .map_or(Vec::default(), |c| c.to_lowercase().collect::<Vec<_>>())
-    }));
+    state
+        .nav_char
+        .extend(widget.items.borrow().iter().enumerate().map(|(idx, v)| {
+            if let Some((_, c)) = widget.nav.iter().rev().find(|(n, _)| *n == idx) {
+                c.to_lowercase().collect::<Vec<_>>()
+            } else if widget.nav_any {
+                v.spans
+                    .iter()
+                    .flat_map(|v| v.content.chars())
+                    .flat_map(|c| c.to_lowercase())
+                    .collect::<Vec<_>>()
+            } else {
+                v.spans
+                    .first()
+                    .and_then(|v| v.content.as_ref().chars().next())
+                    .map_or(Vec::default(), |c| c.to_lowercase().collect::<Vec<_>>())
+            }
+        }));
 
     let inner = widget.block.inner_if_some(area);
 
+    let button_width = max(widget.closed_glyph.width(), widget.open_glyph.width()) as u16 + 2;
     state.item_area = Rect::new(
         inner.x,
         inner.y,
-        inner.width.saturating_sub(3),
+        inner.width.saturating_sub(button_width),
         inner.height,
     );
     state.button_area = Rect::new(
-        inner.right().saturating_sub(min(3, inner.width)),
+        inner.right().saturating_sub(min(button_width, inner.width)),
         inner.y,
-        3,
+        min(button_width, inner.width),
         inner.height,
     );
 
-    let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
+    let focus_style = fallback(
+        &[Some(
+            widget.focus_style.unwrap_or(revert_style(widget.style)),
+        )],
+        widget.style,
+    );
 
+    buf.set_style(area, widget.style);
+    widget.block.render(area, buf);
     if state.is_focused() {
-        if widget.block.is_some() {
-            widget.block.render(area, buf);
-        }
         buf.set_style(inner, focus_style);
     } else {
-        if widget.block.is_some() {
-            widget.block.render(area, buf);
-        } else {
-            buf.set_style(inner, widget.style);
-        }
         if let Some(button_style) = widget.button_style {
             buf.set_style(state.button_area, button_style);
         }
     }
 
-    if let Some(selected) = state.selected {
-        if let Some(item) = widget.items.borrow().get(selected) {
-            item.render(state.item_area, buf);
+    // base/focus style is the background, the item only
+    // patches the attributes its spans set. same as the popup.
+    let items = widget.items.borrow();
+    let keys = widget.keys.borrow();
+    match state
+        .selected
+        .and_then(|v| Some((keys.get(v)?, items.get(v)?)))
+    {
+        Some((key, item)) => {
+            if let Some(display) = &widget.display {
+                (display.0)(key, item).render(state.item_area, buf);
+            } else {
+                item.render(state.item_area, buf);
+            }
+        }
+        None => {
+            if let Some(placeholder) = &widget.placeholder {
+                placeholder.render(state.item_area, buf);
+            }
         }
     }
 
@@ -555,13 +785,18 @@ fn render_choice<T: PartialEq>(
     } else {
         state.button_area.height.saturating_sub(1) / 2
     };
-    let bc = if state.is_popup_active() {
-        " ◆ "
+    let bc = if state.popup_active() {
+        widget.open_glyph
     } else {
-        " ▼ "
+        widget.closed_glyph
     };
-    Span::from(bc).render(
-        Rect::new(state.button_area.x, state.button_area.y + dy, 3, 1),
+    Line::from(bc).centered().render(
+        Rect::new(
+            state.button_area.x,
+            state.button_area.y + dy,
+            state.button_area.width,
+            1,
+        ),
         buf,
     );
 }
@@ -587,14 +822,48 @@ fn render_popup<T: PartialEq>(
         let len = widget
             .popup_len
             .unwrap_or_else(|| min(5, widget.items.borrow().len()) as u16);
+        let len = min(len, widget.items.borrow().len() as u16);
+
+        let rel_area = if let Some(anchor) = widget.popup_anchor {
+            Rect::new(
+                area.x + anchor.x,
+                area.y + anchor.y,
+                anchor.width,
+                anchor.height,
+            )
+        } else {
+            area
+        };
 
-        let popup_len = len + widget.popup.get_block_size().height;
+        let block_height = widget.popup.get_block_size().height;
+        let boundary = widget.popup.boundary_area.unwrap_or(buf.area);
+        let (placement, space) = widget.popup_strategy.layout(
+            widget.popup_placement,
+            rel_area,
+            boundary,
+            len + block_height,
+        );
+        // never larger than the space on the chosen side,
+        // but keep at least one item row.
+        let popup_len = min(len + block_height, max(space, block_height + 1));
         let popup_style = widget.popup.style;
-        let pop_area = Rect::new(0, 0, area.width, popup_len);
+        let hover_style = fallback(
+            &[Some(widget.hover_style.unwrap_or(
+                popup_style.add_modifier(Modifier::UNDERLINED),
+            ))],
+            popup_style,
+        );
+        let select_style = fallback(
+            &[Some(
+                widget.select_style.unwrap_or(revert_style(widget.style)),
+            )],
+            popup_style,
+        );
+        let pop_area = Rect::new(0, 0, rel_area.width, popup_len);
 
         widget
             .popup
-            .ref_constraint(widget.popup_placement.into_constraint(area))
+            .ref_constraint(placement.into_constraint(rel_area))
             .render(pop_area, buf, &mut state.popup);
 
         let inner = state.popup.widget_area;
@@ -605,6 +874,18 @@ fn render_popup<T: PartialEq>(
             .len()
             .saturating_sub(inner.height as usize);
         state.popup.v_scroll.page_len = inner.height as usize;
+        // the popup may be shorter than expected.
+        // only on opening, afterwards the popup can be scrolled freely.
+        if let Some(selected) = state.selected {
+            let scroll = &state.popup.v_scroll;
+            if (state.recenter
+                && (selected < scroll.offset || selected >= scroll.offset + scroll.page_len))
+                || scroll.offset > scroll.max_offset
+            {
+                state.center_selected();
+            }
+        }
+        state.recenter = false;
 
         state.item_areas.clear();
         let mut row = inner.y;
@@ -619,7 +900,9 @@ fn render_popup<T: PartialEq>(
 
             if let Some(item) = widget.items.borrow().get(idx) {
                 let style = if state.selected == Some(idx) {
-                    widget.select_style.unwrap_or(revert_style(widget.style))
+                    select_style
+                } else if state.hovered() == Some(idx) {
+                    hover_style
                 } else {
                     popup_style
                 };
@@ -633,8 +916,20 @@ fn render_popup<T: PartialEq>(
             row += 1;
             idx += 1;
         }
+
+        if let Some(more) = &widget.more {
+            let more_area = Rect::new(
+                state.popup.area.x,
+                state.popup.area.y,
+                inner.right().saturating_sub(state.popup.area.x),
+                state.popup.area.height,
+            );
+            more.render(more_area, buf, &state.popup.v_scroll);
+        }
     } else {
         state.popup.clear_areas();
+        state.item_areas.clear();
+        state.mouse_items.hover.set(None);
     }
 }
 
@@ -646,6 +941,7 @@ where
         Self {
             area: self.area,
             nav_char: self.nav_char.clone(),
+            nav_any: self.nav_any,
             keys: self.keys.clone(),
             item_area: self.item_area,
             button_area: self.button_area,
@@ -655,6 +951,8 @@ where
             popup: self.popup.clone(),
             focus: FocusFlag::named(self.focus.name()),
             mouse: Default::default(),
+            mouse_items: Default::default(),
+            recenter: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -668,6 +966,7 @@ where
         Self {
             area: Default::default(),
             nav_char: Default::default(),
+            nav_any: Default::default(),
             keys: Default::default(),
             item_area: Default::default(),
             button_area: Default::default(),
@@ -677,6 +976,8 @@ where
             popup: Default::default(),
             focus: Default::default(),
             mouse: Default::default(),
+            mouse_items: Default::default(),
+            recenter: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -713,6 +1014,19 @@ where
     }
 }
 
+/// Item indexes of the popup list.
+impl<T> ScrollTo for ChoiceState<T>
+where
+    T: PartialEq,
+{
+    type Range = std::ops::Range<usize>;
+
+    fn ensure_visible(&mut self, range: std::ops::Range<usize>) -> bool {
+        let end = max(range.end, range.start + 1);
+        self.popup.v_scroll.scroll_to_range(range.start..end)
+    }
+}
+
 impl<T> ChoiceState<T>
 where
     T: PartialEq,
@@ -729,10 +1043,16 @@ where
     }
 
     /// Popup is active?
-    pub fn is_popup_active(&self) -> bool {
+    pub fn popup_active(&self) -> bool {
         self.popup.is_active()
     }
 
+    /// Popup is active?
+    #[deprecated(since = "0.34.0", note = "use popup_active()")]
+    pub fn is_popup_active(&self) -> bool {
+        self.popup_active()
+    }
+
     /// Flip the popup state.
     pub fn flip_popup_active(&mut self) {
         self.popup.flip_active();
@@ -787,21 +1107,29 @@ where
 
     /// Get the selected value or None if no value
     /// is selected or there are no items.
+    ///
+    /// This is the preferred way to access the value.
     pub fn value_opt_ref(&self) -> Option<&T> {
-        if let Some(selected) = self.selected {
-            Some(&self.keys[selected])
-        } else {
-            None
-        }
+        self.selected.and_then(|v| self.keys.get(v))
     }
 
     /// Get the selected value.
     ///
+    /// __Panic__
     /// Panics if there is no selection or no items.
+    /// Use [value_opt_ref](Self::value_opt_ref) if the
+    /// Choice can be empty.
     pub fn value_ref(&self) -> &T {
         &self.keys[self.selected.expect("selection")]
     }
 
+    /// Clear the selection.
+    ///
+    /// Returns true if there was a selection.
+    pub fn clear_selection(&mut self) -> bool {
+        self.selected.take().is_some()
+    }
+
     /// Select
     pub fn select(&mut self, select: Option<usize>) -> bool {
         let old_selected = self.selected;
@@ -824,6 +1152,11 @@ where
         self.selected
     }
 
+    /// Item under the mouse cursor in the popup.
+    pub fn hovered(&self) -> Option<usize> {
+        self.mouse_items.hover.get().map(|v| self.offset() + v)
+    }
+
     /// Items?
     pub fn is_empty(&self) -> bool {
         self.keys.is_empty()
@@ -864,6 +1197,16 @@ where
         self.popup.v_scroll.scroll_by()
     }
 
+    /// Scroll the selected value to the middle of the item list.
+    fn center_selected(&mut self) {
+        let selected = self.selected.unwrap_or_default();
+        let scroll = &mut self.popup.v_scroll;
+        scroll.offset = min(
+            selected.saturating_sub(scroll.page_len.saturating_sub(1) / 2),
+            scroll.max_offset,
+        );
+    }
+
     /// Scroll the item list to the selected value.
     pub fn scroll_to_selected(&mut self) -> bool {
         if let Some(selected) = self.selected {
@@ -880,17 +1223,27 @@ where
 {
     /// Get the selected value or None if no value
     /// is selected or there are no items.
+    ///
+    /// This is the preferred way to access the value.
     pub fn value_opt(&self) -> Option<T> {
-        if let Some(selected) = self.selected {
-            Some(self.keys[selected].clone())
-        } else {
-            None
-        }
+        self.value_opt_ref().cloned()
+    }
+
+    /// Get the selected value, or the default key if
+    /// nothing is selected.
+    ///
+    /// Returns None if there is neither a selection nor
+    /// a default key.
+    pub fn value_or_default(&self) -> Option<T> {
+        self.value_opt().or_else(|| self.default_key.clone())
     }
 
     /// Get the selected value.
     ///
+    /// __Panic__
     /// Panics if there is no selection or no items.
+    /// Use [value_opt](Self::value_opt) if the Choice
+    /// can be empty.
     pub fn value(&self) -> T {
         self.keys[self.selected.expect("selection")].clone()
     }
@@ -900,7 +1253,8 @@ impl<T> ChoiceState<T>
 where
     T: PartialEq,
 {
-    /// Select by first character.
+    /// Select the next item matching the character.
+    /// See [nav_char](ChoiceState::nav_char).
     pub fn select_by_char(&mut self, c: char) -> bool {
         if self.nav_char.is_empty() {
             return false;
@@ -918,7 +1272,12 @@ where
                 break;
             }
 
-            if self.nav_char[idx] == c {
+            let found = if self.nav_any {
+                self.nav_char[idx].windows(c.len()).any(|v| v == c)
+            } else {
+                self.nav_char[idx] == c
+            };
+            if found {
                 self.selected = Some(idx);
                 return true;
             }
@@ -1009,6 +1368,16 @@ impl<T: PartialEq> HandleEvent<crossterm::event::Event, Regular, Outcome> for Ch
                         Outcome::Continue
                     }
                 }
+                ct_event!(keycode press PageUp) if self.popup_active() => {
+                    self.move_up(max(1, self.page_len() / 2)).into()
+                }
+                ct_event!(keycode press PageDown) if self.popup_active() => {
+                    self.move_down(max(1, self.page_len() / 2)).into()
+                }
+                ct_event!(keycode press Home) if self.popup_active() => self.move_to(0).into(),
+                ct_event!(keycode press End) if self.popup_active() => {
+                    self.move_to(self.len().saturating_sub(1)).into()
+                }
                 ct_event!(keycode press Down) => {
                     let r0 = if !self.popup.is_active() {
                         self.popup.set_active(true);
@@ -1052,7 +1421,7 @@ impl<T: PartialEq> HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for
                 if self.item_area.contains((*x, *y).into())
                     || self.button_area.contains((*x, *y).into()) =>
             {
-                if !self.gained_focus() && !self.is_popup_active() && !self.popup.active.lost() {
+                if !self.gained_focus() && !self.popup_active() && !self.popup.active.lost() {
                     self.set_popup_active(true);
                     Outcome::Changed
                 } else {
@@ -1087,11 +1456,16 @@ impl<T: PartialEq> HandleEvent<crossterm::event::Event, Popup, Outcome> for Choi
         let mut r2 = match sas.handle(event, MouseOnly) {
             ScrollOutcome::Up(n) => self.move_up(n).into(),
             ScrollOutcome::Down(n) => self.move_down(n).into(),
-            ScrollOutcome::VPos(n) => self.move_to(n).into(),
+            ScrollOutcome::VPos(n) => self.set_offset(n).into(),
             _ => Outcome::Continue,
         };
 
         r2 = r2.or_else(|| match event {
+            ct_event!(mouse any for m)
+                if self.popup_active() && self.mouse_items.hover(&self.item_areas, m) =>
+            {
+                Outcome::Changed
+            }
             ct_event!(mouse any for m) if self.mouse.doubleclick(self.popup.widget_area, m) => {
                 if let Some(n) = item_at(&self.item_areas, m.column, m.row) {
                     let r = self.move_to(self.offset() + n).into();
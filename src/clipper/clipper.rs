@@ -1,6 +1,12 @@
 use crate::_private::NonExhaustive;
 use crate::clipper::ClipperStyle;
-use crate::layout::GenericLayout;
+use crate::empty::EmptyRender;
+use crate::layout::{GenericLayout, HitPart};
+use crate::render_timing::RenderTimer;
+use crate::scroll_indicator::scroll_fraction;
+use crate::scroll_step::ScrollStep;
+use crate::scroll_to::{ensure_area_visible, ScrollTo};
+use crate::util::{buffer_text, copy_buffer, text_hash};
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Outcome, Regular};
 use rat_focus::{ContainerFlag, FocusContainer};
 use rat_reloc::RelocatableState;
@@ -9,10 +15,10 @@ use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Position, Rect, Size};
 use ratatui::prelude::{Style, Widget};
-use ratatui::text::Line;
+use ratatui::text::{Line, Text};
 use ratatui::widgets::{Block, StatefulWidget};
 use std::borrow::Cow;
-use std::cmp::{max, min};
+use std::cmp::max;
 use std::hash::Hash;
 use std::marker::PhantomData;
 use std::rc::Rc;
@@ -28,6 +34,8 @@ where
     vscroll: Option<Scroll<'a>>,
     label_style: Option<Style>,
     label_alignment: Option<Alignment>,
+    scroll_step: Option<ScrollStep>,
+    empty: Option<EmptyRender<'a>>,
     phantom: PhantomData<W>,
 }
 
@@ -51,6 +59,8 @@ where
     vscroll: Option<Scroll<'a>>,
     label_style: Option<Style>,
     label_alignment: Option<Alignment>,
+    empty: Option<EmptyRender<'a>>,
+    _timer: RenderTimer,
 }
 
 #[derive(Debug)]
@@ -65,6 +75,8 @@ where
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
     vscroll: Option<Scroll<'a>>,
+    empty: Option<EmptyRender<'a>>,
+    _timer: RenderTimer,
     phantom: PhantomData<W>,
 }
 
@@ -97,6 +109,8 @@ where
 
     /// For the buffer to survive render()
     buffer: Option<Buffer>,
+    /// Offset of the last render.
+    offset: Position,
 
     /// Only construct with `..Default::default()`.
     pub non_exhaustive: NonExhaustive,
@@ -114,6 +128,8 @@ where
             vscroll: self.vscroll.clone(),
             label_style: self.label_style.clone(),
             label_alignment: self.label_alignment.clone(),
+            scroll_step: self.scroll_step,
+            empty: self.empty.clone(),
             phantom: Default::default(),
         }
     }
@@ -131,6 +147,8 @@ where
             vscroll: Default::default(),
             label_style: Default::default(),
             label_alignment: Default::default(),
+            scroll_step: Default::default(),
+            empty: Default::default(),
             phantom: Default::default(),
         }
     }
@@ -189,6 +207,30 @@ where
         self
     }
 
+    /// How far the mouse wheel scrolls.
+    ///
+    /// __Default__
+    /// 1/10 of the page.
+    pub fn scroll_step(mut self, step: ScrollStep) -> Self {
+        self.scroll_step = Some(step);
+        self
+    }
+
+    /// Render something if the layout has no widgets.
+    ///
+    /// Called with the inner area after the block and the
+    /// scrollbars.
+    pub fn empty_render(mut self, render: impl Fn(Rect, &mut Buffer) + 'a) -> Self {
+        self.empty = Some(EmptyRender::new(render));
+        self
+    }
+
+    /// Text shown in the middle if the layout has no widgets.
+    pub fn empty_text(mut self, text: impl Into<Text<'a>>, style: Style) -> Self {
+        self.empty = Some(EmptyRender::text(text, style));
+        self
+    }
+
     /// Combined style.
     pub fn styles(mut self, styles: ClipperStyle) -> Self {
         self.style = styles.style;
@@ -281,6 +323,7 @@ where
 
     /// Calculates the layout and creates a temporary buffer.
     pub fn into_buffer(self, area: Rect, state: &mut ClipperState<W>) -> ClipperBuffer<'a, W> {
+        let timer = RenderTimer::start("Clipper", state.container.name());
         state.area = area;
 
         let sa = ScrollArea::new()
@@ -303,6 +346,10 @@ where
         state
             .hscroll
             .set_max_offset(max_pos.x.saturating_sub(state.widget_area.width) as usize);
+        if let Some(step) = self.scroll_step {
+            step.apply(&mut state.hscroll);
+            step.apply(&mut state.vscroll);
+        }
 
         let offset = Position::new(state.hscroll.offset as u16, state.vscroll.offset as u16);
 
@@ -328,6 +375,12 @@ where
             vscroll: self.vscroll,
             label_style: self.label_style,
             label_alignment: self.label_alignment,
+            empty: if state.layout.is_empty() {
+                self.empty
+            } else {
+                None
+            },
+            _timer: timer,
         }
     }
 }
@@ -385,7 +438,38 @@ where
         true
     }
 
+    /// Render the label for the given widget with the set
+    /// style and alignment.
+    ///
+    /// The closure is only called if the label is visible.
+    /// Style and alignment of the Line take precedence.
+    #[inline(always)]
+    pub fn render_label_with<'l, FN>(&mut self, widget: W, label_fn: FN) -> bool
+    where
+        FN: FnOnce() -> Line<'l>,
+    {
+        let Some(idx) = self.layout.try_index_of(widget) else {
+            return false;
+        };
+        let Some(label_area) = self.locate_area(self.layout.label(idx)) else {
+            return false;
+        };
+
+        let mut label = label_fn();
+        if let Some(style) = self.label_style {
+            label.style = style.patch(label.style);
+        }
+        if label.alignment.is_none() {
+            label.alignment = self.label_alignment;
+        }
+        label.render(label_area, &mut self.buffer);
+
+        true
+    }
+
     /// Render a stateless widget and its label.
+    ///
+    /// The closure is only called if the widget is visible.
     #[inline(always)]
     pub fn render_widget<FN, WW>(&mut self, widget: W, render_fn: FN) -> bool
     where
@@ -407,6 +491,9 @@ where
     }
 
     /// Render a stateful widget and its label.
+    ///
+    /// The closure is only called if the widget is visible,
+    /// otherwise the areas in the state are cleared with hidden().
     #[inline(always)]
     pub fn render<FN, WW, SS>(&mut self, widget: W, render_fn: FN, state: &mut SS) -> bool
     where
@@ -430,6 +517,61 @@ where
         true
     }
 
+    /// Render an optional stateful widget and its label.
+    ///
+    /// The closure is only called if the widget is visible.
+    #[inline(always)]
+    pub fn render_opt<FN, WW, SS>(&mut self, widget: W, render_fn: FN, state: &mut SS) -> bool
+    where
+        FN: FnOnce() -> Option<WW>,
+        WW: StatefulWidget<State = SS>,
+        SS: RelocatableState,
+    {
+        let Some(idx) = self.layout.try_index_of(widget) else {
+            return false;
+        };
+
+        self.render_auto_label(idx);
+
+        let Some(widget_area) = self.locate_area(self.layout.widget(idx)) else {
+            self.hidden(state);
+            return false;
+        };
+        if let Some(widget) = render_fn() {
+            widget.render(widget_area, &mut self.buffer, state);
+            self.relocate(state);
+            true
+        } else {
+            self.hidden(state);
+            false
+        }
+    }
+
+    /// Render a stateful widget and its label.
+    /// The closure can return a second value, which will be forwarded
+    /// if the widget is visible.
+    #[inline(always)]
+    pub fn render2<FN, WW, SS, R>(&mut self, widget: W, render_fn: FN, state: &mut SS) -> Option<R>
+    where
+        FN: FnOnce() -> (WW, R),
+        WW: StatefulWidget<State = SS>,
+        SS: RelocatableState,
+    {
+        let idx = self.layout.try_index_of(widget)?;
+
+        self.render_auto_label(idx);
+
+        let Some(widget_area) = self.locate_area(self.layout.widget(idx)) else {
+            self.hidden(state);
+            return None;
+        };
+        let (widget, remainder) = render_fn();
+        widget.render(widget_area, &mut self.buffer, state);
+        self.relocate(state);
+
+        Some(remainder)
+    }
+
     /// Render all visible blocks.
     pub fn render_block(&mut self) {
         for (idx, block_area) in self.layout.block_area_iter().enumerate() {
@@ -521,6 +663,8 @@ where
             vscroll: self.vscroll,
             offset: self.offset,
             buffer: self.buffer,
+            empty: self.empty,
+            _timer: self._timer,
             phantom: Default::default(),
             style: self.style,
         }
@@ -549,53 +693,15 @@ where
                     .v_scroll(&mut state.vscroll),
             );
 
-        let src_area = self.buffer.area;
-        let tgt_area = state.widget_area;
-        let offset = self.offset;
-
-        // extra offset due to buffer starts right of offset.
-        let off_x0 = src_area.x.saturating_sub(offset.x);
-        let off_y0 = src_area.y.saturating_sub(offset.y);
-        // cut source buffer due to start left of offset.
-        let cut_x0 = offset.x.saturating_sub(src_area.x);
-        let cut_y0 = offset.y.saturating_sub(src_area.y);
-
-        // length to copy
-        let len_src = src_area.width.saturating_sub(cut_x0);
-        let len_tgt = tgt_area.width.saturating_sub(off_x0);
-        let len = min(len_src, len_tgt);
-
-        // area height to copy
-        let height_src = src_area.height.saturating_sub(cut_y0);
-        let height_tgt = tgt_area.height.saturating_sub(off_y0);
-        let height = min(height_src, height_tgt);
-
-        // ** slow version **
-        // for y in 0..height {
-        //     for x in 0..len {
-        //         let src_pos = Position::new(src_area.x + cut_x0 + x, src_area.y + cut_y0 + y);
-        //         let src_cell = self.buffer.cell(src_pos).expect("src-cell");
-        //
-        //         let tgt_pos = Position::new(tgt_area.x + off_x0 + x, tgt_area.y + off_y0 + y);
-        //         let tgt_cell = buf.cell_mut(tgt_pos).expect("tgt_cell");
-        //
-        //         *tgt_cell = src_cell.clone();
-        //     }
-        // }
-
-        for y in 0..height {
-            let src_0 = self
-                .buffer
-                .index_of(src_area.x + cut_x0, src_area.y + cut_y0 + y);
-            let tgt_0 = buf.index_of(tgt_area.x + off_x0, tgt_area.y + off_y0 + y);
-
-            let src = &self.buffer.content[src_0..src_0 + len as usize];
-            let tgt = &mut buf.content[tgt_0..tgt_0 + len as usize];
-            tgt.clone_from_slice(src);
+        if let Some(empty) = &self.empty {
+            empty.render(state.widget_area, buf);
         }
 
+        copy_buffer(&self.buffer, self.offset, state.widget_area, buf);
+
         // keep buffer
         state.buffer = Some(self.buffer);
+        state.offset = self.offset;
     }
 }
 
@@ -612,6 +718,7 @@ where
             vscroll: Default::default(),
             container: Default::default(),
             buffer: None,
+            offset: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -630,6 +737,7 @@ where
             vscroll: self.vscroll.clone(),
             container: ContainerFlag::named(self.container.name()),
             buffer: None,
+            offset: Default::default(),
             non_exhaustive: NonExhaustive,
         }
     }
@@ -653,6 +761,31 @@ where
         self.layout.clone()
     }
 
+    /// Plain text of the visible region of the last render,
+    /// one string per row.
+    ///
+    /// Trailing whitespace is trimmed. Styles and anything
+    /// outside the clipper buffer (block, scrollbars) are not included.
+    pub fn visible_text(&self) -> Vec<String> {
+        let Some(buffer) = &self.buffer else {
+            return Vec::default();
+        };
+        let visible = Rect::new(
+            self.offset.x,
+            self.offset.y,
+            self.widget_area.width,
+            self.widget_area.height,
+        );
+        buffer_text(buffer, visible)
+    }
+
+    /// Hash of the [visible_text](ClipperState::visible_text).
+    ///
+    /// Can be used to detect that nothing visible changed.
+    pub fn visible_text_hash(&self) -> u64 {
+        text_hash(&self.visible_text())
+    }
+
     /// Show the area for the given handle.
     pub fn show(&mut self, widget: W) {
         let Some(idx) = self.layout.try_index_of(widget) else {
@@ -702,6 +835,35 @@ where
 
         None
     }
+
+    /// Find the widget at the given screen position.
+    ///
+    /// Uses the widget area and offsets of the last render.
+    /// Returns the widget and whether the label or the widget
+    /// itself was hit.
+    pub fn widget_at(&self, pos: (u16, u16)) -> Option<(W, HitPart)> {
+        if !self.widget_area.contains(pos.into()) {
+            return None;
+        }
+        let x = (pos.0 - self.widget_area.x) as usize + self.hscroll.offset();
+        let y = (pos.1 - self.widget_area.y) as usize + self.vscroll.offset();
+        self.layout.widget_at_pos(Position::new(
+            u16::try_from(x).ok()?,
+            u16::try_from(y).ok()?,
+        ))
+    }
+}
+
+/// Coordinates of the layout.
+impl<W> ScrollTo for ClipperState<W>
+where
+    W: Eq + Clone + Hash,
+{
+    type Range = Rect;
+
+    fn ensure_visible(&mut self, range: Rect) -> bool {
+        ensure_area_visible(&mut self.hscroll, &mut self.vscroll, range)
+    }
 }
 
 impl<W> ClipperState<W>
@@ -722,6 +884,11 @@ where
         self.vscroll.page_len()
     }
 
+    /// Vertical scroll position as a fraction 0.0..=1.0.
+    pub fn vertical_fraction(&self) -> f64 {
+        scroll_fraction(&self.vscroll)
+    }
+
     pub fn horizontal_offset(&self) -> usize {
         self.hscroll.offset()
     }
@@ -736,6 +903,11 @@ where
         self.hscroll.page_len()
     }
 
+    /// Horizontal scroll position as a fraction 0.0..=1.0.
+    pub fn horizontal_fraction(&self) -> f64 {
+        scroll_fraction(&self.hscroll)
+    }
+
     pub fn horizontal_scroll_to(&mut self, pos: usize) -> bool {
         self.hscroll.scroll_to_pos(pos)
     }
@@ -808,3 +980,29 @@ where
         }
     }
 }
+
+/// Handle all events.
+/// Key events are only processed if the container is focused,
+/// focus is only here for consistency with the other widgets.
+/// Mouse events are processed if they are in range.
+pub fn handle_events<W>(
+    state: &mut ClipperState<W>,
+    _focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome
+where
+    W: Eq + Clone + Hash,
+{
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events<W>(
+    state: &mut ClipperState<W>,
+    event: &crossterm::event::Event,
+) -> Outcome
+where
+    W: Eq + Clone + Hash,
+{
+    HandleEvent::handle(state, event, MouseOnly)
+}
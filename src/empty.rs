@@ -0,0 +1,64 @@
+//!
+//! Render something in place of empty content.
+//!
+//! List, View and Clipper have an `empty_render()` and an
+//! `empty_text()` builder. The hook is called with the inner
+//! area after the block and the scrollbars have been rendered,
+//! and before anything else.
+//!
+//! For a Table use [render_empty](crate::table::empty::render_empty)
+//! after rendering the table.
+//!
+//! ```rust ignore
+//! List::new(items)
+//!     .empty_text("No mail.", Style::new().dim())
+//!     .render(area, buf, &mut state.list);
+//!
+//! View::new()
+//!     .layout(layout)
+//!     .empty_render(|area, buf| logo(area, buf))
+//!     .into_buffer(area, &mut state.view);
+//! ```
+//!
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Text;
+use ratatui::widgets::Widget;
+use std::cmp::min;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+/// Render hook for empty content.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+pub struct EmptyRender<'a>(Rc<dyn Fn(Rect, &mut Buffer) + 'a>);
+
+impl Debug for EmptyRender<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("EmptyRender")
+    }
+}
+
+impl<'a> EmptyRender<'a> {
+    /// Render function. Gets the inner area of the widget.
+    pub fn new(render: impl Fn(Rect, &mut Buffer) + 'a) -> Self {
+        Self(Rc::new(render))
+    }
+
+    /// Text centered in the area.
+    pub fn text(text: impl Into<Text<'a>>, style: Style) -> Self {
+        let text = text.into();
+        Self::new(move |area, buf| {
+            let h = min(text.height() as u16, area.height);
+            let text_area = Rect::new(area.x, area.y + (area.height - h) / 2, area.width, h);
+            text.clone().centered().style(style).render(text_area, buf);
+        })
+    }
+
+    /// Render.
+    pub fn render(&self, area: Rect, buf: &mut Buffer) {
+        (self.0)(area, buf);
+    }
+}
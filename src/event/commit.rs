@@ -0,0 +1,116 @@
+//!
+//! Commit signal for the text input widgets.
+//!
+//! The input widgets report every edit as TextChanged. Forms that
+//! validate or save the value want to know when the user is done
+//! with the field instead. [handle_commit] runs the regular
+//! event-handling of the widget and reports
+//! [CommitOutcome::Committed] on Enter and when the widget
+//! lost the focus.
+//!
+//! ```rust ignore
+//! // after the focus handling.
+//! match handle_commit(&mut state.amount, event) {
+//!     CommitOutcome::Committed => validate(&mut state.amount),
+//!     r => r.into(),
+//! }
+//! ```
+//!
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, Outcome, Regular};
+use rat_focus::HasFocus;
+use rat_text::event::TextOutcome;
+
+/// Result of [handle_commit].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CommitOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// Text content has changed.
+    TextChanged,
+    /// The user is done with the field. Enter has been pressed
+    /// or the widget lost the focus.
+    Committed,
+}
+
+impl ConsumedEvent for CommitOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != CommitOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for CommitOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            CommitOutcome::Changed
+        } else {
+            CommitOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for CommitOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => CommitOutcome::Continue,
+            Outcome::Unchanged => CommitOutcome::Unchanged,
+            Outcome::Changed => CommitOutcome::Changed,
+        }
+    }
+}
+
+impl From<TextOutcome> for CommitOutcome {
+    fn from(value: TextOutcome) -> Self {
+        match value {
+            TextOutcome::Continue => CommitOutcome::Continue,
+            TextOutcome::Unchanged => CommitOutcome::Unchanged,
+            TextOutcome::Changed => CommitOutcome::Changed,
+            TextOutcome::TextChanged => CommitOutcome::TextChanged,
+        }
+    }
+}
+
+impl From<CommitOutcome> for Outcome {
+    fn from(value: CommitOutcome) -> Self {
+        match value {
+            CommitOutcome::Continue => Outcome::Continue,
+            CommitOutcome::Unchanged => Outcome::Unchanged,
+            CommitOutcome::Changed => Outcome::Changed,
+            CommitOutcome::TextChanged => Outcome::Changed,
+            CommitOutcome::Committed => Outcome::Changed,
+        }
+    }
+}
+
+/// Regular event-handling of the widget plus the commit signal.
+///
+/// Call it after the focus handling, as focus-lost is only
+/// known then. Works with TextInput, MaskedInput, DateInput
+/// and NumberInput.
+///
+/// Enter is reported as [CommitOutcome::Committed] if the
+/// widget doesn't use it itself, so it counts as consumed.
+pub fn handle_commit<S>(state: &mut S, event: &crossterm::event::Event) -> CommitOutcome
+where
+    S: HandleEvent<crossterm::event::Event, Regular, TextOutcome> + HasFocus,
+{
+    let r: CommitOutcome = state.handle(event, Regular).into();
+
+    if state.focus().lost() {
+        return CommitOutcome::Committed;
+    }
+    if r == CommitOutcome::Continue && state.is_focused() {
+        if let ct_event!(keycode press Enter) = event {
+            return CommitOutcome::Committed;
+        }
+    }
+    r
+}
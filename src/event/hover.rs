@@ -0,0 +1,60 @@
+//!
+//! Result of hover tracking.
+//!
+
+use rat_event::{ConsumedEvent, Outcome};
+
+/// Result of hover tracking for list and table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HoverOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it. The mouse left the rows.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// The mouse moved to a new row.
+    Hovered(usize),
+}
+
+impl ConsumedEvent for HoverOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != HoverOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for HoverOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            HoverOutcome::Changed
+        } else {
+            HoverOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for HoverOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => HoverOutcome::Continue,
+            Outcome::Unchanged => HoverOutcome::Unchanged,
+            Outcome::Changed => HoverOutcome::Changed,
+        }
+    }
+}
+
+impl From<HoverOutcome> for Outcome {
+    fn from(value: HoverOutcome) -> Self {
+        match value {
+            HoverOutcome::Continue => Outcome::Continue,
+            HoverOutcome::Unchanged => Outcome::Unchanged,
+            HoverOutcome::Changed => Outcome::Changed,
+            HoverOutcome::Hovered(_) => Outcome::Changed,
+        }
+    }
+}
@@ -0,0 +1,199 @@
+//!
+//! Detect key sequences like "g g".
+//!
+
+use rat_event::{ConsumedEvent, HandleEvent, Regular};
+use std::time::{Duration, Instant};
+
+/// Detects key chords.
+///
+/// A chord is a sequence of key presses, like "g g" or
+/// "leader x". Every key event is fed to [handle](HandleEvent::handle).
+/// If the key could start a chord it is withheld, and
+/// handed back with [ChordOutcome::Replay] if the chord doesn't
+/// complete.
+///
+/// Only key presses count. Key releases and other events
+/// are ignored.
+///
+/// ```rust
+/// use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+/// use rat_widget::event::KeyChords;
+///
+/// let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::NONE);
+/// let chords = KeyChords::new().chord([g, g], "top");
+/// ```
+#[derive(Debug, Clone)]
+pub struct KeyChords<T> {
+    chords: Vec<(Vec<crossterm::event::KeyEvent>, T)>,
+    timeout: Duration,
+
+    pending: Vec<crossterm::event::KeyEvent>,
+    last: Option<Instant>,
+}
+
+/// Result of [KeyChords].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChordOutcome<T> {
+    /// Not part of any chord. Process the event as usual.
+    Continue,
+    /// The event may start/continue a chord. It is withheld
+    /// for now.
+    Pending,
+    /// A chord has been completed.
+    Chord(T),
+    /// The withheld events didn't complete a chord.
+    /// Process these events as usual. The last one is
+    /// the current event.
+    Replay(Vec<crossterm::event::KeyEvent>),
+}
+
+impl<T> ConsumedEvent for ChordOutcome<T> {
+    fn is_consumed(&self) -> bool {
+        !matches!(self, ChordOutcome::Continue)
+    }
+}
+
+impl<T> Default for KeyChords<T> {
+    fn default() -> Self {
+        Self {
+            chords: Default::default(),
+            timeout: Duration::from_millis(1000),
+            pending: Default::default(),
+            last: None,
+        }
+    }
+}
+
+impl<T> KeyChords<T>
+where
+    T: Clone,
+{
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chord.
+    pub fn chord(
+        mut self,
+        keys: impl IntoIterator<Item = crossterm::event::KeyEvent>,
+        value: T,
+    ) -> Self {
+        let keys = keys.into_iter().collect::<Vec<_>>();
+        if !keys.is_empty() {
+            self.chords.push((keys, value));
+        }
+        self
+    }
+
+    /// Maximum time between two keys of a chord.
+    ///
+    /// __Default__
+    /// 1 second.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Some keys are withheld.
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Drop the withheld keys.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+        self.last = None;
+    }
+
+    /// Hand back the withheld keys if the timeout has expired.
+    ///
+    /// Call this from a timer to process a lone prefix key
+    /// without waiting for the next key.
+    pub fn expire(&mut self) -> Option<Vec<crossterm::event::KeyEvent>> {
+        self.expire_at(Instant::now())
+    }
+
+    /// Same as [expire](Self::expire) with an explicit time.
+    pub fn expire_at(&mut self, now: Instant) -> Option<Vec<crossterm::event::KeyEvent>> {
+        if self.is_expired(now) {
+            self.last = None;
+            Some(std::mem::take(&mut self.pending))
+        } else {
+            None
+        }
+    }
+
+    /// Same as [handle](HandleEvent::handle) with an explicit time.
+    ///
+    /// If the timeout expired before this key, the withheld keys
+    /// are replayed together with this key.
+    pub fn handle_at(&mut self, event: &crossterm::event::Event, now: Instant) -> ChordOutcome<T> {
+        use crossterm::event::{Event, KeyEventKind};
+
+        let key = match event {
+            Event::Key(key) if key.kind == KeyEventKind::Press => *key,
+            _ => return ChordOutcome::Continue,
+        };
+
+        if self.is_expired(now) {
+            self.pending.push(key);
+            self.last = None;
+            return ChordOutcome::Replay(std::mem::take(&mut self.pending));
+        }
+
+        self.pending.push(key);
+
+        let mut prefix = false;
+        for (keys, value) in &self.chords {
+            if keys.len() < self.pending.len() {
+                continue;
+            }
+            if !keys
+                .iter()
+                .zip(self.pending.iter())
+                .all(|(a, b)| same_key(a, b))
+            {
+                continue;
+            }
+            if keys.len() == self.pending.len() {
+                let value = value.clone();
+                self.clear();
+                return ChordOutcome::Chord(value);
+            }
+            prefix = true;
+        }
+
+        if prefix {
+            self.last = Some(now);
+            ChordOutcome::Pending
+        } else if self.pending.len() > 1 {
+            self.last = None;
+            ChordOutcome::Replay(std::mem::take(&mut self.pending))
+        } else {
+            self.clear();
+            ChordOutcome::Continue
+        }
+    }
+
+    fn is_expired(&self, now: Instant) -> bool {
+        if let Some(last) = self.last {
+            !self.pending.is_empty() && now.duration_since(last) > self.timeout
+        } else {
+            false
+        }
+    }
+}
+
+fn same_key(a: &crossterm::event::KeyEvent, b: &crossterm::event::KeyEvent) -> bool {
+    a.code == b.code && a.modifiers == b.modifiers
+}
+
+impl<T> HandleEvent<crossterm::event::Event, Regular, ChordOutcome<T>> for KeyChords<T>
+where
+    T: Clone,
+{
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> ChordOutcome<T> {
+        self.handle_at(event, Instant::now())
+    }
+}
@@ -0,0 +1,41 @@
+//!
+//! Report which widget handled an event.
+//!
+
+use rat_event::{ConsumedEvent, HandleEvent, Outcome};
+use rat_focus::HasFocus;
+
+/// Calls the event-handler of the widget and tags the
+/// result with the name of its FocusFlag.
+///
+/// The name is only returned if the event has been consumed.
+/// It is a String, the FocusFlag is handed out by value and
+/// can't lend the name.
+///
+/// ```rust ignore
+/// let (r, name) = handle_named(&mut state.list, event, Regular);
+/// let (r, name) = if r.is_consumed() {
+///     (r, name)
+/// } else {
+///     handle_named(&mut state.table, event, Regular)
+/// };
+/// if let Some(name) = name {
+///     debug!("{:?} handled by {}", r, name);
+/// }
+/// ```
+pub fn handle_named<S, Q, R>(
+    state: &mut S,
+    event: &crossterm::event::Event,
+    qualifier: Q,
+) -> (Outcome, Option<String>)
+where
+    S: HasFocus + HandleEvent<crossterm::event::Event, Q, R>,
+    R: ConsumedEvent + Into<Outcome>,
+{
+    let r: Outcome = state.handle(event, qualifier).into();
+    if r == Outcome::Continue {
+        (r, None)
+    } else {
+        (r, Some(state.focus().name().to_string()))
+    }
+}
@@ -0,0 +1,128 @@
+//!
+//! Readline key bindings for the single-line inputs.
+//!
+
+use crate::date_input::DateInputState;
+use crate::event::TextOutcome;
+use crate::number_input::NumberInputState;
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use rat_event::{ct_event, HandleEvent, Regular};
+use rat_text::upos_type;
+use std::ops::Range;
+
+/// Qualifier for readline style key bindings.
+///
+/// Use it instead of [Regular] to get
+///
+/// * Ctrl+A/Ctrl+E: start/end of line
+/// * Ctrl+K: kill to the end of the line
+/// * Ctrl+U: kill to the start of the line
+/// * Ctrl+W: kill the previous word
+/// * Alt+F/Alt+B: next/previous word
+/// * Ctrl+Y: yank the killed text back
+///
+/// Killed text goes to the clipboard of the widget, if there is one.
+///
+/// For [MaskedInputState] and the wrappers words are the sections
+/// of the mask, and killing resets the sections to their default.
+///
+/// All other events are handled with [Regular], so this replaces
+/// Ctrl+A for select-all. That's why it's not the default.
+///
+/// ```rust ignore
+/// state.name.handle(event, Readline);
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Readline;
+
+/// Copy the range to the clipboard and delete it.
+fn kill_text(state: &mut TextInputState, range: Range<upos_type>) -> bool {
+    if range.is_empty() {
+        return false;
+    }
+    if let Some(clip) = state.clipboard() {
+        _ = clip.set_string(state.str_slice(range.clone()).as_ref());
+    }
+    state.delete_range(range)
+}
+
+/// Copy the range to the clipboard and reset it.
+fn kill_masked(state: &mut MaskedInputState, range: Range<upos_type>) -> bool {
+    if range.is_empty() {
+        return false;
+    }
+    if let Some(clip) = state.clipboard() {
+        _ = clip.set_string(state.str_slice(range.clone()).as_ref());
+    }
+    state.delete_range(range)
+}
+
+fn tc(r: bool) -> TextOutcome {
+    if r {
+        TextOutcome::TextChanged
+    } else {
+        TextOutcome::Unchanged
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Readline, TextOutcome> for TextInputState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Readline) -> TextOutcome {
+        if self.focus.get() {
+            let r = match event {
+                ct_event!(key press CONTROL-'a') => self.move_to_line_start(false).into(),
+                ct_event!(key press CONTROL-'e') => self.move_to_line_end(false).into(),
+                ct_event!(key press ALT-'f') => self.move_to_next_word(false).into(),
+                ct_event!(key press ALT-'b') => self.move_to_prev_word(false).into(),
+                ct_event!(key press CONTROL-'k') => tc(kill_text(self, self.cursor()..self.len())),
+                ct_event!(key press CONTROL-'u') => tc(kill_text(self, 0..self.cursor())),
+                ct_event!(key press CONTROL-'w') => {
+                    let cursor = self.cursor();
+                    tc(kill_text(self, self.prev_word_start(cursor)..cursor))
+                }
+                ct_event!(key press CONTROL-'y') => tc(self.paste_from_clip()),
+                _ => TextOutcome::Continue,
+            };
+            if r != TextOutcome::Continue {
+                return r;
+            }
+        }
+        self.handle(event, Regular)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Readline, TextOutcome> for MaskedInputState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Readline) -> TextOutcome {
+        if self.focus.get() {
+            let r = match event {
+                ct_event!(key press CONTROL-'a') => self.move_to_line_start(false).into(),
+                ct_event!(key press CONTROL-'e') => self.move_to_line_end(false).into(),
+                ct_event!(key press ALT-'f') => self.move_to_next_section(false).into(),
+                ct_event!(key press ALT-'b') => self.move_to_prev_section(false).into(),
+                ct_event!(key press CONTROL-'k') => {
+                    tc(kill_masked(self, self.cursor()..self.len()))
+                }
+                ct_event!(key press CONTROL-'u') => tc(kill_masked(self, 0..self.cursor())),
+                ct_event!(key press CONTROL-'w') => tc(self.delete_prev_section()),
+                ct_event!(key press CONTROL-'y') => tc(self.paste_from_clip()),
+                _ => TextOutcome::Continue,
+            };
+            if r != TextOutcome::Continue {
+                return r;
+            }
+        }
+        self.handle(event, Regular)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Readline, TextOutcome> for DateInputState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Readline) -> TextOutcome {
+        self.widget.handle(event, Readline)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Readline, TextOutcome> for NumberInputState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Readline) -> TextOutcome {
+        self.widget.handle(event, Readline)
+    }
+}
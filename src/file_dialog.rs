@@ -5,6 +5,7 @@
 use crate::_private::NonExhaustive;
 use crate::button::{Button, ButtonOutcome, ButtonState, ButtonStyle};
 use crate::event::{FileOutcome, TextOutcome};
+use crate::focus::FocusMemory;
 use crate::layout::{layout_dialog, layout_grid, DialogItem};
 use crate::list::edit::{EditList, EditListState};
 use crate::list::selection::RowSelection;
@@ -101,6 +102,8 @@ pub struct FileDialogState {
     new_state: ButtonState,
     cancel_state: ButtonState,
     ok_state: ButtonState,
+
+    focus_memory: FocusMemory,
 }
 
 pub(crate) mod event {
@@ -184,6 +187,7 @@ impl Debug for FileDialogState {
             .field("name_state", &self.save_name_state)
             .field("cancel_state", &self.cancel_state)
             .field("ok_state", &self.ok_state)
+            .field("focus_memory", &self.focus_memory)
             .finish()
     }
 }
@@ -223,6 +227,7 @@ impl Default for FileDialogState {
             new_state: Default::default(),
             cancel_state: Default::default(),
             ok_state: Default::default(),
+            focus_memory: Default::default(),
         };
         s.use_default_roots = true;
         s.dir_state.list.set_scroll_selection(true);
@@ -614,6 +619,28 @@ impl FileDialogState {
         Self::default()
     }
 
+    /// Remember the currently focused widget of the underlying ui.
+    ///
+    /// Call this before opening the dialog.
+    pub fn remember_focus(&mut self, focus: &Focus) {
+        self.focus_memory.save(focus);
+    }
+
+    /// The widget remembered by [remember_focus](Self::remember_focus).
+    pub fn previous_focus(&self) -> Option<FocusFlag> {
+        self.focus_memory.previous()
+    }
+
+    /// Restore the focus remembered by [remember_focus](Self::remember_focus)
+    /// after the dialog closed.
+    ///
+    /// Falls back to the first widget if the remembered one is
+    /// no longer part of the given Focus. Returns true if the
+    /// remembered widget was found.
+    pub fn restore_focus(&mut self, focus: &Focus) -> bool {
+        self.focus_memory.restore(focus)
+    }
+
     /// Set a filter.
     pub fn set_filter(&mut self, filter: impl Fn(&Path) -> bool + 'static) {
         self.filter = Some(Box::new(filter));
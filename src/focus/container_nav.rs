@@ -0,0 +1,128 @@
+//!
+//! Jump between groups of widgets.
+//!
+
+use rat_focus::{ContainerFlag, Focus, FocusFlag, Navigation};
+use ratatui::layout::Rect;
+use std::ops::Range;
+
+/// Can the widget be reached with keyboard navigation.
+pub(crate) fn reachable(navigable: Navigation) -> bool {
+    matches!(
+        navigable,
+        Navigation::Reach
+            | Navigation::ReachLeaveFront
+            | Navigation::ReachLeaveBack
+            | Navigation::Regular
+    )
+}
+
+/// Start indizes of all groups.
+///
+/// Every start and end of a container starts a new group,
+/// widgets before/after/between containers form groups too.
+fn group_starts(
+    len: usize,
+    containers: &[(ContainerFlag, (Rect, u16), Range<usize>)],
+) -> Vec<usize> {
+    let mut starts = vec![0];
+    for (_, _, range) in containers {
+        starts.push(range.start);
+        starts.push(range.end);
+    }
+    starts.retain(|v| *v < len);
+    starts.sort();
+    starts.dedup();
+    starts
+}
+
+/// First reachable widget in the range.
+fn first_reachable(navigable: &[Navigation], range: Range<usize>) -> Option<usize> {
+    range.into_iter().find(|v| reachable(navigable[*v]))
+}
+
+fn focused_idx(focus: &Focus, flags: &[FocusFlag]) -> Option<usize> {
+    let focused = focus.focused()?;
+    flags.iter().position(|v| *v == focused)
+}
+
+/// Focus the first widget of the next container.
+///
+/// The containers are the ones added with FocusBuilder::container()
+/// or FocusBuilder::start()/end(). Widgets outside of any container
+/// are grouped too.
+///
+/// Wraps around at the end. Doesn't change a focus with
+/// Navigation::Lock.
+///
+/// Returns true if the focus changed.
+pub fn next_container(focus: &Focus) -> bool {
+    let (flags, _, _, navigable, containers) = focus.clone_destruct();
+    let starts = group_starts(flags.len(), &containers);
+    let current = focused_idx(focus, &flags);
+
+    if let Some(current) = current {
+        if navigable[current] == Navigation::Lock {
+            return false;
+        }
+    }
+
+    let next = starts
+        .iter()
+        .filter(|v| current.map(|c| **v > c).unwrap_or(true))
+        .find_map(|v| first_reachable(&navigable, *v..flags.len()))
+        .or_else(|| first_reachable(&navigable, 0..flags.len()));
+
+    match next {
+        Some(next) if Some(next) != current => {
+            focus.focus_flag(flags[next].clone());
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Focus the first widget of the previous container.
+///
+/// See [next_container].
+pub fn prev_container(focus: &Focus) -> bool {
+    let (flags, _, _, navigable, containers) = focus.clone_destruct();
+    let starts = group_starts(flags.len(), &containers);
+    let current = focused_idx(focus, &flags);
+
+    if let Some(current) = current {
+        if navigable[current] == Navigation::Lock {
+            return false;
+        }
+    }
+
+    // start of the current group
+    let group = current
+        .and_then(|c| starts.iter().rev().find(|v| **v <= c).copied())
+        .unwrap_or(flags.len());
+
+    let prev = starts
+        .iter()
+        .enumerate()
+        .rev()
+        .filter(|(_, v)| **v < group)
+        .find_map(|(i, v)| {
+            let end = starts.get(i + 1).copied().unwrap_or(flags.len());
+            first_reachable(&navigable, *v..end)
+        })
+        .or_else(|| {
+            // wrap around
+            starts.iter().enumerate().rev().find_map(|(i, v)| {
+                let end = starts.get(i + 1).copied().unwrap_or(flags.len());
+                first_reachable(&navigable, *v..end)
+            })
+        });
+
+    match prev {
+        Some(prev) if Some(prev) != current => {
+            focus.focus_flag(flags[prev].clone());
+            true
+        }
+        _ => false,
+    }
+}
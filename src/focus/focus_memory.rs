@@ -0,0 +1,73 @@
+//!
+//! Remember the focused widget while a dialog is open.
+//!
+
+use crate::focus::focus_name;
+use rat_focus::{Focus, FocusFlag};
+
+/// Remembers the focused widget of the underlying ui
+/// and restores it later.
+///
+/// Modal dialogs take the focus while they are open.
+/// Call [save](FocusMemory::save) before the dialog opens
+/// and [restore](FocusMemory::restore) after it closed.
+///
+/// The Focus is usually rebuilt in the meantime.
+/// The widget is looked up by its FocusFlag first, and by
+/// the name of the flag second. If it can't be found at
+/// all the first widget gets the focus.
+#[derive(Debug, Default, Clone)]
+pub struct FocusMemory {
+    flag: Option<FocusFlag>,
+}
+
+impl FocusMemory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the currently focused widget.
+    pub fn save(&mut self, focus: &Focus) {
+        self.flag = focus.focused();
+    }
+
+    /// Anything remembered?
+    pub fn is_saved(&self) -> bool {
+        self.flag.is_some()
+    }
+
+    /// The remembered widget.
+    pub fn previous(&self) -> Option<FocusFlag> {
+        self.flag.clone()
+    }
+
+    /// Forget the remembered widget.
+    pub fn clear(&mut self) {
+        self.flag = None;
+    }
+
+    /// Restore the focus to the remembered widget.
+    ///
+    /// Falls back to the first widget if the remembered one
+    /// no longer exists in the given Focus.
+    ///
+    /// Returns true if the remembered widget was found.
+    /// Does nothing and returns false if nothing was remembered.
+    pub fn restore(&mut self, focus: &Focus) -> bool {
+        let Some(flag) = self.flag.take() else {
+            return false;
+        };
+
+        focus.focus_flag(flag.clone());
+        if focus.focused().as_ref() == Some(&flag) {
+            return true;
+        }
+
+        if focus_name(focus, flag.name()) {
+            return true;
+        }
+
+        focus.first();
+        false
+    }
+}
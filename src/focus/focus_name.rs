@@ -0,0 +1,43 @@
+//!
+//! Find widgets by the name of their FocusFlag.
+//!
+
+use rat_focus::{Focus, FocusFlag};
+
+/// Find the FocusFlag with this name.
+///
+/// Returns the first match if names are not unique.
+pub fn flag_by_name(focus: &Focus, name: &str) -> Option<FocusFlag> {
+    if name.is_empty() {
+        return None;
+    }
+    let (flags, ..) = focus.clone_destruct();
+    flags.into_iter().find(|v| v.name() == name)
+}
+
+/// Focus the widget with this name.
+///
+/// This is useful after the widget states have been rebuilt,
+/// the FocusFlags are new instances but keep their names.
+/// Save [Focus::focused_name] before and restore it after the
+/// rebuild.
+///
+/// Returns false and leaves the focus as is if there is no
+/// widget with this name.
+///
+/// ```rust ignore
+/// let name = focus.focused_name();
+/// state = State::new(document);
+/// let focus = build_focus(&state);
+/// if !name.is_some_and(|v| focus_name(&focus, &v)) {
+///     focus.first();
+/// }
+/// ```
+pub fn focus_name(focus: &Focus, name: &str) -> bool {
+    if let Some(flag) = flag_by_name(focus, name) {
+        focus.focus_flag(flag);
+        true
+    } else {
+        false
+    }
+}
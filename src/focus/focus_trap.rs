@@ -0,0 +1,83 @@
+//!
+//! Keep the focus inside a modal layer.
+//!
+
+use crate::focus::FocusMemory;
+use rat_focus::{ContainerFlag, Focus, FocusBuilder, FocusContainer, FocusFlag};
+
+/// Restricts the focus to the widgets of a modal layer.
+///
+/// While active the Focus is built for the modal container only,
+/// so Tab/BackTab cycle within the modal and mouse clicks on the
+/// background don't move the focus.
+///
+/// ```rust ignore
+/// // open
+/// state.trap.activate(&focus, &state.dialog);
+///
+/// // each event
+/// let focus = state.trap.build(&state.dialog, || app_focus(state));
+///
+/// // close
+/// state.trap.deactivate();
+/// ```
+///
+/// The focused widget of the underlying ui is remembered on
+/// [activate](FocusTrap::activate) and restored with the first
+/// [build](FocusTrap::build) after [deactivate](FocusTrap::deactivate).
+#[derive(Debug, Default, Clone)]
+pub struct FocusTrap {
+    modal: Option<ContainerFlag>,
+    memory: FocusMemory,
+}
+
+impl FocusTrap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Trap the focus in the given container.
+    ///
+    /// Remembers the currently focused widget and focuses
+    /// the first widget of the modal.
+    pub fn activate(&mut self, focus: &Focus, modal: &dyn FocusContainer) {
+        self.memory.save(focus);
+        self.modal = modal.container();
+
+        focus.none();
+        FocusBuilder::for_container(modal).first();
+    }
+
+    /// Release the focus. The remembered focus is restored
+    /// with the next [build](FocusTrap::build).
+    pub fn deactivate(&mut self) {
+        self.modal = None;
+    }
+
+    /// Focus is trapped.
+    pub fn is_active(&self) -> bool {
+        self.modal.is_some()
+    }
+
+    /// The widget that was focused when the trap was activated.
+    pub fn previous(&self) -> Option<FocusFlag> {
+        self.memory.previous()
+    }
+
+    /// Build the Focus for the current event.
+    ///
+    /// While active this is the Focus of the modal container,
+    /// otherwise the result of `build`. After deactivation the
+    /// remembered focus is restored once.
+    pub fn build(&mut self, modal: &dyn FocusContainer, build: impl FnOnce() -> Focus) -> Focus {
+        if self.is_active() {
+            FocusBuilder::for_container(modal)
+        } else {
+            let focus = build();
+            if self.memory.is_saved() {
+                self.memory.restore(&focus);
+            }
+            focus
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//!
+//! Focus follows the mouse.
+//!
+
+use rat_focus::{Focus, FocusFlag, Navigation};
+use std::time::{Duration, Instant};
+
+/// Hover-to-focus instead of click-to-focus.
+///
+/// Feed every event to [handle](FocusFollowsMouse::handle). The
+/// widget under the mouse is found with the areas registered
+/// in the Focus, the topmost area wins.
+///
+/// * With a dwell delay the focus switches only after the mouse
+///   rested on a widget for that long. Mouse moves stop while
+///   the mouse rests, so call [expire](FocusFollowsMouse::expire)
+///   from a timer too.
+/// * While the focused widget has an open popup (an area with
+///   z > 0) the focus is never taken away.
+/// * A focused widget with Navigation::Lock keeps the focus.
+/// * Widgets with Navigation::Mouse are focused when hovered
+///   like every other widget, they just can't be reached with
+///   the keyboard. Widgets with Navigation::None are never
+///   focused.
+///
+/// ```rust ignore
+/// if state.follow_mouse.handle(&focus, event, Some(Duration::from_millis(300))) {
+///     r = Outcome::Changed;
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct FocusFollowsMouse {
+    hovered: Option<FocusFlag>,
+    since: Option<Instant>,
+    delay: Option<Duration>,
+}
+
+impl FocusFollowsMouse {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Widget under the mouse.
+    pub fn hovered(&self) -> Option<FocusFlag> {
+        self.hovered.clone()
+    }
+
+    /// Track the mouse and switch the focus.
+    ///
+    /// Returns true if the focus changed.
+    pub fn handle(
+        &mut self,
+        focus: &Focus,
+        event: &crossterm::event::Event,
+        delay: Option<Duration>,
+    ) -> bool {
+        self.handle_at(focus, event, delay, Instant::now())
+    }
+
+    /// Same as [handle](Self::handle) with an explicit time.
+    pub fn handle_at(
+        &mut self,
+        focus: &Focus,
+        event: &crossterm::event::Event,
+        delay: Option<Duration>,
+        now: Instant,
+    ) -> bool {
+        use crossterm::event::{Event, MouseEventKind};
+
+        let Event::Mouse(mouse) = event else {
+            return false;
+        };
+        if !matches!(
+            mouse.kind,
+            MouseEventKind::Moved | MouseEventKind::ScrollUp | MouseEventKind::ScrollDown
+        ) {
+            return false;
+        }
+
+        let hovered = widget_at(focus, mouse.column, mouse.row);
+        if hovered != self.hovered {
+            self.hovered = hovered;
+            self.since = Some(now);
+        }
+        self.delay = delay;
+
+        self.expire_at(focus, now)
+    }
+
+    /// Switch the focus if the dwell delay has passed.
+    ///
+    /// Returns true if the focus changed.
+    pub fn expire(&mut self, focus: &Focus) -> bool {
+        self.expire_at(focus, Instant::now())
+    }
+
+    /// Same as [expire](Self::expire) with an explicit time.
+    pub fn expire_at(&mut self, focus: &Focus, now: Instant) -> bool {
+        let Some(hovered) = self.hovered.clone() else {
+            return false;
+        };
+        let Some(since) = self.since else {
+            return false;
+        };
+        if let Some(delay) = self.delay {
+            if now.duration_since(since) < delay {
+                return false;
+            }
+        }
+
+        if focus.focused().as_ref() == Some(&hovered) {
+            self.since = None;
+            return false;
+        }
+        if focus.navigation() == Some(Navigation::Lock) || popup_open(focus) {
+            return false;
+        }
+
+        self.since = None;
+        focus.focus_flag(hovered);
+        true
+    }
+}
+
+/// Topmost widget at the position.
+fn widget_at(focus: &Focus, col: u16, row: u16) -> Option<FocusFlag> {
+    let (flags, _, areas, navigable, _) = focus.clone_destruct();
+    let mut found: Option<(usize, u16)> = None;
+    for (idx, (area, z)) in areas.iter().enumerate() {
+        if navigable[idx] == Navigation::None || !area.contains((col, row).into()) {
+            continue;
+        }
+        if found.map(|(_, zz)| zz <= *z).unwrap_or(true) {
+            found = Some((idx, *z));
+        }
+    }
+    found.map(|(idx, _)| flags[idx].clone())
+}
+
+/// The focused widget has a visible area with z > 0.
+fn popup_open(focus: &Focus) -> bool {
+    let Some(focused) = focus.focused() else {
+        return false;
+    };
+    let (flags, _, areas, _, _) = focus.clone_destruct();
+    flags
+        .iter()
+        .zip(areas.iter())
+        .any(|(flag, (area, z))| *flag == focused && *z > 0 && !area.is_empty())
+}
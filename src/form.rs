@@ -0,0 +1,561 @@
+//!
+//! Jump to a field of a form by its label.
+//!
+//! [FieldPalette] lists the labels of a [GenericLayout] with
+//! their page. Typing filters the list, Enter returns the
+//! chosen field.
+//!
+//! ```rust ignore
+//! state.palette.set_layout(state.pager.layout());
+//!
+//! // render last
+//! FieldPalette::new().render(area, buf, &mut state.palette);
+//!
+//! // events first, the palette is modal.
+//! match state.palette.handle(event, Dialog) {
+//!     PaletteOutcome::Selected(flag, page) => {
+//!         state.pager.set_page(page);
+//!         focus.focus_flag(flag);
+//!         Outcome::Changed
+//!     }
+//!     r => r.into(),
+//! }
+//! ```
+//!
+//! Only [FormLabel::Str](crate::layout::FormLabel::Str) and
+//! [FormLabel::String](crate::layout::FormLabel::String) labels
+//! have a text. Fields without one are not listed.
+//!
+use crate::_private::NonExhaustive;
+use crate::layout::GenericLayout;
+use crate::util::{reset_buf_area, revert_style};
+use rat_event::util::{item_at, MouseFlags};
+use rat_event::{ct_event, ConsumedEvent, Dialog, HandleEvent, Outcome};
+use rat_focus::FocusFlag;
+use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Line;
+#[cfg(feature = "unstable-widget-ref")]
+use ratatui::widgets::StatefulWidgetRef;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::cmp::min;
+use std::rc::Rc;
+
+/// Renders the palette as a centered popup.
+#[derive(Debug, Clone)]
+pub struct FieldPalette<'a> {
+    style: Style,
+    select_style: Option<Style>,
+    page_style: Option<Style>,
+    block: Option<Block<'a>>,
+    width: u16,
+    height: u16,
+}
+
+/// Combined style.
+#[derive(Debug, Clone)]
+pub struct FieldPaletteStyle {
+    pub style: Style,
+    pub select: Option<Style>,
+    /// Page number.
+    pub page: Option<Style>,
+    pub block: Option<Block<'static>>,
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// One field of the palette.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FieldEntry {
+    /// Label text.
+    pub label: String,
+    /// Page of the field.
+    pub page: usize,
+    /// Focus of the field.
+    pub flag: FocusFlag,
+}
+
+/// State & event-handling.
+#[derive(Debug, Clone)]
+pub struct FieldPaletteState {
+    /// Full area.
+    /// __read only__. renewed for each render.
+    pub area: Rect,
+    /// Area inside the block.
+    /// __read only__. renewed for each render.
+    pub inner: Rect,
+    /// Filter line.
+    /// __read only__. renewed for each render.
+    pub filter_area: Rect,
+    /// Rows of the list.
+    /// __read only__. renewed for each render.
+    pub row_areas: Vec<Rect>,
+
+    /// Palette is active.
+    /// __read+write__
+    pub active: bool,
+    /// Layout the fields are taken from.
+    /// __read+write__
+    pub layout: Rc<GenericLayout<FocusFlag>>,
+    /// All fields with a label.
+    /// __read only__. renewed with open().
+    pub entries: Vec<FieldEntry>,
+    /// Filter text.
+    /// __read only__
+    pub filter: String,
+    /// Entries matching the filter.
+    /// __read only__
+    pub matches: Vec<usize>,
+    /// Selected index into matches.
+    /// __read+write__
+    pub selected: usize,
+    /// First visible match.
+    /// __read+write__
+    pub offset: usize,
+
+    /// Mouse flags.
+    /// __read+write__
+    pub mouse: MouseFlags,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// Result of event-handling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaletteOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// A field has been chosen. Focus the flag and
+    /// switch to the page. The palette is closed.
+    Selected(FocusFlag, usize),
+}
+
+impl ConsumedEvent for PaletteOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != PaletteOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for PaletteOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            PaletteOutcome::Changed
+        } else {
+            PaletteOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for PaletteOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => PaletteOutcome::Continue,
+            Outcome::Unchanged => PaletteOutcome::Unchanged,
+            Outcome::Changed => PaletteOutcome::Changed,
+        }
+    }
+}
+
+impl From<PaletteOutcome> for Outcome {
+    fn from(value: PaletteOutcome) -> Self {
+        match value {
+            PaletteOutcome::Continue => Outcome::Continue,
+            PaletteOutcome::Unchanged => Outcome::Unchanged,
+            PaletteOutcome::Changed => Outcome::Changed,
+            PaletteOutcome::Selected(_, _) => Outcome::Changed,
+        }
+    }
+}
+
+impl Default for FieldPalette<'_> {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            select_style: None,
+            page_style: None,
+            block: None,
+            width: 40,
+            height: 12,
+        }
+    }
+}
+
+impl Default for FieldPaletteStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            select: None,
+            page: None,
+            block: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl<'a> FieldPalette<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Combined style.
+    pub fn styles(mut self, styles: FieldPaletteStyle) -> Self {
+        self.style = styles.style;
+        if styles.select.is_some() {
+            self.select_style = styles.select;
+        }
+        if styles.page.is_some() {
+            self.page_style = styles.page;
+        }
+        if styles.block.is_some() {
+            self.block = styles.block;
+        }
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Base style.
+    pub fn style(mut self, style: impl Into<Style>) -> Self {
+        self.style = style.into();
+        self.block = self.block.map(|v| v.style(self.style));
+        self
+    }
+
+    /// Style for the selected field.
+    pub fn select_style(mut self, style: impl Into<Style>) -> Self {
+        self.select_style = Some(style.into());
+        self
+    }
+
+    /// Style for the page number.
+    pub fn page_style(mut self, style: impl Into<Style>) -> Self {
+        self.page_style = Some(style.into());
+        self
+    }
+
+    /// Block.
+    ///
+    /// __Default__
+    /// Bordered with title "Go to".
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block.style(self.style));
+        self
+    }
+
+    /// Size of the popup. It is centered in the render area.
+    ///
+    /// __Default__
+    /// 40x12
+    pub fn size(mut self, width: u16, height: u16) -> Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+}
+
+#[cfg(feature = "unstable-widget-ref")]
+impl<'a> StatefulWidgetRef for FieldPalette<'a> {
+    type State = FieldPaletteState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for FieldPalette<'_> {
+    type State = FieldPaletteState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_ref(&self, area, buf, state);
+    }
+}
+
+fn render_ref(
+    widget: &FieldPalette<'_>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut FieldPaletteState,
+) {
+    state.row_areas.clear();
+    if !state.active {
+        state.area = Default::default();
+        state.inner = Default::default();
+        state.filter_area = Default::default();
+        return;
+    }
+
+    let width = min(widget.width, area.width);
+    let height = min(widget.height, area.height);
+    state.area = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let block;
+    let block = if let Some(block) = &widget.block {
+        block
+    } else {
+        block = Block::bordered().title("Go to").style(widget.style);
+        &block
+    };
+    state.inner = block.inner(state.area);
+
+    reset_buf_area(state.area, buf);
+    block.render(state.area, buf);
+
+    state.filter_area =
+        Rect::new(state.inner.x, state.inner.y, state.inner.width, 1).intersection(state.inner);
+    Line::from(format!("> {}", state.filter)).render(state.filter_area, buf);
+
+    let list_area = Rect::new(
+        state.inner.x,
+        state.inner.y.saturating_add(1),
+        state.inner.width,
+        state.inner.height.saturating_sub(1),
+    );
+    let page_len = list_area.height as usize;
+    if state.selected < state.offset {
+        state.offset = state.selected;
+    } else if page_len > 0 && state.selected >= state.offset + page_len {
+        state.offset = state.selected + 1 - page_len;
+    }
+
+    let select_style = widget.select_style.unwrap_or(revert_style(widget.style));
+    let page_style = widget
+        .page_style
+        .unwrap_or(widget.style.add_modifier(Modifier::DIM));
+
+    for (row, idx) in state
+        .matches
+        .iter()
+        .enumerate()
+        .skip(state.offset)
+        .take(page_len)
+    {
+        let entry = &state.entries[*idx];
+        let row_area = Rect::new(
+            list_area.x,
+            list_area.y + (row - state.offset) as u16,
+            list_area.width,
+            1,
+        );
+        state.row_areas.push(row_area);
+
+        Line::from(entry.label.as_str()).render(row_area, buf);
+        Line::from(format!("{}", entry.page + 1))
+            .alignment(Alignment::Right)
+            .style(page_style)
+            .render(row_area, buf);
+        if row == state.selected {
+            buf.set_style(row_area, select_style);
+        }
+    }
+}
+
+impl Default for FieldPaletteState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            inner: Default::default(),
+            filter_area: Default::default(),
+            row_areas: Default::default(),
+            active: false,
+            layout: Default::default(),
+            entries: Default::default(),
+            filter: Default::default(),
+            matches: Default::default(),
+            selected: 0,
+            offset: 0,
+            mouse: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for FieldPaletteState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        self.filter_area = relocate_area(self.filter_area, shift, clip);
+        relocate_areas(&mut self.row_areas, shift, clip);
+    }
+}
+
+impl FieldPaletteState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the layout.
+    pub fn set_layout(&mut self, layout: Rc<GenericLayout<FocusFlag>>) {
+        self.layout = layout;
+    }
+
+    /// Palette is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Show the palette with the fields of the current layout.
+    pub fn open(&mut self) {
+        self.entries.clear();
+        for idx in 0..self.layout.widget_len() {
+            let Some(label) = self.layout.label_str(idx) else {
+                continue;
+            };
+            let flag = self.layout.widget_key(idx);
+            let page = self.layout.page_of(flag.clone()).unwrap_or_default();
+            self.entries.push(FieldEntry {
+                label: label.to_string(),
+                page,
+                flag,
+            });
+        }
+
+        self.active = true;
+        self.filter.clear();
+        self.offset = 0;
+        self.update_matches();
+    }
+
+    /// Hide the palette.
+    pub fn close(&mut self) {
+        self.active = false;
+        self.filter.clear();
+        self.entries.clear();
+        self.matches.clear();
+    }
+
+    /// Set the filter text.
+    pub fn set_filter(&mut self, filter: impl Into<String>) {
+        self.filter = filter.into();
+        self.update_matches();
+    }
+
+    /// Currently selected field.
+    pub fn selected_entry(&self) -> Option<&FieldEntry> {
+        self.matches
+            .get(self.selected)
+            .and_then(|v| self.entries.get(*v))
+    }
+
+    // Case-insensitive substring match.
+    fn update_matches(&mut self) {
+        let filter = self.filter.to_lowercase();
+        self.matches = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.label.to_lowercase().contains(&filter))
+            .map(|(idx, _)| idx)
+            .collect();
+        self.selected = 0;
+        self.offset = 0;
+    }
+
+    /// Choose the selected field and close.
+    fn choose(&mut self) -> PaletteOutcome {
+        if let Some(entry) = self.selected_entry() {
+            let r = PaletteOutcome::Selected(entry.flag.clone(), entry.page);
+            self.close();
+            r
+        } else {
+            PaletteOutcome::Unchanged
+        }
+    }
+
+    fn move_down(&mut self) -> bool {
+        if self.selected + 1 < self.matches.len() {
+            self.selected += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn move_up(&mut self) -> bool {
+        if self.selected > 0 {
+            self.selected -= 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Ctrl+G opens the palette. While it is active the palette
+/// consumes all events.
+impl HandleEvent<crossterm::event::Event, Dialog, PaletteOutcome> for FieldPaletteState {
+    fn handle(&mut self, event: &crossterm::event::Event, _: Dialog) -> PaletteOutcome {
+        if !self.active {
+            return match event {
+                ct_event!(key press CONTROL-'g') => {
+                    self.open();
+                    PaletteOutcome::Changed
+                }
+                _ => PaletteOutcome::Continue,
+            };
+        }
+
+        match event {
+            ct_event!(keycode press Esc) => {
+                self.close();
+                PaletteOutcome::Changed
+            }
+            ct_event!(keycode press Enter) => self.choose(),
+            ct_event!(keycode press Down) => self.move_down().into(),
+            ct_event!(keycode press Up) => self.move_up().into(),
+            ct_event!(keycode press Backspace) => {
+                if self.filter.pop().is_some() {
+                    self.update_matches();
+                    PaletteOutcome::Changed
+                } else {
+                    PaletteOutcome::Unchanged
+                }
+            }
+            ct_event!(key press c) | ct_event!(key press SHIFT-c) => {
+                self.filter.push(*c);
+                self.update_matches();
+                PaletteOutcome::Changed
+            }
+            ct_event!(mouse down Left for x, y) => {
+                if let Some(row) = item_at(&self.row_areas, *x, *y) {
+                    self.selected = self.offset + row;
+                    self.choose()
+                } else if !self.area.contains((*x, *y).into()) {
+                    self.close();
+                    PaletteOutcome::Changed
+                } else {
+                    PaletteOutcome::Unchanged
+                }
+            }
+            ct_event!(scroll down for x, y) if self.area.contains((*x, *y).into()) => {
+                self.move_down().into()
+            }
+            ct_event!(scroll up for x, y) if self.area.contains((*x, *y).into()) => {
+                self.move_up().into()
+            }
+            // mandatory consume everything else.
+            _ => PaletteOutcome::Unchanged,
+        }
+    }
+}
+
+/// Handle events for the palette.
+pub fn handle_events(
+    state: &mut FieldPaletteState,
+    event: &crossterm::event::Event,
+) -> PaletteOutcome {
+    HandleEvent::handle(state, event, Dialog)
+}
@@ -52,6 +52,15 @@ where
     blocks: Vec<Option<Block<'static>>>,
 }
 
+/// Result of [GenericLayout::widget_at].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitPart {
+    /// Label area.
+    Label,
+    /// Widget area.
+    Widget,
+}
+
 impl<W> Default for GenericLayout<W>
 where
     W: Eq + Hash + Clone,
@@ -208,7 +217,38 @@ where
             return None;
         };
 
-        Some((self.widget_areas[idx].y / self.page_size.height) as usize)
+        // a layout without pages has a page height of 0.
+        let page = self.widget_areas[idx]
+            .y
+            .checked_div(self.page_size.height)
+            .unwrap_or_default();
+        Some(page as usize)
+    }
+
+    /// Find the widget at the given position.
+    ///
+    /// The position is relative to the top-left corner
+    /// of the page. Widget areas are checked before labels.
+    pub fn widget_at(&self, page: usize, pos: Position) -> Option<(W, HitPart)> {
+        let page_y = (page as u32) * (self.page_size.height as u32) + pos.y as u32;
+        let page_y = u16::try_from(page_y).ok()?;
+        self.widget_at_pos(Position::new(pos.x, page_y))
+    }
+
+    /// Find the widget at the given position in layout coordinates.
+    ///
+    /// Widget areas are checked before labels.
+    pub fn widget_at_pos(&self, pos: Position) -> Option<(W, HitPart)> {
+        if let Some(idx) = self.widget_areas.iter().position(|v| v.contains(pos)) {
+            return self
+                .rwidgets
+                .get(&idx)
+                .map(|v| (v.clone(), HitPart::Widget));
+        }
+        if let Some(idx) = self.label_areas.iter().position(|v| v.contains(pos)) {
+            return self.rwidgets.get(&idx).map(|v| (v.clone(), HitPart::Label));
+        }
+        None
     }
 
     /// Any widgets/blocks?
@@ -10,6 +10,12 @@ pub enum DialogItem {
     Content,
     /// Button area
     Buttons,
+    /// Area of the left button group.
+    /// Only with [layout_dialog2].
+    LeftButtons,
+    /// Area of the right button group.
+    /// Only with [layout_dialog2].
+    RightButtons,
     /// Area for the nth button.
     Button(usize),
 }
@@ -55,3 +61,47 @@ pub fn layout_dialog<const N: usize>(
 
     gen_layout
 }
+
+/// Calculates a layout for a dialog with two groups of buttons.
+///
+/// The button row is split in two halves, the left buttons
+/// are placed in the left half with left_flex, the right buttons
+/// in the right half with right_flex.
+///
+/// The buttons are numbered left to right, so the left group
+/// is Button(0)..Button(L) and the right group is
+/// Button(L)..Button(L+R).
+#[allow(clippy::too_many_arguments)]
+pub fn layout_dialog2<const L: usize, const R: usize>(
+    area: Rect,
+    padding: Padding,
+    left_buttons: [Constraint; L],
+    left_flex: Flex,
+    right_buttons: [Constraint; R],
+    right_flex: Flex,
+    button_spacing: u16,
+) -> GenericLayout<DialogItem> {
+    let mut gen_layout = layout_dialog(area, padding, [], button_spacing, Flex::Start);
+
+    let button_area = gen_layout.widget_for(DialogItem::Buttons);
+    let l_groups = Layout::horizontal([Constraint::Fill(1), Constraint::Fill(1)])
+        .spacing(button_spacing)
+        .split(button_area);
+
+    let l_left = Layout::horizontal(left_buttons)
+        .spacing(button_spacing)
+        .flex(left_flex)
+        .areas::<L>(l_groups[0]);
+    let l_right = Layout::horizontal(right_buttons)
+        .spacing(button_spacing)
+        .flex(right_flex)
+        .areas::<R>(l_groups[1]);
+
+    gen_layout.add(DialogItem::LeftButtons, l_groups[0], None, Rect::default());
+    gen_layout.add(DialogItem::RightButtons, l_groups[1], None, Rect::default());
+    for (n, area) in l_left.iter().chain(l_right.iter()).enumerate() {
+        gen_layout.add(DialogItem::Button(n), *area, None, Rect::default());
+    }
+
+    gen_layout
+}
@@ -127,6 +127,21 @@ pub enum FormWidget {
     WideStretchXY(u16, u16),
 }
 
+/// Position of the labels.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LabelPosition {
+    /// Label left of the widget.
+    #[default]
+    Left,
+    /// Label in a row above the widget.
+    Above,
+    /// Label left of the widget if the layout is at least
+    /// this wide, otherwise above the widget.
+    ///
+    /// __unit: cols__
+    Auto(u16),
+}
+
 /// Create a layout with a single column of label+widget.
 ///
 /// There are a number of possible constraints that influence
@@ -151,6 +166,7 @@ pub enum FormWidget {
 /// * Spacing/Line spacing.
 /// * Supports Flex.
 /// * Manual page breaks.
+/// * Labels left of or above the widgets, see [LabelPosition].
 ///
 #[derive(Debug)]
 pub struct LayoutForm<W>
@@ -165,6 +181,8 @@ where
     mirror: bool,
     /// Flex
     flex: Flex,
+    /// Label position.
+    label_position: LabelPosition,
     /// Areas
     widgets: Vec<WidgetDef<W>>,
     /// Containers/Blocks
@@ -274,6 +292,8 @@ struct Page {
     bottom: u16,
     // maximum widget + label height
     max_height: u16,
+    // labels above the widgets
+    label_above: bool,
 
     // page number
     page_no: u16,
@@ -328,6 +348,7 @@ where
             line_spacing: Default::default(),
             mirror: Default::default(),
             flex: Default::default(),
+            label_position: Default::default(),
             widgets: Default::default(),
             page_breaks: Default::default(),
             max_label: Default::default(),
@@ -380,6 +401,16 @@ where
         self
     }
 
+    /// Position of the labels. Default is left of the widget.
+    ///
+    /// [LabelPosition::Auto] is evaluated for each layout, so
+    /// rebuilding the layout after a resize reflows the form.
+    #[inline]
+    pub fn label_position(mut self, position: LabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
     /// Set a reference label width
     pub fn min_label(mut self, width: u16) -> Self {
         self.max_label = width;
@@ -531,12 +562,27 @@ where
         self.page_breaks.push(self.widgets.len() - 1);
     }
 
+    // Labels above the widgets for this layout width?
+    fn is_label_above(&self, page_width: u16) -> bool {
+        match self.label_position {
+            LabelPosition::Left => false,
+            LabelPosition::Above => true,
+            LabelPosition::Auto(min_width) => page_width < min_width,
+        }
+    }
+
     // Adjust widths to the available sapce.
-    fn adjust_widths(&mut self, page_width: u16, border: Padding) {
+    fn adjust_widths(&mut self, page_width: u16, border: Padding, label_above: bool) {
         // cut excess
         let page_width = page_width.saturating_sub(
             border.left + self.max_left_padding + self.max_right_padding + border.right,
         );
+        if label_above {
+            // label and widget share the column.
+            self.max_label = min(self.max_label, page_width);
+            self.max_widget = min(self.max_widget, page_width);
+            return;
+        }
         if self.max_label + self.spacing + self.max_widget > page_width {
             let mut reduce = self.max_label + self.spacing + self.max_widget - page_width;
 
@@ -583,7 +629,14 @@ where
     }
 
     // Find horizontal positions for label and widget.
-    fn find_pos(&self, layout_width: u16, border: Padding) -> Positions {
+    fn find_pos(&self, layout_width: u16, border: Padding, label_above: bool) -> Positions {
+        // with the labels above label and widget share one column.
+        let (max_label, spacing, max_widget) = if label_above {
+            (0, 0, max(self.max_label, self.max_widget))
+        } else {
+            (self.max_label, self.spacing, self.max_widget)
+        };
+
         let label_x;
         let widget_x;
         let container_left;
@@ -593,73 +646,77 @@ where
         match self.flex {
             Flex::Legacy => {
                 label_x = border.left + self.max_left_padding;
-                widget_x = label_x + self.max_label + self.spacing;
+                widget_x = label_x + max_label + spacing;
 
                 container_left = label_x.saturating_sub(self.max_left_padding);
                 container_right = layout_width.saturating_sub(border.right);
 
-                total_width = self.max_label + self.spacing + self.max_widget;
+                total_width = max_label + spacing + max_widget;
             }
             Flex::Start => {
                 label_x = border.left + self.max_left_padding;
-                widget_x = label_x + self.max_label + self.spacing;
+                widget_x = label_x + max_label + spacing;
 
                 container_left = label_x.saturating_sub(self.max_left_padding);
-                container_right = widget_x + self.max_widget + self.max_right_padding;
+                container_right = widget_x + max_widget + self.max_right_padding;
 
-                total_width = self.max_label + self.spacing + self.max_widget;
+                total_width = max_label + spacing + max_widget;
             }
             Flex::Center => {
                 let rest = layout_width.saturating_sub(
                     border.left
                         + self.max_left_padding
-                        + self.max_label
-                        + self.spacing
-                        + self.max_widget
+                        + max_label
+                        + spacing
+                        + max_widget
                         + self.max_right_padding
                         + border.right,
                 );
                 label_x = border.left + self.max_left_padding + rest / 2;
-                widget_x = label_x + self.max_label + self.spacing;
+                widget_x = label_x + max_label + spacing;
 
                 container_left = label_x.saturating_sub(self.max_left_padding);
-                container_right = widget_x + self.max_widget + self.max_right_padding;
+                container_right = widget_x + max_widget + self.max_right_padding;
 
-                total_width = self.max_label + self.spacing + self.max_widget;
+                total_width = max_label + spacing + max_widget;
             }
             Flex::End => {
-                widget_x = layout_width
-                    .saturating_sub(border.right + self.max_right_padding + self.max_widget);
-                label_x = widget_x.saturating_sub(self.spacing + self.max_label);
+                widget_x =
+                    layout_width.saturating_sub(border.right + self.max_right_padding + max_widget);
+                label_x = widget_x.saturating_sub(spacing + max_label);
 
                 container_left = label_x.saturating_sub(self.max_left_padding);
                 container_right = layout_width.saturating_sub(border.right);
 
-                total_width = self.max_label + self.spacing + self.max_widget;
+                total_width = max_label + spacing + max_widget;
             }
             Flex::SpaceAround => {
                 let rest = layout_width.saturating_sub(
                     border.left
                         + self.max_left_padding
-                        + self.max_label
-                        + self.max_widget
+                        + max_label
+                        + max_widget
                         + self.max_right_padding
                         + border.right,
                 );
-                let spacing = rest / 3;
+                let spacing = if label_above { rest / 2 } else { rest / 3 };
 
                 label_x = border.left + self.max_left_padding + spacing;
-                widget_x = label_x + self.max_label + spacing;
+                widget_x = if label_above {
+                    label_x
+                } else {
+                    label_x + max_label + spacing
+                };
 
                 container_left = border.left;
                 container_right = layout_width.saturating_sub(border.right);
 
-                total_width = self.max_label + spacing + self.max_widget;
+                total_width = max_label + spacing + max_widget;
             }
             Flex::SpaceBetween => {
                 label_x = border.left + self.max_left_padding;
-                widget_x = layout_width
-                    .saturating_sub(border.right + self.max_right_padding + self.max_widget);
+                widget_x =
+                    layout_width.saturating_sub(border.right + self.max_right_padding + max_widget);
 
                 container_left = label_x.saturating_sub(self.max_left_padding);
                 container_right = layout_width.saturating_sub(border.right);
@@ -670,14 +727,26 @@ where
             }
         }
 
-        Positions {
-            container_left,
-            label_x,
-            label_width: self.max_label,
-            widget_x,
-            widget_width: self.max_widget,
-            container_right,
-            total_width,
+        if label_above {
+            Positions {
+                container_left,
+                label_x: widget_x,
+                label_width: self.max_label,
+                widget_x,
+                widget_width: self.max_widget,
+                container_right,
+                total_width,
+            }
+        } else {
+            Positions {
+                container_left,
+                label_x,
+                label_width: max_label,
+                widget_x,
+                widget_width: max_widget,
+                container_right,
+                total_width,
+            }
         }
     }
 
@@ -696,12 +765,14 @@ where
     /// Calculate the layout for the given page size and padding.
     fn _layout<const ENDLESS: bool>(mut self, page: Size, border: Padding) -> GenericLayout<W> {
         self.validate_containers();
-        self.adjust_widths(page.width, border);
-        let pos_even = self.find_pos(page.width, border);
+        let label_above = self.is_label_above(page.width);
+        self.adjust_widths(page.width, border, label_above);
+        let pos_even = self.find_pos(page.width, border, label_above);
         let pos_odd = if self.mirror {
             self.find_pos(
                 page.width,
                 Padding::new(border.right, border.left, border.top, border.bottom),
+                label_above,
             )
         } else {
             pos_even
@@ -720,6 +791,7 @@ where
             top: border.top,
             bottom: border.bottom,
             max_height: page.height.saturating_sub(border.top + border.bottom),
+            label_above,
 
             page_no: 0,
             y_page: 0,
@@ -931,12 +1003,13 @@ impl Page {
         widget: &WidgetDef<W>,
         pos: &Positions,
     ) -> (Rect, Rect) {
-        let stacked = matches!(
-            widget.widget,
-            FormWidget::Wide(_, _)
-                | FormWidget::WideStretchX(_, _)
-                | FormWidget::WideStretchXY(_, _)
-        );
+        let stacked = self.label_above
+            || matches!(
+                widget.widget,
+                FormWidget::Wide(_, _)
+                    | FormWidget::WideStretchX(_, _)
+                    | FormWidget::WideStretchXY(_, _)
+            );
 
         let mut label_height = match &widget.label {
             FormLabel::None => 0,
@@ -5,9 +5,9 @@ mod layout_form;
 mod layout_grid;
 mod layout_middle;
 
-pub use generic_layout::GenericLayout;
-pub use layout_dialog::{layout_dialog, DialogItem};
+pub use generic_layout::{GenericLayout, HitPart};
+pub use layout_dialog::{layout_dialog, layout_dialog2, DialogItem};
 pub use layout_edit::{layout_edit, EditConstraint};
-pub use layout_form::{FormLabel, FormWidget, LayoutForm};
+pub use layout_form::{FormLabel, FormWidget, LabelPosition, LayoutForm};
 pub use layout_grid::layout_grid;
 pub use layout_middle::layout_middle;
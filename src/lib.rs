@@ -10,6 +10,25 @@ pub mod event {
     //!
     //! Event-handler traits and Keybindings.
     //!
+    //! All outcome types of this crate follow the same rules:
+    //!
+    //! * `Continue` means the event was not used. Everything else
+    //!   is consumed ([ConsumedEvent]) and further processing
+    //!   may stop.
+    //! * They convert from [Outcome] and from `bool`
+    //!   (true → Changed, false → Unchanged).
+    //! * They convert into [Outcome]. Any widget specific result
+    //!   becomes `Outcome::Changed`.
+    //!
+    //! This way results of different widgets chain with `or_else`
+    //! and fold into a plain Outcome.
+    //!
+    //! ```rust ignore
+    //! let r: Outcome = state.pager.handle(event, Regular).into();
+    //! let r = r.or_else(|| state.calendar.handle(event, Regular).into());
+    //! let r = r.or_else(|| state.ok.handle(event, Regular).into());
+    //! ```
+    //!
 
     pub use rat_event::*;
 
@@ -22,6 +41,17 @@ pub mod event {
     pub use rat_popup::event::PopupOutcome;
     pub use rat_scrolled::event::ScrollOutcome;
     pub use rat_text::event::{ReadOnly, TextOutcome};
+
+    mod commit;
+    pub use commit::{handle_commit, CommitOutcome};
+    mod hover;
+    pub use hover::HoverOutcome;
+    mod key_chord;
+    pub use key_chord::{ChordOutcome, KeyChords};
+    mod named;
+    pub use named::handle_named;
+    mod readline;
+    pub use readline::Readline;
 }
 
 /// Module for focus-handling functionality.
@@ -31,6 +61,68 @@ pub mod focus {
         handle_focus, match_focus, on_gained, on_lost, ContainerFlag, Focus, FocusBuilder,
         FocusContainer, FocusFlag, HasFocus, Navigation,
     };
+
+    pub(crate) mod container_nav;
+    mod focus_memory;
+    mod focus_name;
+    mod focus_trap;
+    mod follow_mouse;
+    pub use container_nav::{next_container, prev_container};
+    pub use focus_memory::FocusMemory;
+    pub use focus_name::{flag_by_name, focus_name};
+    pub use focus_trap::FocusTrap;
+    pub use follow_mouse::FocusFollowsMouse;
+}
+
+/// Commonly used widgets, states, styles and the event-handling traits.
+///
+/// ```
+/// use rat_widget::prelude::*;
+/// ```
+pub mod prelude {
+    pub use crate::event::{
+        ct_event, flow, ConsumedEvent, Dialog, HandleEvent, MouseOnly, Outcome, Popup, Regular,
+    };
+    pub use crate::focus::{Focus, FocusBuilder, FocusFlag, HasFocus};
+    pub use crate::reloc::RelocatableState;
+
+    pub use crate::button::{Button, ButtonOutcome, ButtonState, ButtonStyle};
+    pub use crate::calendar::{
+        Agenda, AgendaState, AgendaStyle, Month, MonthState, MonthStyle, SelectedDate,
+        SelectionMove,
+    };
+    pub use crate::checkbox::{Checkbox, CheckboxState, CheckboxStyle};
+    pub use crate::choice::{Choice, ChoiceState, ChoiceStyle};
+    pub use crate::clipper::{Clipper, ClipperState, ClipperStyle};
+    pub use crate::date_input::{DateInput, DateInputState};
+    pub use crate::event::FileOutcome;
+    pub use crate::event::{
+        CalOutcome, MenuOutcome, PagerOutcome, ScrollOutcome, TabbedOutcome, TextOutcome,
+    };
+    pub use crate::file_dialog::{FileDialog, FileDialogState, FileDialogStyle};
+    pub use crate::layout::{GenericLayout, LayoutForm};
+    pub use crate::list::{List, ListState, ListStyle};
+    pub use crate::menu::{
+        MenuLine, MenuLineState, MenuStyle, Menubar, MenubarState, PopupMenu, PopupMenuState,
+    };
+    pub use crate::msgdialog::{MsgDialog, MsgDialogState, MsgDialogStyle};
+    pub use crate::number_input::{NumberInput, NumberInputState};
+    pub use crate::pager::{DualPager, DualPagerState, PagerStyle, SinglePager, SinglePagerState};
+    pub use crate::paragraph::{Paragraph, ParagraphState, ParagraphStyle};
+    pub use crate::popup::Placement;
+    pub use crate::radio::{Radio, RadioState, RadioStyle};
+    pub use crate::rect_adjust::{RectAdjust, RectAdjustState};
+    pub use crate::scrolled::{Scroll, ScrollStyle};
+    pub use crate::slider::{Slider, SliderState, SliderStyle};
+    pub use crate::splitter::{Split, SplitState, SplitStyle};
+    pub use crate::statusline::{StatusLine, StatusLineState};
+    pub use crate::tabbed::{Tabbed, TabbedState, TabbedStyle};
+    pub use crate::table::{Table, TableData, TableState, TableStyle};
+    pub use crate::text::TextStyle;
+    pub use crate::text_input::{TextInput, TextInputState};
+    pub use crate::text_input_mask::{MaskedInput, MaskedInputState};
+    pub use crate::textarea::{TextArea, TextAreaState};
+    pub use crate::view::{View, ViewState, ViewStyle};
 }
 
 /// Some functions that calculate more complicate layouts.
@@ -61,6 +153,9 @@ pub mod text {
         ipos_type, screen_cursor, upos_type, Cursor, Glyph, Grapheme, HasScreenCursor, Locale,
         TextError, TextPosition, TextRange, TextStyle,
     };
+
+    pub mod ctrl_glyph;
+    pub mod graphemes;
 }
 
 // --- widget modules here --- (alphabetical)
@@ -79,7 +174,9 @@ pub mod date_input {
         handle_events, handle_mouse_events, handle_readonly_events, DateInput, DateInputState,
     };
 }
+pub mod empty;
 pub mod file_dialog;
+pub mod form;
 /// Line numbers widget.
 /// For use with TextArea mostly.
 pub mod line_number {
@@ -103,6 +200,9 @@ pub mod menu {
     pub mod popup_menu {
         pub use rat_menu::popup_menu::{handle_mouse_events, handle_popup_events};
     }
+
+    pub mod overflow_menuline;
+    pub mod vertical_menubar;
 }
 pub mod msgdialog;
 /// Number input with pattern.
@@ -113,6 +213,9 @@ pub mod number_input {
     pub use rat_text::number_input::{
         handle_events, handle_mouse_events, handle_readonly_events, NumberInput, NumberInputState,
     };
+
+    pub mod rounding;
+    pub mod spin;
 }
 pub mod pager;
 pub mod paired;
@@ -120,8 +223,19 @@ pub mod paragraph;
 /// PopupCore helps with managing popup widgets.
 pub mod popup {
     pub use rat_popup::{Placement, PopupConstraint, PopupCore, PopupCoreState, PopupStyle};
+
+    mod placement_strategy;
+    mod popup_group;
+    pub use placement_strategy::PlacementStrategy;
+    pub use popup_group::PopupGroup;
 }
 pub mod radio;
+pub mod rect_adjust;
+pub mod render_timing;
+pub mod reset;
+pub mod scroll_indicator;
+pub mod scroll_step;
+pub mod scroll_to;
 pub mod shadow;
 pub mod splitter;
 pub mod statusline;
@@ -142,6 +256,17 @@ pub mod table {
         edit, selection, textdata, Table, TableContext, TableData, TableDataIter, TableSelection,
         TableState, TableStyle,
     };
+
+    pub mod column_nav;
+    pub mod columns;
+    pub mod debug;
+    pub mod empty;
+    pub mod export;
+    pub mod fit;
+    pub mod group;
+    pub mod hover;
+    pub mod styles;
+    pub mod tree;
 }
 pub mod tabbed;
 /// Text-Input widget
@@ -155,6 +280,13 @@ pub mod text_input {
     pub use rat_text::text_input::{
         handle_events, handle_mouse_events, handle_readonly_events, TextInput, TextInputState,
     };
+
+    pub mod clip_fill;
+    pub mod field_fill;
+    pub mod overflow;
+    pub mod paste;
+    pub mod select_on_focus;
+    pub mod transform;
 }
 /// Text-Input with pattern/mask.
 ///
@@ -167,6 +299,8 @@ pub mod text_input_mask {
     pub use rat_text::text_input_mask::{
         handle_events, handle_mouse_events, handle_readonly_events, MaskedInput, MaskedInputState,
     };
+
+    pub mod section;
 }
 /// Text-Area.
 ///
@@ -179,10 +313,19 @@ pub mod textarea {
     pub use rat_text::text_area::{
         handle_events, handle_mouse_events, handle_readonly_events, TextArea, TextAreaState,
     };
+
+    pub mod cursor_margin;
+    pub mod goto;
+    pub mod marks;
+    pub mod search;
+    pub mod wheel;
+    pub mod whitespace;
 }
 pub mod range_op;
 pub mod slider;
 pub mod util;
+pub mod validate;
+pub mod value_history;
 pub mod view;
 
 mod _private {
@@ -3,21 +3,33 @@
 //!
 
 use crate::_private::NonExhaustive;
-use crate::event::util::MouseFlags;
+use crate::empty::EmptyRender;
+use crate::event::util::{item_at, MouseFlags};
+use crate::event::{ConsumedEvent, HandleEvent, HoverOutcome, MouseOnly, Outcome, Regular};
 use crate::list::selection::{RowSelection, RowSetSelection};
-use crate::util::{fallback_select_style, revert_style};
+use crate::scroll_indicator::{
+    MoreIndicator, ScrollIndicator, ScrollIndicatorOverlay, ScrollIndicatorState,
+    ScrollIndicatorStyle,
+};
+use crate::scroll_step::ScrollStep;
+use crate::scroll_to::ScrollTo;
+use crate::util::{fallback, fallback_select_style, render_mode, revert_style, RenderMode};
 use rat_focus::{FocusFlag, HasFocus};
 use rat_reloc::{relocate_area, relocate_areas, RelocatableState};
 use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState, ScrollStyle};
 use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
-use ratatui::widgets::{Block, ListDirection, ListItem, StatefulWidget};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Text};
+use ratatui::widgets::{Block, ListDirection, ListItem, StatefulWidget, Widget};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::{StatefulWidgetRef, WidgetRef};
 use std::cmp::min;
 use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{Debug, Formatter};
 use std::marker::PhantomData;
+use std::rc::Rc;
 
 pub mod edit;
 
@@ -45,15 +57,52 @@ pub struct List<'a, Selection> {
     scroll: Option<Scroll<'a>>,
 
     items: Vec<ListItem<'a>>,
+    // separators/headers before the given item.
+    decorations: Vec<(usize, ListDecoration<'a>)>,
+    group_headers: Option<GroupHeaderFn<'a>>,
+    sticky_headers: bool,
+    empty_message: Option<Text<'a>>,
+    empty_style: Option<Style>,
+    empty_render: Option<EmptyRender<'a>>,
+    indicator: Option<ScrollIndicator>,
+    indicator_style: Option<ScrollIndicatorStyle>,
+    more_indicator: bool,
+    more_style: Option<Style>,
+    scroll_step: Option<ScrollStep>,
+    hover_highlight: bool,
+    has_more: bool,
+    loading_text: Option<Line<'a>>,
 
     style: Style,
     select_style: Option<Style>,
     focus_style: Option<Style>,
+    separator_style: Option<Style>,
+    header_style: Option<Style>,
+    hover_style: Option<Style>,
+    loading_style: Option<Style>,
     direction: ListDirection,
 
     _phantom: PhantomData<Selection>,
 }
 
+/// Non-selectable rows between the items.
+#[derive(Debug, Clone)]
+enum ListDecoration<'a> {
+    Separator,
+    Header(Line<'a>),
+}
+
+/// Header function for groups of items.
+#[allow(clippy::type_complexity)]
+#[derive(Clone)]
+struct GroupHeaderFn<'a>(Rc<dyn Fn(usize) -> Option<Line<'a>> + 'a>);
+
+impl Debug for GroupHeaderFn<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("GroupHeaderFn")
+    }
+}
+
 /// Collected styles.
 #[derive(Debug, Clone)]
 pub struct ListStyle {
@@ -63,13 +112,81 @@ pub struct ListStyle {
     pub select: Option<Style>,
     /// Style for selection when focused.
     pub focus: Option<Style>,
+    /// Style for separators.
+    pub separator: Option<Style>,
+    /// Style for section headers.
+    pub header: Option<Style>,
 
     pub block: Option<Block<'static>>,
     pub scroll: Option<ScrollStyle>,
+    /// Scroll position overlay.
+    pub indicator: Option<ScrollIndicatorStyle>,
+    /// Style for the more above/below marks.
+    pub more: Option<Style>,
+    /// Style for the hovered item.
+    pub hover: Option<Style>,
+    /// Style for the loading row.
+    pub loading: Option<Style>,
 
     pub non_exhaustive: NonExhaustive,
 }
 
+/// Result of event handling with [handle_load_more].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ListOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// The end of the list has been reached and more
+    /// items should be loaded.
+    LoadMore,
+}
+
+impl ConsumedEvent for ListOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != ListOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for ListOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            ListOutcome::Changed
+        } else {
+            ListOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for ListOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => ListOutcome::Continue,
+            Outcome::Unchanged => ListOutcome::Unchanged,
+            Outcome::Changed => ListOutcome::Changed,
+        }
+    }
+}
+
+impl From<ListOutcome> for Outcome {
+    fn from(value: ListOutcome) -> Self {
+        match value {
+            ListOutcome::Continue => Outcome::Continue,
+            ListOutcome::Unchanged => Outcome::Unchanged,
+            ListOutcome::Changed => Outcome::Changed,
+            ListOutcome::LoadMore => Outcome::Changed,
+        }
+    }
+}
+
 /// State & event handling.
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListState<Selection> {
@@ -82,13 +199,42 @@ pub struct ListState<Selection> {
     /// Areas for the rendered items.
     /// __readonly__. renewed for each render.
     pub row_areas: Vec<Rect>,
+    /// The first visible row is covered by a sticky header.
+    /// __readonly__. renewed for each render.
+    pub sticky_header: bool,
+    /// Display rows of the headers if they are sticky.
+    /// __readonly__. renewed for each render.
+    header_rows: Vec<usize>,
 
     /// Length in items.
     /// __mostly readonly__. renewed for each render.
     pub rows: usize,
+    /// Maps the display rows to the items. Separators
+    /// and headers map to None.
+    /// Empty if there are no separators/headers.
+    /// __readonly__. renewed for each render.
+    pub row_items: Vec<Option<usize>>,
+    /// Maps the items to the display rows.
+    /// Empty if there are no separators/headers.
+    /// __readonly__. renewed for each render.
+    pub item_rows: Vec<usize>,
     /// Offset etc.
     /// __read+write__
     pub scroll: ScrollState,
+    /// Scroll position overlay.
+    /// __read only__
+    pub indicator: ScrollIndicatorState,
+    /// Hovered item.
+    /// __read only__ renewed by [handle_hover].
+    pub hovered: Option<usize>,
+    /// Hover tracking is active.
+    /// __read only__ renewed for each render.
+    hover_highlight: bool,
+    /// A loading row follows the items.
+    /// __read only__ renewed for each render.
+    pub has_more: bool,
+    /// Number of items when LoadMore was reported.
+    load_more_at: Option<usize>,
 
     /// Focus
     /// __read+write__
@@ -108,8 +254,14 @@ impl Default for ListStyle {
             style: Default::default(),
             select: None,
             focus: None,
+            separator: None,
+            header: None,
             block: None,
             scroll: None,
+            indicator: None,
+            more: None,
+            hover: None,
+            loading: None,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -128,9 +280,27 @@ impl<'a, Selection> List<'a, Selection> {
             block: None,
             scroll: None,
             items,
+            decorations: Default::default(),
+            group_headers: None,
+            sticky_headers: false,
+            empty_message: None,
+            empty_style: None,
+            empty_render: None,
+            indicator: None,
+            indicator_style: None,
+            more_indicator: false,
+            more_style: None,
+            scroll_step: None,
+            hover_highlight: false,
+            has_more: false,
+            loading_text: None,
             style: Default::default(),
             select_style: Default::default(),
             focus_style: Default::default(),
+            separator_style: Default::default(),
+            header_style: Default::default(),
+            hover_style: Default::default(),
+            loading_style: Default::default(),
             direction: Default::default(),
             _phantom: Default::default(),
         }
@@ -147,11 +317,165 @@ impl<'a, Selection> List<'a, Selection> {
         self
     }
 
+    /// Add an item.
+    pub fn item(mut self, item: impl Into<ListItem<'a>>) -> Self {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Add a separator line after the current items.
+    ///
+    /// Separators can't be selected and don't count as items,
+    /// the selection still refers to the index of the item.
+    pub fn separator(mut self) -> Self {
+        self.decorations
+            .push((self.items.len(), ListDecoration::Separator));
+        self
+    }
+
+    /// Add a section header after the current items.
+    ///
+    /// Headers can't be selected and don't count as items,
+    /// the selection still refers to the index of the item.
+    pub fn header(mut self, header: impl Into<Line<'a>>) -> Self {
+        self.decorations
+            .push((self.items.len(), ListDecoration::Header(header.into())));
+        self
+    }
+
+    /// Group the items.
+    ///
+    /// The function is called for each item when rendering and
+    /// returns the header if a new group starts with this item.
+    /// The header of the current group stays visible at the top
+    /// while scrolling, see [sticky_headers](List::sticky_headers).
+    pub fn group_headers<F>(mut self, headers: F) -> Self
+    where
+        F: Fn(usize) -> Option<Line<'a>> + 'a,
+    {
+        self.group_headers = Some(GroupHeaderFn(Rc::new(headers)));
+        self.sticky_headers = true;
+        self
+    }
+
+    /// There are more items to load.
+    ///
+    /// Shows a loading row after the items. It can't be selected.
+    /// Use [handle_load_more] to know when to load the next items.
+    pub fn has_more(mut self, has_more: bool) -> Self {
+        self.has_more = has_more;
+        self
+    }
+
+    /// Text of the loading row.
+    ///
+    /// __Default__
+    /// "loading…"
+    pub fn loading_text(mut self, text: impl Into<Line<'a>>) -> Self {
+        self.loading_text = Some(text.into());
+        self
+    }
+
+    /// Style for the loading row.
+    pub fn loading_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.loading_style = Some(style.into());
+        self
+    }
+
+    /// Keep the header of the current section visible at the
+    /// top of the list while scrolling through the section.
+    pub fn sticky_headers(mut self, sticky: bool) -> Self {
+        self.sticky_headers = sticky;
+        self
+    }
+
+    /// Message shown in the middle of the list if
+    /// there are no items. Uses the base style dimmed.
+    #[inline]
+    pub fn empty_message(mut self, message: impl Into<Text<'a>>) -> Self {
+        self.empty_message = Some(message.into());
+        self
+    }
+
+    /// Message shown in the middle of the list if
+    /// there are no items, with its own style.
+    #[inline]
+    pub fn empty_text(mut self, message: impl Into<Text<'a>>, style: Style) -> Self {
+        self.empty_message = Some(message.into());
+        self.empty_style = Some(style);
+        self
+    }
+
+    /// Render something else if there are no items.
+    ///
+    /// Called with the inner area after the block and the
+    /// scrollbar. Replaces the [empty_message](List::empty_message).
+    #[inline]
+    pub fn empty_render(mut self, render: impl Fn(Rect, &mut Buffer) + 'a) -> Self {
+        self.empty_render = Some(EmptyRender::new(render));
+        self
+    }
+
+    /// Show the scroll position in the bottom-right corner
+    /// while scrolling.
+    #[inline]
+    pub fn indicator(mut self, indicator: ScrollIndicator) -> Self {
+        self.indicator = Some(indicator);
+        self
+    }
+
+    /// Mark the top/bottom edge with ▲/▼ if there are more
+    /// items above/below.
+    #[inline]
+    pub fn more_indicator(mut self, show: bool) -> Self {
+        self.more_indicator = show;
+        self
+    }
+
+    /// Style for the more above/below marks.
+    ///
+    /// __Default__
+    /// The base style.
+    #[inline]
+    pub fn more_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.more_style = Some(style.into());
+        self
+    }
+
+    /// Highlight the item under the mouse cursor.
+    ///
+    /// This needs [handle_hover] to track the mouse.
+    /// The hovered item is not selected.
+    #[inline]
+    pub fn hover_highlight(mut self, hover: bool) -> Self {
+        self.hover_highlight = hover;
+        self
+    }
+
+    /// Style for the hovered item.
+    ///
+    /// __Default__
+    /// The base style underlined.
+    #[inline]
+    pub fn hover_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.hover_style = Some(style.into());
+        self
+    }
+
+    /// How far the mouse wheel scrolls.
+    ///
+    /// __Default__
+    /// 1/10 of the page.
+    #[inline]
+    pub fn scroll_step(mut self, step: ScrollStep) -> Self {
+        self.scroll_step = Some(step);
+        self
+    }
+
     /// Border support.
     #[inline]
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.block = Some(block);
-        self.block = self.block.map(|v| v.style(self.style));
         self
     }
 
@@ -182,13 +506,30 @@ impl<'a, Selection> List<'a, Selection> {
         if styles.focus.is_some() {
             self.focus_style = styles.focus;
         }
+        if styles.separator.is_some() {
+            self.separator_style = styles.separator;
+        }
+        if styles.header.is_some() {
+            self.header_style = styles.header;
+        }
         if let Some(styles) = styles.scroll {
             self.scroll = self.scroll.map(|v| v.styles(styles));
         }
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
-        self.block = self.block.map(|v| v.style(self.style));
+        if styles.indicator.is_some() {
+            self.indicator_style = styles.indicator;
+        }
+        if styles.more.is_some() {
+            self.more_style = styles.more;
+        }
+        if styles.hover.is_some() {
+            self.hover_style = styles.hover;
+        }
+        if styles.loading.is_some() {
+            self.loading_style = styles.loading;
+        }
         self
     }
 
@@ -196,7 +537,6 @@ impl<'a, Selection> List<'a, Selection> {
     #[inline]
     pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
         self.style = style.into();
-        self.block = self.block.map(|v| v.style(self.style));
         self
     }
 
@@ -214,6 +554,20 @@ impl<'a, Selection> List<'a, Selection> {
         self
     }
 
+    /// Separator style.
+    #[inline]
+    pub fn separator_style<S: Into<Style>>(mut self, separator_style: S) -> Self {
+        self.separator_style = Some(separator_style.into());
+        self
+    }
+
+    /// Header style.
+    #[inline]
+    pub fn header_style<S: Into<Style>>(mut self, header_style: S) -> Self {
+        self.header_style = Some(header_style.into());
+        self
+    }
+
     /// List direction.
     #[inline]
     pub fn direction(mut self, direction: ListDirection) -> Self {
@@ -265,11 +619,128 @@ fn render_list<Selection: ListSelection>(
         .v_scroll(widget.scroll.as_ref());
     state.inner = sa.inner(area, None, Some(&state.scroll));
 
+    // NoColor reserves a column for the selection marker.
+    let marker = render_mode() == RenderMode::NoColor;
+    let list_area = if marker {
+        Rect::new(
+            state.inner.x.saturating_add(2),
+            state.inner.y,
+            state.inner.width.saturating_sub(2),
+            state.inner.height,
+        )
+    } else {
+        state.inner
+    };
+
+    // the focused selection builds on the select style.
+    let focus_style = fallback(
+        &[
+            widget.select_style,
+            Some(widget.focus_style.unwrap_or(revert_style(widget.style))),
+        ],
+        widget.style,
+    );
+    let select_style = fallback(
+        &[Some(
+            widget
+                .select_style
+                .unwrap_or(fallback_select_style(widget.style)),
+        )],
+        widget.style,
+    );
+    let separator_style = widget
+        .separator_style
+        .unwrap_or(widget.style.add_modifier(Modifier::DIM));
+    let header_style = widget
+        .header_style
+        .unwrap_or(widget.style.add_modifier(Modifier::BOLD));
+
+    let (style, select_style) = if state.is_focused() {
+        (widget.style, focus_style)
+    } else {
+        (widget.style, select_style)
+    };
+    let hover_style = fallback(
+        &[Some(widget.hover_style.unwrap_or(
+            widget.style.add_modifier(Modifier::UNDERLINED),
+        ))],
+        widget.style,
+    );
+    state.hover_highlight = widget.hover_highlight;
+    if !state.hover_highlight {
+        state.hovered = None;
+    }
+    let loading_style = widget
+        .loading_style
+        .unwrap_or(widget.style.add_modifier(Modifier::DIM | Modifier::ITALIC));
+    state.has_more = widget.has_more;
+
+    // merge items and separators/headers into display rows.
+    state.row_items.clear();
+    state.item_rows.clear();
+    let mut headers = Vec::new();
+    let mut decorations = widget.decorations;
+    if let Some(group_headers) = &widget.group_headers {
+        for i in 0..widget.items.len() {
+            if let Some(header) = (group_headers.0)(i) {
+                decorations.push((i, ListDecoration::Header(header)));
+            }
+        }
+        decorations.sort_by_key(|(n, _)| *n);
+    }
+    let mut items = Vec::with_capacity(widget.items.len() + decorations.len());
+    let mut decorations = decorations.into_iter().peekable();
+    for (i, item) in widget.items.into_iter().enumerate() {
+        while let Some((_, decoration)) = decorations.next_if(|(n, _)| *n <= i) {
+            render_decoration(
+                decoration,
+                list_area.width,
+                separator_style,
+                header_style,
+                &mut items,
+                &mut headers,
+                &mut state.row_items,
+            );
+        }
+        state.item_rows.push(items.len());
+        state.row_items.push(Some(i));
+        if state.selection.is_selected(i) {
+            items.push(item.style(select_style));
+        } else if state.hovered == Some(i) {
+            items.push(item.style(hover_style));
+        } else {
+            items.push(item);
+        }
+    }
+    for (_, decoration) in decorations {
+        render_decoration(
+            decoration,
+            list_area.width,
+            separator_style,
+            header_style,
+            &mut items,
+            &mut headers,
+            &mut state.row_items,
+        );
+    }
+    if widget.has_more {
+        let text = widget
+            .loading_text
+            .unwrap_or_else(|| Line::from("loading\u{2026}"));
+        items.push(ListItem::new(text).style(loading_style));
+        state.row_items.push(None);
+    }
+    if headers.is_empty() && state.row_items.len() == state.rows {
+        // identity
+        state.row_items.clear();
+        state.item_rows.clear();
+    }
+
     // area for each item
     state.row_areas.clear();
     let mut item_area = Rect::new(state.inner.x, state.inner.y, state.inner.width, 1);
     let mut total_height = 0;
-    for item in widget.items.iter().skip(state.offset()) {
+    for item in items.iter().skip(state.offset()) {
         item_area.height = item.height() as u16;
 
         state.row_areas.push(item_area);
@@ -288,60 +759,128 @@ fn render_list<Selection: ListSelection>(
         state.scroll.set_page_len(state.row_areas.len());
     }
 
-    let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
-    let select_style = widget
-        .select_style
-        .unwrap_or(fallback_select_style(widget.style));
-
     // max_v_offset
     let mut n = 0;
     let mut height = 0;
-    for item in widget.items.iter().rev() {
+    for item in items.iter().rev() {
         height += item.height();
         if height > state.inner.height as usize {
             break;
         }
         n += 1;
     }
-    state.scroll.set_max_offset(state.rows.saturating_sub(n));
-
-    let (style, select_style) = if state.is_focused() {
-        (widget.style, focus_style)
-    } else {
-        (widget.style, select_style)
-    };
+    state.scroll.set_max_offset(items.len().saturating_sub(n));
+    if let Some(step) = widget.scroll_step {
+        step.apply(&mut state.scroll);
+    }
 
+    buf.set_style(area, widget.style);
     sa.render(
         area,
         buf,
         &mut ScrollAreaState::new().v_scroll(&mut state.scroll),
     );
 
-    // rendering
-    let items = widget
-        .items
-        .into_iter()
-        .enumerate()
-        .map(|(i, v)| {
-            if state.selection.is_selected(i) {
-                v.style(select_style)
-            } else {
-                v.style(style)
-            }
-        })
-        .collect::<Vec<_>>();
+    if state.rows == 0 && !state.has_more {
+        if let Some(empty_render) = &widget.empty_render {
+            empty_render.render(state.inner, buf);
+        } else if let Some(message) = widget.empty_message {
+            let style = widget
+                .empty_style
+                .unwrap_or(widget.style.add_modifier(Modifier::DIM));
+            EmptyRender::text(message, style).render(state.inner, buf);
+        }
+    }
 
+    // rendering
     let mut list_state = ratatui::widgets::ListState::default().with_offset(state.scroll.offset());
 
     StatefulWidget::render(
         ratatui::widgets::List::default()
             .items(items)
-            .style(widget.style)
             .direction(widget.direction),
-        state.inner,
+        list_area,
         buf,
         &mut list_state,
     );
+
+    if marker {
+        for (i, row_area) in state.row_areas.iter().enumerate() {
+            let Some(item) = state.item_of_row(state.scroll.offset() + i) else {
+                continue;
+            };
+            if state.selection.is_selected(item) {
+                buf.set_stringn(row_area.x, row_area.y, ">", row_area.width as usize, style);
+            }
+        }
+    }
+
+    // header of the current section.
+    state.sticky_header = false;
+    state.header_rows.clear();
+    if widget.sticky_headers {
+        state
+            .header_rows
+            .extend(headers.iter().map(|(row, _)| *row));
+        let offset = state.scroll.offset();
+        if let Some(first_area) = state.row_areas.first() {
+            if state.item_of_row(offset).is_some() {
+                if let Some((_, header)) = headers.iter().rev().find(|(row, _)| *row < offset) {
+                    let header_area = Rect::new(list_area.x, first_area.y, list_area.width, 1);
+                    buf.set_style(header_area, header_style);
+                    for x in header_area.left()..header_area.right() {
+                        if let Some(cell) = buf.cell_mut((x, header_area.y)) {
+                            cell.set_symbol(" ");
+                        }
+                    }
+                    header.render(header_area, buf);
+                    state.sticky_header = true;
+                }
+            }
+        }
+    }
+
+    if let Some(indicator) = widget.indicator {
+        let mut overlay = ScrollIndicatorOverlay::new(indicator);
+        if let Some(styles) = widget.indicator_style {
+            overlay = overlay.styles(styles);
+        }
+        overlay.render(state.inner, buf, &state.scroll, &mut state.indicator);
+    }
+
+    if widget.more_indicator {
+        let more_area = Rect::new(
+            area.x,
+            area.y,
+            state.inner.right().saturating_sub(area.x),
+            area.height,
+        );
+        MoreIndicator::new()
+            .style(widget.more_style.unwrap_or(widget.style))
+            .render(more_area, buf, &state.scroll);
+    }
+}
+
+/// Add a separator/header to the display rows.
+fn render_decoration<'a>(
+    decoration: ListDecoration<'a>,
+    width: u16,
+    separator_style: Style,
+    header_style: Style,
+    items: &mut Vec<ListItem<'a>>,
+    headers: &mut Vec<(usize, Line<'a>)>,
+    row_items: &mut Vec<Option<usize>>,
+) {
+    match decoration {
+        ListDecoration::Separator => {
+            items.push(ListItem::new("\u{2500}".repeat(width as usize)).style(separator_style));
+        }
+        ListDecoration::Header(line) => {
+            headers.push((items.len(), line.clone()));
+            items.push(ListItem::new(line).style(header_style));
+        }
+    }
+    row_items.push(None);
 }
 
 impl<Selection> HasFocus for ListState<Selection> {
@@ -362,8 +901,17 @@ impl<Selection: Default> Default for ListState<Selection> {
             area: Default::default(),
             inner: Default::default(),
             row_areas: Default::default(),
+            sticky_header: Default::default(),
+            header_rows: Default::default(),
             rows: Default::default(),
+            row_items: Default::default(),
+            item_rows: Default::default(),
             scroll: Default::default(),
+            indicator: Default::default(),
+            hovered: Default::default(),
+            hover_highlight: Default::default(),
+            has_more: Default::default(),
+            load_more_at: Default::default(),
             focus: Default::default(),
             selection: Default::default(),
             mouse: Default::default(),
@@ -377,8 +925,17 @@ impl<Selection: Clone> Clone for ListState<Selection> {
             area: self.area,
             inner: self.inner,
             row_areas: self.row_areas.clone(),
+            sticky_header: self.sticky_header,
+            header_rows: self.header_rows.clone(),
             rows: self.rows,
+            row_items: self.row_items.clone(),
+            item_rows: self.item_rows.clone(),
             scroll: self.scroll.clone(),
+            indicator: self.indicator.clone(),
+            hovered: self.hovered,
+            hover_highlight: self.hover_highlight,
+            has_more: self.has_more,
+            load_more_at: self.load_more_at,
             focus: FocusFlag::named(self.focus.name()),
             selection: self.selection.clone(),
             mouse: Default::default(),
@@ -395,6 +952,27 @@ impl<Selection> RelocatableState for ListState<Selection> {
     }
 }
 
+/// Item indexes.
+impl<Selection: ListSelection> ScrollTo for ListState<Selection> {
+    type Range = std::ops::Range<usize>;
+
+    fn ensure_visible(&mut self, range: std::ops::Range<usize>) -> bool {
+        let start = self.row_of_item(range.start);
+        let end = if range.end > range.start {
+            self.row_of_item(range.end - 1) + 1
+        } else {
+            start + 1
+        };
+        // keep the row before below the sticky header.
+        let start = if self.sticky_covers(start) {
+            start - 1
+        } else {
+            start
+        };
+        self.scroll.scroll_to_range(start..end)
+    }
+}
+
 impl<Selection: ListSelection> ListState<Selection> {
     /// New initial state.
     pub fn new() -> Self
@@ -464,12 +1042,24 @@ impl<Selection: ListSelection> ListState<Selection> {
         }
     }
 
+    /// Scroll to the given item.
+    ///
+    /// If the item is the first of a section, the header is
+    /// scrolled into view too. Inside a section with a sticky
+    /// header the row before stays at the top, below the header.
     #[inline]
     pub fn scroll_to(&mut self, pos: usize) -> bool {
+        let pos = self.row_of_item(pos);
         if pos >= self.offset() + self.page_len() {
             self.set_offset(pos - self.page_len() + 1)
-        } else if pos < self.offset() {
-            self.set_offset(pos)
+        } else if pos < self.offset() || (pos == self.offset() && self.sticky_covers(pos)) {
+            let header =
+                pos > 0 && !self.item_rows.is_empty() && self.item_of_row(pos - 1).is_none();
+            if header || self.sticky_covers(pos) {
+                self.set_offset(pos - 1)
+            } else {
+                self.set_offset(pos)
+            }
         } else {
             false
         }
@@ -480,6 +1070,35 @@ impl<Selection: ListSelection> ListState<Selection> {
         self.scroll.scroll_up(n)
     }
 
+    /// The loading row is visible or the last item is selected.
+    pub fn at_end(&self) -> bool {
+        if !self.has_more {
+            return false;
+        }
+        // the loading row is the last display row.
+        let loading_row = self.row_items.len().saturating_sub(1);
+        loading_row < self.offset() + self.page_len()
+            || self.selection.lead_selection() == Some(self.rows.saturating_sub(1))
+    }
+
+    /// Should more items be loaded?
+    ///
+    /// Returns true once each time the end of the list is reached.
+    /// Loading more items or leaving the end rearms this.
+    pub fn load_more(&mut self) -> bool {
+        if self.at_end() {
+            if self.load_more_at != Some(self.rows) {
+                self.load_more_at = Some(self.rows);
+                true
+            } else {
+                false
+            }
+        } else {
+            self.load_more_at = None;
+            false
+        }
+    }
+
     #[inline]
     pub fn scroll_down(&mut self, n: usize) -> bool {
         self.scroll.scroll_down(n)
@@ -487,30 +1106,95 @@ impl<Selection: ListSelection> ListState<Selection> {
 }
 
 impl<Selection: ListSelection> ListState<Selection> {
-    /// Returns the row-area for the given row, if it is visible.
-    pub fn row_area(&self, row: usize) -> Option<Rect> {
-        if row < self.scroll.offset() || row >= self.scroll.offset() + self.scroll.page_len() {
-            return None;
+    /// Display row of the item. This differs from the item index
+    /// if there are separators or headers.
+    pub fn row_of_item(&self, item: usize) -> usize {
+        if self.item_rows.is_empty() {
+            item
+        } else {
+            self.item_rows
+                .get(item)
+                .copied()
+                .unwrap_or(self.row_items.len())
         }
+    }
 
-        Some(self.row_areas[row - self.scroll.offset])
+    /// Item at the display row. None for separators and headers.
+    pub fn item_of_row(&self, row: usize) -> Option<usize> {
+        if self.row_items.is_empty() {
+            Some(row)
+        } else {
+            self.row_items.get(row).copied().flatten()
+        }
+    }
+
+    /// Item at the display row, or the next item after
+    /// a separator/header.
+    fn item_near_row(&self, row: usize) -> usize {
+        if self.row_items.is_empty() {
+            row
+        } else {
+            self.row_items
+                .iter()
+                .skip(row)
+                .find_map(|v| *v)
+                .unwrap_or(self.rows.saturating_sub(1))
+        }
+    }
+
+    /// Index into row_areas, None if covered by the sticky header.
+    fn visible_row(&self, idx: usize) -> Option<usize> {
+        if self.sticky_header && idx == 0 {
+            None
+        } else {
+            Some(self.scroll.offset() + idx)
+        }
+    }
+
+    /// The sticky header would cover the display row
+    /// if it were the first visible row.
+    fn sticky_covers(&self, row: usize) -> bool {
+        self.item_of_row(row).is_some() && self.header_rows.iter().any(|v| *v < row)
+    }
+
+    /// Returns the row-area for the given item, if it is visible.
+    /// None if the row is covered by the sticky header.
+    ///
+    /// The areas are renewed with each render.
+    pub fn row_area(&self, row: usize) -> Option<Rect> {
+        self.row_of_item(row)
+            .checked_sub(self.scroll.offset())
+            .filter(|v| self.visible_row(*v).is_some())
+            .and_then(|v| self.row_areas.get(v))
+            .copied()
+    }
+
+    /// Returns the item at the given screen position, if any.
+    ///
+    /// This uses the row-areas of the last render.
+    pub fn index_at(&self, pos: (u16, u16)) -> Option<usize> {
+        item_at(&self.row_areas, pos.0, pos.1)
+            .and_then(|v| self.visible_row(v))
+            .and_then(|v| self.item_of_row(v))
     }
 
     #[inline]
     pub fn row_at_clicked(&self, pos: (u16, u16)) -> Option<usize> {
         self.mouse
             .row_at(&self.row_areas, pos.1)
-            .map(|v| self.scroll.offset() + v)
+            .and_then(|v| self.visible_row(v))
+            .and_then(|v| self.item_of_row(v))
     }
 
-    /// Row when dragging. Can go outside the area.
+    /// Item when dragging. Can go outside the area.
     #[inline]
     pub fn row_at_drag(&self, pos: (u16, u16)) -> usize {
-        match self.mouse.row_at_drag(self.inner, &self.row_areas, pos.1) {
+        let row = match self.mouse.row_at_drag(self.inner, &self.row_areas, pos.1) {
             Ok(v) => self.scroll.offset() + v,
             Err(v) if v <= 0 => self.scroll.offset().saturating_sub((-v) as usize),
             Err(v) => self.scroll.offset() + self.row_areas.len() + v as usize,
-        }
+        };
+        self.item_near_row(row)
     }
 }
 
@@ -708,6 +1392,87 @@ impl ListState<RowSetSelection> {
     }
 }
 
+/// Handle all events.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events<Selection>(
+    state: &mut ListState<Selection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome
+where
+    ListState<Selection>: HandleEvent<crossterm::event::Event, Regular, Outcome>,
+{
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events<Selection>(
+    state: &mut ListState<Selection>,
+    event: &crossterm::event::Event,
+) -> Outcome
+where
+    ListState<Selection>: HandleEvent<crossterm::event::Event, MouseOnly, Outcome>,
+{
+    HandleEvent::handle(state, event, MouseOnly)
+}
+
+/// Handle all events and report when more items should be loaded.
+///
+/// Returns [ListOutcome::LoadMore] once each time the end of
+/// the list is reached, see [List::has_more]. Call it after
+/// the first render too, if you want to fill the list initially.
+pub fn handle_load_more<Selection: ListSelection>(
+    state: &mut ListState<Selection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> ListOutcome
+where
+    ListState<Selection>: HandleEvent<crossterm::event::Event, Regular, Outcome>,
+{
+    let r = handle_events(state, focus, event);
+    if state.load_more() {
+        ListOutcome::LoadMore
+    } else {
+        r.into()
+    }
+}
+
+/// Track the item under the mouse for [List::hover_highlight].
+///
+/// Returns [HoverOutcome::Hovered] when the mouse moves to
+/// another item and [HoverOutcome::Changed] when it leaves the items.
+/// Does nothing if hover_highlight is off.
+pub fn handle_hover<Selection: ListSelection>(
+    state: &mut ListState<Selection>,
+    event: &crossterm::event::Event,
+) -> HoverOutcome {
+    if !state.hover_highlight {
+        return HoverOutcome::Continue;
+    }
+    match event {
+        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
+            kind: crossterm::event::MouseEventKind::Moved,
+            column,
+            row,
+            ..
+        }) => {
+            let hovered = state.index_at((*column, *row));
+            if hovered == state.hovered {
+                HoverOutcome::Continue
+            } else {
+                state.hovered = hovered;
+                match hovered {
+                    Some(n) => HoverOutcome::Hovered(n),
+                    None => HoverOutcome::Changed,
+                }
+            }
+        }
+        _ => HoverOutcome::Continue,
+    }
+}
+
 pub mod selection {
     use crate::event::{ct_event, flow, HandleEvent, MouseOnly, Outcome, Regular};
     use crate::list::{ListSelection, ListState};
@@ -0,0 +1,748 @@
+//!
+//! MenuLine that copes with a narrow terminal.
+//!
+//! If the items don't fit the width, the title is truncated with
+//! an ellipsis first. If that is not enough, the items that don't
+//! fit collapse into a "»" at the right edge, which opens a
+//! [PopupMenu] with the hidden items.
+//!
+//! * Left/Right/Home/End go through all items. Selecting a
+//!   hidden item opens the popup with that item selected.
+//! * Clicking the "»" opens/closes the popup.
+//! * The item areas of the state are empty for the hidden items,
+//!   so hover and clicks only hit the rendered items.
+//!
+//! The results are the same as for the MenuLine, with the index
+//! of the item regardless of where it is shown.
+//!
+//! ```rust ignore
+//! let (line, popup) = OverflowMenuLine::new()
+//!     .title("Sample")
+//!     .item_parsed("_Choose")
+//!     .item_parsed("_Quit")
+//!     .into_widgets();
+//! line.render(menu_area, buf, &mut state.menu);
+//! // after all other widgets
+//! popup.render(menu_area, buf, &mut state.menu);
+//!
+//! let r = overflow_menuline::handle_popup_events(&mut state.menu, focus, event);
+//! // ... the other widgets
+//! let r = r.or_else(|| overflow_menuline::handle_events(&mut state.menu, focus, event));
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::event::MenuOutcome;
+use crate::menu::{MenuBuilder, MenuItem, MenuLineState, MenuStyle, PopupMenu, PopupMenuState};
+use crate::popup::Placement;
+use crate::util::{fallback_select_style, revert_style};
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Popup, Regular};
+use rat_focus::{FocusBuilder, FocusFlag, HasFocus, Navigation};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::cmp::min;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// MenuLine with overflow.
+///
+/// This handles the configuration only, call
+/// [OverflowMenuLine::into_widgets] for the widgets for rendering.
+#[derive(Debug, Clone)]
+pub struct OverflowMenuLine<'a> {
+    title: Line<'a>,
+    menu: MenuBuilder<'a>,
+    overflow: &'a str,
+
+    style: Style,
+    title_style: Option<Style>,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+    highlight_style: Option<Style>,
+    disabled_style: Option<Style>,
+    right_style: Option<Style>,
+
+    popup_placement: Placement,
+    popup: PopupMenu<'a>,
+}
+
+/// Renders the line.
+#[derive(Debug, Clone)]
+pub struct OverflowMenuLineBar<'a> {
+    title: Line<'a>,
+    items: Vec<MenuItem<'a>>,
+    overflow: &'a str,
+
+    style: Style,
+    title_style: Option<Style>,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+    highlight_style: Option<Style>,
+    disabled_style: Option<Style>,
+    right_style: Option<Style>,
+}
+
+/// Renders the popup with the hidden items.
+#[derive(Debug, Clone)]
+pub struct OverflowMenuLinePopup<'a> {
+    items: Vec<MenuItem<'a>>,
+
+    style: Style,
+    focus_style: Option<Style>,
+    highlight_style: Option<Style>,
+    disabled_style: Option<Style>,
+    right_style: Option<Style>,
+
+    popup_placement: Placement,
+    popup: PopupMenu<'a>,
+}
+
+/// State & event-handling.
+#[derive(Debug, Clone)]
+pub struct OverflowMenuLineState {
+    /// Area for the line and the popup.
+    /// __readonly__. renewed for each render.
+    pub area: Rect,
+    /// State of the line.
+    /// The item areas are empty for hidden items.
+    pub line: MenuLineState,
+    /// Area of the overflow marker.
+    /// __readonly__. renewed for each render.
+    pub area_more: Rect,
+    /// First hidden item, if any.
+    /// __readonly__. renewed for each render.
+    pub first_hidden: Option<usize>,
+    /// State of the popup.
+    pub popup: PopupMenuState,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+impl Default for OverflowMenuLine<'_> {
+    fn default() -> Self {
+        Self {
+            title: Default::default(),
+            menu: Default::default(),
+            overflow: "»",
+            style: Default::default(),
+            title_style: None,
+            select_style: None,
+            focus_style: None,
+            highlight_style: None,
+            disabled_style: None,
+            right_style: None,
+            popup_placement: Placement::AboveOrBelow,
+            popup: Default::default(),
+        }
+    }
+}
+
+impl<'a> OverflowMenuLine<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Title text. Truncated first if the items don't fit.
+    #[inline]
+    pub fn title(mut self, title: impl Into<Line<'a>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Add an item.
+    pub fn item(mut self, item: MenuItem<'a>) -> Self {
+        self.menu.item(item);
+        self
+    }
+
+    /// Parse the text.
+    ///
+    /// __See__
+    ///
+    /// [MenuItem::new_parsed]
+    pub fn item_parsed(mut self, text: &'a str) -> Self {
+        self.menu.item_parsed(text);
+        self
+    }
+
+    /// Add a text-item.
+    pub fn item_str(mut self, txt: &'a str) -> Self {
+        self.menu.item_str(txt);
+        self
+    }
+
+    /// Add an owned text as item.
+    pub fn item_string(mut self, txt: String) -> Self {
+        self.menu.item_string(txt);
+        self
+    }
+
+    /// Marker for the hidden items.
+    ///
+    /// __Default__
+    /// "»"
+    pub fn overflow(mut self, overflow: &'a str) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Combined style.
+    #[inline]
+    pub fn styles(mut self, styles: MenuStyle) -> Self {
+        self.popup = self.popup.styles(styles.clone());
+
+        self.style = styles.style;
+        if styles.highlight.is_some() {
+            self.highlight_style = styles.highlight;
+        }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
+        if styles.focus.is_some() {
+            self.focus_style = styles.focus;
+        }
+        if styles.title.is_some() {
+            self.title_style = styles.title;
+        }
+        if styles.select.is_some() {
+            self.select_style = styles.select;
+        }
+        if styles.right.is_some() {
+            self.right_style = styles.right;
+        }
+        if let Some(placement) = styles.popup.placement {
+            self.popup_placement = placement;
+        }
+        self
+    }
+
+    /// Base style.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Menu-title style.
+    #[inline]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = Some(style);
+        self
+    }
+
+    /// Selection
+    #[inline]
+    pub fn select_style(mut self, style: Style) -> Self {
+        self.select_style = Some(style);
+        self
+    }
+
+    /// Selection + Focus
+    #[inline]
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Style for the hotkey.
+    #[inline]
+    pub fn right_style(mut self, style: Style) -> Self {
+        self.right_style = Some(style);
+        self
+    }
+
+    /// Fixed width for the popup.
+    /// If not set it uses 1.5 times the length of the longest item.
+    pub fn popup_width(mut self, width: u16) -> Self {
+        self.popup = self.popup.width(width);
+        self
+    }
+
+    /// Placement relative to the overflow marker. Default is
+    /// [Placement::AboveOrBelow].
+    pub fn popup_placement(mut self, placement: Placement) -> Self {
+        self.popup_placement = placement;
+        self
+    }
+
+    /// Block for borders.
+    pub fn popup_block(mut self, block: Block<'a>) -> Self {
+        self.popup = self.popup.block(block);
+        self
+    }
+
+    /// Create the widgets for the line and for the popup.
+    ///
+    /// The popup should be rendered after all widgets that
+    /// might be below the popup have been rendered.
+    pub fn into_widgets(self) -> (OverflowMenuLineBar<'a>, OverflowMenuLinePopup<'a>) {
+        let items = self.menu.items();
+        (
+            OverflowMenuLineBar {
+                title: self.title,
+                items: items.clone(),
+                overflow: self.overflow,
+                style: self.style,
+                title_style: self.title_style,
+                select_style: self.select_style,
+                focus_style: self.focus_style,
+                highlight_style: self.highlight_style,
+                disabled_style: self.disabled_style,
+                right_style: self.right_style,
+            },
+            OverflowMenuLinePopup {
+                items,
+                style: self.style,
+                focus_style: self.focus_style,
+                highlight_style: self.highlight_style,
+                disabled_style: self.disabled_style,
+                right_style: self.right_style,
+                popup_placement: self.popup_placement,
+                popup: self.popup,
+            },
+        )
+    }
+}
+
+fn item_width(item: &MenuItem<'_>) -> u16 {
+    item.item_width() + item.right_width() + if item.right.is_empty() { 0 } else { 2 }
+}
+
+/// Truncate to the width, with an ellipsis if something is cut.
+fn truncate(text: &str, width: u16) -> String {
+    if Span::from(text).width() as u16 <= width {
+        return text.to_string();
+    }
+    let mut result = String::new();
+    let mut used = 0;
+    for g in text.graphemes(true) {
+        let w = Span::from(g).width() as u16;
+        if used + w + 1 > width {
+            break;
+        }
+        result.push_str(g);
+        used += w;
+    }
+    result.push('…');
+    result
+}
+
+/// Width for the title and the number of visible items.
+fn layout(title_width: u16, item_widths: &[u16], marker_width: u16, width: u16) -> (u16, usize) {
+    let gap = |w: u16| if w > 0 { w + 1 } else { 0 };
+    let items_width = item_widths.iter().sum::<u16>() + item_widths.len().saturating_sub(1) as u16;
+
+    if gap(title_width) + items_width <= width {
+        return (title_width, item_widths.len());
+    }
+    // title with one char and the ellipsis.
+    let min_title = min(title_width, 2);
+    if min_title > 0 && gap(min_title) + items_width <= width {
+        return (width - 1 - items_width, item_widths.len());
+    }
+
+    // room for the marker
+    let available = width.saturating_sub(gap(min_title) + gap(marker_width));
+    let mut used = 0;
+    let mut visible = 0;
+    for w in item_widths {
+        let next = if visible == 0 { *w } else { used + 1 + w };
+        if next > available {
+            break;
+        }
+        used = next;
+        visible += 1;
+    }
+    (min_title, visible)
+}
+
+impl StatefulWidget for OverflowMenuLineBar<'_> {
+    type State = OverflowMenuLineState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_bar(&self, area, buf, state);
+    }
+}
+
+fn render_bar(
+    widget: &OverflowMenuLineBar<'_>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut OverflowMenuLineState,
+) {
+    state.area = area;
+    state.line.area = area;
+    state.line.item_areas.clear();
+    state.line.navchar = widget
+        .items
+        .iter()
+        .map(|v| v.navchar.map(|w| w.to_ascii_lowercase()))
+        .collect();
+    state.line.disabled = widget.items.iter().map(|v| v.disabled).collect();
+
+    let focus_style = if state.line.is_focused() {
+        widget
+            .focus_style
+            .unwrap_or_else(|| revert_style(widget.style))
+    } else {
+        widget
+            .select_style
+            .unwrap_or_else(|| fallback_select_style(widget.style))
+    };
+    let title_style = widget
+        .title_style
+        .unwrap_or_else(|| widget.style.underlined());
+    let highlight_style = widget
+        .highlight_style
+        .unwrap_or_else(|| Style::new().underlined());
+    let right_style = widget.right_style.unwrap_or_else(|| Style::new().italic());
+    let disabled_style = widget.disabled_style.unwrap_or(widget.style);
+
+    buf.set_style(area, widget.style);
+
+    let item_widths = widget.items.iter().map(item_width).collect::<Vec<_>>();
+    let marker_width = Span::from(widget.overflow).width() as u16;
+    let (title_width, visible) = layout(
+        widget.title.width() as u16,
+        &item_widths,
+        marker_width,
+        area.width,
+    );
+    state.first_hidden = if visible < widget.items.len() {
+        Some(visible)
+    } else {
+        None
+    };
+
+    let mut item_area = Rect::new(area.x, area.y, 0, 1);
+
+    if title_width > 0 {
+        item_area.width = title_width;
+        buf.set_style(item_area, title_style);
+        if title_width < widget.title.width() as u16 {
+            Span::from(truncate(&widget.title.to_string(), title_width)).render(item_area, buf);
+        } else {
+            widget.title.clone().render(item_area, buf);
+        }
+        item_area.x += item_area.width + 1;
+    }
+
+    for (n, item) in widget.items.iter().enumerate() {
+        if n >= visible {
+            state.line.item_areas.push(Rect::default());
+            continue;
+        }
+
+        item_area.width = item_widths[n];
+        item_area = item_area.intersection(area);
+        state.line.item_areas.push(item_area);
+
+        let style = if item.disabled {
+            disabled_style
+        } else if state.line.selected == Some(n) {
+            focus_style
+        } else {
+            widget.style
+        };
+        let right_style = style.patch(right_style);
+
+        let item_line = if let Some(highlight) = item.highlight.clone() {
+            Line::from_iter([
+                Span::from(&item.item[..highlight.start - 1]), // account for _
+                Span::from(&item.item[highlight.start..highlight.end]).style(highlight_style),
+                Span::from(&item.item[highlight.end..]),
+                if !item.right.is_empty() {
+                    Span::from(format!("({})", item.right)).style(right_style)
+                } else {
+                    Span::default()
+                },
+            ])
+        } else {
+            Line::from_iter([
+                Span::from(item.item.as_ref()),
+                if !item.right.is_empty() {
+                    Span::from(format!("({})", item.right)).style(right_style)
+                } else {
+                    Span::default()
+                },
+            ])
+        };
+        item_line.style(style).render(item_area, buf);
+
+        item_area.x += item_area.width + 1;
+    }
+
+    if let Some(first_hidden) = state.first_hidden {
+        state.area_more = Rect::new(
+            area.right().saturating_sub(marker_width),
+            area.y,
+            marker_width,
+            1,
+        )
+        .intersection(area);
+        let style = match state.line.selected {
+            Some(n) if n >= first_hidden => focus_style,
+            _ => widget.style,
+        };
+        Span::from(widget.overflow)
+            .style(style)
+            .render(state.area_more, buf);
+    } else {
+        state.area_more = Rect::default();
+        state.popup.set_active(false);
+    }
+}
+
+impl StatefulWidget for OverflowMenuLinePopup<'_> {
+    type State = OverflowMenuLineState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_popup(self, area, buf, state);
+    }
+}
+
+fn render_popup(
+    widget: OverflowMenuLinePopup<'_>,
+    _area: Rect,
+    buf: &mut Buffer,
+    state: &mut OverflowMenuLineState,
+) {
+    state.area = state.line.area;
+
+    let Some(first_hidden) = state.first_hidden else {
+        return;
+    };
+
+    if state.popup.is_active() {
+        let mut popup = widget
+            .popup
+            .constraint(widget.popup_placement.into_constraint(state.area_more))
+            .style(widget.style)
+            .focus_style_opt(widget.focus_style)
+            .highlight_style_opt(widget.highlight_style)
+            .disabled_style_opt(widget.disabled_style)
+            .right_style_opt(widget.right_style);
+        for item in widget.items.into_iter().skip(first_hidden) {
+            popup = popup.item(item);
+        }
+        popup.render(state.area_more, buf, &mut state.popup);
+
+        state.area = state.line.area.union(state.popup.popup.area);
+    } else {
+        state.popup.clear_areas();
+    }
+}
+
+impl Default for OverflowMenuLineState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            line: Default::default(),
+            area_more: Default::default(),
+            first_hidden: None,
+            popup: Default::default(),
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl HasFocus for OverflowMenuLineState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.add_widget(self.focus(), self.area(), self.area_z(), self.navigable());
+        builder.add_widget(
+            self.focus(),
+            self.popup.popup.area,
+            self.popup.popup.area_z,
+            Navigation::Mouse,
+        );
+    }
+
+    fn focus(&self) -> FocusFlag {
+        self.line.focus.clone()
+    }
+
+    fn area(&self) -> Rect {
+        self.line.area
+    }
+}
+
+impl OverflowMenuLineState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// New state with a focus name.
+    pub fn named(name: &str) -> Self {
+        Self {
+            line: MenuLineState::named(name),
+            ..Default::default()
+        }
+    }
+
+    /// Selected item.
+    pub fn selected(&self) -> Option<usize> {
+        self.line.selected()
+    }
+
+    /// Select an item. Opens the popup for a hidden item,
+    /// closes it otherwise.
+    pub fn select(&mut self, select: Option<usize>) -> bool {
+        let r = self.line.select(select);
+        self.sync_popup();
+        r
+    }
+
+    /// Is the item hidden in the popup.
+    pub fn is_hidden(&self, n: usize) -> bool {
+        self.first_hidden.is_some_and(|first| n >= first)
+    }
+
+    /// Popup visible/active.
+    pub fn popup_active(&self) -> bool {
+        self.popup.is_active()
+    }
+
+    /// Popup visible/active.
+    pub fn set_popup_active(&mut self, active: bool) {
+        self.popup.set_active(active);
+    }
+
+    /// Show the selection of the line in the popup.
+    fn sync_popup(&mut self) {
+        match (self.line.selected, self.first_hidden) {
+            (Some(n), Some(first)) if n >= first => {
+                self.popup.select(Some(n - first));
+                self.popup.set_active(true);
+            }
+            _ => {
+                self.popup.select(None);
+                self.popup.set_active(false);
+            }
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Popup, MenuOutcome> for OverflowMenuLineState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Popup) -> MenuOutcome {
+        if !self.is_focused() {
+            self.set_popup_active(false);
+        }
+
+        let Some(first) = self.first_hidden else {
+            return MenuOutcome::Continue;
+        };
+
+        if self.popup_active() {
+            // the marker toggles.
+            if let ct_event!(mouse down Left for x,y) = event {
+                if self.area_more.contains((*x, *y).into()) {
+                    self.set_popup_active(false);
+                    return MenuOutcome::Changed;
+                }
+            }
+            match self.popup.handle(event, Popup) {
+                MenuOutcome::Hide => {
+                    // only hide on focus lost. ignore this one.
+                    MenuOutcome::Continue
+                }
+                MenuOutcome::Selected(n) => {
+                    self.line.selected = Some(first + n);
+                    MenuOutcome::Selected(first + n)
+                }
+                MenuOutcome::Activated(n) => {
+                    self.line.selected = Some(first + n);
+                    MenuOutcome::Activated(first + n)
+                }
+                r => r,
+            }
+        } else {
+            MenuOutcome::Continue
+        }
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, MenuOutcome> for OverflowMenuLineState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> MenuOutcome {
+        if !self.is_focused() {
+            self.set_popup_active(false);
+        }
+
+        if self.line.is_focused() {
+            flow!(match self.line.handle(event, Regular) {
+                MenuOutcome::Selected(n) => {
+                    self.sync_popup();
+                    MenuOutcome::Selected(n)
+                }
+                r => r,
+            });
+        }
+
+        self.handle(event, MouseOnly)
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, MouseOnly, MenuOutcome> for OverflowMenuLineState {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: MouseOnly) -> MenuOutcome {
+        flow!(match event {
+            ct_event!(mouse down Left for x,y) if self.area_more.contains((*x, *y).into()) => {
+                if let Some(first) = self.first_hidden {
+                    match self.line.selected {
+                        Some(n) if n >= first => self.popup.select(Some(n - first)),
+                        _ => self.popup.select(None),
+                    };
+                    self.popup.flip_active();
+                    MenuOutcome::Changed
+                } else {
+                    MenuOutcome::Continue
+                }
+            }
+            _ => MenuOutcome::Continue,
+        });
+
+        match self.line.handle(event, MouseOnly) {
+            MenuOutcome::Selected(n) => {
+                self.sync_popup();
+                MenuOutcome::Selected(n)
+            }
+            r => r,
+        }
+    }
+}
+
+/// Handle the events for the line.
+///
+/// Keyboard events are processed if focus is true.
+/// Mouse events are processed if they are in range.
+///
+/// Attention:
+/// For the event-handling of the popup you need to call handle_popup_events().
+pub fn handle_events(
+    state: &mut OverflowMenuLineState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> MenuOutcome {
+    state.line.focus.set(focus);
+    state.handle(event, Regular)
+}
+
+/// Handle the events for the popup.
+///
+/// Call this before the other event-handlers to cope with
+/// overlapping regions.
+pub fn handle_popup_events(
+    state: &mut OverflowMenuLineState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> MenuOutcome {
+    state.line.focus.set(focus);
+    state.handle(event, Popup)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut OverflowMenuLineState,
+    event: &crossterm::event::Event,
+) -> MenuOutcome {
+    state.handle(event, MouseOnly)
+}
@@ -0,0 +1,529 @@
+//!
+//! Menubar with the main menu running down the side.
+//!
+//! Stacks the titles of the main menu one per row, e.g. for a
+//! sidebar, and opens the submenus to the right of the title row.
+//! Uses the same [MenuStructure] and [MenubarState] as the
+//! [Menubar](crate::menu::Menubar), only the layout and the
+//! keyboard navigation differ:
+//!
+//! * Up/Down move between the titles.
+//! * Right and Enter open the submenu, Left closes it.
+//!
+//! The item areas of the state are one row each, so clicking and
+//! dragging over the titles work as for the Menubar.
+//!
+//! ```rust ignore
+//! let (column, popup) = VerticalMenubar::new(&MENU)
+//!     .styles(THEME.menu_style())
+//!     .into_widgets();
+//! column.render(sidebar_area, buf, &mut state.menu);
+//! // after all other widgets
+//! popup.render(sidebar_area, buf, &mut state.menu);
+//!
+//! let r = vertical_menubar::handle_popup_events(&mut state.menu, focus, event);
+//! // ... the other widgets
+//! let r = r.or_else(|| vertical_menubar::handle_events(&mut state.menu, focus, event));
+//! ```
+//!
+use crate::event::MenuOutcome;
+use crate::menu::{MenuBuilder, MenuItem, MenuStructure, MenuStyle, MenubarState, PopupMenu};
+use crate::popup::Placement;
+use crate::util::{fallback_select_style, revert_style};
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Popup};
+use rat_focus::HasFocus;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Style, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+use std::cmp::max;
+
+/// Vertical menubar.
+///
+/// This handles the configuration only, call
+/// [VerticalMenubar::into_widgets] for the widgets for rendering.
+#[derive(Debug, Clone)]
+pub struct VerticalMenubar<'a> {
+    structure: Option<&'a dyn MenuStructure<'a>>,
+
+    title: Line<'a>,
+    style: Style,
+    title_style: Option<Style>,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+    highlight_style: Option<Style>,
+    disabled_style: Option<Style>,
+    right_style: Option<Style>,
+
+    popup_placement: Placement,
+    popup: PopupMenu<'a>,
+}
+
+/// Renders the column of titles.
+#[derive(Debug, Clone)]
+pub struct VerticalMenubarColumn<'a> {
+    structure: Option<&'a dyn MenuStructure<'a>>,
+
+    title: Line<'a>,
+    style: Style,
+    title_style: Option<Style>,
+    select_style: Option<Style>,
+    focus_style: Option<Style>,
+    highlight_style: Option<Style>,
+    disabled_style: Option<Style>,
+    right_style: Option<Style>,
+}
+
+/// Renders the submenu of the selected title.
+#[derive(Debug, Clone)]
+pub struct VerticalMenubarPopup<'a> {
+    structure: Option<&'a dyn MenuStructure<'a>>,
+
+    style: Style,
+    focus_style: Option<Style>,
+    highlight_style: Option<Style>,
+    disabled_style: Option<Style>,
+    right_style: Option<Style>,
+
+    popup_placement: Placement,
+    popup: PopupMenu<'a>,
+}
+
+impl Default for VerticalMenubar<'_> {
+    fn default() -> Self {
+        Self {
+            structure: None,
+            title: Default::default(),
+            style: Default::default(),
+            title_style: None,
+            select_style: None,
+            focus_style: None,
+            highlight_style: None,
+            disabled_style: None,
+            right_style: None,
+            popup_placement: Placement::Right,
+            popup: Default::default(),
+        }
+    }
+}
+
+impl<'a> VerticalMenubar<'a> {
+    pub fn new(structure: &'a dyn MenuStructure<'a>) -> Self {
+        Self {
+            structure: Some(structure),
+            ..Default::default()
+        }
+    }
+
+    /// Title text, rendered in the first row.
+    #[inline]
+    pub fn title(mut self, title: impl Into<Line<'a>>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Combined style.
+    ///
+    /// The popup placement of the style is not used, the
+    /// submenus open to the right.
+    #[inline]
+    pub fn styles(mut self, styles: MenuStyle) -> Self {
+        self.popup = self.popup.styles(styles.clone());
+
+        self.style = styles.style;
+        if styles.highlight.is_some() {
+            self.highlight_style = styles.highlight;
+        }
+        if styles.disabled.is_some() {
+            self.disabled_style = styles.disabled;
+        }
+        if styles.focus.is_some() {
+            self.focus_style = styles.focus;
+        }
+        if styles.title.is_some() {
+            self.title_style = styles.title;
+        }
+        if styles.select.is_some() {
+            self.select_style = styles.select;
+        }
+        if styles.right.is_some() {
+            self.right_style = styles.right;
+        }
+        self
+    }
+
+    /// Base style.
+    #[inline]
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Menu-title style.
+    #[inline]
+    pub fn title_style(mut self, style: Style) -> Self {
+        self.title_style = Some(style);
+        self
+    }
+
+    /// Selection
+    #[inline]
+    pub fn select_style(mut self, style: Style) -> Self {
+        self.select_style = Some(style);
+        self
+    }
+
+    /// Selection + Focus
+    #[inline]
+    pub fn focus_style(mut self, style: Style) -> Self {
+        self.focus_style = Some(style);
+        self
+    }
+
+    /// Style for the hotkey.
+    #[inline]
+    pub fn right_style(mut self, style: Style) -> Self {
+        self.right_style = Some(style);
+        self
+    }
+
+    /// Fixed width for the submenus.
+    /// If not set it uses 1.5 times the length of the longest item.
+    pub fn popup_width(mut self, width: u16) -> Self {
+        self.popup = self.popup.width(width);
+        self
+    }
+
+    /// Placement relative to the title row. Default is
+    /// [Placement::Right].
+    pub fn popup_placement(mut self, placement: Placement) -> Self {
+        self.popup_placement = placement;
+        self
+    }
+
+    /// Block for borders.
+    pub fn popup_block(mut self, block: Block<'a>) -> Self {
+        self.popup = self.popup.block(block);
+        self
+    }
+
+    /// Width of the longest title.
+    pub fn width(&self) -> u16 {
+        let mut width = self.title.width() as u16;
+        if let Some(structure) = self.structure {
+            let mut menu = MenuBuilder::new();
+            structure.menus(&mut menu);
+            for item in menu.items() {
+                width = max(width, item_width(&item));
+            }
+        }
+        width
+    }
+
+    /// Create the widgets for the column of titles and for the
+    /// submenu popup.
+    ///
+    /// The popup should be rendered after all widgets that
+    /// might be below the popup have been rendered.
+    pub fn into_widgets(self) -> (VerticalMenubarColumn<'a>, VerticalMenubarPopup<'a>) {
+        (
+            VerticalMenubarColumn {
+                structure: self.structure,
+                title: self.title,
+                style: self.style,
+                title_style: self.title_style,
+                select_style: self.select_style,
+                focus_style: self.focus_style,
+                highlight_style: self.highlight_style,
+                disabled_style: self.disabled_style,
+                right_style: self.right_style,
+            },
+            VerticalMenubarPopup {
+                structure: self.structure,
+                style: self.style,
+                focus_style: self.focus_style,
+                highlight_style: self.highlight_style,
+                disabled_style: self.disabled_style,
+                right_style: self.right_style,
+                popup_placement: self.popup_placement,
+                popup: self.popup,
+            },
+        )
+    }
+}
+
+fn item_width(item: &MenuItem<'_>) -> u16 {
+    item.item_width() + item.right_width() + if item.right.is_empty() { 0 } else { 2 }
+}
+
+impl StatefulWidget for VerticalMenubarColumn<'_> {
+    type State = MenubarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_column(&self, area, buf, state);
+    }
+}
+
+fn render_column(
+    widget: &VerticalMenubarColumn<'_>,
+    area: Rect,
+    buf: &mut Buffer,
+    state: &mut MenubarState,
+) {
+    let mut menu = MenuBuilder::new();
+    if let Some(structure) = widget.structure {
+        structure.menus(&mut menu);
+    }
+    let items = menu.items();
+
+    state.area = area;
+    state.bar.area = area;
+    state.bar.item_areas.clear();
+    state.bar.navchar = items
+        .iter()
+        .map(|v| v.navchar.map(|w| w.to_ascii_lowercase()))
+        .collect();
+    state.bar.disabled = items.iter().map(|v| v.disabled).collect();
+
+    let focus_style = if state.bar.is_focused() {
+        widget
+            .focus_style
+            .unwrap_or_else(|| revert_style(widget.style))
+    } else {
+        widget
+            .select_style
+            .unwrap_or_else(|| fallback_select_style(widget.style))
+    };
+    let title_style = widget
+        .title_style
+        .unwrap_or_else(|| widget.style.underlined());
+    let highlight_style = widget
+        .highlight_style
+        .unwrap_or_else(|| Style::new().underlined());
+    let right_style = widget.right_style.unwrap_or_else(|| Style::new().italic());
+    let disabled_style = widget.disabled_style.unwrap_or(widget.style);
+
+    buf.set_style(area, widget.style);
+
+    let mut item_area = Rect::new(area.x, area.y, area.width, 1);
+
+    if widget.title.width() > 0 {
+        let title_area = item_area.intersection(area);
+        buf.set_style(title_area, title_style);
+        widget.title.clone().render(title_area, buf);
+        item_area.y += 1;
+    }
+
+    for (n, item) in items.iter().enumerate() {
+        let area = item_area.intersection(area);
+        state.bar.item_areas.push(area);
+
+        let style = if item.disabled {
+            disabled_style
+        } else if state.bar.selected == Some(n) {
+            focus_style
+        } else {
+            widget.style
+        };
+        let right_style = style.patch(right_style);
+
+        let item_line = if let Some(highlight) = item.highlight.clone() {
+            Line::from_iter([
+                Span::from(&item.item[..highlight.start - 1]), // account for _
+                Span::from(&item.item[highlight.start..highlight.end]).style(highlight_style),
+                Span::from(&item.item[highlight.end..]),
+                if !item.right.is_empty() {
+                    Span::from(format!("({})", item.right)).style(right_style)
+                } else {
+                    Span::default()
+                },
+            ])
+        } else {
+            Line::from_iter([
+                Span::from(item.item.as_ref()),
+                if !item.right.is_empty() {
+                    Span::from(format!("({})", item.right)).style(right_style)
+                } else {
+                    Span::default()
+                },
+            ])
+        };
+        // the whole row shows the selection.
+        buf.set_style(area, style);
+        item_line.style(style).render(area, buf);
+
+        item_area.y += 1;
+    }
+}
+
+impl StatefulWidget for VerticalMenubarPopup<'_> {
+    type State = MenubarState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        render_popup(self, area, buf, state);
+    }
+}
+
+fn render_popup(
+    widget: VerticalMenubarPopup<'_>,
+    _area: Rect,
+    buf: &mut Buffer,
+    state: &mut MenubarState,
+) {
+    state.area = state.bar.area;
+
+    let Some(selected) = state.bar.selected() else {
+        return;
+    };
+    let Some(structure) = widget.structure else {
+        return;
+    };
+
+    if state.popup.is_active() {
+        let item = state.bar.item_areas[selected];
+
+        let mut popup = widget
+            .popup
+            .constraint(widget.popup_placement.into_constraint(item))
+            .style(widget.style)
+            .focus_style_opt(widget.focus_style)
+            .highlight_style_opt(widget.highlight_style)
+            .disabled_style_opt(widget.disabled_style)
+            .right_style_opt(widget.right_style);
+
+        let mut submenu = MenuBuilder::new();
+        structure.submenu(selected, &mut submenu);
+        let items = submenu.items();
+
+        if !items.is_empty() {
+            for item in items {
+                popup = popup.item(item);
+            }
+            popup.render(item, buf, &mut state.popup);
+
+            state.area = state.bar.area.union(state.popup.popup.area);
+        }
+    } else {
+        state.popup = Default::default();
+    }
+}
+
+/// Open the submenu for a newly selected title, if a submenu
+/// was already open.
+fn selected(state: &mut MenubarState, changed: bool, keep_open: bool) -> MenuOutcome {
+    if changed {
+        if keep_open {
+            state.popup.select(None);
+            state.popup.set_active(true);
+        }
+        MenuOutcome::Selected(state.bar.selected.expect("selected"))
+    } else {
+        MenuOutcome::Unchanged
+    }
+}
+
+/// Handle the events for the column of titles.
+///
+/// Keyboard events are processed if focus is true.
+/// Mouse events are the same as for the Menubar.
+///
+/// Attention:
+/// For the event-handling of the popup-menus you need to call handle_popup_events().
+pub fn handle_events(
+    state: &mut MenubarState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> MenuOutcome {
+    state.bar.focus.set(focus);
+    if !state.is_focused() {
+        state.set_popup_active(false);
+    }
+
+    if state.bar.is_focused() {
+        let open = state.popup_active();
+        flow!(match event {
+            ct_event!(keycode press Up) => {
+                let r = state.bar.prev_item();
+                selected(state, r, open)
+            }
+            ct_event!(keycode press Down) => {
+                let r = state.bar.next_item();
+                selected(state, r, open)
+            }
+            ct_event!(keycode press Home) => {
+                let r = state.bar.select(Some(0));
+                selected(state, r, open)
+            }
+            ct_event!(keycode press End) => {
+                let r = state.bar.select(Some(state.bar.len().saturating_sub(1)));
+                selected(state, r, open)
+            }
+            ct_event!(keycode press Right) => {
+                if state.bar.selected().is_some() && !open {
+                    state.popup.select(None);
+                    state.popup.set_active(true);
+                    MenuOutcome::Changed
+                } else {
+                    MenuOutcome::Unchanged
+                }
+            }
+            ct_event!(keycode press Left) => {
+                if open {
+                    state.set_popup_active(false);
+                    MenuOutcome::Changed
+                } else {
+                    MenuOutcome::Unchanged
+                }
+            }
+            ct_event!(keycode press Enter) => {
+                if let Some(selected) = state.bar.selected() {
+                    state.popup.select(None);
+                    state.popup.set_active(true);
+                    MenuOutcome::Activated(selected)
+                } else {
+                    MenuOutcome::Continue
+                }
+            }
+            ct_event!(key press ANY-c) => {
+                let old_selected = state.bar.selected();
+                match state.bar.navigate(*c) {
+                    r @ MenuOutcome::Selected(_) => {
+                        if state.bar.selected != old_selected {
+                            state.popup.select(None);
+                        }
+                        state.popup.set_active(true);
+                        r
+                    }
+                    r => r,
+                }
+            }
+
+            ct_event!(key release _)
+            | ct_event!(keycode release Up)
+            | ct_event!(keycode release Down)
+            | ct_event!(keycode release Home)
+            | ct_event!(keycode release End)
+            | ct_event!(keycode release Right)
+            | ct_event!(keycode release Left)
+            | ct_event!(keycode release Enter) => MenuOutcome::Unchanged,
+
+            _ => MenuOutcome::Continue,
+        });
+    }
+
+    state.handle(event, MouseOnly)
+}
+
+/// Handle the events for the submenu.
+///
+/// Call this before the other event-handlers to cope with
+/// overlapping regions. Same as for the Menubar.
+pub fn handle_popup_events(
+    state: &mut MenubarState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> MenuOutcome {
+    state.bar.focus.set(focus);
+    state.handle(event, Popup)
+}
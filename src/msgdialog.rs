@@ -4,16 +4,18 @@
 
 use crate::_private::NonExhaustive;
 use crate::button::{Button, ButtonOutcome, ButtonState, ButtonStyle};
+use crate::focus::FocusMemory;
 use crate::layout::{layout_dialog, DialogItem};
 use crate::paragraph::{Paragraph, ParagraphState};
 use crate::util::{block_padding2, reset_buf_area};
 use rat_event::{ct_event, ConsumedEvent, Dialog, HandleEvent, Outcome, Regular};
-use rat_focus::{Focus, FocusBuilder};
+use rat_focus::{ContainerFlag, Focus, FocusBuilder, FocusContainer, FocusFlag};
+use rat_reloc::{relocate_area, RelocatableState};
 use rat_scrolled::{Scroll, ScrollStyle};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Alignment, Constraint, Flex, Rect};
 use ratatui::style::Style;
-use ratatui::text::{Line, Text};
+use ratatui::text::{Line, Span, Text};
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
 use ratatui::widgets::{Block, Padding, StatefulWidget, Widget};
@@ -59,12 +61,24 @@ pub struct MsgDialogState {
     pub message_title: RefCell<String>,
     /// Dialog text.
     /// __read+write__
-    pub message: RefCell<String>,
+    pub message: RefCell<Text<'static>>,
+    /// Maximum number of lines kept by
+    /// [append_lines](MsgDialogState::append_lines).
+    /// __read+write__
+    pub max_lines: Cell<Option<usize>>,
+
+    /// Container flag for use with [FocusTrap](crate::focus::FocusTrap).
+    /// __read only__
+    pub container: ContainerFlag,
 
+    /// Focus of the underlying ui.
+    focus_memory: RefCell<FocusMemory>,
     /// Ok button
     button: RefCell<ButtonState>,
     /// message-text
     paragraph: RefCell<ParagraphState>,
+    /// Scroll to the bottom with the next render.
+    follow: Cell<bool>,
 }
 
 impl<'a> MsgDialog<'a> {
@@ -134,6 +148,10 @@ impl Default for MsgDialogStyle {
 }
 
 impl MsgDialogState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     /// Show the dialog.
     pub fn set_active(&self, active: bool) {
         self.active.set(active);
@@ -142,6 +160,37 @@ impl MsgDialogState {
         self.paragraph.borrow_mut().set_col_offset(0);
     }
 
+    /// Show the dialog and remember the currently focused
+    /// widget of the underlying ui.
+    pub fn open_with_focus(&self, focus: &Focus) {
+        self.focus_memory.borrow_mut().save(focus);
+        self.set_active(true);
+    }
+
+    /// Close the dialog. Clears the message.
+    ///
+    /// This is called when the dialog is closed with Ok or Esc.
+    /// The focus remembered by [open_with_focus](Self::open_with_focus)
+    /// is still available via [previous_focus](Self::previous_focus).
+    pub fn close(&self) {
+        self.clear();
+    }
+
+    /// The widget that was focused when the dialog was opened
+    /// with [open_with_focus](Self::open_with_focus).
+    pub fn previous_focus(&self) -> Option<FocusFlag> {
+        self.focus_memory.borrow().previous()
+    }
+
+    /// Restore the focus remembered by [open_with_focus](Self::open_with_focus).
+    ///
+    /// Falls back to the first widget if the remembered one is
+    /// no longer part of the given Focus. Returns true if the
+    /// remembered widget was found.
+    pub fn restore_focus(&self, focus: &Focus) -> bool {
+        self.focus_memory.borrow_mut().restore(focus)
+    }
+
     /// Dialog is active.
     pub fn active(&self) -> bool {
         self.active.get()
@@ -159,13 +208,84 @@ impl MsgDialogState {
     }
 
     /// *Append* to the message.
+    ///
+    /// Each '\n' starts a new line.
     pub fn append(&self, msg: &str) {
         self.set_active(true);
         let mut message = self.message.borrow_mut();
-        if !message.is_empty() {
-            message.push('\n');
+        for t in msg.split('\n') {
+            message.lines.push(Line::from(t.to_string()));
+        }
+    }
+
+    /// *Append* lines to the message, for use as a log viewer.
+    ///
+    /// Unlike [append](Self::append) this keeps the scroll position
+    /// of an open dialog. If it is scrolled to the bottom it follows
+    /// the new lines. With [max_lines](Self::set_max_lines) the oldest
+    /// lines are dropped.
+    pub fn append_lines(&self, lines: &[&str]) {
+        if !self.active.get() {
+            self.set_active(true);
+        }
+
+        let mut message = self.message.borrow_mut();
+        for line in lines {
+            for t in line.split('\n') {
+                message.lines.push(Line::from(t.to_string()));
+            }
+        }
+
+        let dropped = match self.max_lines.get() {
+            Some(max) if message.lines.len() > max => {
+                let n = message.lines.len() - max;
+                message.lines.drain(0..n);
+                n
+            }
+            _ => 0,
+        };
+
+        let mut paragraph = self.paragraph.borrow_mut();
+        if paragraph.vscroll.offset() >= paragraph.vscroll.max_offset() {
+            self.follow.set(true);
+        } else {
+            let offset = paragraph.vscroll.offset().saturating_sub(dropped);
+            paragraph.set_line_offset(offset);
         }
-        message.push_str(msg);
+    }
+
+    /// Limit the number of lines kept by [append_lines](Self::append_lines).
+    pub fn set_max_lines(&self, max_lines: Option<usize>) {
+        self.max_lines.set(max_lines);
+    }
+
+    /// *Append* a styled line to the message.
+    pub fn append_line(&self, line: Line<'_>) {
+        self.set_active(true);
+        self.message.borrow_mut().lines.push(owned_line(line));
+    }
+
+    /// Replace the message.
+    pub fn set_text(&self, text: Text<'_>) {
+        self.set_active(true);
+        let mut message = self.message.borrow_mut();
+        message.lines.clear();
+        message.lines.extend(text.lines.into_iter().map(owned_line));
+        message.style = text.style;
+        message.alignment = text.alignment;
+    }
+}
+
+/// Copy all the content to get a 'static Line.
+fn owned_line(line: Line<'_>) -> Line<'static> {
+    Line {
+        spans: line
+            .spans
+            .into_iter()
+            .map(|v| Span::styled(v.content.into_owned(), v.style))
+            .collect(),
+        style: line.style,
+        alignment: line.alignment,
     }
 }
 
@@ -176,8 +296,12 @@ impl Default for MsgDialogState {
             area: Default::default(),
             inner: Default::default(),
             message: Default::default(),
+            max_lines: Default::default(),
+            container: ContainerFlag::named("msg_dialog"),
+            focus_memory: Default::default(),
             button: Default::default(),
             paragraph: Default::default(),
+            follow: Default::default(),
             message_title: Default::default(),
         };
         s.paragraph.borrow().focus.set(true);
@@ -185,12 +309,34 @@ impl Default for MsgDialogState {
     }
 }
 
+impl RelocatableState for MsgDialogState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+        self.inner = relocate_area(self.inner, shift, clip);
+        self.button.get_mut().relocate(shift, clip);
+        self.paragraph.get_mut().relocate(shift, clip);
+    }
+}
+
 impl MsgDialogState {
     fn focus(&self) -> Focus {
-        let mut fb = FocusBuilder::default();
-        fb.widget(&*self.paragraph.borrow())
+        FocusBuilder::for_container(self)
+    }
+}
+
+impl FocusContainer for MsgDialogState {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder
+            .widget(&*self.paragraph.borrow())
             .widget(&*self.button.borrow());
-        fb.build()
+    }
+
+    fn container(&self) -> Option<ContainerFlag> {
+        Some(self.container.clone())
+    }
+
+    fn area(&self) -> Rect {
+        self.area
     }
 }
 
@@ -252,17 +398,22 @@ fn render_ref(widget: &MsgDialog<'_>, area: Rect, buf: &mut Buffer, state: &mut
                 Scroll::new().style(widget.style)
             };
 
-            let message = state.message.borrow();
-            let mut lines = Vec::new();
-            for t in message.split('\n') {
-                lines.push(Line::from(t));
+            let text = state.message.borrow().clone();
+            let alignment = text.alignment.unwrap_or(Alignment::Center);
+            let paragraph = || {
+                Paragraph::new(text.clone())
+                    .alignment(alignment)
+                    .scroll(scroll.clone())
+            };
+            let content = l_dlg.widget_for(DialogItem::Content);
+            let mut paragraph_state = state.paragraph.borrow_mut();
+            if state.follow.take() {
+                // max_offset is only known after the render.
+                paragraph().render(content, buf, &mut paragraph_state);
+                let max_offset = paragraph_state.vscroll.max_offset();
+                paragraph_state.set_line_offset(max_offset);
             }
-            let text = Text::from(lines).alignment(Alignment::Center);
-            Paragraph::new(text).scroll(scroll).render(
-                l_dlg.widget_for(DialogItem::Content),
-                buf,
-                &mut state.paragraph.borrow_mut(),
-            );
+            paragraph().render(content, buf, &mut paragraph_state);
         }
 
         Button::new("Ok")
@@ -283,8 +434,7 @@ impl HandleEvent<crossterm::event::Event, Dialog, Outcome> for MsgDialogState {
 
             let mut r = match self.button.borrow_mut().handle(event, Regular) {
                 ButtonOutcome::Pressed => {
-                    self.clear();
-                    self.active.set(false);
+                    self.close();
                     Outcome::Changed
                 }
                 v => v.into(),
@@ -292,8 +442,7 @@ impl HandleEvent<crossterm::event::Event, Dialog, Outcome> for MsgDialogState {
             r = r.or_else(|| self.paragraph.borrow_mut().handle(event, Regular));
             r = r.or_else(|| match event {
                 ct_event!(keycode press Esc) => {
-                    self.clear();
-                    self.active.set(false);
+                    self.close();
                     Outcome::Changed
                 }
                 _ => Outcome::Continue,
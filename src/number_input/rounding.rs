@@ -0,0 +1,130 @@
+//!
+//! Round the number to a fixed count of decimals when the
+//! user commits the input.
+//!
+//! The digits shown are defined by the format pattern, use
+//! one with the same number of decimals, e.g. `###,##0.00`.
+//! Missing decimals are then padded with zeros.
+//!
+//! ```rust ignore
+//! // after the focus handling.
+//! let r = rounding::handle_commit(&mut state.price, event, 2, Rounding::HalfEven);
+//! let r = r.or_else(|| state.price.handle(event, Regular));
+//! ```
+//!
+use crate::event::TextOutcome;
+use crate::number_input::NumberInputState;
+use rat_event::ct_event;
+
+/// Rounding mode.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Ties round away from zero.
+    #[default]
+    HalfUp,
+    /// Ties round to the even digit.
+    HalfEven,
+    /// Drop the extra digits.
+    Truncate,
+}
+
+/// Round the value to the given number of decimals.
+///
+/// Values that are a tie in decimal, but not exactly representable
+/// as f64 (like 2.675), are still treated as a tie.
+pub fn round(value: f64, decimals: usize, mode: Rounding) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    let scaled = value.abs() * scale;
+
+    let floor = scaled.floor();
+    let fract = scaled - floor;
+    let eps = f64::EPSILON * 16.0 * scaled.max(1.0);
+
+    let rounded = match mode {
+        Rounding::Truncate => {
+            // 0.29 * 100 = 28.999999999999996
+            if 1.0 - fract < eps {
+                floor + 1.0
+            } else {
+                floor
+            }
+        }
+        Rounding::HalfUp => {
+            if fract + eps >= 0.5 {
+                floor + 1.0
+            } else {
+                floor
+            }
+        }
+        Rounding::HalfEven => {
+            if (fract - 0.5).abs() < eps {
+                if floor % 2.0 == 0.0 {
+                    floor
+                } else {
+                    floor + 1.0
+                }
+            } else if fract > 0.5 {
+                floor + 1.0
+            } else {
+                floor
+            }
+        }
+    };
+
+    (rounded / scale).copysign(value)
+}
+
+/// Replace the value with the rounded value.
+///
+/// Blank decimals count as zero. Does nothing if the text
+/// is empty or not a number.
+/// Returns true if the text changed.
+pub fn apply(state: &mut NumberInputState, decimals: usize, mode: Rounding) -> bool {
+    let old_text = state.widget.text().to_string();
+    let value = match state.value_opt::<f64>() {
+        Ok(Some(value)) => value,
+        Ok(None) => return false,
+        Err(_) => {
+            // missing decimals are blank in the mask: "1.5 "
+            let trimmed = old_text.trim_end();
+            let padded = format!("{}{}", trimmed, "0".repeat(old_text.len() - trimmed.len()));
+            state.widget.set_text(padded);
+            match state.value_opt::<f64>() {
+                Ok(Some(value)) => value,
+                _ => {
+                    state.widget.set_text(old_text);
+                    return false;
+                }
+            }
+        }
+    };
+    if state.set_value(round(value, decimals, mode)).is_err() {
+        state.widget.set_text(old_text);
+        return false;
+    }
+    old_text != state.widget.text()
+}
+
+/// Round on Enter or when the widget lost the focus.
+///
+/// Call it after the focus handling, as focus-lost is only
+/// known then. Enter is not consumed, so this returns
+/// [TextOutcome::Continue] even if the text changed on Enter,
+/// but [TextOutcome::TextChanged] when it changes on focus lost.
+pub fn handle_commit(
+    state: &mut NumberInputState,
+    event: &crossterm::event::Event,
+    decimals: usize,
+    mode: Rounding,
+) -> TextOutcome {
+    if state.widget.focus.lost() {
+        if apply(state, decimals, mode) {
+            return TextOutcome::TextChanged;
+        }
+    } else if state.widget.focus.get() {
+        if let ct_event!(keycode press Enter) = event {
+            apply(state, decimals, mode);
+        }
+    }
+    TextOutcome::Continue
+}
@@ -0,0 +1,150 @@
+//!
+//! Spin the value of a NumberInput.
+//!
+//! Up/Down change the value by the step when the widget has
+//! the focus, the mouse wheel does the same when over the widget.
+//! With Shift held the larger shift-step is used.
+//!
+//! The new value is clamped to the range and then goes through
+//! `set_value()`, so a value that doesn't fit the pattern
+//! leaves the text as it is.
+//!
+//! ```rust ignore
+//! let spin = NumberSpin::new().step(0.5).shift_step(10.0).range(0.0, 100.0);
+//! let r = spin::handle_events(&mut state.amount, &spin, event);
+//! let r = r.or_else(|| state.amount.handle(event, Regular));
+//! ```
+//!
+use crate::event::TextOutcome;
+use crate::number_input::NumberInputState;
+use rat_event::{ct_event, flow};
+use ratatui::layout::Position;
+
+/// Step sizes and range for spinning a number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NumberSpin {
+    step: f64,
+    shift_step: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+}
+
+impl Default for NumberSpin {
+    fn default() -> Self {
+        Self {
+            step: 1.0,
+            shift_step: 10.0,
+            min: None,
+            max: None,
+        }
+    }
+}
+
+impl NumberSpin {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Step for Up/Down and the mouse wheel. Default is 1.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Step with Shift held. Default is 10.
+    pub fn shift_step(mut self, step: f64) -> Self {
+        self.shift_step = step;
+        self
+    }
+
+    /// Clamp the value to this range.
+    pub fn range(mut self, min: f64, max: f64) -> Self {
+        self.min = Some(min);
+        self.max = Some(max);
+        self
+    }
+
+    /// Add delta to the value.
+    ///
+    /// An empty input counts as 0. Does nothing if the text is
+    /// not a number.
+    ///
+    /// Returns true if the text changed.
+    pub fn spin(&self, state: &mut NumberInputState, delta: f64) -> bool {
+        let value = match state.value_opt::<f64>() {
+            Ok(Some(value)) => value,
+            Ok(None) => 0.0,
+            Err(_) => return false,
+        };
+
+        let mut new_value = value + delta;
+        if let Some(min) = self.min {
+            new_value = new_value.max(min);
+        }
+        if let Some(max) = self.max {
+            new_value = new_value.min(max);
+        }
+
+        let old_text = state.widget.text().to_string();
+        let cursor = state.widget.cursor();
+        if state.set_value(new_value).is_err() {
+            state.widget.set_text(old_text);
+            return false;
+        }
+        state.widget.set_cursor(cursor, false);
+        old_text != state.widget.text()
+    }
+
+    fn spin_outcome(&self, state: &mut NumberInputState, delta: f64) -> TextOutcome {
+        if self.spin(state, delta) {
+            TextOutcome::TextChanged
+        } else {
+            TextOutcome::Unchanged
+        }
+    }
+}
+
+/// Up/Down spin the value, Shift+Up/Down use the shift-step.
+/// Includes [handle_mouse_events].
+///
+/// Up/Down only if the widget has the focus.
+pub fn handle_events(
+    state: &mut NumberInputState,
+    spin: &NumberSpin,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    if state.widget.focus.get() {
+        flow!(match event {
+            ct_event!(keycode press Up) => spin.spin_outcome(state, spin.step),
+            ct_event!(keycode press Down) => spin.spin_outcome(state, -spin.step),
+            ct_event!(keycode press SHIFT-Up) => spin.spin_outcome(state, spin.shift_step),
+            ct_event!(keycode press SHIFT-Down) => spin.spin_outcome(state, -spin.shift_step),
+            _ => TextOutcome::Continue,
+        });
+    }
+    handle_mouse_events(state, spin, event)
+}
+
+/// The mouse wheel spins the value, with Shift held the
+/// shift-step is used.
+///
+/// Only if the mouse is over the widget.
+pub fn handle_mouse_events(
+    state: &mut NumberInputState,
+    spin: &NumberSpin,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    let area = state.widget.area;
+    let over = |x: u16, y: u16| area.contains(Position::new(x, y));
+    match event {
+        ct_event!(scroll up for x,y) if over(*x, *y) => spin.spin_outcome(state, spin.step),
+        ct_event!(scroll down for x,y) if over(*x, *y) => spin.spin_outcome(state, -spin.step),
+        ct_event!(scroll SHIFT up for x,y) if over(*x, *y) => {
+            spin.spin_outcome(state, spin.shift_step)
+        }
+        ct_event!(scroll SHIFT down for x,y) if over(*x, *y) => {
+            spin.spin_outcome(state, -spin.shift_step)
+        }
+        _ => TextOutcome::Continue,
+    }
+}
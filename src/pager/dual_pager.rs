@@ -1,12 +1,15 @@
 use crate::_private::NonExhaustive;
 use crate::event::PagerOutcome;
-use crate::layout::GenericLayout;
+use crate::layout::{GenericLayout, HitPart};
 use crate::pager::{PageNavigation, PageNavigationState, Pager, PagerBuffer, PagerStyle};
-use rat_event::{HandleEvent, MouseOnly, Regular};
+use crate::render_timing::RenderTimer;
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Regular};
+use rat_focus::FocusContainer;
 use rat_reloc::RelocatableState;
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Alignment, Rect, Size};
+use ratatui::layout::{Alignment, Position, Rect, Size};
 use ratatui::prelude::{StatefulWidget, Style};
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Widget};
 use std::borrow::Cow;
 use std::cell::{RefCell, RefMut};
@@ -36,6 +39,7 @@ where
 {
     pager0: PagerBuffer<'a, W>,
     pager1: PagerBuffer<'a, W>,
+    _timer: RenderTimer,
 }
 
 /// Widget state.
@@ -52,6 +56,14 @@ where
     /// __read+write__
     pub nav: PageNavigationState,
 
+    /// Column ratio set with the keyboard, Ctrl+Alt+Left/Right.
+    /// Overrides the column ratio of the widget.
+    /// __read+write__
+    pub column_ratio: Option<(u16, u16)>,
+    /// Minimum width of a column when resizing.
+    /// __read+write__
+    pub min_column_width: u16,
+
     /// Only construct with `..Default::default()`.
     pub non_exhaustive: NonExhaustive,
 }
@@ -77,6 +89,36 @@ where
         Self::default()
     }
 
+    /// Relative width of the left and right column.
+    ///
+    /// __Default__
+    /// Both columns get the same width.
+    pub fn column_ratio(mut self, left: u16, right: u16) -> Self {
+        self.page_nav = self.page_nav.column_ratio([left, right]);
+        self
+    }
+
+    /// Use the column ratio of the state, if the user
+    /// resized the columns.
+    ///
+    /// Call this before [layout_size](DualPager::layout_size),
+    /// otherwise the layout doesn't follow the new split.
+    pub fn column_ratio_from(mut self, state: &DualPagerState<W>) -> Self {
+        if let Some((left, right)) = state.column_ratio {
+            self = self.column_ratio(left, right);
+        }
+        self
+    }
+
+    /// Gap between the columns.
+    ///
+    /// __Default__
+    /// Default is 0.
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.page_nav = self.page_nav.gap(gap);
+        self
+    }
+
     /// Base style.
     pub fn style(mut self, style: Style) -> Self {
         self.pager = self.pager.style(style);
@@ -121,18 +163,36 @@ where
         self
     }
 
+    /// Column the layout is calculated for.
+    ///
+    /// Both columns share one layout, the left column shows
+    /// the even and the right column the odd pages. By default
+    /// the layout uses the smaller column. Use this to lay out
+    /// the pages for the wider column, the pages in the smaller
+    /// column are clipped then.
+    pub fn layout_column(mut self, column: usize) -> Self {
+        self.page_nav = self.page_nav.layout_column(column);
+        self
+    }
+
     /// Calculate the layout page size.
+    ///
+    /// With a column_ratio this is the size of the smaller column,
+    /// or the size of the [layout_column](DualPager::layout_column).
     pub fn layout_size(&self, area: Rect) -> Size {
         self.page_nav.layout_size(area)
     }
 
     /// Run the layout and create the second stage.
     pub fn into_buffer(
-        self,
+        mut self,
         area: Rect,
         buf: &'a mut Buffer,
         state: &mut DualPagerState<W>,
     ) -> DualPagerBuffer<'a, W> {
+        let timer = RenderTimer::start("DualPager", state.nav.container.name());
+        self = self.column_ratio_from(state);
+
         state.nav.page_count = (state.layout.page_count() + 1) / 2;
         state.nav.set_page(state.nav.page);
 
@@ -153,6 +213,7 @@ where
                 .layout(state.layout.clone())
                 .page(state.nav.page * 2 + 1)
                 .into_buffer(state.nav.widget_areas[1], buf),
+            _timer: timer,
         }
     }
 }
@@ -193,6 +254,24 @@ where
         }
     }
 
+    /// Render a label with the set style and alignment.
+    ///
+    /// The closure is only called if the label is visible.
+    #[inline(always)]
+    pub fn render_label_with<'l, FN>(&mut self, widget: W, label_fn: FN) -> bool
+    where
+        FN: FnOnce() -> Line<'l>,
+    {
+        let Some(idx) = self.pager0.widget_idx(widget) else {
+            return false;
+        };
+        if self.pager0.is_label_visible(idx) {
+            self.pager0.render_label_with(idx, label_fn)
+        } else {
+            self.pager1.render_label_with(idx, label_fn)
+        }
+    }
+
     /// Render a stateless widget and its label, if any.
     #[inline(always)]
     pub fn render_widget<FN, WW>(&mut self, widget: W, render_fn: FN) -> bool
@@ -378,6 +457,8 @@ where
         Self {
             layout: Default::default(),
             nav: Default::default(),
+            column_ratio: None,
+            min_column_width: 10,
             non_exhaustive: NonExhaustive,
         }
     }
@@ -419,6 +500,24 @@ where
         self.layout.page_of(widget).map(|v| v / 2)
     }
 
+    /// Find the widget at the given screen position.
+    ///
+    /// Uses the page areas of the last render.
+    /// Returns the widget and whether the label or the widget
+    /// itself was hit.
+    pub fn widget_at(&self, pos: (u16, u16)) -> Option<(W, HitPart)> {
+        let (n, area) = self
+            .nav
+            .widget_areas
+            .iter()
+            .enumerate()
+            .find(|(_, v)| v.contains(pos.into()))?;
+        self.layout.widget_at(
+            self.nav.page * 2 + n,
+            Position::new(pos.0 - area.x, pos.1 - area.y),
+        )
+    }
+
     /// Set the visible page.
     pub fn set_page(&mut self, page: usize) -> bool {
         self.nav.set_page(page)
@@ -438,6 +537,30 @@ where
     pub fn prev_page(&mut self) -> bool {
         self.nav.prev_page()
     }
+
+    /// Move the split between the columns by n cells.
+    ///
+    /// Uses the column widths of the last render and stores
+    /// the result in column_ratio. Both columns keep at least
+    /// min_column_width.
+    pub fn move_split(&mut self, n: i16) -> bool {
+        let [left, right] = self.nav.widget_areas.as_slice() else {
+            return false;
+        };
+        let total = left.width + right.width;
+        if total < 2 * self.min_column_width {
+            return false;
+        }
+        let new_left = (left.width as i32 + n as i32).clamp(
+            self.min_column_width as i32,
+            (total - self.min_column_width) as i32,
+        ) as u16;
+        if new_left == left.width {
+            return false;
+        }
+        self.column_ratio = Some((new_left, total - new_left));
+        true
+    }
 }
 
 impl<W> HandleEvent<crossterm::event::Event, Regular, PagerOutcome> for DualPagerState<W>
@@ -445,7 +568,17 @@ where
     W: Eq + Hash + Clone,
 {
     fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> PagerOutcome {
-        self.nav.handle(event, Regular)
+        let r = if self.nav.container.is_container_focused() {
+            match event {
+                ct_event!(keycode press CONTROL_ALT-Left) => self.move_split(-1).into(),
+                ct_event!(keycode press CONTROL_ALT-Right) => self.move_split(1).into(),
+                _ => PagerOutcome::Continue,
+            }
+        } else {
+            PagerOutcome::Continue
+        };
+
+        r.or_else(|| self.nav.handle(event, Regular))
     }
 }
 
@@ -79,6 +79,107 @@ pub use pager_nav::{PageNavigation, PageNavigationState};
 pub use pager_style::*;
 pub use single_pager::*;
 
+use crate::focus::container_nav::reachable;
+use crate::layout::{GenericLayout, HitPart};
+use rat_event::{ct_event, Outcome};
+use rat_focus::{Focus, FocusFlag, Navigation};
+
+/// Focus a widget when its label is clicked.
+///
+/// Works with the widget_at() of [SinglePagerState], [DualPagerState]
+/// and [ClipperState](crate::clipper::ClipperState) if the layout
+/// uses FocusFlags as keys.
+///
+/// ```rust ignore
+/// r = r.or_else(|| handle_label_click(event, &focus, |pos| state.pager.widget_at(pos)));
+/// ```
+///
+/// Clicks on the widget itself are left to the widget.
+pub fn handle_label_click(
+    event: &crossterm::event::Event,
+    focus: &Focus,
+    widget_at: impl Fn((u16, u16)) -> Option<(FocusFlag, HitPart)>,
+) -> Outcome {
+    match event {
+        ct_event!(mouse down Left for x,y) => match widget_at((*x, *y)) {
+            Some((flag, HitPart::Label)) => {
+                focus.focus_flag(flag);
+                Outcome::Changed
+            }
+            _ => Outcome::Continue,
+        },
+        _ => Outcome::Continue,
+    }
+}
+
+/// Page-aware Tab/BackTab navigation.
+///
+/// Moves the focus to the next/previous widget of the layout.
+/// If this widget is on another page, the page is switched
+/// with the show function before the widget is focused.
+/// At the end of the layout it wraps around to the first widget,
+/// and vice versa.
+///
+/// Call this before the Focus handles the event. It only acts
+/// if the focused widget is part of the layout, otherwise Tab
+/// is left to the Focus.
+///
+/// ```rust ignore
+/// let r = handle_page_focus(event, &focus, &state.pager.layout(), |w| state.pager.show(w));
+/// let f = r.or_else(|| focus.handle(event, Regular));
+/// ```
+pub fn handle_page_focus(
+    event: &crossterm::event::Event,
+    focus: &Focus,
+    layout: &GenericLayout<FocusFlag>,
+    mut show: impl FnMut(FocusFlag),
+) -> Outcome {
+    let forward = match event {
+        ct_event!(keycode press Tab) => true,
+        ct_event!(keycode press SHIFT-BackTab) => false,
+        _ => return Outcome::Continue,
+    };
+
+    let Some(focused) = focus.focused() else {
+        return Outcome::Continue;
+    };
+    let Some(current) = layout.try_index_of(focused) else {
+        return Outcome::Continue;
+    };
+
+    let (flags, _, _, navigable, _) = focus.clone_destruct();
+    let navigable_of = |flag: &FocusFlag| {
+        flags
+            .iter()
+            .position(|v| v == flag)
+            .map(|i| navigable[i])
+            .unwrap_or(Navigation::None)
+    };
+    if navigable_of(&layout.widget_key(current)) == Navigation::Lock {
+        return Outcome::Continue;
+    }
+
+    let len = layout.widget_len();
+    let next = (1..len)
+        .map(|n| {
+            if forward {
+                (current + n) % len
+            } else {
+                (current + len - n) % len
+            }
+        })
+        .map(|idx| layout.widget_key(idx))
+        .find(|flag| reachable(navigable_of(flag)));
+
+    if let Some(next) = next {
+        show(next.clone());
+        focus.focus_flag(next);
+        Outcome::Changed
+    } else {
+        Outcome::Unchanged
+    }
+}
+
 pub(crate) mod event {
     use rat_event::{ConsumedEvent, Outcome};
 
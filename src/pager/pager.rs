@@ -211,6 +211,33 @@ where
         true
     }
 
+    /// Render a label with the set style and alignment.
+    ///
+    /// The closure is only called if the label is visible,
+    /// use it for labels that are expensive to create.
+    /// Style and alignment of the Line take precedence.
+    #[inline(always)]
+    pub fn render_label_with<'l, FN>(&mut self, idx: usize, label_fn: FN) -> bool
+    where
+        FN: FnOnce() -> Line<'l>,
+    {
+        let Some(label_area) = self.locate_area(self.layout.label(idx)) else {
+            return false;
+        };
+
+        let mut buffer = self.buffer.borrow_mut();
+        let mut label = label_fn();
+        if let Some(style) = self.label_style {
+            label.style = style.patch(label.style);
+        }
+        if label.alignment.is_none() {
+            label.alignment = self.label_alignment;
+        }
+        label.render(label_area, *buffer);
+
+        true
+    }
+
     /// Render a stateless widget.
     #[inline(always)]
     pub fn render_widget<FN, WW>(&mut self, idx: usize, render_fn: FN) -> bool
@@ -311,19 +338,26 @@ where
     /// Relocate an area from layout coordinates to screen coordinates.
     /// A result None indicates that the area is invisible.
     ///
-    /// This will clip the area to the page_area.
+    /// This will clip the area to the page_area and the
+    /// widget_area, if the page is wider than the widget.
     #[inline]
     pub fn locate_area(&self, area: Rect) -> Option<Rect> {
         let area = self.page_area.intersection(area);
         if area.is_empty() {
             None
         } else {
-            Some(Rect::new(
+            let area = Rect::new(
                 area.x - self.page_area.x + self.widget_area.x,
                 area.y - self.page_area.y + self.widget_area.y,
                 area.width,
                 area.height,
-            ))
+            )
+            .intersection(self.widget_area);
+            if area.is_empty() {
+                None
+            } else {
+                Some(area)
+            }
         }
     }
 
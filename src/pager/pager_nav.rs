@@ -1,7 +1,7 @@
 use crate::_private::NonExhaustive;
 use crate::event::PagerOutcome;
 use crate::pager::PagerStyle;
-use crate::util::revert_style;
+use crate::util::{fallback, revert_style};
 use rat_event::util::MouseFlagsN;
 use rat_event::{ct_event, ConsumedEvent, HandleEvent, MouseOnly, Regular};
 use rat_focus::{ContainerFlag, FocusContainer};
@@ -16,6 +16,9 @@ use std::cmp::min;
 #[derive(Debug, Clone)]
 pub struct PageNavigation<'a> {
     pages: u8,
+    column_ratio: Vec<u16>,
+    layout_column: Option<usize>,
+    gap: u16,
     block: Option<Block<'a>>,
     style: Style,
     nav_style: Option<Style>,
@@ -61,6 +64,9 @@ impl Default for PageNavigation<'_> {
     fn default() -> Self {
         Self {
             pages: 1,
+            column_ratio: Default::default(),
+            layout_column: None,
+            gap: 0,
             block: Default::default(),
             style: Default::default(),
             nav_style: Default::default(),
@@ -84,10 +90,39 @@ impl<'a> PageNavigation<'a> {
         self
     }
 
+    /// Relative widths of the pages.
+    ///
+    /// If this is empty or doesn't match the number of pages
+    /// all pages get the same width.
+    pub fn column_ratio(mut self, ratio: impl Into<Vec<u16>>) -> Self {
+        self.column_ratio = ratio.into();
+        self
+    }
+
+    /// Column the layout is calculated for.
+    ///
+    /// There is only one layout for all columns. By default
+    /// [layout_size](PageNavigation::layout_size) uses the
+    /// narrowest column, so every page fits. With a column_ratio
+    /// this can select the wide column instead, the pages shown
+    /// in a narrower column are clipped then.
+    pub fn layout_column(mut self, column: usize) -> Self {
+        self.layout_column = Some(column);
+        self
+    }
+
+    /// Gap between the pages.
+    ///
+    /// __Default__
+    /// Default is 0.
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.gap = gap;
+        self
+    }
+
     /// Base style.
     pub fn style(mut self, style: Style) -> Self {
         self.style = style;
-        self.block = self.block.map(|v| v.style(style));
         self
     }
 
@@ -105,7 +140,7 @@ impl<'a> PageNavigation<'a> {
 
     /// Block for border
     pub fn block(mut self, block: Block<'a>) -> Self {
-        self.block = Some(block.style(self.style));
+        self.block = Some(block);
         self
     }
 
@@ -121,14 +156,55 @@ impl<'a> PageNavigation<'a> {
         if let Some(block) = styles.block {
             self.block = Some(block);
         }
-        self.block = self.block.map(|v| v.style(styles.style));
         self
     }
 
     /// Calculate the layout size for one column.
+    ///
+    /// If the columns have different widths this is the
+    /// width of the smallest column, or the width of the
+    /// [layout_column](PageNavigation::layout_column).
     pub fn layout_size(&self, area: Rect) -> Size {
         let inner = self.inner(area);
-        Size::new(inner.width / self.pages as u16, inner.height)
+        let columns = self.column_areas(inner);
+        let width = if let Some(column) = self.layout_column.and_then(|v| columns.get(v)) {
+            column.width
+        } else {
+            columns.iter().map(|v| v.width).min().unwrap_or_default()
+        };
+        Size::new(width, inner.height)
+    }
+
+    /// Calculate the areas for each column.
+    pub fn column_areas(&self, widget_area: Rect) -> Vec<Rect> {
+        let pages = self.pages as u16;
+        let gaps = self.gap.saturating_mul(pages.saturating_sub(1));
+        let width = widget_area.width.saturating_sub(gaps);
+
+        let ratio_sum = if self.column_ratio.len() == self.pages as usize {
+            self.column_ratio.iter().map(|v| *v as u32).sum::<u32>()
+        } else {
+            0
+        };
+        let ratio_sum = (ratio_sum > 0).then_some(ratio_sum);
+
+        let mut areas = Vec::with_capacity(self.pages as usize);
+        let mut x = widget_area.x;
+        for i in 0..self.pages as usize {
+            let column_width = if let Some(ratio_sum) = ratio_sum {
+                (width as u32 * self.column_ratio[i] as u32 / ratio_sum) as u16
+            } else {
+                width / pages
+            };
+            areas.push(Rect::new(
+                x,
+                widget_area.y,
+                column_width,
+                widget_area.height,
+            ));
+            x += column_width + self.gap;
+        }
+        areas
     }
 
     // Calculate the view area for all columns.
@@ -154,13 +230,7 @@ impl StatefulWidget for PageNavigation<'_> {
 
         let widget_area = self.inner(area);
 
-        let width = widget_area.width / self.pages as u16;
-        let mut column_area = Rect::new(widget_area.x, widget_area.y, width, widget_area.height);
-        state.widget_areas.clear();
-        for _ in 0..self.pages {
-            state.widget_areas.push(column_area);
-            column_area.x += column_area.width;
-        }
+        state.widget_areas = self.column_areas(widget_area);
 
         let p1 = 5;
         let p4 = widget_area.width.saturating_sub(p1);
@@ -169,9 +239,10 @@ impl StatefulWidget for PageNavigation<'_> {
 
         // render
         let title = format!(" {}/{} ", state.page + 1, state.page_count);
+        buf.set_style(area, self.style);
         let block = self
             .block
-            .unwrap_or_else(|| Block::new().borders(Borders::TOP).style(self.style))
+            .unwrap_or_else(|| Block::new().borders(Borders::TOP))
             .title_bottom(title)
             .title_alignment(Alignment::Right);
         let block = if let Some(title_style) = self.title_style {
@@ -182,9 +253,10 @@ impl StatefulWidget for PageNavigation<'_> {
         block.render(area, buf);
 
         // active areas
-        let nav_style = self.nav_style.unwrap_or(self.style);
+        let nav_style = fallback(&[self.nav_style], self.style);
+        let hover_style = fallback(&[Some(revert_style(nav_style))], nav_style);
         if matches!(state.mouse.hover.get(), Some(0)) {
-            buf.set_style(state.prev_area, revert_style(nav_style));
+            buf.set_style(state.prev_area, hover_style);
         } else {
             buf.set_style(state.prev_area, nav_style);
         }
@@ -194,7 +266,7 @@ impl StatefulWidget for PageNavigation<'_> {
             Span::from(" [·] ").render(state.prev_area, buf);
         }
         if matches!(state.mouse.hover.get(), Some(1)) {
-            buf.set_style(state.next_area, revert_style(nav_style));
+            buf.set_style(state.next_area, hover_style);
         } else {
             buf.set_style(state.next_area, nav_style);
         }
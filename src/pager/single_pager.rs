@@ -1,12 +1,14 @@
 use crate::_private::NonExhaustive;
 use crate::event::PagerOutcome;
-use crate::layout::GenericLayout;
+use crate::layout::{GenericLayout, HitPart};
 use crate::pager::{PageNavigation, PageNavigationState, Pager, PagerBuffer, PagerStyle};
+use crate::render_timing::RenderTimer;
 use rat_event::{HandleEvent, MouseOnly, Regular};
 use rat_reloc::RelocatableState;
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Alignment, Rect, Size};
+use ratatui::layout::{Alignment, Position, Rect, Size};
 use ratatui::prelude::{StatefulWidget, Style};
+use ratatui::text::Line;
 use ratatui::widgets::{Block, Widget};
 use std::borrow::Cow;
 use std::cell::{RefCell, RefMut};
@@ -35,6 +37,7 @@ where
     W: Eq + Hash + Clone,
 {
     pager: PagerBuffer<'a, W>,
+    _timer: RenderTimer,
 }
 
 /// Widget state.
@@ -138,6 +141,7 @@ where
         buf: &'a mut Buffer,
         state: &mut SinglePagerState<W>,
     ) -> SinglePagerBuffer<'a, W> {
+        let timer = RenderTimer::start("SinglePager", state.nav.container.name());
         state.nav.page_count = state.layout.page_count();
         state.nav.set_page(state.nav.page);
 
@@ -149,6 +153,7 @@ where
                 .layout(state.layout.clone())
                 .page(state.nav.page)
                 .into_buffer(state.nav.widget_areas[0], Rc::new(RefCell::new(buf))),
+            _timer: timer,
         }
     }
 }
@@ -167,6 +172,9 @@ where
     }
 
     /// Render all blocks for the current page.
+    ///
+    /// These are the blocks added with LayoutForm::start().
+    /// Call it before rendering the widgets.
     pub fn render_block(&mut self) {
         self.pager.render_block()
     }
@@ -184,6 +192,20 @@ where
         self.pager.render_label(idx, render_fn)
     }
 
+    /// Render a label with the set style and alignment.
+    ///
+    /// The closure is only called if the label is visible.
+    #[inline(always)]
+    pub fn render_label_with<'l, FN>(&mut self, widget: W, label_fn: FN) -> bool
+    where
+        FN: FnOnce() -> Line<'l>,
+    {
+        let Some(idx) = self.pager.widget_idx(widget) else {
+            return false;
+        };
+        self.pager.render_label_with(idx, label_fn)
+    }
+
     /// Render a stateless widget and its label, if any.
     #[inline(always)]
     pub fn render_widget<FN, WW>(&mut self, widget: W, render_fn: FN) -> bool
@@ -370,6 +392,20 @@ where
         self.layout.page_of(widget)
     }
 
+    /// Find the widget at the given screen position.
+    ///
+    /// Uses the page area of the last render.
+    /// Returns the widget and whether the label or the widget
+    /// itself was hit.
+    pub fn widget_at(&self, pos: (u16, u16)) -> Option<(W, HitPart)> {
+        let area = self.nav.widget_areas.first()?;
+        if !area.contains(pos.into()) {
+            return None;
+        }
+        self.layout
+            .widget_at(self.nav.page, Position::new(pos.0 - area.x, pos.1 - area.y))
+    }
+
     /// Set the visible page.
     pub fn set_page(&mut self, page: usize) -> bool {
         self.nav.set_page(page)
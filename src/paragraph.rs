@@ -3,6 +3,7 @@
 //!
 
 use crate::_private::NonExhaustive;
+use crate::scroll_step::ScrollStep;
 use crate::util::revert_style;
 use rat_event::{ct_event, flow, HandleEvent, MouseOnly, Outcome, Regular};
 use rat_focus::{FocusFlag, HasFocus};
@@ -15,11 +16,10 @@ use ratatui::style::Style;
 use ratatui::text::Text;
 #[cfg(feature = "unstable-widget-ref")]
 use ratatui::widgets::StatefulWidgetRef;
-use ratatui::widgets::{Block, StatefulWidget, Widget, Wrap};
-use std::cell::RefCell;
-use std::cmp::min;
-use std::mem;
-use std::ops::DerefMut;
+use ratatui::widgets::{Block, Padding, StatefulWidget, Widget, Wrap};
+use std::cmp::{max, min};
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::ops::Range;
 
 /// List widget.
 ///
@@ -31,11 +31,15 @@ pub struct Paragraph<'a> {
     focus_style: Option<Style>,
 
     wrap: Option<Wrap>,
-    para: RefCell<ratatui::widgets::Paragraph<'a>>,
+    wrap_marker: Option<char>,
+    alignment: Option<Alignment>,
+    text: Text<'a>,
+    text_id: Option<u64>,
 
     block: Option<Block<'a>>,
     vscroll: Option<Scroll<'a>>,
     hscroll: Option<Scroll<'a>>,
+    scroll_step: Option<ScrollStep>,
 }
 
 #[derive(Debug)]
@@ -61,6 +65,8 @@ pub struct ParagraphState {
 
     /// Text lines
     pub lines: usize,
+    /// Cached line breaks.
+    layout: ParagraphLayout,
 
     /// Vertical scroll.
     /// __read+write__
@@ -94,18 +100,26 @@ impl<'a> Paragraph<'a> {
         T: Into<Text<'a>>,
     {
         Self {
-            para: RefCell::new(ratatui::widgets::Paragraph::new(text)),
+            text: text.into(),
             ..Default::default()
         }
     }
 
     /// Text
     pub fn text(mut self, text: impl Into<Text<'a>>) -> Self {
-        let mut para = ratatui::widgets::Paragraph::new(text);
-        if let Some(wrap) = self.wrap {
-            para = para.wrap(wrap);
-        }
-        self.para = RefCell::new(para);
+        self.text = text.into();
+        self
+    }
+
+    /// Identifies the text for the layout cache.
+    ///
+    /// The line breaks are cached in the state and only
+    /// recalculated if the text, the width or the wrap changes.
+    /// Without an id the text is hashed for each render,
+    /// with an id you must change it whenever the text changes.
+    /// A generation counter will do.
+    pub fn text_id(mut self, id: u64) -> Self {
+        self.text_id = Some(id);
         self
     }
 
@@ -135,6 +149,15 @@ impl<'a> Paragraph<'a> {
         self
     }
 
+    /// How far the mouse wheel scrolls.
+    ///
+    /// __Default__
+    /// 1/10 of the page.
+    pub fn scroll_step(mut self, step: ScrollStep) -> Self {
+        self.scroll_step = Some(step);
+        self
+    }
+
     /// Styles.
     pub fn styles(mut self, styles: ParagraphStyle) -> Self {
         self.style = styles.style;
@@ -168,20 +191,22 @@ impl<'a> Paragraph<'a> {
     /// Word wrap.
     pub fn wrap(mut self, wrap: Wrap) -> Self {
         self.wrap = Some(wrap);
+        self
+    }
 
-        let mut para = mem::take(self.para.borrow_mut().deref_mut());
-        para = para.wrap(wrap);
-        self.para = RefCell::new(para);
-
+    /// Show a marker at the end of each soft-wrapped row.
+    ///
+    /// Only used with [wrap](Self::wrap). The last column of the
+    /// widget is reserved for the marker, real line breaks are
+    /// left blank.
+    pub fn wrap_marker(mut self, marker: Option<char>) -> Self {
+        self.wrap_marker = marker;
         self
     }
 
     /// Text alignment.
     pub fn alignment(mut self, alignment: Alignment) -> Self {
-        let mut para = mem::take(self.para.borrow_mut().deref_mut());
-        para = para.alignment(alignment);
-        self.para = RefCell::new(para);
-
+        self.alignment = Some(alignment);
         self
     }
 
@@ -202,20 +227,51 @@ impl<'a> Paragraph<'a> {
 
     /// Line width when not wrapped.
     pub fn line_width(&self) -> usize {
-        self.para.borrow().line_width()
+        self.text.width()
     }
 
-    /// Line height for the supposed width.
-    pub fn line_height(&self, width: u16) -> usize {
-        let sa = ScrollArea::new()
+    /// Inherent width. Width of the longest line.
+    pub fn width(&self) -> u16 {
+        let padding = self.padding();
+        self.text.width() as u16 + padding.left + padding.right
+    }
+
+    /// Inherent height. Number of lines when not wrapped.
+    pub fn height(&self) -> u16 {
+        let padding = self.padding();
+        self.text.height() as u16 + padding.top + padding.bottom
+    }
+
+    // Block and scrollbars.
+    fn padding(&self) -> Padding {
+        ScrollArea::new()
             .block(self.block.as_ref())
             .h_scroll(self.hscroll.as_ref())
-            .v_scroll(self.vscroll.as_ref());
-        let padding = sa.padding();
+            .v_scroll(self.vscroll.as_ref())
+            .padding()
+    }
+
+    /// Line height for the supposed width.
+    pub fn line_height(&self, width: u16) -> usize {
+        let padding = self.padding();
 
-        self.para
-            .borrow()
-            .line_count(width.saturating_sub(padding.left + padding.right))
+        let mut para = ratatui::widgets::Paragraph::new(self.text.clone());
+        if let Some(wrap) = self.wrap {
+            para = para.wrap(wrap);
+        }
+        para.line_count(text_width(
+            self,
+            width.saturating_sub(padding.left + padding.right),
+        ))
+    }
+}
+
+/// Reserves a column for the wrap marker.
+fn text_width(widget: &Paragraph<'_>, width: u16) -> u16 {
+    if widget.wrap.is_some() && widget.wrap_marker.is_some() {
+        width.saturating_sub(1)
+    } else {
+        width
     }
 }
 
@@ -244,9 +300,6 @@ fn render_paragraph(
 ) {
     state.area = area;
 
-    // take paragraph
-    let mut para = mem::take(widget.para.borrow_mut().deref_mut());
-
     // update scroll
     let sa = ScrollArea::new()
         .block(widget.block.as_ref())
@@ -256,7 +309,14 @@ fn render_paragraph(
     let tmp_inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
     let pad_inner = sa.padding();
 
-    state.lines = para.line_count(area.width.saturating_sub(pad_inner.left + pad_inner.right));
+    state.layout.update(
+        widget,
+        text_width(
+            widget,
+            area.width.saturating_sub(pad_inner.left + pad_inner.right),
+        ),
+    );
+    state.lines = state.layout.line_count();
 
     state
         .vscroll
@@ -265,9 +325,16 @@ fn render_paragraph(
     state.hscroll.set_max_offset(if widget.wrap.is_some() {
         0
     } else {
-        para.line_width().saturating_sub(tmp_inner.width as usize)
+        state
+            .layout
+            .max_width
+            .saturating_sub(tmp_inner.width as usize)
     });
     state.hscroll.set_page_len(tmp_inner.width as usize);
+    if let Some(step) = widget.scroll_step {
+        step.apply(&mut state.hscroll);
+        step.apply(&mut state.vscroll);
+    }
     state.inner = sa.inner(area, Some(&state.hscroll), Some(&state.vscroll));
 
     sa.render(
@@ -278,8 +345,40 @@ fn render_paragraph(
             .v_scroll(&mut state.vscroll),
     );
 
-    para = para.scroll((state.vscroll.offset() as u16, state.hscroll.offset() as u16));
-    (&para).render(state.inner, buf);
+    let text_area = Rect::new(
+        state.inner.x,
+        state.inner.y,
+        text_width(widget, state.inner.width),
+        state.inner.height,
+    );
+
+    // Only the visible lines are rendered.
+    let offset = state.vscroll.offset();
+    let first = state.layout.line_at(offset);
+    let last = min(
+        state.layout.line_at(offset + text_area.height as usize) + 1,
+        widget.text.lines.len(),
+    );
+    let text = Text {
+        lines: widget.text.lines[first..last].to_vec(),
+        style: widget.text.style,
+        alignment: widget.text.alignment,
+    };
+    let mut para = ratatui::widgets::Paragraph::new(text).scroll((
+        (offset - state.layout.starts[first]) as u16,
+        state.hscroll.offset() as u16,
+    ));
+    if let Some(wrap) = widget.wrap {
+        para = para.wrap(wrap);
+    }
+    if let Some(alignment) = widget.alignment {
+        para = para.alignment(alignment);
+    }
+    para.render(text_area, buf);
+
+    if let (Some(_), Some(marker)) = (widget.wrap, widget.wrap_marker) {
+        render_wrap_marker(marker, first..last, text_area, buf, state);
+    }
 
     if state.is_focused() {
         let focus_style = widget.focus_style.unwrap_or(revert_style(widget.style));
@@ -312,8 +411,99 @@ fn render_paragraph(
             buf.set_style(Rect::new(state.inner.x, y as u16, 1, 1), focus_style);
         }
     }
+}
+
+/// Marker in the column right of the text for each soft-wrapped row.
+fn render_wrap_marker(
+    marker: char,
+    lines: Range<usize>,
+    text_area: Rect,
+    buf: &mut Buffer,
+    state: &ParagraphState,
+) {
+    let offset = state.vscroll.offset();
+    let x = text_area.right();
+    for line in lines {
+        let start = state.layout.starts[line];
+        let end = state.layout.starts[line + 1];
+        for r in start..end.saturating_sub(1) {
+            if r >= offset && r < offset + text_area.height as usize {
+                let y = text_area.y + (r - offset) as u16;
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char(marker);
+                }
+            }
+        }
+    }
+}
+
+/// Cached line breaks.
+///
+/// Holds the first display row for each line of the text.
+/// Is recalculated when the text, the width or the wrap
+/// changes.
+#[derive(Debug, Default, Clone)]
+struct ParagraphLayout {
+    /// (text-id, width, wrap)
+    key: Option<(u64, u16, Option<bool>)>,
+    /// Start row for each line. The last entry is
+    /// the total number of rows.
+    starts: Vec<usize>,
+    /// Maximum line width.
+    max_width: usize,
+}
+
+impl ParagraphLayout {
+    fn update(&mut self, widget: &Paragraph<'_>, width: u16) {
+        let id = widget.text_id.unwrap_or_else(|| {
+            let mut hasher = DefaultHasher::new();
+            widget.text.hash(&mut hasher);
+            hasher.finish()
+        });
+        let key = (id, width, widget.wrap.map(|v| v.trim));
+        if self.key == Some(key) && self.starts.len() == widget.text.lines.len() + 1 {
+            return;
+        }
+
+        self.key = Some(key);
+        self.starts.clear();
+        self.max_width = 0;
+
+        let mut row = 0;
+        for line in widget.text.lines.iter() {
+            self.starts.push(row);
+            self.max_width = max(self.max_width, line.width());
+            row += if let Some(wrap) = widget.wrap {
+                ratatui::widgets::Paragraph::new(line.clone())
+                    .wrap(wrap)
+                    .line_count(width)
+            } else {
+                1
+            };
+        }
+        self.starts.push(row);
+    }
 
-    *widget.para.borrow_mut().deref_mut() = para;
+    /// Total number of rows.
+    fn line_count(&self) -> usize {
+        self.starts.last().copied().unwrap_or(0)
+    }
+
+    /// Text line for the given row.
+    fn line_at(&self, row: usize) -> usize {
+        self.starts
+            .partition_point(|v| *v <= row)
+            .saturating_sub(1)
+            .min(self.starts.len().saturating_sub(2))
+    }
+
+    /// First row of the given text line.
+    fn row_of(&self, line: usize) -> usize {
+        self.starts
+            .get(line)
+            .copied()
+            .unwrap_or_else(|| self.line_count())
+    }
 }
 
 impl HasFocus for ParagraphState {
@@ -341,6 +531,7 @@ impl Clone for ParagraphState {
             area: self.area,
             inner: self.inner,
             lines: self.lines,
+            layout: self.layout.clone(),
             vscroll: self.vscroll.clone(),
             hscroll: self.hscroll.clone(),
             focus: FocusFlag::named(self.focus.name()),
@@ -359,6 +550,7 @@ impl Default for ParagraphState {
             hscroll: Default::default(),
             non_exhaustive: NonExhaustive,
             lines: 0,
+            layout: Default::default(),
         }
     }
 }
@@ -375,6 +567,21 @@ impl ParagraphState {
         }
     }
 
+    /// Number of rows, including the soft-wrapped ones.
+    ///
+    /// Valid after the first render.
+    pub fn line_count(&self) -> usize {
+        self.lines
+    }
+
+    /// Scroll to the given line of the text.
+    ///
+    /// The line is an index into the lines of the text, not a row.
+    /// This uses the line breaks of the last render.
+    pub fn scroll_to_line(&mut self, line: usize) -> bool {
+        self.vscroll.set_offset(self.layout.row_of(line))
+    }
+
     /// Current offset.
     pub fn line_offset(&self) -> usize {
         self.vscroll.offset()
@@ -497,3 +704,20 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ParagraphState
         }
     }
 }
+
+/// Handle all events.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut ParagraphState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(state: &mut ParagraphState, event: &crossterm::event::Event) -> Outcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
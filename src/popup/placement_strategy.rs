@@ -0,0 +1,106 @@
+//!
+//! How a popup adapts to the available space.
+//!
+
+use rat_popup::Placement;
+use ratatui::layout::Rect;
+use std::cmp::{max, min};
+
+/// How the popup adapts if the [Placement] doesn't give
+/// enough space.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PlacementStrategy {
+    /// Use the placement as is. The popup is shortened
+    /// to the space available on the chosen side.
+    ///
+    /// AboveOrBelow/BelowOrAbove use the preferred side if the
+    /// popup fits, otherwise the side with more space.
+    #[default]
+    Fixed,
+    /// Use the preferred side of the placement if the popup
+    /// fits. Otherwise flip to the side with more space and
+    /// shrink the popup to fit, but not below `min_len`.
+    ///
+    /// Only for placements above/below the widget.
+    FlipShrink { min_len: u16 },
+}
+
+impl PlacementStrategy {
+    /// Calculate the placement and the height of the popup.
+    ///
+    /// * placement: the configured placement.
+    /// * area: the area the popup is placed relative to.
+    /// * boundary: outer limit for the popup.
+    /// * height: full height of the popup.
+    pub fn layout(
+        &self,
+        placement: Placement,
+        area: Rect,
+        boundary: Rect,
+        height: u16,
+    ) -> (Placement, u16) {
+        let above = area.y.saturating_sub(boundary.y);
+        let below = boundary.bottom().saturating_sub(area.bottom());
+
+        match self {
+            PlacementStrategy::Fixed => {
+                let space = match placement {
+                    Placement::AboveLeft
+                    | Placement::AboveCenter
+                    | Placement::AboveRight
+                    | Placement::Above => above,
+                    Placement::BelowLeft
+                    | Placement::BelowCenter
+                    | Placement::BelowRight
+                    | Placement::Below => below,
+                    Placement::AboveOrBelow => {
+                        return if height <= above || above >= below {
+                            (Placement::Above, min(height, above))
+                        } else {
+                            (Placement::Below, min(height, below))
+                        };
+                    }
+                    Placement::BelowOrAbove => {
+                        return if height <= below || below >= above {
+                            (Placement::Below, min(height, below))
+                        } else {
+                            (Placement::Above, min(height, above))
+                        };
+                    }
+                    _ => boundary.height,
+                };
+                (placement, min(height, space))
+            }
+            PlacementStrategy::FlipShrink { min_len } => {
+                let (prefer_above, preferred, flipped) = match placement {
+                    Placement::AboveLeft => (true, placement, Placement::BelowLeft),
+                    Placement::AboveCenter => (true, placement, Placement::BelowCenter),
+                    Placement::AboveRight => (true, placement, Placement::BelowRight),
+                    Placement::Above | Placement::AboveOrBelow => {
+                        (true, Placement::Above, Placement::Below)
+                    }
+                    Placement::BelowLeft => (false, placement, Placement::AboveLeft),
+                    Placement::BelowCenter => (false, placement, Placement::AboveCenter),
+                    Placement::BelowRight => (false, placement, Placement::AboveRight),
+                    Placement::Below | Placement::BelowOrAbove => {
+                        (false, Placement::Below, Placement::Above)
+                    }
+                    _ => return PlacementStrategy::Fixed.layout(placement, area, boundary, height),
+                };
+                let (space, other) = if prefer_above {
+                    (above, below)
+                } else {
+                    (below, above)
+                };
+
+                if height <= space {
+                    (preferred, height)
+                } else if other > space {
+                    (flipped, max(min(height, other), *min_len))
+                } else {
+                    (preferred, max(space, *min_len))
+                }
+            }
+        }
+    }
+}
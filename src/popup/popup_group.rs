@@ -0,0 +1,80 @@
+//!
+//! Keep only one popup of a group open.
+//!
+
+use rat_popup::PopupCoreState;
+
+/// Keeps only one popup of a group open.
+///
+/// Call [update](PopupGroup::update) after event-handling
+/// with all popups of the group. If a second popup has been
+/// opened all other popups of the group are closed.
+///
+/// The popups must be given in the same order for every call.
+///
+/// The group doesn't hold on to the popups. The widget states
+/// are plain values owned by the application state, there is
+/// no Rc to take a weak reference from. Instead, the popups
+/// are passed in with each call.
+///
+/// ```rust ignore
+/// // ... event handling
+///
+/// if state.popups.update(&mut [&mut state.choice1.popup, &mut state.choice2.popup]) {
+///     r = max(r, Outcome::Changed);
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct PopupGroup {
+    /// Index of the open popup.
+    /// __read only__
+    pub active: Option<usize>,
+}
+
+impl PopupGroup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Closes all but the most recently opened popup.
+    ///
+    /// Returns true if any popup has been closed.
+    pub fn update(&mut self, popups: &mut [&mut PopupCoreState]) -> bool {
+        // a newly opened popup wins, otherwise keep the current one.
+        let keep = popups
+            .iter()
+            .enumerate()
+            .filter(|(_, v)| v.is_active())
+            .map(|(i, _)| i)
+            .find(|v| Some(*v) != self.active)
+            .or(self
+                .active
+                .filter(|v| popups.get(*v).map(|p| p.is_active()).unwrap_or(false)));
+
+        let mut changed = false;
+        for (i, popup) in popups.iter_mut().enumerate() {
+            if popup.is_active() && Some(i) != keep {
+                popup.set_active(false);
+                changed = true;
+            }
+        }
+
+        self.active = keep;
+        changed
+    }
+
+    /// Close all popups.
+    ///
+    /// Returns true if any popup has been closed.
+    pub fn close_all(&mut self, popups: &mut [&mut PopupCoreState]) -> bool {
+        let mut changed = false;
+        for popup in popups.iter_mut() {
+            if popup.is_active() {
+                popup.set_active(false);
+                changed = true;
+            }
+        }
+        self.active = None;
+        changed
+    }
+}
@@ -0,0 +1,326 @@
+//!
+//! Move/resize mode for any Rect.
+//!
+//! The application enters the mode with [RectAdjustState::start]
+//! with whatever binding it likes. While active
+//!
+//! * arrow keys move the rect
+//! * Shift+arrow keys resize it
+//! * Enter confirms and returns [RectAdjustOutcome::Confirm]
+//! * Esc restores the original and returns [RectAdjustOutcome::Cancel]
+//!
+//! The tentative rect is [RectAdjustState::rect], and [RectAdjust]
+//! renders an outline there as preview.
+//!
+//! ```rust ignore
+//! match rect_adjust::handle_events(&mut state.adjust, event) {
+//!     RectAdjustOutcome::Confirm(area) => state.dialog_area = area,
+//!     r => r.into(),
+//! }
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, Outcome, Regular};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::style::Style;
+use ratatui::widgets::{Block, StatefulWidget, Widget};
+
+/// Renders the preview outline of the tentative rect.
+///
+/// Renders nothing if the state is not active.
+/// The area given to render clips the outline.
+#[derive(Debug, Clone)]
+pub struct RectAdjust<'a> {
+    style: Style,
+    block: Block<'a>,
+}
+
+/// Combined styles.
+#[derive(Debug, Clone)]
+pub struct RectAdjustStyle {
+    pub style: Style,
+    pub block: Option<Block<'static>>,
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// State for move/resize mode.
+#[derive(Debug, Clone)]
+pub struct RectAdjustState {
+    /// Tentative rect.
+    /// __read only__ while the mode is active.
+    pub rect: Rect,
+    /// Rect when the mode was entered.
+    /// __read only__
+    pub original: Rect,
+    /// The rect is kept inside this boundary.
+    /// __read+write__
+    pub boundary: Option<Rect>,
+    /// Minimum size.
+    /// __read+write__
+    pub min_size: Size,
+    /// Mode is active.
+    /// __read only__
+    pub active: bool,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// Result of event-handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RectAdjustOutcome {
+    /// The given event was not handled at all.
+    Continue,
+    /// The event was handled, no repaint necessary.
+    Unchanged,
+    /// The tentative rect changed.
+    Changed,
+    /// Enter has been pressed. Contains the final rect.
+    Confirm(Rect),
+    /// Esc has been pressed. Contains the restored original rect.
+    Cancel(Rect),
+}
+
+impl ConsumedEvent for RectAdjustOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != RectAdjustOutcome::Continue
+    }
+}
+
+impl From<bool> for RectAdjustOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            RectAdjustOutcome::Changed
+        } else {
+            RectAdjustOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for RectAdjustOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => RectAdjustOutcome::Continue,
+            Outcome::Unchanged => RectAdjustOutcome::Unchanged,
+            Outcome::Changed => RectAdjustOutcome::Changed,
+        }
+    }
+}
+
+impl From<RectAdjustOutcome> for Outcome {
+    fn from(value: RectAdjustOutcome) -> Self {
+        match value {
+            RectAdjustOutcome::Continue => Outcome::Continue,
+            RectAdjustOutcome::Unchanged => Outcome::Unchanged,
+            RectAdjustOutcome::Changed => Outcome::Changed,
+            RectAdjustOutcome::Confirm(_) => Outcome::Changed,
+            RectAdjustOutcome::Cancel(_) => Outcome::Changed,
+        }
+    }
+}
+
+impl Default for RectAdjust<'_> {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            block: Block::bordered(),
+        }
+    }
+}
+
+impl<'a> RectAdjust<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Combined styles.
+    pub fn styles(mut self, styles: RectAdjustStyle) -> Self {
+        self.style = styles.style;
+        if let Some(block) = styles.block {
+            self.block = block;
+        }
+        self
+    }
+
+    /// Style for the outline.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Block used for the outline.
+    ///
+    /// __Default__
+    /// Block::bordered()
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+}
+
+impl Default for RectAdjustStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            block: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl StatefulWidget for RectAdjust<'_> {
+    type State = RectAdjustState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if !state.active {
+            return;
+        }
+        let outline = state.rect.intersection(area);
+        if outline.is_empty() {
+            return;
+        }
+        self.block.border_style(self.style).render(outline, buf);
+    }
+}
+
+impl Default for RectAdjustState {
+    fn default() -> Self {
+        Self {
+            rect: Default::default(),
+            original: Default::default(),
+            boundary: None,
+            min_size: Size::new(1, 1),
+            active: false,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RectAdjustState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the rect inside this boundary.
+    pub fn with_boundary(mut self, boundary: Rect) -> Self {
+        self.boundary = Some(boundary);
+        self
+    }
+
+    /// Minimum size for resizing.
+    pub fn with_min_size(mut self, min_size: Size) -> Self {
+        self.min_size = min_size;
+        self
+    }
+
+    /// Mode is active.
+    pub fn is_active(&self) -> bool {
+        self.active
+    }
+
+    /// Enter the move/resize mode for the given rect.
+    ///
+    /// The rect is clamped to the boundary.
+    pub fn start(&mut self, rect: Rect) {
+        self.original = rect;
+        self.rect = self.clamp(rect);
+        self.active = true;
+    }
+
+    /// Leave the mode and return the tentative rect.
+    pub fn confirm(&mut self) -> Rect {
+        self.active = false;
+        self.rect
+    }
+
+    /// Leave the mode and restore the original rect.
+    pub fn cancel(&mut self) -> Rect {
+        self.active = false;
+        self.rect = self.original;
+        self.rect
+    }
+
+    /// Move the rect. Clamped to the boundary.
+    pub fn move_by(&mut self, dx: i16, dy: i16) -> bool {
+        let mut rect = self.rect;
+        rect.x = rect.x.saturating_add_signed(dx);
+        rect.y = rect.y.saturating_add_signed(dy);
+        let rect = self.clamp(rect);
+        let old = self.rect;
+        self.rect = rect;
+        old != rect
+    }
+
+    /// Resize the rect. The top-left corner stays in place.
+    /// Clamped to the boundary and min_size.
+    pub fn resize_by(&mut self, dw: i16, dh: i16) -> bool {
+        let mut rect = self.rect;
+        rect.width = rect
+            .width
+            .saturating_add_signed(dw)
+            .max(self.min_size.width);
+        rect.height = rect
+            .height
+            .saturating_add_signed(dh)
+            .max(self.min_size.height);
+        if let Some(boundary) = self.boundary {
+            rect.width = rect.width.min(boundary.right().saturating_sub(rect.x));
+            rect.height = rect.height.min(boundary.bottom().saturating_sub(rect.y));
+        }
+        let rect = self.clamp(rect);
+        let old = self.rect;
+        self.rect = rect;
+        old != rect
+    }
+
+    fn clamp(&self, mut rect: Rect) -> Rect {
+        if let Some(boundary) = self.boundary {
+            rect.width = rect.width.min(boundary.width);
+            rect.height = rect.height.min(boundary.height);
+            rect.x = rect
+                .x
+                .clamp(boundary.x, boundary.right().saturating_sub(rect.width));
+            rect.y = rect
+                .y
+                .clamp(boundary.y, boundary.bottom().saturating_sub(rect.height));
+        }
+        rect
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, RectAdjustOutcome> for RectAdjustState {
+    fn handle(
+        &mut self,
+        event: &crossterm::event::Event,
+        _qualifier: Regular,
+    ) -> RectAdjustOutcome {
+        if !self.active {
+            return RectAdjustOutcome::Continue;
+        }
+
+        match event {
+            ct_event!(keycode press Left) => self.move_by(-1, 0).into(),
+            ct_event!(keycode press Right) => self.move_by(1, 0).into(),
+            ct_event!(keycode press Up) => self.move_by(0, -1).into(),
+            ct_event!(keycode press Down) => self.move_by(0, 1).into(),
+            ct_event!(keycode press SHIFT-Left) => self.resize_by(-1, 0).into(),
+            ct_event!(keycode press SHIFT-Right) => self.resize_by(1, 0).into(),
+            ct_event!(keycode press SHIFT-Up) => self.resize_by(0, -1).into(),
+            ct_event!(keycode press SHIFT-Down) => self.resize_by(0, 1).into(),
+            ct_event!(keycode press Enter) => RectAdjustOutcome::Confirm(self.confirm()),
+            ct_event!(keycode press Esc) => RectAdjustOutcome::Cancel(self.cancel()),
+            crossterm::event::Event::Key(_) => RectAdjustOutcome::Unchanged,
+            _ => RectAdjustOutcome::Continue,
+        }
+    }
+}
+
+/// Handle all events.
+///
+/// All key events are consumed while the mode is active.
+pub fn handle_events(
+    state: &mut RectAdjustState,
+    event: &crossterm::event::Event,
+) -> RectAdjustOutcome {
+    HandleEvent::handle(state, event, Regular)
+}
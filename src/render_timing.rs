@@ -0,0 +1,107 @@
+//!
+//! Render timing diagnostics.
+//!
+//! With the feature `render-timing` View, Clipper, SinglePager and
+//! DualPager record the time from into_buffer() until they are
+//! done, named after their container flag. Widgets from other
+//! crates (Table, TextArea, ...) can be measured with [timed].
+//!
+//! The entries are collected per thread. Read them after the
+//! frame is rendered and reset them before the next one.
+//!
+//! ```rust ignore
+//! terminal.draw(|frame| render(frame, state))?;
+//! let report = render_timing::render_report();
+//! render_timing::reset_render_report();
+//! ```
+//!
+//! Without the feature nothing is recorded, the report is always
+//! empty and there is no runtime overhead.
+//!
+use std::time::Duration;
+
+#[cfg(feature = "render-timing")]
+use std::cell::RefCell;
+#[cfg(feature = "render-timing")]
+use std::time::Instant;
+
+/// One timed render.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderEntry {
+    /// Widget type.
+    pub widget: &'static str,
+    /// Name of the container/focus flag, if any.
+    pub name: String,
+    /// Time spent.
+    pub elapsed: Duration,
+}
+
+#[cfg(feature = "render-timing")]
+thread_local! {
+    static REPORT: RefCell<Vec<RenderEntry>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Entries recorded since the last reset, in the order
+/// the renders finished.
+pub fn render_report() -> Vec<RenderEntry> {
+    #[cfg(feature = "render-timing")]
+    {
+        REPORT.with_borrow(|v| v.clone())
+    }
+    #[cfg(not(feature = "render-timing"))]
+    {
+        Vec::new()
+    }
+}
+
+/// Clear the report.
+pub fn reset_render_report() {
+    #[cfg(feature = "render-timing")]
+    REPORT.with_borrow_mut(|v| v.clear());
+}
+
+/// Time the closure and add an entry to the report.
+pub fn timed<R>(widget: &'static str, name: &str, f: impl FnOnce() -> R) -> R {
+    let _timer = RenderTimer::start(widget, name);
+    f()
+}
+
+/// Records an entry when dropped.
+///
+/// Zero sized without the feature.
+#[derive(Debug)]
+pub(crate) struct RenderTimer {
+    #[cfg(feature = "render-timing")]
+    widget: &'static str,
+    #[cfg(feature = "render-timing")]
+    name: String,
+    #[cfg(feature = "render-timing")]
+    start: Instant,
+}
+
+impl RenderTimer {
+    #[allow(unused_variables)]
+    #[inline]
+    pub(crate) fn start(widget: &'static str, name: &str) -> Self {
+        Self {
+            #[cfg(feature = "render-timing")]
+            widget,
+            #[cfg(feature = "render-timing")]
+            name: name.to_string(),
+            #[cfg(feature = "render-timing")]
+            start: Instant::now(),
+        }
+    }
+}
+
+#[cfg(feature = "render-timing")]
+impl Drop for RenderTimer {
+    fn drop(&mut self) {
+        let entry = RenderEntry {
+            widget: self.widget,
+            name: std::mem::take(&mut self.name),
+            elapsed: self.start.elapsed(),
+        };
+        REPORT.with_borrow_mut(|v| v.push(entry));
+    }
+}
@@ -0,0 +1,109 @@
+//!
+//! Reset the text widgets of a form.
+//!
+//! The `clear()` functions of the text widgets differ in what
+//! they reset. [TextReset] gives them the same surface, and
+//! [reset_form] clears all the fields of a form.
+//!
+//! All functions of [TextReset] reset the cursor, the selection,
+//! the scroll offset and the undo buffer.
+//!
+//! ```rust ignore
+//! // on cancel
+//! reset_form(&mut [&mut state.name, &mut state.zip, &mut state.notes]);
+//! ```
+//!
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use crate::textarea::TextAreaState;
+
+/// Uniform clear/set for the text widgets.
+pub trait TextReset {
+    /// Empty. For masked input this is the empty mask.
+    fn is_empty(&self) -> bool;
+
+    /// Replace the content.
+    fn set_value(&mut self, value: &str);
+
+    /// Empty content.
+    ///
+    /// Returns true if there was any content.
+    fn reset(&mut self) -> bool;
+}
+
+/// Reset all fields.
+///
+/// Returns true if any field had content.
+pub fn reset_form(fields: &mut [&mut dyn TextReset]) -> bool {
+    let mut r = false;
+    for field in fields.iter_mut() {
+        r |= field.reset();
+    }
+    r
+}
+
+/// Restore the empty-mask template.
+///
+/// Sets the cursor to the default position of the mask.
+/// Returns true if there was any content.
+pub fn reset_to_mask(state: &mut MaskedInputState) -> bool {
+    let r = !state.is_empty();
+    state.offset = 0;
+    state.value.clear();
+    r
+}
+
+/// Cursor at the start.
+impl TextReset for TextInputState {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn set_value(&mut self, value: &str) {
+        self.set_value(value);
+        self.set_cursor(0, false);
+    }
+
+    fn reset(&mut self) -> bool {
+        let r = !self.is_empty();
+        self.set_value("");
+        self.set_cursor(0, false);
+        r
+    }
+}
+
+/// Resets to the empty mask, cursor at the default position.
+/// The value is padded or truncated to the mask.
+impl TextReset for MaskedInputState {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn set_value(&mut self, value: &str) {
+        self.set_text(value);
+        self.set_default_cursor();
+    }
+
+    fn reset(&mut self) -> bool {
+        reset_to_mask(self)
+    }
+}
+
+/// Cursor at the start, both scroll offsets at 0.
+impl TextReset for TextAreaState {
+    fn is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    fn set_value(&mut self, value: &str) {
+        self.set_text(value);
+        self.set_cursor((0, 0), false);
+    }
+
+    fn reset(&mut self) -> bool {
+        let r = !self.is_empty();
+        self.set_text("");
+        self.set_cursor((0, 0), false);
+        r
+    }
+}
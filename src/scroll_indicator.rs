@@ -0,0 +1,266 @@
+//!
+//! Transient scroll position overlay.
+//!
+//! Shows a small label like "45%" or "120/2000" in the bottom-right
+//! corner of the widget area while scrolling happens. It disappears
+//! after a number of renders without a change of the offset.
+//! There are no timers involved.
+//!
+//! List and View support it directly. For other widgets render it
+//! after the widget with their ScrollState.
+//!
+//! [MoreIndicator] is a lighter alternative for narrow popups.
+//! It marks the top/bottom edge with ▲/▼ if there are more
+//! items above/below.
+//!
+//! ```rust ignore
+//! TextArea::new().render(area, buf, &mut state.text);
+//! ScrollIndicatorOverlay::new(ScrollIndicator::Lines).render(
+//!     state.text.inner,
+//!     buf,
+//!     &state.text.vscroll,
+//!     &mut state.text_indicator,
+//! );
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::util::revert_style;
+use rat_scrolled::ScrollState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::text::Span;
+use ratatui::widgets::Widget;
+
+/// What the indicator shows.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollIndicator {
+    /// Percentage of the max offset, "45%".
+    #[default]
+    Percent,
+    /// First visible line and the total, "120/2000".
+    Lines,
+}
+
+/// Renders the indicator.
+#[derive(Debug, Clone)]
+pub struct ScrollIndicatorOverlay {
+    indicator: ScrollIndicator,
+    style: Style,
+    fade: usize,
+}
+
+/// Combined style.
+#[derive(Debug, Clone)]
+pub struct ScrollIndicatorStyle {
+    pub style: Style,
+    /// Number of renders without scrolling until the
+    /// indicator disappears.
+    pub fade: Option<usize>,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// Marks the top/bottom edge if there is more to scroll.
+#[derive(Debug, Clone)]
+pub struct MoreIndicator {
+    style: Style,
+    above: &'static str,
+    below: &'static str,
+}
+
+/// Bookkeeping for the indicator.
+///
+/// Lives in the state of the widget that owns the ScrollState.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ScrollIndicatorState {
+    /// Offset seen with the last render.
+    /// __read only__
+    pub offset: Option<usize>,
+    /// Renders since the offset changed.
+    /// __read only__
+    pub idle: usize,
+}
+
+impl Default for ScrollIndicatorStyle {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            fade: None,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl Default for ScrollIndicatorOverlay {
+    fn default() -> Self {
+        Self {
+            indicator: Default::default(),
+            style: Default::default(),
+            fade: 10,
+        }
+    }
+}
+
+impl ScrollIndicatorOverlay {
+    pub fn new(indicator: ScrollIndicator) -> Self {
+        Self {
+            indicator,
+            ..Default::default()
+        }
+    }
+
+    /// Combined style.
+    pub fn styles(mut self, styles: ScrollIndicatorStyle) -> Self {
+        self.style = styles.style;
+        if let Some(fade) = styles.fade {
+            self.fade = fade;
+        }
+        self
+    }
+
+    /// Style for the label.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Number of renders without scrolling until the
+    /// indicator disappears.
+    ///
+    /// __Default__
+    /// 10
+    pub fn fade(mut self, fade: usize) -> Self {
+        self.fade = fade;
+        self
+    }
+
+    /// Label for the scroll position.
+    pub fn label(&self, scroll: &ScrollState) -> String {
+        match self.indicator {
+            ScrollIndicator::Percent => {
+                format!("{}%", (scroll_fraction(scroll) * 100.0) as usize)
+            }
+            ScrollIndicator::Lines => {
+                let total = scroll.max_offset + scroll.page_len;
+                format!("{}/{}", (scroll.offset + 1).min(total), total)
+            }
+        }
+    }
+
+    /// Render the indicator in the bottom-right corner of the area.
+    ///
+    /// The area should be the inner area of the widget, without
+    /// block and scrollbars. Only the cells of the label are touched.
+    pub fn render(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        scroll: &ScrollState,
+        state: &mut ScrollIndicatorState,
+    ) {
+        if !state.update(scroll, self.fade) {
+            return;
+        }
+
+        let label = format!(" {} ", self.label(scroll));
+        let width = (label.len() as u16).min(area.width);
+        if width == 0 || area.height == 0 {
+            return;
+        }
+        let label_area = Rect::new(area.right() - width, area.bottom() - 1, width, 1);
+        let style = if self.style == Style::default() {
+            revert_style(self.style)
+        } else {
+            self.style
+        };
+        Span::styled(label, style).render(label_area, buf);
+    }
+}
+
+impl Default for MoreIndicator {
+    fn default() -> Self {
+        Self {
+            style: Default::default(),
+            above: "▲",
+            below: "▼",
+        }
+    }
+}
+
+impl MoreIndicator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Style for the marks.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Glyphs for more above/below.
+    ///
+    /// __Default__
+    /// "▲" and "▼"
+    pub fn glyphs(mut self, above: &'static str, below: &'static str) -> Self {
+        self.above = above;
+        self.below = below;
+        self
+    }
+
+    /// Render the marks in the top-right and bottom-right cell
+    /// of the area.
+    ///
+    /// Use the rows of the border for the area, if there is
+    /// one, and the right edge of the inner area.
+    pub fn render(&self, area: Rect, buf: &mut Buffer, scroll: &ScrollState) {
+        if area.is_empty() {
+            return;
+        }
+        let x = area.right() - 1;
+        if scroll.offset > 0 {
+            buf.set_string(x, area.top(), self.above, self.style);
+        }
+        if scroll.offset < scroll.max_offset {
+            buf.set_string(x, area.bottom() - 1, self.below, self.style);
+        }
+    }
+}
+
+impl ScrollIndicatorState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track the offset. Call once per render.
+    ///
+    /// Returns true if the indicator should be visible.
+    /// The first render only records the offset.
+    pub fn update(&mut self, scroll: &ScrollState, fade: usize) -> bool {
+        match self.offset {
+            None => {
+                self.idle = fade;
+            }
+            Some(offset) if offset != scroll.offset => {
+                self.idle = 0;
+            }
+            Some(_) => {
+                self.idle = self.idle.saturating_add(1);
+            }
+        }
+        self.offset = Some(scroll.offset);
+        self.idle < fade
+    }
+}
+
+/// Scroll position as a fraction 0.0..=1.0 of the max offset.
+///
+/// Returns 1.0 if there is nothing to scroll.
+pub fn scroll_fraction(scroll: &ScrollState) -> f64 {
+    if scroll.max_offset == 0 {
+        1.0
+    } else {
+        scroll.offset.min(scroll.max_offset) as f64 / scroll.max_offset as f64
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! Configurable scroll step for the mouse wheel.
+//!
+//! The widgets use [ScrollState::scroll_by] for the wheel, which
+//! defaults to 1/10 of the page. View, Clipper, List and Paragraph
+//! have a `scroll_step()` builder, for other widgets apply it
+//! to their ScrollState after rendering.
+//!
+//! ```rust ignore
+//! TextArea::new().render(area, buf, &mut state.text);
+//! ScrollStep::Lines(3).apply(&mut state.text.vscroll);
+//! ```
+//!
+use rat_scrolled::ScrollState;
+
+/// How far one wheel notch scrolls.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrollStep {
+    /// Number of lines/columns.
+    Lines(usize),
+    /// One page.
+    Page,
+}
+
+impl ScrollStep {
+    /// Set scroll_by. Uses the page_len of the ScrollState,
+    /// so call this after the page_len is known.
+    pub fn apply(&self, scroll: &mut ScrollState) {
+        let step = match self {
+            ScrollStep::Lines(n) => *n,
+            ScrollStep::Page => scroll.page_len(),
+        };
+        scroll.set_scroll_by(Some(step.max(1)));
+    }
+}
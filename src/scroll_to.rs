@@ -0,0 +1,82 @@
+//!
+//! Scroll some part of a widget into view.
+//!
+//! [ScrollTo] is implemented by the scrollable states, so
+//! generic code can keep something visible regardless of the
+//! widget. Each widget uses its own coordinates, see
+//! [ScrollTo::Range].
+//!
+//! | State | Range |
+//! |---|---|
+//! | [ListState](crate::list::ListState) | item indexes |
+//! | [ChoiceState](crate::choice::ChoiceState) | item indexes of the popup |
+//! | [TableState](crate::table::TableState) | row indexes |
+//! | [TextAreaState](crate::textarea::TextAreaState) | text positions |
+//! | [ViewState](crate::view::ViewState) | view buffer coordinates |
+//! | [ClipperState](crate::clipper::ClipperState) | layout coordinates |
+//!
+//! ```rust ignore
+//! // keep the focused widget visible.
+//! if let Some(area) = state.layout.area_of(focused) {
+//!     state.clipper.ensure_visible(area);
+//! }
+//! ```
+//!
+use crate::table::{TableSelection, TableState};
+use crate::text::TextRange;
+use crate::textarea::TextAreaState;
+use ratatui::layout::Rect;
+use std::ops::Range;
+
+/// Scroll a range into view.
+pub trait ScrollTo {
+    /// Coordinates of the range.
+    type Range;
+
+    /// Scroll until the range is visible. If the range is
+    /// bigger than the visible area, its start is shown.
+    ///
+    /// Returns true if the offset changed.
+    fn ensure_visible(&mut self, range: Self::Range) -> bool;
+}
+
+/// Row indexes.
+impl<Selection: TableSelection> ScrollTo for TableState<Selection> {
+    type Range = Range<usize>;
+
+    fn ensure_visible(&mut self, range: Range<usize>) -> bool {
+        let end = range.end.max(range.start + 1);
+        self.vscroll.scroll_to_range(range.start..end)
+    }
+}
+
+/// Text positions. Horizontal scrolling uses the grapheme
+/// columns, which is exact for text without wide characters
+/// or tabs.
+impl ScrollTo for TextAreaState {
+    type Range = TextRange;
+
+    fn ensure_visible(&mut self, range: TextRange) -> bool {
+        let rows = range.start.y as usize..range.end.y as usize + 1;
+        let r = self.vscroll.scroll_to_range(rows);
+        let s = if range.start.y == range.end.y {
+            let end = range.end.x.max(range.start.x + 1);
+            self.hscroll
+                .scroll_to_range(range.start.x as usize..end as usize)
+        } else {
+            self.hscroll.scroll_to_pos(range.start.x as usize)
+        };
+        r || s
+    }
+}
+
+/// Scroll the area of some plane into view.
+pub(crate) fn ensure_area_visible(
+    hscroll: &mut rat_scrolled::ScrollState,
+    vscroll: &mut rat_scrolled::ScrollState,
+    area: Rect,
+) -> bool {
+    let h = hscroll.scroll_to_range(area.left() as usize..area.right().max(area.x + 1) as usize);
+    let v = vscroll.scroll_to_range(area.top() as usize..area.bottom().max(area.y + 1) as usize);
+    h || v
+}
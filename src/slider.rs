@@ -19,7 +19,7 @@
 
 use crate::_private::NonExhaustive;
 use crate::range_op::RangeOp;
-use crate::util::revert_style;
+use crate::util::{block_size, revert_style};
 use map_range_int::MapRange;
 use rat_event::util::MouseFlags;
 use rat_event::{ct_event, HandleEvent, MouseOnly, Outcome, Regular};
@@ -342,6 +342,48 @@ where
         self.block = self.block.map(|v| v.style(self.style));
         self
     }
+
+    /// Inherent width.
+    ///
+    /// This is the minimum, any extra space goes to the track.
+    pub fn width(&self) -> u16 {
+        let knob = text_size(self.render_knob_str(1, false).as_ref());
+        let lower = text_size(self.lower_bound.as_deref().unwrap_or_default());
+        let upper = text_size(self.upper_bound.as_deref().unwrap_or_default());
+        let w = match self.direction {
+            Direction::Horizontal => lower.0 + knob.0 + upper.0,
+            Direction::Vertical => lower.0.max(knob.0).max(upper.0),
+        };
+        w + block_size(&self.block).width
+    }
+
+    /// Inherent height.
+    ///
+    /// This is the minimum, any extra space goes to the track.
+    pub fn height(&self) -> u16 {
+        let knob = text_size(self.render_knob_str(1, false).as_ref());
+        let lower = text_size(self.lower_bound.as_deref().unwrap_or_default());
+        let upper = text_size(self.upper_bound.as_deref().unwrap_or_default());
+        let h = match self.direction {
+            Direction::Horizontal => lower.1.max(knob.1).max(upper.1),
+            Direction::Vertical => lower.1 + 1 + upper.1,
+        };
+        h + block_size(&self.block).height
+    }
+}
+
+// Width and line count. The empty string has no size.
+fn text_size(text: &str) -> (u16, u16) {
+    if text.is_empty() {
+        (0, 0)
+    } else {
+        let w = text
+            .split('\n')
+            .map(|v| v.width())
+            .max()
+            .unwrap_or_default();
+        (w as u16, text.split('\n').count() as u16)
+    }
 }
 
 impl<'a, T> Slider<'a, T>
@@ -753,6 +795,13 @@ macro_rules! slider_new {
             pub fn new() -> Self {
                 Self::new_range((<$tt>::MIN, <$tt>::MAX), 1)
             }
+
+            pub fn named(name: &str) -> Self {
+                Self {
+                    focus: FocusFlag::named(name),
+                    ..Self::new()
+                }
+            }
         }
     };
 }
@@ -784,6 +833,13 @@ macro_rules! slider_new_f {
             pub fn new() -> Self {
                 Self::new_range((<$tt>::MIN, <$tt>::MAX), 1.)
             }
+
+            pub fn named(name: &str) -> Self {
+                Self {
+                    focus: FocusFlag::named(name),
+                    ..Self::new()
+                }
+            }
         }
     };
 }
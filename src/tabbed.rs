@@ -489,6 +489,26 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, TabbedOutcome> for TabbedSt
     }
 }
 
+/// Handle all events.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut TabbedState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> TabbedOutcome {
+    state.focus.set(focus);
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(
+    state: &mut TabbedState,
+    event: &crossterm::event::Event,
+) -> TabbedOutcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
+
 /// The design space for tabs is too big to capture with a handful of parameters.
 ///
 /// This trait splits off the layout and rendering of the actual tabs from
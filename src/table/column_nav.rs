@@ -0,0 +1,264 @@
+//!
+//! Keyboard navigation between the columns of a table.
+//!
+//! [handle_events] moves the selected column with Left/Right and
+//! jumps to the first/last column with Home/End, and scrolls the
+//! column into view. This works for every selection with a
+//! column part, [CellSelection] and [ColumnSelection]. With
+//! [RowSelection], [RowSetSelection] and [NoSelection] it does
+//! nothing, and the keys go on to the table, which scrolls
+//! horizontally.
+//!
+//! [ColumnSelection] selects a whole column. The table renders it
+//! with the select_column_style. It has no event-handling in
+//! rat-ftable, use [handle_column_selection] instead.
+//!
+//! ```rust ignore
+//! let r = column_nav::handle_events(&mut state.table, focus, event);
+//! let r = r.or_else(|| state.table.handle(event, Regular));
+//!
+//! if let Some(column) = state.table.selection.selected_column() {
+//!     // ...
+//! }
+//! ```
+//!
+use crate::event::Outcome;
+use crate::table::selection::{CellSelection, NoSelection, RowSelection, RowSetSelection};
+use crate::table::{TableSelection, TableState};
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::ScrollAreaState;
+use std::cmp::{max, min};
+
+/// Column part of a selection.
+pub trait ColumnNav: TableSelection {
+    /// The selection has a column part.
+    fn has_column(&self) -> bool;
+
+    /// Selected column, if the selection has a column part.
+    fn selected_column(&self) -> Option<usize>;
+
+    /// Select a column, clamped to maximum.
+    ///
+    /// Returns false if the selection has no column part.
+    fn move_to_column(&mut self, column: usize, maximum: usize) -> bool;
+}
+
+/// Select a whole column.
+#[derive(Debug, Default, Clone)]
+pub struct ColumnSelection {
+    /// Selected column.
+    pub lead_column: Option<usize>,
+}
+
+impl TableSelection for ColumnSelection {
+    fn is_selected_row(&self, _row: usize) -> bool {
+        false
+    }
+
+    fn is_selected_column(&self, column: usize) -> bool {
+        self.lead_column == Some(column)
+    }
+
+    fn is_selected_cell(&self, _column: usize, _row: usize) -> bool {
+        false
+    }
+
+    fn lead_selection(&self) -> Option<(usize, usize)> {
+        // no row part, scroll_to_selected() would jump
+        // to the first row.
+        None
+    }
+}
+
+impl ColumnSelection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clear the selection.
+    pub fn clear(&mut self) {
+        self.lead_column = None;
+    }
+
+    /// Select a column.
+    pub fn select(&mut self, column: Option<usize>) -> bool {
+        let old = self.lead_column;
+        self.lead_column = column;
+        old != self.lead_column
+    }
+}
+
+impl ColumnNav for ColumnSelection {
+    fn has_column(&self) -> bool {
+        true
+    }
+
+    fn selected_column(&self) -> Option<usize> {
+        self.lead_column
+    }
+
+    fn move_to_column(&mut self, column: usize, maximum: usize) -> bool {
+        self.select(Some(min(column, maximum)))
+    }
+}
+
+impl ColumnNav for CellSelection {
+    fn has_column(&self) -> bool {
+        true
+    }
+
+    fn selected_column(&self) -> Option<usize> {
+        self.lead_cell.map(|(column, _)| column)
+    }
+
+    fn move_to_column(&mut self, column: usize, maximum: usize) -> bool {
+        self.move_to_col(column, maximum)
+    }
+}
+
+impl ColumnNav for RowSelection {
+    fn has_column(&self) -> bool {
+        false
+    }
+
+    fn selected_column(&self) -> Option<usize> {
+        None
+    }
+
+    fn move_to_column(&mut self, _column: usize, _maximum: usize) -> bool {
+        false
+    }
+}
+
+impl ColumnNav for RowSetSelection {
+    fn has_column(&self) -> bool {
+        false
+    }
+
+    fn selected_column(&self) -> Option<usize> {
+        None
+    }
+
+    fn move_to_column(&mut self, _column: usize, _maximum: usize) -> bool {
+        false
+    }
+}
+
+impl ColumnNav for NoSelection {
+    fn has_column(&self) -> bool {
+        false
+    }
+
+    fn selected_column(&self) -> Option<usize> {
+        None
+    }
+
+    fn move_to_column(&mut self, _column: usize, _maximum: usize) -> bool {
+        false
+    }
+}
+
+/// Select a column and scroll it into view.
+///
+/// Returns false if nothing changed or the selection has
+/// no column part.
+pub fn move_to_column<Selection: ColumnNav>(
+    state: &mut TableState<Selection>,
+    column: usize,
+) -> bool {
+    if state.columns == 0 {
+        return false;
+    }
+    let maximum = state.columns - 1;
+    let r = state.selection.move_to_column(column, maximum);
+    let s = if let Some(column) = state.selection.selected_column() {
+        state.scroll_to_col(column)
+    } else {
+        false
+    };
+    r || s
+}
+
+/// Left/Right move the selected column, Home/End jump to the
+/// first/last column.
+///
+/// Returns [Outcome::Continue] if the selection has no column
+/// part, so the table can use the keys.
+pub fn handle_events<Selection: ColumnNav>(
+    state: &mut TableState<Selection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    if !focus || !state.selection.has_column() {
+        return Outcome::Continue;
+    }
+
+    let column = state.selection.selected_column();
+    let new_column = match event {
+        ct_event!(keycode press Left) => column.map(|v| v.saturating_sub(1)).unwrap_or(0),
+        ct_event!(keycode press Right) => column.map(|v| v + 1).unwrap_or(0),
+        ct_event!(keycode press Home) => 0,
+        ct_event!(keycode press End) => state.columns.saturating_sub(1),
+        _ => return Outcome::Continue,
+    };
+    move_to_column(state, new_column).into()
+}
+
+/// All events for a table with [ColumnSelection].
+///
+/// Column navigation like [handle_events]. Up/Down, PageUp/PageDown
+/// and Ctrl+Home/End scroll the rows. A click selects the column,
+/// the mouse wheel scrolls.
+pub fn handle_column_selection(
+    state: &mut TableState<ColumnSelection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    flow!(handle_events(state, focus, event));
+
+    if focus {
+        flow!(match event {
+            ct_event!(keycode press Up) => state.scroll_up(1).into(),
+            ct_event!(keycode press Down) => state.scroll_down(1).into(),
+            ct_event!(keycode press CONTROL-Home) => state.scroll_to_row(0).into(),
+            ct_event!(keycode press CONTROL-End) => {
+                state.scroll_to_row(state.rows.saturating_sub(1)).into()
+            }
+            ct_event!(keycode press PageUp) => state
+                .scroll_up(max(1, state.page_len().saturating_sub(1)))
+                .into(),
+            ct_event!(keycode press PageDown) => state
+                .scroll_down(max(1, state.page_len().saturating_sub(1)))
+                .into(),
+            _ => Outcome::Continue,
+        });
+    }
+
+    flow!(match event {
+        ct_event!(mouse down Left for column, row) => {
+            if let Some(column) = state.column_at_clicked((*column, *row)) {
+                move_to_column(state, column).into()
+            } else {
+                Outcome::Continue
+            }
+        }
+        _ => Outcome::Continue,
+    });
+
+    let mut sas = ScrollAreaState::new()
+        .area(state.inner)
+        .h_scroll(&mut state.hscroll)
+        .v_scroll(&mut state.vscroll);
+    match sas.handle(event, MouseOnly) {
+        ScrollOutcome::Up(v) => state.scroll_up(v).into(),
+        ScrollOutcome::Down(v) => state.scroll_down(v).into(),
+        ScrollOutcome::VPos(v) => state.set_row_offset(v).into(),
+        ScrollOutcome::Left(v) => state.scroll_left(v).into(),
+        ScrollOutcome::Right(v) => state.scroll_right(v).into(),
+        ScrollOutcome::HPos(v) => state.set_x_offset(v).into(),
+        ScrollOutcome::Continue => Outcome::Continue,
+        ScrollOutcome::Unchanged => Outcome::Unchanged,
+        ScrollOutcome::Changed => Outcome::Changed,
+    }
+}
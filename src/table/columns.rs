@@ -0,0 +1,446 @@
+//!
+//! Reorder and hide table columns at runtime.
+//!
+//! [TableColumnsState] keeps the column order and the hidden
+//! columns, and [OrderedColumns] presents the data to the Table
+//! in this order. Get the order with [column_order](TableColumnsState::column_order)
+//! and [hidden](TableColumnsState::hidden) to persist it.
+//!
+//! In column mode Left/Right move the selected column, Shift+Left/Right
+//! select another column and Space hides/shows it. Hidden columns
+//! are shown with the hidden style while in column mode.
+//!
+//! The Table only sees display columns. Use [data_column](TableColumnsState::data_column)
+//! to map them back, e.g. after [TableState::column_at_clicked].
+//! For [TableExport](crate::table::export::TableExport) use
+//! [from_columns](crate::table::export::TableExport::from_columns).
+//!
+//! ```rust ignore
+//! state.columns.set_columns(5);
+//! Table::new()
+//!     .data(OrderedColumns::new(data, &state.columns))
+//!     .render(area, buf, &mut state.table);
+//!
+//! // events, before the table.
+//! match columns::handle_events(&mut state.columns, focus, event) {
+//!     ColumnsOutcome::OrderChanged => save(state.columns.column_order(), state.columns.hidden()),
+//!     r => r.into(),
+//! }
+//! ```
+//!
+use crate::event::{ConsumedEvent, Outcome};
+use crate::table::textdata::Row;
+use crate::table::{TableContext, TableData};
+use rat_event::ct_event;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Modifier, Style};
+
+/// Column order and visibility.
+#[derive(Debug, Default, Clone)]
+pub struct TableColumnsState {
+    /// Data columns in display order.
+    order: Vec<usize>,
+    /// Hidden flag per data column.
+    hidden: Vec<bool>,
+    /// Column mode is active.
+    /// __read only__ use [enter](TableColumnsState::enter)/[leave](TableColumnsState::leave).
+    pub active: bool,
+    /// Selected position in the column order, used in column mode.
+    /// __read+write__
+    pub selected: usize,
+}
+
+/// Result of event handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColumnsOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// The column order or the hidden columns changed.
+    /// Save the settings now.
+    OrderChanged,
+}
+
+impl ConsumedEvent for ColumnsOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != ColumnsOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for ColumnsOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            ColumnsOutcome::Changed
+        } else {
+            ColumnsOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for ColumnsOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => ColumnsOutcome::Continue,
+            Outcome::Unchanged => ColumnsOutcome::Unchanged,
+            Outcome::Changed => ColumnsOutcome::Changed,
+        }
+    }
+}
+
+impl From<ColumnsOutcome> for Outcome {
+    fn from(value: ColumnsOutcome) -> Self {
+        match value {
+            ColumnsOutcome::Continue => Outcome::Continue,
+            ColumnsOutcome::Unchanged => Outcome::Unchanged,
+            ColumnsOutcome::Changed => Outcome::Changed,
+            ColumnsOutcome::OrderChanged => Outcome::Changed,
+        }
+    }
+}
+
+impl TableColumnsState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of data columns.
+    ///
+    /// Resets order and visibility if the number changes.
+    pub fn set_columns(&mut self, columns: usize) {
+        if self.order.len() != columns {
+            self.order = (0..columns).collect();
+            self.hidden = vec![false; columns];
+            self.selected = 0;
+        }
+    }
+
+    /// Number of data columns.
+    pub fn columns(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Data columns in display order, including hidden columns.
+    pub fn column_order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Set the column order.
+    ///
+    /// Must be a permutation of all data columns, otherwise
+    /// this is ignored and returns false.
+    pub fn set_column_order(&mut self, order: Vec<usize>) -> bool {
+        let mut seen = vec![false; order.len()];
+        for c in order.iter() {
+            match seen.get_mut(*c) {
+                Some(v) if !*v => *v = true,
+                _ => return false,
+            }
+        }
+        self.hidden.resize(order.len(), false);
+        self.order = order;
+        self.selected = self.selected.min(self.order.len().saturating_sub(1));
+        true
+    }
+
+    /// Hidden flag per data column.
+    pub fn hidden(&self) -> &[bool] {
+        &self.hidden
+    }
+
+    /// Set the hidden flags per data column.
+    pub fn set_hidden(&mut self, hidden: Vec<bool>) {
+        self.hidden = hidden;
+        self.hidden.resize(self.order.len(), false);
+    }
+
+    /// Data column is hidden.
+    pub fn is_hidden(&self, column: usize) -> bool {
+        self.hidden.get(column).copied().unwrap_or_default()
+    }
+
+    /// Hide/show the data column.
+    pub fn set_column_hidden(&mut self, column: usize, hidden: bool) {
+        if let Some(v) = self.hidden.get_mut(column) {
+            *v = hidden;
+        }
+    }
+
+    /// Data columns as displayed by the table.
+    ///
+    /// In column mode this includes the hidden columns.
+    pub fn display_columns(&self) -> Vec<usize> {
+        if self.active {
+            self.order.clone()
+        } else {
+            self.visible_columns()
+        }
+    }
+
+    /// Visible data columns in display order.
+    pub fn visible_columns(&self) -> Vec<usize> {
+        self.order
+            .iter()
+            .copied()
+            .filter(|v| !self.is_hidden(*v))
+            .collect()
+    }
+
+    /// Data column for the display column.
+    pub fn data_column(&self, display_column: usize) -> Option<usize> {
+        self.display_columns().get(display_column).copied()
+    }
+
+    /// Display column for the data column.
+    pub fn display_column(&self, data_column: usize) -> Option<usize> {
+        self.display_columns()
+            .iter()
+            .position(|v| *v == data_column)
+    }
+
+    /// Data column selected in column mode.
+    pub fn selected_column(&self) -> Option<usize> {
+        self.order.get(self.selected).copied()
+    }
+
+    /// Start column mode.
+    pub fn enter(&mut self) {
+        self.active = true;
+    }
+
+    /// End column mode.
+    pub fn leave(&mut self) {
+        self.active = false;
+    }
+
+    /// Select the previous column.
+    pub fn select_prev(&mut self) -> bool {
+        if self.selected > 0 {
+            self.selected -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Select the next column.
+    pub fn select_next(&mut self) -> bool {
+        if self.selected + 1 < self.order.len() {
+            self.selected += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the selected column to the left.
+    pub fn move_left(&mut self) -> bool {
+        if self.selected > 0 && self.selected < self.order.len() {
+            self.order.swap(self.selected - 1, self.selected);
+            self.selected -= 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move the selected column to the right.
+    pub fn move_right(&mut self) -> bool {
+        if self.selected + 1 < self.order.len() {
+            self.order.swap(self.selected, self.selected + 1);
+            self.selected += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Hide/show the selected column.
+    pub fn toggle_hidden(&mut self) -> bool {
+        if let Some(column) = self.selected_column() {
+            self.hidden[column] = !self.hidden[column];
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Presents the data columns to the Table in display order.
+///
+/// Create it for each render, like the TableData itself.
+#[derive(Debug)]
+pub struct OrderedColumns<'s, T> {
+    data: T,
+    state: &'s TableColumnsState,
+    columns: Vec<usize>,
+    select_style: Style,
+    hidden_style: Style,
+}
+
+impl<'s, T> OrderedColumns<'s, T> {
+    pub fn new(data: T, state: &'s TableColumnsState) -> Self {
+        Self {
+            data,
+            state,
+            columns: state.display_columns(),
+            select_style: Style::new().add_modifier(Modifier::REVERSED),
+            hidden_style: Style::new().add_modifier(Modifier::DIM | Modifier::CROSSED_OUT),
+        }
+    }
+
+    /// Header style for the selected column in column mode.
+    pub fn select_style(mut self, style: Style) -> Self {
+        self.select_style = style;
+        self
+    }
+
+    /// Header style for hidden columns in column mode.
+    pub fn hidden_style(mut self, style: Style) -> Self {
+        self.hidden_style = style;
+        self
+    }
+
+    fn reorder<'a>(&self, row: Row<'a>, header: bool) -> Row<'a> {
+        let mut row = row;
+        let mut cells = std::mem::take(&mut row.cells)
+            .into_iter()
+            .map(Some)
+            .collect::<Vec<_>>();
+        row.cells = self
+            .columns
+            .iter()
+            .map(|c| {
+                let mut cell = cells.get_mut(*c).and_then(|v| v.take()).unwrap_or_default();
+                if header && self.state.active {
+                    let mut style = cell.style.unwrap_or_default();
+                    if self.state.is_hidden(*c) {
+                        style = style.patch(self.hidden_style);
+                    }
+                    if self.state.selected_column() == Some(*c) {
+                        style = style.patch(self.select_style);
+                    }
+                    cell.style = Some(style);
+                }
+                cell
+            })
+            .collect();
+        row
+    }
+}
+
+impl<'a, T> TableData<'a> for OrderedColumns<'_, T>
+where
+    T: TableData<'a>,
+{
+    fn rows(&self) -> usize {
+        self.data.rows()
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        self.data.header().map(|v| self.reorder(v, true))
+    }
+
+    fn footer(&self) -> Option<Row<'a>> {
+        self.data.footer().map(|v| self.reorder(v, false))
+    }
+
+    fn row_height(&self, row: usize) -> u16 {
+        self.data.row_height(row)
+    }
+
+    fn row_style(&self, row: usize) -> Option<Style> {
+        self.data.row_style(row)
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        let widths = self.data.widths();
+        self.columns
+            .iter()
+            .map(|c| widths.get(*c).copied().unwrap_or_default())
+            .collect()
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if let Some(column) = self.columns.get(column) {
+            self.data.render_cell(ctx, *column, row, area, buf);
+        }
+    }
+}
+
+/// Column mode.
+///
+/// * Ctrl+O starts column mode.
+/// * Left/Right move the selected column.
+/// * Shift+Left/Right select another column.
+/// * Space hides/shows the selected column.
+/// * Esc or Enter end column mode.
+///
+/// Column mode is modal and consumes all key events.
+/// Call it before the event-handling of the table.
+pub fn handle_events(
+    state: &mut TableColumnsState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> ColumnsOutcome {
+    if !focus {
+        return ColumnsOutcome::Continue;
+    }
+
+    if !state.active {
+        return match event {
+            ct_event!(key press CONTROL-'o') => {
+                state.enter();
+                ColumnsOutcome::Changed
+            }
+            _ => ColumnsOutcome::Continue,
+        };
+    }
+
+    match event {
+        ct_event!(keycode press Esc) | ct_event!(keycode press Enter) => {
+            state.leave();
+            ColumnsOutcome::Changed
+        }
+        ct_event!(keycode press Left) => {
+            if state.move_left() {
+                ColumnsOutcome::OrderChanged
+            } else {
+                ColumnsOutcome::Unchanged
+            }
+        }
+        ct_event!(keycode press Right) => {
+            if state.move_right() {
+                ColumnsOutcome::OrderChanged
+            } else {
+                ColumnsOutcome::Unchanged
+            }
+        }
+        ct_event!(keycode press SHIFT-Left) => state.select_prev().into(),
+        ct_event!(keycode press SHIFT-Right) => state.select_next().into(),
+        ct_event!(key press ' ') => {
+            if state.toggle_hidden() {
+                ColumnsOutcome::OrderChanged
+            } else {
+                ColumnsOutcome::Unchanged
+            }
+        }
+        crossterm::event::Event::Key(_) => ColumnsOutcome::Unchanged,
+        _ => ColumnsOutcome::Continue,
+    }
+}
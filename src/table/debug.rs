@@ -0,0 +1,208 @@
+//!
+//! Checks the contract of a [TableData] implementation.
+//!
+//! Wrap your data with [DebugData] while hunting strange
+//! rendering or off-by-one selections. Violations are logged
+//! with the row/column and replaced with something harmless,
+//! so the layout stays intact.
+//!
+//! * rows() must give the same count within one render.
+//! * row_height() must be > 0.
+//! * No calls for rows >= rows().
+//! * render_cell() must stay inside the cell area.
+//!
+//! ```rust ignore
+//! let data = DebugData::new(DataSlice(&data.table_data)).debug_data(cfg!(debug_assertions));
+//! Table::new().data(data).render(area, buf, &mut state.table);
+//! ```
+//!
+//! The wrapper is meant to be created for each render, like
+//! the TableData itself. With debug_data(false) it only delegates.
+//!
+use crate::table::textdata::Row;
+use crate::table::{TableContext, TableData};
+use log::warn;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use std::cell::Cell;
+
+/// Wraps a [TableData] and validates the calls.
+#[derive(Debug)]
+pub struct DebugData<T> {
+    data: T,
+    debug: bool,
+    rows: Cell<Option<usize>>,
+}
+
+impl<T> DebugData<T> {
+    pub fn new(data: T) -> Self {
+        Self {
+            data,
+            debug: true,
+            rows: Cell::new(None),
+        }
+    }
+
+    /// Run the checks.
+    ///
+    /// __Default__
+    /// true
+    pub fn debug_data(mut self, debug: bool) -> Self {
+        self.debug = debug;
+        self
+    }
+
+    /// The wrapped data.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<'a, T> DebugData<T>
+where
+    T: TableData<'a>,
+{
+    // Row count of the first call.
+    fn checked_rows(&self) -> usize {
+        let rows = self.data.rows();
+        match self.rows.get() {
+            None => self.rows.set(Some(rows)),
+            Some(first) if first != rows => {
+                warn!("table data: rows() changed from {} to {}", first, rows);
+            }
+            _ => {}
+        }
+        self.rows.get().unwrap_or(rows)
+    }
+
+    fn in_range(&self, fn_name: &str, column: Option<usize>, row: usize) -> bool {
+        let rows = self.checked_rows();
+        if row >= rows {
+            match column {
+                Some(column) => warn!(
+                    "table data: {}(column {}, row {}) out of range, rows() is {}",
+                    fn_name, column, row, rows
+                ),
+                None => warn!(
+                    "table data: {}(row {}) out of range, rows() is {}",
+                    fn_name, row, rows
+                ),
+            }
+            false
+        } else {
+            true
+        }
+    }
+}
+
+impl<'a, T> TableData<'a> for DebugData<T>
+where
+    T: TableData<'a>,
+{
+    fn rows(&self) -> usize {
+        if self.debug {
+            self.checked_rows()
+        } else {
+            self.data.rows()
+        }
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        self.data.header()
+    }
+
+    fn footer(&self) -> Option<Row<'a>> {
+        self.data.footer()
+    }
+
+    fn row_height(&self, row: usize) -> u16 {
+        if !self.debug {
+            return self.data.row_height(row);
+        }
+        if !self.in_range("row_height", None, row) {
+            return 1;
+        }
+        let height = self.data.row_height(row);
+        if height == 0 {
+            warn!("table data: row_height(row {}) is 0", row);
+            1
+        } else {
+            height
+        }
+    }
+
+    fn row_style(&self, row: usize) -> Option<Style> {
+        if self.debug && !self.in_range("row_style", None, row) {
+            return None;
+        }
+        self.data.row_style(row)
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        self.data.widths()
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if !self.debug {
+            self.data.render_cell(ctx, column, row, area, buf);
+            return;
+        }
+        if !self.in_range("render_cell", Some(column), row) {
+            return;
+        }
+
+        // Render into a scratch buffer with a margin around the
+        // cell, anything that ends up in the margin is a violation.
+        let margin = Rect::new(
+            area.x.saturating_sub(area.width.max(1)),
+            area.y.saturating_sub(area.height.max(1)),
+            area.width.max(1).saturating_mul(3),
+            area.height.max(1).saturating_mul(3),
+        );
+        let mut scratch = Buffer::empty(margin);
+        scratch.merge(&cell_buffer(buf, area));
+        self.data.render_cell(ctx, column, row, area, &mut scratch);
+
+        let empty = ratatui::buffer::Cell::EMPTY;
+        let mut outside = false;
+        for y in margin.top()..margin.bottom() {
+            for x in margin.left()..margin.right() {
+                if area.contains((x, y).into()) {
+                    if let (Some(src), Some(dst)) = (scratch.cell((x, y)), buf.cell_mut((x, y))) {
+                        *dst = src.clone();
+                    }
+                } else if scratch.cell((x, y)) != Some(&empty) {
+                    outside = true;
+                }
+            }
+        }
+        if outside {
+            warn!(
+                "table data: render_cell(column {}, row {}) writes outside of {:?}",
+                column, row, area
+            );
+        }
+    }
+}
+
+/// Copy of the cell area.
+fn cell_buffer(buf: &Buffer, area: Rect) -> Buffer {
+    let area = area.intersection(buf.area);
+    let mut cell_buf = Buffer::empty(area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            if let (Some(src), Some(dst)) = (buf.cell((x, y)), cell_buf.cell_mut((x, y))) {
+                *dst = src.clone();
+            }
+        }
+    }
+    cell_buf
+}
@@ -0,0 +1,56 @@
+//!
+//! Render something in place of the rows of an empty Table.
+//!
+//! The Table is rendered by rat-ftable and has no hook of its
+//! own, call [render_empty] after rendering the table.
+//!
+//! ```rust ignore
+//! Table::new()
+//!     .data(data)
+//!     .render(area, buf, &mut state.table);
+//! render_empty(&EmptyRender::text("No data.", Style::new().dim()), &state.table, buf);
+//!
+//! // or just a message with the dimmed table style.
+//! empty_message("No data.", table_style.style, &state.table, buf);
+//! ```
+//!
+use crate::empty::EmptyRender;
+use crate::table::TableState;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Modifier, Style};
+use ratatui::text::Text;
+
+/// Render the hook in the row area if the table has no rows.
+///
+/// Header and footer stay visible.
+/// Returns true if the hook was called.
+pub fn render_empty<Selection>(
+    empty: &EmptyRender<'_>,
+    table: &TableState<Selection>,
+    buf: &mut Buffer,
+) -> bool {
+    if table.rows == 0 && !table.table_area.is_empty() {
+        empty.render(table.table_area, buf);
+        true
+    } else {
+        false
+    }
+}
+
+/// Message centered in the row area if the table has no rows.
+///
+/// Uses the base style of the table dimmed, like
+/// [List::empty_message](crate::list::List::empty_message).
+/// Returns true if the message was rendered.
+pub fn empty_message<'a, Selection>(
+    message: impl Into<Text<'a>>,
+    style: Style,
+    table: &TableState<Selection>,
+    buf: &mut Buffer,
+) -> bool {
+    render_empty(
+        &EmptyRender::text(message, style.add_modifier(Modifier::DIM)),
+        table,
+        buf,
+    )
+}
@@ -0,0 +1,217 @@
+//!
+//! Export table contents as text.
+//!
+//! The table doesn't own its data and the cells are rendered
+//! directly by [TableData](crate::table::TableData). So this
+//! needs a second accessor that gives the plain text of a cell.
+//!
+//! ```rust no_run
+//! # use rat_widget::table::export::{ExportFormat, ExportRows, TableExport};
+//! # use rat_widget::table::TableState;
+//! # use rat_widget::table::selection::RowSelection;
+//! # let state = TableState::<RowSelection>::default();
+//! # let data: Vec<Vec<String>> = Vec::new();
+//! let text = TableExport::new()
+//!     .format(ExportFormat::Csv)
+//!     .export(&state, ExportRows::Selected, |column, row| data[row][column].clone());
+//! // ... put text into the clipboard.
+//! ```
+//!
+//! With a [TableColumnsState] use [from_columns](TableExport::from_columns)
+//! to export the visible columns in their display order.
+//!
+use crate::table::columns::TableColumnsState;
+use crate::table::textdata::Row;
+use crate::table::{TableSelection, TableState};
+use std::borrow::Cow;
+use std::cmp::min;
+
+/// Text format.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Tab separated. Tabs and line-breaks inside a
+    /// value are replaced with a space.
+    #[default]
+    Tsv,
+    /// Comma separated, quoted as needed.
+    Csv,
+}
+
+/// Which rows to export.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportRows {
+    /// All rows where the selection reports
+    /// [is_selected_row](TableSelection::is_selected_row).
+    Selected,
+    /// Rows visible with the last render.
+    Visible,
+    /// All rows.
+    All,
+}
+
+/// Creates a TSV or CSV text from the table.
+#[derive(Debug, Default, Clone)]
+pub struct TableExport {
+    format: ExportFormat,
+    header: Option<Vec<String>>,
+    columns: Option<Vec<usize>>,
+    rows: Option<usize>,
+}
+
+impl TableExport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Export the visible columns in display order.
+    pub fn from_columns(columns: &TableColumnsState) -> Self {
+        Self {
+            columns: Some(columns.visible_columns()),
+            ..Default::default()
+        }
+    }
+
+    /// Text format.
+    ///
+    /// __Default__
+    /// Default is TSV.
+    pub fn format(mut self, format: ExportFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Write a header line with the text of the cells of this row.
+    pub fn header(mut self, header: &Row<'_>) -> Self {
+        self.header = Some(
+            header
+                .cells
+                .iter()
+                .map(|v| {
+                    v.content
+                        .lines
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                })
+                .collect(),
+        );
+        self
+    }
+
+    /// Write a header line with these titles.
+    pub fn header_text(mut self, header: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.header = Some(header.into_iter().map(|v| v.into()).collect());
+        self
+    }
+
+    /// Export only these columns in this order.
+    ///
+    /// __Default__
+    /// All columns of the table.
+    pub fn columns(mut self, columns: impl Into<Vec<usize>>) -> Self {
+        self.columns = Some(columns.into());
+        self
+    }
+
+    /// Number of rows.
+    ///
+    /// __Default__
+    /// The rows of the table with the last render.
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = Some(rows);
+        self
+    }
+
+    /// Export the rows.
+    ///
+    /// The number of rows and columns is known to the state only
+    /// after the first render. Before that nothing is exported,
+    /// unless [rows](TableExport::rows) and [columns](TableExport::columns)
+    /// are set. [ExportRows::Visible] always needs a render.
+    ///
+    /// cell_text is called with (column, row) and must return
+    /// the plain text of the cell.
+    pub fn export<'b, Selection, F, S>(
+        &self,
+        state: &TableState<Selection>,
+        rows: ExportRows,
+        cell_text: F,
+    ) -> String
+    where
+        Selection: TableSelection,
+        F: Fn(usize, usize) -> S,
+        S: Into<Cow<'b, str>>,
+    {
+        let all_columns;
+        let columns = if let Some(columns) = &self.columns {
+            columns.as_slice()
+        } else {
+            all_columns = (0..state.columns).collect::<Vec<_>>();
+            all_columns.as_slice()
+        };
+
+        let mut buf = String::new();
+
+        if let Some(header) = &self.header {
+            for (n, column) in columns.iter().enumerate() {
+                let text = header.get(*column).map(|v| v.as_str()).unwrap_or_default();
+                self.write_value(&mut buf, n, text);
+            }
+            buf.push('\n');
+        }
+
+        let range = match rows {
+            ExportRows::Selected | ExportRows::All => 0..self.rows.unwrap_or(state.rows),
+            ExportRows::Visible => {
+                let offset = min(state.vscroll.offset(), state.rows);
+                offset..min(offset + state.row_areas.len(), state.rows)
+            }
+        };
+        for row in range {
+            if rows == ExportRows::Selected && !state.selection.is_selected_row(row) {
+                continue;
+            }
+            for (n, column) in columns.iter().enumerate() {
+                let text = cell_text(*column, row).into();
+                self.write_value(&mut buf, n, text.as_ref());
+            }
+            buf.push('\n');
+        }
+
+        buf
+    }
+
+    fn write_value(&self, buf: &mut String, n: usize, text: &str) {
+        match self.format {
+            ExportFormat::Tsv => {
+                if n > 0 {
+                    buf.push('\t');
+                }
+                for c in text.chars() {
+                    match c {
+                        '\t' | '\n' | '\r' => buf.push(' '),
+                        c => buf.push(c),
+                    }
+                }
+            }
+            ExportFormat::Csv => {
+                if n > 0 {
+                    buf.push(',');
+                }
+                if text.contains([',', '"', '\n', '\r']) {
+                    buf.push('"');
+                    for c in text.chars() {
+                        if c == '"' {
+                            buf.push('"');
+                        }
+                        buf.push(c);
+                    }
+                    buf.push('"');
+                } else {
+                    buf.push_str(text);
+                }
+            }
+        }
+    }
+}
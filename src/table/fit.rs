@@ -0,0 +1,134 @@
+//!
+//! Column widths from the table contents.
+//!
+//! The table doesn't own its data, so this uses the same kind
+//! of cell accessor as [export](crate::table::export).
+//!
+//! ```rust no_run
+//! # use rat_widget::table::export::ExportRows;
+//! # use rat_widget::table::fit::FitColumns;
+//! # use rat_widget::table::TableState;
+//! # use rat_widget::table::selection::RowSelection;
+//! # let state = TableState::<RowSelection>::default();
+//! # let data: Vec<Vec<String>> = Vec::new();
+//! let widths = FitColumns::new()
+//!     .max_width(30)
+//!     .header_text(["Name", "Size"])
+//!     .fit(&state, ExportRows::Visible, |column, row| data[row][column].clone());
+//! // ... use as Table::widths(widths).
+//! ```
+//!
+use crate::table::export::ExportRows;
+use crate::table::{TableSelection, TableState};
+use ratatui::layout::Constraint;
+use std::borrow::Cow;
+use std::cmp::min;
+
+/// Calculates column widths from the cell texts.
+#[derive(Debug, Clone)]
+pub struct FitColumns {
+    min_width: u16,
+    max_width: u16,
+    header: Option<Vec<String>>,
+}
+
+impl Default for FitColumns {
+    fn default() -> Self {
+        Self {
+            min_width: 1,
+            max_width: u16::MAX,
+            header: None,
+        }
+    }
+}
+
+impl FitColumns {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Minimum column width.
+    ///
+    /// __Default__
+    /// 1
+    pub fn min_width(mut self, width: u16) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Maximum column width.
+    ///
+    /// __Default__
+    /// unlimited
+    pub fn max_width(mut self, width: u16) -> Self {
+        self.max_width = width;
+        self
+    }
+
+    /// Include these header titles.
+    pub fn header_text(mut self, header: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.header = Some(header.into_iter().map(|v| v.into()).collect());
+        self
+    }
+
+    /// Measure the rows and return a Constraint::Length for
+    /// each column.
+    ///
+    /// The state must have been rendered at least once
+    /// to know the number of rows and columns. For large tables
+    /// use [ExportRows::Visible], which only samples the rows
+    /// visible with the last render.
+    ///
+    /// cell_text is called with (column, row) and must return
+    /// the displayed text of the cell. Multi-line text counts
+    /// with its longest line.
+    pub fn fit<'b, Selection, F, S>(
+        &self,
+        state: &TableState<Selection>,
+        rows: ExportRows,
+        cell_text: F,
+    ) -> Vec<Constraint>
+    where
+        Selection: TableSelection,
+        F: Fn(usize, usize) -> S,
+        S: Into<Cow<'b, str>>,
+    {
+        let mut widths = vec![0u16; state.columns];
+
+        if let Some(header) = &self.header {
+            for (width, text) in widths.iter_mut().zip(header.iter()) {
+                *width = text_width(text);
+            }
+        }
+
+        let range = match rows {
+            ExportRows::Selected | ExportRows::All => 0..state.rows,
+            ExportRows::Visible => {
+                let offset = min(state.vscroll.offset(), state.rows);
+                offset..min(offset + state.row_areas.len(), state.rows)
+            }
+        };
+        for row in range {
+            if rows == ExportRows::Selected && !state.selection.is_selected_row(row) {
+                continue;
+            }
+            for (column, width) in widths.iter_mut().enumerate() {
+                let text = cell_text(column, row).into();
+                *width = (*width).max(text_width(text.as_ref()));
+            }
+        }
+
+        widths
+            .into_iter()
+            .map(|v| Constraint::Length(v.max(self.min_width).min(self.max_width)))
+            .collect()
+    }
+}
+
+fn text_width(text: &str) -> u16 {
+    text.lines()
+        .map(unicode_display_width::width)
+        .max()
+        .unwrap_or_default()
+        .min(u16::MAX as u64) as u16
+}
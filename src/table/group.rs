@@ -0,0 +1,408 @@
+//!
+//! Group rows with collapsible group headers.
+//!
+//! The table still doesn't own the data. [TableGroups] maps
+//! the data rows to their groups and renders the header and the
+//! optional footer (aggregate) line of a group. [TableGroupState]
+//! keeps the collapsed groups and the resulting display rows, and
+//! [GroupedData] presents them to the Table.
+//!
+//! The Table only sees display rows, selection and scrolling work
+//! with those. Use [TableGroupState::row] to map them back.
+//!
+//! ```rust ignore
+//! state.groups.update(data.len(), &DayGroups(&data));
+//! Table::new()
+//!     .data(GroupedData::new(DataSlice(&data), DayGroups(&data), &state.groups))
+//!     .render(area, buf, &mut state.table);
+//!
+//! // events, before the table.
+//! let r = group::handle_events(&mut state.groups, &mut state.table, focus, event);
+//! ```
+//!
+use crate::event::{ConsumedEvent, Outcome};
+use crate::table::selection::RowSelection;
+use crate::table::textdata::Row;
+use crate::table::{TableContext, TableData, TableState};
+use rat_event::ct_event;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use std::collections::HashSet;
+
+/// Grouping adapter.
+pub trait TableGroups {
+    /// Group of a data row. The rows of one group must
+    /// be contiguous.
+    fn group(&self, row: usize) -> usize;
+
+    /// Render one cell of the group header.
+    fn render_header(
+        &self,
+        ctx: &TableContext,
+        group: usize,
+        column: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    );
+
+    /// Style for the header row.
+    fn header_style(&self, group: usize) -> Option<Style> {
+        _ = group;
+        None
+    }
+
+    /// The group has a footer line.
+    fn has_footer(&self, group: usize) -> bool {
+        _ = group;
+        false
+    }
+
+    /// Render one cell of the group footer.
+    fn render_footer(
+        &self,
+        ctx: &TableContext,
+        group: usize,
+        column: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        _ = (ctx, group, column, area, buf);
+    }
+
+    /// Style for the footer row.
+    fn footer_style(&self, group: usize) -> Option<Style> {
+        _ = group;
+        None
+    }
+}
+
+/// What is displayed in a table row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GroupRow {
+    /// Header of the group.
+    Header(usize),
+    /// Data row.
+    Data(usize),
+    /// Footer of the group.
+    Footer(usize),
+}
+
+/// Collapsed groups and display rows.
+#[derive(Debug, Default, Clone)]
+pub struct TableGroupState {
+    /// Collapsed groups.
+    /// __read+write__ call [update](TableGroupState::update) after changes.
+    pub collapsed: HashSet<usize>,
+    /// Display rows.
+    /// __read only__ renewed with update().
+    pub rows: Vec<GroupRow>,
+    /// Display rows with all groups expanded.
+    all: Vec<GroupRow>,
+}
+
+/// Result of event handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GroupOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// Group has been expanded.
+    Expanded(usize),
+    /// Group has been collapsed.
+    Collapsed(usize),
+}
+
+impl ConsumedEvent for GroupOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != GroupOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for GroupOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            GroupOutcome::Changed
+        } else {
+            GroupOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for GroupOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => GroupOutcome::Continue,
+            Outcome::Unchanged => GroupOutcome::Unchanged,
+            Outcome::Changed => GroupOutcome::Changed,
+        }
+    }
+}
+
+impl From<GroupOutcome> for Outcome {
+    fn from(value: GroupOutcome) -> Self {
+        match value {
+            GroupOutcome::Continue => Outcome::Continue,
+            GroupOutcome::Unchanged => Outcome::Unchanged,
+            GroupOutcome::Changed => Outcome::Changed,
+            GroupOutcome::Expanded(_) => Outcome::Changed,
+            GroupOutcome::Collapsed(_) => Outcome::Changed,
+        }
+    }
+}
+
+impl TableGroupState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuild the display rows for the data.
+    ///
+    /// Call whenever the data changes. Collapsing/expanding
+    /// via this state updates the rows itself.
+    pub fn update(&mut self, rows: usize, groups: &dyn TableGroups) {
+        self.all.clear();
+
+        let mut current = None;
+        for row in 0..rows {
+            let group = groups.group(row);
+            if current != Some(group) {
+                if let Some(current) = current {
+                    if groups.has_footer(current) {
+                        self.all.push(GroupRow::Footer(current));
+                    }
+                }
+                self.all.push(GroupRow::Header(group));
+                current = Some(group);
+            }
+            self.all.push(GroupRow::Data(row));
+        }
+        if let Some(current) = current {
+            if groups.has_footer(current) {
+                self.all.push(GroupRow::Footer(current));
+            }
+        }
+
+        self.filter();
+    }
+
+    // Hide the data rows of collapsed groups.
+    // Header and footer stay visible.
+    fn filter(&mut self) {
+        self.rows.clear();
+        let mut collapsed = false;
+        for row in self.all.iter() {
+            match row {
+                GroupRow::Header(group) => {
+                    collapsed = self.collapsed.contains(group);
+                    self.rows.push(*row);
+                }
+                GroupRow::Data(_) => {
+                    if !collapsed {
+                        self.rows.push(*row);
+                    }
+                }
+                GroupRow::Footer(_) => {
+                    self.rows.push(*row);
+                }
+            }
+        }
+    }
+
+    /// What is displayed in the given table row.
+    pub fn row(&self, display_row: usize) -> Option<GroupRow> {
+        self.rows.get(display_row).copied()
+    }
+
+    /// Data row for the table row.
+    pub fn data_row(&self, display_row: usize) -> Option<usize> {
+        match self.row(display_row) {
+            Some(GroupRow::Data(row)) => Some(row),
+            _ => None,
+        }
+    }
+
+    /// Table row of a data row. None if the group is collapsed.
+    pub fn display_row(&self, data_row: usize) -> Option<usize> {
+        self.rows
+            .iter()
+            .position(|v| *v == GroupRow::Data(data_row))
+    }
+
+    /// Table row of the group header.
+    pub fn header_row(&self, group: usize) -> Option<usize> {
+        self.rows.iter().position(|v| *v == GroupRow::Header(group))
+    }
+
+    /// Group is collapsed.
+    pub fn is_collapsed(&self, group: usize) -> bool {
+        self.collapsed.contains(&group)
+    }
+
+    /// Collapse the group. Returns true if this changed anything.
+    pub fn collapse(&mut self, group: usize) -> bool {
+        let r = self.collapsed.insert(group);
+        self.filter();
+        r
+    }
+
+    /// Expand the group. Returns true if this changed anything.
+    pub fn expand(&mut self, group: usize) -> bool {
+        let r = self.collapsed.remove(&group);
+        self.filter();
+        r
+    }
+
+    /// Collapse/expand the group.
+    pub fn toggle(&mut self, group: usize) -> GroupOutcome {
+        if self.is_collapsed(group) {
+            self.expand(group);
+            GroupOutcome::Expanded(group)
+        } else {
+            self.collapse(group);
+            GroupOutcome::Collapsed(group)
+        }
+    }
+}
+
+/// Presents the grouped rows to the Table.
+///
+/// Create it for each render, like the TableData itself.
+#[derive(Debug)]
+pub struct GroupedData<'s, T, G> {
+    data: T,
+    groups: G,
+    state: &'s TableGroupState,
+}
+
+impl<'s, T, G> GroupedData<'s, T, G> {
+    pub fn new(data: T, groups: G, state: &'s TableGroupState) -> Self {
+        Self {
+            data,
+            groups,
+            state,
+        }
+    }
+}
+
+impl<'a, T, G> TableData<'a> for GroupedData<'_, T, G>
+where
+    T: TableData<'a>,
+    G: TableGroups,
+{
+    fn rows(&self) -> usize {
+        self.state.rows.len()
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        self.data.header()
+    }
+
+    fn footer(&self) -> Option<Row<'a>> {
+        self.data.footer()
+    }
+
+    fn row_height(&self, row: usize) -> u16 {
+        match self.state.row(row) {
+            Some(GroupRow::Data(row)) => self.data.row_height(row),
+            _ => 1,
+        }
+    }
+
+    fn row_style(&self, row: usize) -> Option<Style> {
+        match self.state.row(row) {
+            Some(GroupRow::Header(group)) => self.groups.header_style(group),
+            Some(GroupRow::Data(row)) => self.data.row_style(row),
+            Some(GroupRow::Footer(group)) => self.groups.footer_style(group),
+            None => None,
+        }
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        self.data.widths()
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        match self.state.row(row) {
+            Some(GroupRow::Header(group)) => {
+                self.groups.render_header(ctx, group, column, area, buf)
+            }
+            Some(GroupRow::Data(row)) => self.data.render_cell(ctx, column, row, area, buf),
+            Some(GroupRow::Footer(group)) => {
+                self.groups.render_footer(ctx, group, column, area, buf)
+            }
+            None => {}
+        }
+    }
+}
+
+/// Collapse/expand groups.
+///
+/// * Left/Right on a selected header collapses/expands the group.
+/// * Clicking a header selects it and toggles the group.
+///
+/// Call it before the event-handling of the table.
+pub fn handle_events(
+    state: &mut TableGroupState,
+    table: &mut TableState<RowSelection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> GroupOutcome {
+    let selected_group = match table.selected().and_then(|v| state.row(v)) {
+        Some(GroupRow::Header(group)) => Some(group),
+        _ => None,
+    };
+
+    if focus {
+        match event {
+            ct_event!(keycode press Left) => {
+                if let Some(group) = selected_group {
+                    return if state.collapse(group) {
+                        GroupOutcome::Collapsed(group)
+                    } else {
+                        GroupOutcome::Unchanged
+                    };
+                }
+            }
+            ct_event!(keycode press Right) => {
+                if let Some(group) = selected_group {
+                    return if state.expand(group) {
+                        GroupOutcome::Expanded(group)
+                    } else {
+                        GroupOutcome::Unchanged
+                    };
+                }
+            }
+            _ => {}
+        }
+    }
+
+    match event {
+        ct_event!(mouse down Left for x, y) if table.table_area.contains((*x, *y).into()) => {
+            let Some(row) = table.row_at_clicked((*x, *y)) else {
+                return GroupOutcome::Continue;
+            };
+            let Some(GroupRow::Header(group)) = state.row(row) else {
+                return GroupOutcome::Continue;
+            };
+            table.select(Some(row));
+            state.toggle(group)
+        }
+        _ => GroupOutcome::Continue,
+    }
+}
@@ -0,0 +1,111 @@
+//!
+//! Highlight the row under the mouse cursor.
+//!
+//! The hovered row is rendered distinct from the selection
+//! and is reported with [HoverOutcome::Hovered]. Clicking
+//! still selects as usual.
+//!
+//! ```rust ignore
+//! Table::new()
+//!     .data(data)
+//!     .render(area, buf, &mut state.table);
+//! state.hover.render(&state.table, buf);
+//!
+//! // events
+//! let r = hover::handle_events(&mut state.hover, &state.table, event);
+//! ```
+//!
+use crate::event::HoverOutcome;
+use crate::table::{TableSelection, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::style::{Modifier, Style};
+
+/// Hover tracking for a Table.
+#[derive(Debug, Clone)]
+pub struct TableHover {
+    /// Hovered row.
+    /// __read only__ renewed by [handle_events].
+    pub hovered: Option<usize>,
+    /// Style for the hovered row.
+    /// __read+write__
+    pub style: Style,
+}
+
+impl Default for TableHover {
+    fn default() -> Self {
+        Self {
+            hovered: None,
+            style: Style::new().add_modifier(Modifier::UNDERLINED),
+        }
+    }
+}
+
+impl TableHover {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Style for the hovered row.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Render the highlight over the rendered table.
+    ///
+    /// Call after rendering the table. A selected row
+    /// is not highlighted.
+    pub fn render<Selection: TableSelection>(
+        &self,
+        table: &TableState<Selection>,
+        buf: &mut Buffer,
+    ) {
+        let Some(hovered) = self.hovered else {
+            return;
+        };
+        if table.selection.is_selected_row(hovered) {
+            return;
+        }
+        let Some(idx) = hovered.checked_sub(table.vscroll.offset()) else {
+            return;
+        };
+        if let Some(row_area) = table.row_areas.get(idx) {
+            buf.set_style(row_area.intersection(table.table_area), self.style);
+        }
+    }
+}
+
+/// Track the row under the mouse.
+///
+/// Returns [HoverOutcome::Hovered] when the mouse moves to
+/// another row and [HoverOutcome::Changed] when it leaves the rows.
+pub fn handle_events<Selection>(
+    hover: &mut TableHover,
+    table: &TableState<Selection>,
+    event: &crossterm::event::Event,
+) -> HoverOutcome {
+    match event {
+        crossterm::event::Event::Mouse(crossterm::event::MouseEvent {
+            kind: crossterm::event::MouseEventKind::Moved,
+            column,
+            row,
+            ..
+        }) => {
+            let hovered = if table.table_area.contains((*column, *row).into()) {
+                table.row_at_clicked((*column, *row))
+            } else {
+                None
+            };
+            if hovered == hover.hovered {
+                HoverOutcome::Continue
+            } else {
+                hover.hovered = hovered;
+                match hovered {
+                    Some(n) => HoverOutcome::Hovered(n),
+                    None => HoverOutcome::Changed,
+                }
+            }
+        }
+        _ => HoverOutcome::Continue,
+    }
+}
@@ -0,0 +1,29 @@
+//!
+//! Table styles in the order of [util::fallback](crate::util::fallback).
+//!
+//! rat-ftable replaces the style of the block with the base
+//! style of the table, the block style never shows. [precedence]
+//! patches the block style onto the base style beforehand, which
+//! gives the order `base < block < select < focus`. Select and
+//! focus are patched in this order by the table itself.
+//!
+//! ```rust ignore
+//! Table::new()
+//!     .data(data)
+//!     .block(Block::bordered())
+//!     .styles(precedence(THEME.table_style(), THEME.block_style()))
+//!     .render(area, buf, &mut state.table);
+//! ```
+//!
+use crate::table::TableStyle;
+use crate::util::compose;
+use ratatui::style::Style;
+
+/// Patch the block style onto the base style of the table.
+///
+/// Header and footer are rendered on top of the base style
+/// and get the block style too.
+pub fn precedence(mut styles: TableStyle, block_style: Style) -> TableStyle {
+    styles.style = compose(styles.style, block_style);
+    styles
+}
@@ -0,0 +1,404 @@
+//!
+//! Show hierarchical rows as a collapsible tree.
+//!
+//! The table still doesn't own the data. The data rows are
+//! in depth-first order, and [TableTree] gives the level of
+//! each row. [TableTreeState] keeps the collapsed rows and the
+//! resulting display rows, and [TreeData] presents them to the
+//! Table.
+//!
+//! The Table only sees display rows, selection and scrolling work
+//! with those. Use [TableTreeState::data_row] to map them back.
+//!
+//! ```rust ignore
+//! state.tree.update(data.len(), &Levels(&data));
+//! Table::new()
+//!     .data(TreeData::new(DataSlice(&data), &state.tree))
+//!     .render(area, buf, &mut state.table);
+//!
+//! // events, before the table.
+//! let r = tree::handle_events(&mut state.tree, &mut state.table, focus, event);
+//! ```
+//!
+use crate::event::{ConsumedEvent, Outcome};
+use crate::table::selection::RowSelection;
+use crate::table::textdata::Row;
+use crate::table::{TableContext, TableData, TableState};
+use rat_event::ct_event;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use std::collections::HashSet;
+
+/// Tree adapter.
+pub trait TableTree {
+    /// Level of a data row. Top level rows are 0, the children
+    /// of a row follow it directly with a higher level.
+    fn level(&self, row: usize) -> usize;
+}
+
+/// Collapsed rows and display rows.
+#[derive(Debug, Default, Clone)]
+pub struct TableTreeState {
+    /// Collapsed data rows.
+    /// __read+write__ call [update](TableTreeState::update) after changes.
+    pub collapsed: HashSet<usize>,
+    /// Display rows. Maps to the data rows.
+    /// __read only__ renewed with update().
+    pub rows: Vec<usize>,
+    /// Level of each data row.
+    levels: Vec<usize>,
+}
+
+/// Result of event handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TreeOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// Data row has been expanded.
+    Expanded(usize),
+    /// Data row has been collapsed.
+    Collapsed(usize),
+}
+
+impl ConsumedEvent for TreeOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != TreeOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for TreeOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            TreeOutcome::Changed
+        } else {
+            TreeOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for TreeOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => TreeOutcome::Continue,
+            Outcome::Unchanged => TreeOutcome::Unchanged,
+            Outcome::Changed => TreeOutcome::Changed,
+        }
+    }
+}
+
+impl From<TreeOutcome> for Outcome {
+    fn from(value: TreeOutcome) -> Self {
+        match value {
+            TreeOutcome::Continue => Outcome::Continue,
+            TreeOutcome::Unchanged => Outcome::Unchanged,
+            TreeOutcome::Changed => Outcome::Changed,
+            TreeOutcome::Expanded(_) => Outcome::Changed,
+            TreeOutcome::Collapsed(_) => Outcome::Changed,
+        }
+    }
+}
+
+impl TableTreeState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rebuild the display rows for the data.
+    ///
+    /// Call whenever the data changes. Collapsing/expanding
+    /// via this state updates the rows itself.
+    pub fn update(&mut self, rows: usize, tree: &dyn TableTree) {
+        self.levels = (0..rows).map(|v| tree.level(v)).collect();
+        self.filter();
+    }
+
+    // Hide the descendants of collapsed rows.
+    fn filter(&mut self) {
+        self.rows.clear();
+        let mut hide_below = None;
+        for (row, level) in self.levels.iter().enumerate() {
+            if let Some(hide_below) = hide_below {
+                if *level > hide_below {
+                    continue;
+                }
+            }
+            hide_below = None;
+            self.rows.push(row);
+            if self.collapsed.contains(&row) && self.has_children(row) {
+                hide_below = Some(*level);
+            }
+        }
+    }
+
+    /// Data row for the table row.
+    pub fn data_row(&self, display_row: usize) -> Option<usize> {
+        self.rows.get(display_row).copied()
+    }
+
+    /// Table row of a data row. None if some parent is collapsed.
+    pub fn display_row(&self, data_row: usize) -> Option<usize> {
+        self.rows.iter().position(|v| *v == data_row)
+    }
+
+    /// Level of the data row.
+    pub fn level(&self, row: usize) -> usize {
+        self.levels.get(row).copied().unwrap_or_default()
+    }
+
+    /// The data row has children.
+    pub fn has_children(&self, row: usize) -> bool {
+        match (self.levels.get(row), self.levels.get(row + 1)) {
+            (Some(level), Some(next)) => next > level,
+            _ => false,
+        }
+    }
+
+    /// Parent of the data row.
+    pub fn parent(&self, row: usize) -> Option<usize> {
+        let level = *self.levels.get(row)?;
+        (0..row).rev().find(|v| self.levels[*v] < level)
+    }
+
+    /// Data rows below the given row.
+    fn descendants(&self, row: usize) -> std::ops::Range<usize> {
+        let level = self.level(row);
+        let end = (row + 1..self.levels.len())
+            .find(|v| self.levels[*v] <= level)
+            .unwrap_or(self.levels.len());
+        row + 1..end
+    }
+
+    /// Data row is collapsed.
+    pub fn is_collapsed(&self, row: usize) -> bool {
+        self.collapsed.contains(&row)
+    }
+
+    /// Collapse the data row. Returns true if this changed anything.
+    pub fn collapse(&mut self, row: usize) -> bool {
+        if !self.has_children(row) {
+            return false;
+        }
+        let r = self.collapsed.insert(row);
+        self.filter();
+        r
+    }
+
+    /// Expand the data row. Returns true if this changed anything.
+    pub fn expand(&mut self, row: usize) -> bool {
+        let r = self.collapsed.remove(&row);
+        self.filter();
+        r
+    }
+
+    /// Collapse/expand the data row.
+    pub fn toggle(&mut self, row: usize) -> TreeOutcome {
+        if !self.has_children(row) {
+            TreeOutcome::Unchanged
+        } else if self.is_collapsed(row) {
+            self.expand(row);
+            TreeOutcome::Expanded(row)
+        } else {
+            self.collapse(row);
+            TreeOutcome::Collapsed(row)
+        }
+    }
+
+    /// Expand all rows. Returns true if this changed anything.
+    ///
+    /// The table keeps the selected data row selected.
+    pub fn expand_all(&mut self, table: &mut TableState<RowSelection>) -> bool {
+        let selected = table.selected().and_then(|v| self.data_row(v));
+        let r = !self.collapsed.is_empty();
+        self.collapsed.clear();
+        self.filter();
+        self.reselect(table, selected);
+        r
+    }
+
+    /// Collapse all rows with children.
+    /// Returns true if this changed anything.
+    ///
+    /// The table keeps the selected data row selected, or
+    /// selects its top-level ancestor.
+    pub fn collapse_all(&mut self, table: &mut TableState<RowSelection>) -> bool {
+        let selected = table.selected().and_then(|v| self.data_row(v));
+        let mut r = false;
+        for row in 0..self.levels.len() {
+            if self.has_children(row) {
+                r |= self.collapsed.insert(row);
+            }
+        }
+        self.filter();
+        self.reselect(table, selected);
+        r
+    }
+
+    /// Select the display row of the data row, or of its
+    /// nearest visible ancestor.
+    fn reselect(&self, table: &mut TableState<RowSelection>, data_row: Option<usize>) {
+        let Some(mut row) = data_row else {
+            return;
+        };
+        let display_row = loop {
+            if let Some(display_row) = self.display_row(row) {
+                break Some(display_row);
+            }
+            match self.parent(row) {
+                Some(parent) => row = parent,
+                None => break None,
+            }
+        };
+        table.select(display_row);
+        table.scroll_to_selected();
+    }
+
+    /// Toggle the whole subtree of the data row.
+    ///
+    /// If the row or any of its descendants is expanded all of
+    /// them are collapsed. Otherwise all of them are expanded.
+    pub fn toggle_subtree(&mut self, row: usize) -> TreeOutcome {
+        if !self.has_children(row) {
+            return TreeOutcome::Unchanged;
+        }
+        let subtree = row..self.descendants(row).end;
+        let expanded = subtree
+            .clone()
+            .any(|v| self.has_children(v) && !self.collapsed.contains(&v));
+        for v in subtree {
+            if expanded {
+                if self.has_children(v) {
+                    self.collapsed.insert(v);
+                }
+            } else {
+                self.collapsed.remove(&v);
+            }
+        }
+        self.filter();
+        if expanded {
+            TreeOutcome::Collapsed(row)
+        } else {
+            TreeOutcome::Expanded(row)
+        }
+    }
+}
+
+/// Presents the visible rows of the tree to the Table.
+///
+/// Create it for each render, like the TableData itself.
+/// Indentation and expand markers are up to the data,
+/// use [TableTreeState::level] and friends for this.
+#[derive(Debug)]
+pub struct TreeData<'s, T> {
+    data: T,
+    state: &'s TableTreeState,
+}
+
+impl<'s, T> TreeData<'s, T> {
+    pub fn new(data: T, state: &'s TableTreeState) -> Self {
+        Self { data, state }
+    }
+}
+
+impl<'a, T> TableData<'a> for TreeData<'_, T>
+where
+    T: TableData<'a>,
+{
+    fn rows(&self) -> usize {
+        self.state.rows.len()
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        self.data.header()
+    }
+
+    fn footer(&self) -> Option<Row<'a>> {
+        self.data.footer()
+    }
+
+    fn row_height(&self, row: usize) -> u16 {
+        match self.state.data_row(row) {
+            Some(row) => self.data.row_height(row),
+            None => 1,
+        }
+    }
+
+    fn row_style(&self, row: usize) -> Option<Style> {
+        self.state
+            .data_row(row)
+            .and_then(|row| self.data.row_style(row))
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        self.data.widths()
+    }
+
+    fn render_cell(
+        &self,
+        ctx: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        if let Some(row) = self.state.data_row(row) {
+            self.data.render_cell(ctx, column, row, area, buf);
+        }
+    }
+}
+
+/// Keyboard navigation for the tree.
+///
+/// * Right expands the selected row.
+/// * Left collapses an expanded row, otherwise it
+///   selects the parent row.
+///   Leaves the key to the table for top-level leaf rows.
+/// * '*' toggles the subtree of the selected row.
+///
+/// Call it before the event-handling of the table.
+pub fn handle_events(
+    state: &mut TableTreeState,
+    table: &mut TableState<RowSelection>,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> TreeOutcome {
+    if !focus {
+        return TreeOutcome::Continue;
+    }
+    let Some(row) = table.selected().and_then(|v| state.data_row(v)) else {
+        return TreeOutcome::Continue;
+    };
+
+    match event {
+        ct_event!(keycode press Right) if state.has_children(row) => {
+            if state.expand(row) {
+                TreeOutcome::Expanded(row)
+            } else {
+                TreeOutcome::Unchanged
+            }
+        }
+        ct_event!(keycode press Left) => {
+            if state.has_children(row) && !state.is_collapsed(row) {
+                state.collapse(row);
+                TreeOutcome::Collapsed(row)
+            } else if let Some(parent) = state.parent(row) {
+                table.select(state.display_row(parent));
+                table.scroll_to_selected();
+                TreeOutcome::Changed
+            } else {
+                TreeOutcome::Continue
+            }
+        }
+        ct_event!(key press '*') | ct_event!(key press SHIFT-'*') => state.toggle_subtree(row),
+        _ => TreeOutcome::Continue,
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! Replacement glyph for control chars.
+//!
+//! Without show_ctrl the text widgets render control chars as
+//! "\u{FFFD}", which some fonts don't cover. [CtrlGlyph] renders a
+//! different replacement over those cells. Render it after the
+//! widget.
+//!
+//! ```rust ignore
+//! TextArea::new().render(area, buf, &mut state.text);
+//! CtrlGlyph::new().replacement("¿").render_text_area(buf, &state.text);
+//! ```
+//!
+//! A literal "\u{FFFD}" in the text is left alone. A TextArea with
+//! show_ctrl on uses the control pictures and is not changed.
+//!
+//! The trailing cells of wide glyphs can't be changed this way,
+//! the terminal output skips them.
+//!
+use crate::text::{upos_type, Glyph};
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use crate::textarea::TextAreaState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use std::borrow::Cow;
+use std::cmp::min;
+
+/// What the widgets render for control chars.
+const REPLACEMENT: &str = "\u{FFFD}";
+
+/// Renders the replacement glyph.
+#[derive(Debug, Clone)]
+pub struct CtrlGlyph {
+    replacement: &'static str,
+    style: Option<Style>,
+}
+
+impl Default for CtrlGlyph {
+    fn default() -> Self {
+        Self {
+            replacement: "·",
+            style: None,
+        }
+    }
+}
+
+impl CtrlGlyph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replacement for control chars.
+    ///
+    /// __Default__
+    /// "·"
+    pub fn replacement(mut self, replacement: &'static str) -> Self {
+        self.replacement = replacement;
+        self
+    }
+
+    /// Style for the replacement.
+    ///
+    /// __Default__
+    /// The style of the cell below.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Render for a TextArea.
+    pub fn render_text_area(&self, buf: &mut Buffer, state: &TextAreaState) {
+        if state.show_ctrl() {
+            return;
+        }
+        let inner = state.inner;
+        let (ox, oy) = state.offset();
+        let rows = oy as upos_type
+            ..min(
+                oy as upos_type + inner.height as upos_type,
+                state.len_lines(),
+            );
+        for g in state.glyphs(rows, ox as u16, inner.width) {
+            self.render_glyph(buf, inner, &g, state.str_slice_byte(g.text_bytes()));
+        }
+    }
+
+    /// Render for a TextInput.
+    pub fn render_text_input(&self, buf: &mut Buffer, state: &TextInputState) {
+        let inner = state.inner;
+        for g in state.glyphs(state.offset() as u16, inner.width) {
+            self.render_glyph(buf, inner, &g, state.str_slice_byte(g.text_bytes()));
+        }
+    }
+
+    /// Render for a MaskedInput.
+    ///
+    /// NumberInput and DateInput use a MaskedInput as `widget`.
+    pub fn render_masked_input(&self, buf: &mut Buffer, state: &MaskedInputState) {
+        let inner = state.inner;
+        for g in state.glyphs(state.offset() as u16, inner.width) {
+            self.render_glyph(buf, inner, &g, state.str_slice_byte(g.text_bytes()));
+        }
+    }
+
+    fn render_glyph(&self, buf: &mut Buffer, inner: Rect, g: &Glyph<'_>, text: Cow<'_, str>) {
+        if g.glyph() != REPLACEMENT || text == REPLACEMENT {
+            return;
+        }
+        let (sx, sy) = g.screen_pos();
+        if let Some(cell) = buf.cell_mut((inner.x + sx, inner.y + sy)) {
+            cell.set_symbol(self.replacement);
+            if let Some(style) = self.style {
+                cell.set_style(style);
+            }
+        }
+    }
+}
@@ -0,0 +1,217 @@
+//!
+//! Grapheme based helpers for text-like widgets.
+//!
+//! All positions are grapheme indexes, not bytes or chars.
+//! A word is a run of non-whitespace graphemes, the same as
+//! the word movements of the text widgets.
+//!
+//! A trailing line break ("\n", "\r\n" or "\r") is not part of
+//! the line, it doesn't count for [str_line_len] and positions
+//! beyond it are clamped.
+//!
+use crate::text::upos_type;
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Grapheme count of the line, excluding a trailing line break.
+///
+/// ```
+/// use rat_widget::text::graphemes::str_line_len;
+///
+/// assert_eq!(str_line_len("abc\n"), 3);
+/// assert_eq!(str_line_len("e\u{301}\r\n"), 1);
+/// assert_eq!(str_line_len(""), 0);
+/// ```
+pub fn str_line_len(s: &str) -> upos_type {
+    strip_line_break(s).graphemes(true).count() as upos_type
+}
+
+/// Split at the grapheme position.
+///
+/// ```
+/// use rat_widget::text::graphemes::split_at;
+///
+/// assert_eq!(split_at("ab\u{1F600}cd", 3), ("ab\u{1F600}", "cd"));
+/// assert_eq!(split_at("ab", 5), ("ab", ""));
+/// ```
+pub fn split_at(s: &str, pos: upos_type) -> (&str, &str) {
+    s.split_at(byte_pos(s, pos))
+}
+
+/// Split into the parts before, inside and after the
+/// grapheme range.
+///
+/// ```
+/// use rat_widget::text::graphemes::split3;
+///
+/// assert_eq!(split3("abcdef", 1..3), ("a", "bc", "def"));
+/// ```
+pub fn split3(s: &str, range: Range<upos_type>) -> (&str, &str, &str) {
+    let start = byte_pos(s, range.start);
+    let end = byte_pos(s, range.end).max(start);
+    (&s[..start], &s[start..end], &s[end..])
+}
+
+/// Start of the next word at or after pos.
+/// Skips whitespace. Returns the line length if there is none.
+///
+/// ```
+/// use rat_widget::text::graphemes::next_word_start;
+///
+/// assert_eq!(next_word_start("ab  cd", 2), 4);
+/// assert_eq!(next_word_start("ab  cd", 4), 4);
+/// assert_eq!(next_word_start("ab  ", 2), 4);
+/// ```
+pub fn next_word_start(s: &str, pos: upos_type) -> upos_type {
+    let g = line_graphemes(s);
+    let mut pos = clamp(&g, pos);
+    while pos < g.len() && is_ws(g[pos]) {
+        pos += 1;
+    }
+    pos as upos_type
+}
+
+/// End of the next word after pos.
+/// Skips whitespace first, then goes to the end of the word.
+///
+/// ```
+/// use rat_widget::text::graphemes::next_word_end;
+///
+/// assert_eq!(next_word_end("ab  cd", 2), 6);
+/// assert_eq!(next_word_end("ab  cd", 0), 2);
+/// ```
+pub fn next_word_end(s: &str, pos: upos_type) -> upos_type {
+    let g = line_graphemes(s);
+    let mut pos = clamp(&g, pos);
+    while pos < g.len() && is_ws(g[pos]) {
+        pos += 1;
+    }
+    while pos < g.len() && !is_ws(g[pos]) {
+        pos += 1;
+    }
+    pos as upos_type
+}
+
+/// Start of the previous word before pos.
+/// Skips whitespace first, then goes to the start of the word.
+///
+/// ```
+/// use rat_widget::text::graphemes::prev_word_start;
+///
+/// assert_eq!(prev_word_start("ab  cd", 4), 0);
+/// assert_eq!(prev_word_start("ab  cd", 5), 4);
+/// ```
+pub fn prev_word_start(s: &str, pos: upos_type) -> upos_type {
+    let g = line_graphemes(s);
+    let mut pos = clamp(&g, pos);
+    while pos > 0 && is_ws(g[pos - 1]) {
+        pos -= 1;
+    }
+    while pos > 0 && !is_ws(g[pos - 1]) {
+        pos -= 1;
+    }
+    pos as upos_type
+}
+
+/// End of the previous word at or before pos.
+/// Skips whitespace backwards.
+///
+/// ```
+/// use rat_widget::text::graphemes::prev_word_end;
+///
+/// assert_eq!(prev_word_end("ab  cd", 4), 2);
+/// assert_eq!(prev_word_end("ab  cd", 2), 2);
+/// ```
+pub fn prev_word_end(s: &str, pos: upos_type) -> upos_type {
+    let g = line_graphemes(s);
+    let mut pos = clamp(&g, pos);
+    while pos > 0 && is_ws(g[pos - 1]) {
+        pos -= 1;
+    }
+    pos as upos_type
+}
+
+/// Start of the word at pos.
+/// Returns pos if it is not inside or at the end of a word.
+///
+/// ```
+/// use rat_widget::text::graphemes::word_start;
+///
+/// assert_eq!(word_start("ab cdef", 5), 3);
+/// assert_eq!(word_start("ab cdef", 3), 3);
+/// ```
+pub fn word_start(s: &str, pos: upos_type) -> upos_type {
+    let g = line_graphemes(s);
+    let mut pos = clamp(&g, pos);
+    while pos > 0 && !is_ws(g[pos - 1]) {
+        pos -= 1;
+    }
+    pos as upos_type
+}
+
+/// End of the word at pos.
+/// Returns pos if it is not inside or at the start of a word.
+///
+/// ```
+/// use rat_widget::text::graphemes::word_end;
+///
+/// assert_eq!(word_end("ab cdef", 4), 7);
+/// assert_eq!(word_end("ab cdef", 2), 2);
+/// ```
+pub fn word_end(s: &str, pos: upos_type) -> upos_type {
+    let g = line_graphemes(s);
+    let mut pos = clamp(&g, pos);
+    while pos < g.len() && !is_ws(g[pos]) {
+        pos += 1;
+    }
+    pos as upos_type
+}
+
+/// Is pos between whitespace and a word, or between a
+/// word and whitespace? The start and end of the line
+/// are no boundary.
+///
+/// ```
+/// use rat_widget::text::graphemes::is_word_boundary;
+///
+/// assert!(is_word_boundary("ab cd", 2));
+/// assert!(is_word_boundary("ab cd", 3));
+/// assert!(!is_word_boundary("ab cd", 1));
+/// assert!(!is_word_boundary("ab cd", 0));
+/// ```
+pub fn is_word_boundary(s: &str, pos: upos_type) -> bool {
+    let g = line_graphemes(s);
+    let pos = pos as usize;
+    if pos == 0 || pos >= g.len() {
+        return false;
+    }
+    is_ws(g[pos - 1]) != is_ws(g[pos])
+}
+
+fn strip_line_break(s: &str) -> &str {
+    s.strip_suffix("\r\n")
+        .or_else(|| s.strip_suffix('\n'))
+        .or_else(|| s.strip_suffix('\r'))
+        .unwrap_or(s)
+}
+
+fn line_graphemes(s: &str) -> Vec<&str> {
+    strip_line_break(s).graphemes(true).collect()
+}
+
+fn clamp(g: &[&str], pos: upos_type) -> usize {
+    (pos as usize).min(g.len())
+}
+
+fn is_ws(g: &str) -> bool {
+    g.chars().all(char::is_whitespace)
+}
+
+/// Byte position of the grapheme, clamped to the line.
+fn byte_pos(s: &str, pos: upos_type) -> usize {
+    let line = strip_line_break(s);
+    line.grapheme_indices(true)
+        .nth(pos as usize)
+        .map(|(idx, _)| idx)
+        .unwrap_or(line.len())
+}
@@ -0,0 +1,92 @@
+//!
+//! Blank out wide glyphs cut by the horizontal offset.
+//!
+//! When a single-line input is scrolled so that a wide glyph
+//! (e.g. CJK) is only partially visible at the left or right
+//! edge, the input renders a placeholder "∃" for the visible
+//! half. [ClipFill] replaces it with a space filler.
+//! Render it after the input.
+//!
+//! ```rust ignore
+//! TextInput::new().render(area, buf, &mut state.name);
+//! ClipFill::new().render_text_input(buf, &state.name);
+//!
+//! DateInput::new().render(area, buf, &mut state.date);
+//! ClipFill::new().render_masked_input(buf, &state.date.widget);
+//! ```
+//!
+//! The style of the cells is kept.
+//!
+use crate::text::Glyph;
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use std::borrow::Cow;
+
+/// Placeholder the inputs render for a partially visible glyph.
+const CLIPPED: &str = "\u{2203}";
+
+/// Renders the filler for clipped glyphs.
+#[derive(Debug, Clone)]
+pub struct ClipFill {
+    fill: &'static str,
+}
+
+impl Default for ClipFill {
+    fn default() -> Self {
+        Self { fill: " " }
+    }
+}
+
+impl ClipFill {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Filler for the visible part of the glyph.
+    ///
+    /// __Default__
+    /// " "
+    pub fn fill(mut self, fill: &'static str) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Render for a TextInput.
+    pub fn render_text_input(&self, buf: &mut Buffer, state: &TextInputState) {
+        let inner = state.inner;
+        for g in state.glyphs(state.offset() as u16, inner.width) {
+            self.render_glyph(buf, inner, &g, state.str_slice_byte(g.text_bytes()));
+        }
+    }
+
+    /// Render for a MaskedInput.
+    ///
+    /// NumberInput and DateInput use a MaskedInput as `widget`.
+    pub fn render_masked_input(&self, buf: &mut Buffer, state: &MaskedInputState) {
+        let inner = state.inner;
+        for g in state.glyphs(state.offset() as u16, inner.width) {
+            self.render_glyph(buf, inner, &g, state.str_slice_byte(g.text_bytes()));
+        }
+    }
+
+    fn render_glyph(&self, buf: &mut Buffer, inner: Rect, g: &Glyph<'_>, text: Cow<'_, str>) {
+        if g.glyph() != CLIPPED || text == CLIPPED {
+            return;
+        }
+        let (sx, _) = g.screen_pos();
+        // clipped left starts at 0 with the visible width,
+        // clipped right runs to the end of the field.
+        let width = if sx == 0 {
+            g.screen_width()
+        } else {
+            inner.width.saturating_sub(sx)
+        };
+        for d in 0..width {
+            if let Some(cell) = buf.cell_mut((inner.x + sx + d, inner.y)) {
+                cell.set_symbol(self.fill);
+            }
+        }
+    }
+}
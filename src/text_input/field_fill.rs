@@ -0,0 +1,125 @@
+//!
+//! Fill the empty part of single-line inputs.
+//!
+//! Form fields often show their extent with "_______" or an
+//! underline, typed text replaces the fill. [EmptyFill] renders
+//! the chosen [FieldFill] over the cells after the content of a
+//! TextInput. For a MaskedInput only the empty editable positions
+//! are filled, literals like '.' or '-' stay as they are.
+//! Render it after the input.
+//!
+//! ```rust ignore
+//! TextInput::new().render(area, buf, &mut state.name);
+//! EmptyFill::new()
+//!     .field_fill(FieldFill::Underscore)
+//!     .render_text_input(buf, &state.name);
+//!
+//! NumberInput::new().render(area, buf, &mut state.amount);
+//! EmptyFill::new()
+//!     .field_fill(FieldFill::Underscore)
+//!     .render_masked_input(buf, &state.amount.widget);
+//! ```
+//!
+//! Only the cells change, the cursor position is not affected.
+//! Mask tokens that always show a digit, like `0` in the masks of
+//! a DateInput, are never empty.
+//!
+use crate::text::upos_type;
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Modifier, Style};
+
+/// How to fill the empty cells.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FieldFill {
+    /// Underscores.
+    #[default]
+    Underscore,
+    /// Underline attribute, the cells stay blank.
+    Underline,
+    /// Some other char.
+    Char(char),
+}
+
+/// Renders the fill.
+#[derive(Debug, Default, Clone)]
+pub struct EmptyFill {
+    fill: FieldFill,
+    style: Option<Style>,
+}
+
+impl EmptyFill {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fill for the empty cells.
+    ///
+    /// __Default__
+    /// Underscore
+    pub fn field_fill(mut self, fill: FieldFill) -> Self {
+        self.fill = fill;
+        self
+    }
+
+    /// Style for the filled cells.
+    ///
+    /// __Default__
+    /// The style of the cell below.
+    pub fn fill_style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Render for a TextInput.
+    ///
+    /// Fills the width after the text.
+    pub fn render_text_input(&self, buf: &mut Buffer, state: &TextInputState) {
+        let inner = state.inner;
+        let start = state.line_width().saturating_sub(state.offset());
+        for x in start..inner.width as upos_type {
+            self.render_cell(buf, inner.x + x as u16, inner.y);
+        }
+    }
+
+    /// Render for a MaskedInput.
+    ///
+    /// Fills the empty editable positions.
+    /// NumberInput and DateInput use a MaskedInput as `widget`.
+    pub fn render_masked_input(&self, buf: &mut Buffer, state: &MaskedInputState) {
+        let inner = state.inner;
+        let selection = state.selection();
+        for g in state.glyphs(state.offset() as u16, inner.width) {
+            let pos = g.pos().x;
+            if selection.contains(&pos) || state.value.section_range(pos).is_none() {
+                continue;
+            }
+            if state.str_slice_byte(g.text_bytes()) != " " {
+                continue;
+            }
+            let (sx, _) = g.screen_pos();
+            self.render_cell(buf, inner.x + sx, inner.y);
+        }
+    }
+
+    fn render_cell(&self, buf: &mut Buffer, x: u16, y: u16) {
+        let Some(cell) = buf.cell_mut((x, y)) else {
+            return;
+        };
+        match self.fill {
+            FieldFill::Underscore => {
+                cell.set_symbol("_");
+            }
+            FieldFill::Underline => {
+                cell.modifier.insert(Modifier::UNDERLINED);
+            }
+            FieldFill::Char(c) => {
+                cell.set_char(c);
+            }
+        }
+        if let Some(style) = self.style {
+            cell.set_style(style);
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//!
+//! Marks hidden content of single-line inputs.
+//!
+//! When the text is wider than the field nothing shows that
+//! part of it is scrolled off. [OverflowIndicator] renders a
+//! marker in the first/last cell of the field if there is
+//! hidden content on that side. Render it after the input.
+//!
+//! ```rust ignore
+//! TextInput::new().render(area, buf, &mut state.name);
+//! OverflowIndicator::new().render_text_input(buf, &state.name);
+//!
+//! NumberInput::new().render(area, buf, &mut state.amount);
+//! OverflowIndicator::new().render_masked_input(buf, &state.amount.widget);
+//! ```
+//!
+//! The marker is drawn over the text. The cursor can still end
+//! up on a marker cell, the input itself doesn't know about it.
+//!
+use crate::text::upos_type;
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+
+/// Renders the overflow markers.
+#[derive(Debug, Clone)]
+pub struct OverflowIndicator {
+    style: Option<Style>,
+    left: &'static str,
+    right: &'static str,
+}
+
+impl Default for OverflowIndicator {
+    fn default() -> Self {
+        Self {
+            style: None,
+            left: "…",
+            right: "…",
+        }
+    }
+}
+
+impl OverflowIndicator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Style for the markers.
+    ///
+    /// __Default__
+    /// The style of the cell below.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Markers for hidden content on the left/right side.
+    ///
+    /// __Default__
+    /// "…" and "…"
+    pub fn glyphs(mut self, left: &'static str, right: &'static str) -> Self {
+        self.left = left;
+        self.right = right;
+        self
+    }
+
+    /// Render for a TextInput.
+    pub fn render_text_input(&self, buf: &mut Buffer, state: &TextInputState) {
+        self.render(buf, state.inner, state.offset(), state.line_width());
+    }
+
+    /// Render for a MaskedInput.
+    ///
+    /// NumberInput and DateInput use a MaskedInput as `widget`.
+    pub fn render_masked_input(&self, buf: &mut Buffer, state: &MaskedInputState) {
+        self.render(buf, state.inner, state.offset(), state.line_width());
+    }
+
+    /// Render the markers for the text area of the input.
+    ///
+    /// * offset: first visible column
+    /// * width: width of the text
+    pub fn render(&self, buf: &mut Buffer, inner: Rect, offset: upos_type, width: upos_type) {
+        if inner.is_empty() {
+            return;
+        }
+        if offset > 0 {
+            self.render_marker(buf, inner.x, inner.y, self.left);
+        }
+        if width.saturating_sub(offset) > inner.width as upos_type {
+            self.render_marker(buf, inner.right() - 1, inner.y, self.right);
+        }
+    }
+
+    fn render_marker(&self, buf: &mut Buffer, x: u16, y: u16, marker: &str) {
+        if let Some(cell) = buf.cell_mut((x, y)) {
+            cell.set_symbol(marker);
+            if let Some(style) = self.style {
+                cell.set_style(style);
+            }
+        }
+    }
+}
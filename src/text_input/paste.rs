@@ -0,0 +1,75 @@
+//!
+//! Multi-line paste into the single-line TextInput.
+//!
+//! TextInput inserts pasted text as is, line breaks included.
+//! Run [handle_paste] before the regular event handling to
+//! apply a [PastePolicy] to Ctrl+V and to bracketed paste events.
+//!
+//! ```rust ignore
+//! let r = paste::handle_paste(&mut state.name, event, PastePolicy::ReplaceWithSpace);
+//! let r = r.or_else(|| state.name.handle(event, Regular));
+//! ```
+//!
+use crate::event::TextOutcome;
+use crate::text_input::TextInputState;
+use rat_event::ct_event;
+
+/// What happens with line breaks in pasted text.
+///
+/// CRLF, LF and CR all count as a line break. A trailing
+/// line break is dropped.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PastePolicy {
+    /// Each line break becomes a single space.
+    #[default]
+    ReplaceWithSpace,
+    /// Line breaks are removed.
+    StripNewlines,
+    /// Only the first line is kept.
+    FirstLineOnly,
+}
+
+impl PastePolicy {
+    /// Convert the pasted text to a single line.
+    pub fn apply(&self, text: &str) -> String {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let mut lines = text.lines();
+        match self {
+            PastePolicy::ReplaceWithSpace => lines.collect::<Vec<_>>().join(" "),
+            PastePolicy::StripNewlines => lines.collect(),
+            PastePolicy::FirstLineOnly => lines.next().unwrap_or_default().to_string(),
+        }
+    }
+}
+
+/// Paste with the given policy.
+///
+/// Handles Ctrl+V with the clipboard of the widget and
+/// crossterm's Event::Paste, if the widget has the focus.
+pub fn handle_paste(
+    state: &mut TextInputState,
+    event: &crossterm::event::Event,
+    policy: PastePolicy,
+) -> TextOutcome {
+    if !state.focus.get() {
+        return TextOutcome::Continue;
+    }
+    let text = match event {
+        ct_event!(key press CONTROL-'v') => {
+            let Some(clip) = state.clipboard() else {
+                return TextOutcome::Unchanged;
+            };
+            let Ok(text) = clip.get_string() else {
+                return TextOutcome::Unchanged;
+            };
+            text
+        }
+        crossterm::event::Event::Paste(text) => text.clone(),
+        _ => return TextOutcome::Continue,
+    };
+    if state.insert_str(policy.apply(&text)) {
+        TextOutcome::TextChanged
+    } else {
+        TextOutcome::Unchanged
+    }
+}
@@ -0,0 +1,104 @@
+//!
+//! Selection behaviour when a single-line input gains the focus.
+//!
+//! Form fields often select the whole text on focus so the user
+//! can overtype it, others put the cursor at the end.
+//! [SelectOnFocus] does this when the focus flag says `gained()`.
+//! Call it after the event-handling, `gained()` is only set for
+//! the event that moved the focus.
+//!
+//! ```rust ignore
+//! let select = SelectOnFocus::new().select_on_focus(SelectBehavior::SelectAll);
+//!
+//! // after focus and widget event-handling
+//! select.apply_text_input(&mut state.name);
+//! select.apply_masked_input(&mut state.date.widget);
+//! ```
+//!
+//! Without an explicit behaviour a TextInput keeps its cursor and
+//! a MaskedInput puts the cursor at its default position, the edit
+//! position of the first editable section.
+//!
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use rat_focus::HasFocus;
+
+/// What to do when the input gains the focus.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SelectBehavior {
+    /// Select the whole text.
+    SelectAll,
+    /// Cursor at the end of the text.
+    CursorEnd,
+    /// Cursor at the default position of the widget.
+    /// The start for a TextInput, the edit position of the first
+    /// editable section for a MaskedInput.
+    CursorDefault,
+    /// Leave cursor and selection as they are.
+    Keep,
+}
+
+/// Applies the [SelectBehavior] on gained focus.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SelectOnFocus {
+    behavior: Option<SelectBehavior>,
+}
+
+impl SelectOnFocus {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Behaviour when the focus is gained.
+    ///
+    /// __Default__
+    /// Keep for a TextInput, CursorDefault for a MaskedInput.
+    pub fn select_on_focus(mut self, behavior: SelectBehavior) -> Self {
+        self.behavior = Some(behavior);
+        self
+    }
+
+    /// Apply to a TextInput if it just gained the focus.
+    ///
+    /// Returns true if the cursor or selection changed.
+    pub fn apply_text_input(&self, state: &mut TextInputState) -> bool {
+        if !state.gained_focus() {
+            return false;
+        }
+        let r = match self.behavior.unwrap_or(SelectBehavior::Keep) {
+            SelectBehavior::SelectAll => state.set_selection(0, state.len()),
+            SelectBehavior::CursorEnd => state.set_cursor(state.len(), false),
+            SelectBehavior::CursorDefault => state.set_cursor(0, false),
+            SelectBehavior::Keep => return false,
+        };
+        let s = state.scroll_cursor_to_visible();
+        r || s
+    }
+
+    /// Apply to a MaskedInput if it just gained the focus.
+    ///
+    /// NumberInput and DateInput use a MaskedInput as `widget`.
+    ///
+    /// Returns true if the cursor or selection changed.
+    pub fn apply_masked_input(&self, state: &mut MaskedInputState) -> bool {
+        if !state.gained_focus() {
+            return false;
+        }
+        let old_selection = state.selection();
+        let old_cursor = state.cursor();
+        match self.behavior.unwrap_or(SelectBehavior::CursorDefault) {
+            SelectBehavior::SelectAll => {
+                state.set_selection(0, state.len());
+            }
+            SelectBehavior::CursorEnd => {
+                state.set_cursor(state.len(), false);
+            }
+            SelectBehavior::CursorDefault => {
+                state.set_default_cursor();
+            }
+            SelectBehavior::Keep => return false,
+        }
+        let s = state.scroll_cursor_to_visible();
+        old_selection != state.selection() || old_cursor != state.cursor() || s
+    }
+}
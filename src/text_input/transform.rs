@@ -0,0 +1,70 @@
+//!
+//! Normalize the text when the user commits the input.
+//!
+//! Unlike a per-keystroke filter this leaves the text alone
+//! while typing and replaces it on Enter or when the
+//! widget loses the focus.
+//!
+//! ```rust ignore
+//! // after the focus handling.
+//! let r = transform::handle_commit(&mut state.country, event, transform::upper);
+//! let r = r.or_else(|| state.country.handle(event, Regular));
+//! ```
+//!
+use crate::event::TextOutcome;
+use crate::text_input::TextInputState;
+use rat_event::ct_event;
+
+/// Uppercase.
+pub fn upper(text: &str) -> String {
+    text.to_uppercase()
+}
+
+/// Lowercase.
+pub fn lower(text: &str) -> String {
+    text.to_lowercase()
+}
+
+/// Remove leading and trailing whitespace.
+pub fn trim(text: &str) -> String {
+    text.trim().to_string()
+}
+
+/// Replace the text with the transformed text.
+///
+/// The cursor stays where it was, limited to the new length.
+/// Returns true if the text changed.
+pub fn apply(state: &mut TextInputState, transform: impl Fn(&str) -> String) -> bool {
+    let new_text = transform(state.text());
+    if new_text == state.text() {
+        return false;
+    }
+    let cursor = state.cursor();
+    state.set_text(new_text);
+    let cursor = cursor.min(state.len());
+    state.set_cursor(cursor, false);
+    true
+}
+
+/// Apply the transform on Enter or when the widget lost the focus.
+///
+/// Call it after the focus handling, as focus-lost is only
+/// known then. Enter is not consumed, so this returns
+/// [TextOutcome::Continue] even if the text changed on Enter,
+/// but [TextOutcome::TextChanged] when it changes on focus lost.
+pub fn handle_commit(
+    state: &mut TextInputState,
+    event: &crossterm::event::Event,
+    transform: impl Fn(&str) -> String,
+) -> TextOutcome {
+    if state.focus.lost() {
+        if apply(state, transform) {
+            return TextOutcome::TextChanged;
+        }
+    } else if state.focus.get() {
+        if let ct_event!(keycode press Enter) = event {
+            apply(state, transform);
+        }
+    }
+    TextOutcome::Continue
+}
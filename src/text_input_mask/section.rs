@@ -0,0 +1,318 @@
+//!
+//! Sections of a masked input.
+//!
+//! A masked value is split into sections, e.g. `DD`, `MM` and
+//! `YYYY` of a date. MaskedInput already navigates them:
+//! Ctrl+Left/Right jump to the previous/next section, Tab and
+//! BackTab select the next/previous section and a double-click
+//! selects the section under the mouse.
+//!
+//! This adds [current_section] and spinning the digits of a
+//! section with Up/Down. [handle_spin] wraps within the digits of
+//! the section, [handle_date_spin] carries into the neighbouring
+//! fields of a date.
+//!
+//! [handle_date_wheel] spins the date field at the cursor with the
+//! mouse wheel, by the larger step of [WheelSteps] if Shift is held.
+//!
+//! ```rust ignore
+//! let r = section::handle_spin(&mut state.zip, event);
+//! let r = r.or_else(|| state.zip.handle(event, Regular));
+//! ```
+//!
+use crate::date_input::DateInputState;
+use crate::event::TextOutcome;
+use crate::text::upos_type;
+use crate::text_input_mask::MaskedInputState;
+use chrono::format::{Fixed, Item, Numeric, StrftimeItems};
+use chrono::{Days, Months, NaiveDate};
+use rat_event::ct_event;
+use ratatui::layout::Position;
+use std::ops::Range;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Range of the editable section at the cursor.
+///
+/// If the cursor is right behind a section, this is the
+/// section left of the cursor.
+pub fn current_section(state: &MaskedInputState) -> Option<Range<upos_type>> {
+    let cursor = state.cursor();
+    state
+        .value
+        .section_range(cursor)
+        .or_else(|| state.value.section_range(cursor.checked_sub(1)?))
+}
+
+/// Add delta to the section at the cursor.
+///
+/// Only for sections made of the digit tokens `0` and `9`.
+/// The value wraps within the digits of the section, a `99`
+/// section goes from 99 to 00. Spaces count as 0.
+///
+/// Returns true if the value changed.
+pub fn spin_section(state: &mut MaskedInputState, delta: i64) -> bool {
+    let Some(range) = current_section(state) else {
+        return false;
+    };
+    if !is_digit_section(state, range.clone()) {
+        return false;
+    }
+
+    let width = (range.end - range.start) as usize;
+    let Some(modulo) = 10i64.checked_pow(width as u32) else {
+        return false;
+    };
+    let text = state.value.str_slice(range.clone()).expect("valid_range");
+    let digits = text.trim().replace(' ', "0");
+    let value = digits.parse::<i64>().unwrap_or(0);
+
+    let new_value = (value + delta).rem_euclid(modulo);
+    let new_text = format!("{:0width$}", new_value, width = width);
+    if new_text == text {
+        return false;
+    }
+
+    let cursor = state.cursor();
+    state.value.begin_undo_seq();
+    state.set_selection(range.start, range.end);
+    for c in new_text.chars() {
+        state.insert_char(c);
+    }
+    state.value.end_undo_seq();
+    state.set_cursor(cursor, false);
+    true
+}
+
+/// Add delta to the date field at the cursor.
+///
+/// Day, month and year carry into each other, 31.01. + 1 day
+/// gives 01.02. A month at the end of a longer month is clamped
+/// to the last day of the shorter month.
+///
+/// Falls back to [spin_section] if the date is not valid or the
+/// section is no day, month or year.
+///
+/// Returns true if the value changed.
+pub fn spin_date(state: &mut DateInputState, delta: i64) -> bool {
+    let Some(range) = current_section(&state.widget) else {
+        return false;
+    };
+
+    if let Ok(date) = state.value() {
+        if let Some(field) = date_field_at(state.format(), state.locale(), range.start) {
+            let new_date = match field {
+                DateField::Day => add_days(date, delta),
+                DateField::Month => add_months(date, delta),
+                DateField::Year => delta
+                    .checked_mul(12)
+                    .and_then(|months| add_months(date, months)),
+            };
+            return match new_date {
+                Some(new_date) if new_date != date => {
+                    let cursor = state.widget.cursor();
+                    state.set_value(new_date);
+                    state.widget.set_cursor(cursor, false);
+                    true
+                }
+                _ => false,
+            };
+        }
+    }
+
+    spin_section(&mut state.widget, delta)
+}
+
+/// Up/Down spin the section at the cursor.
+///
+/// Only if the widget has the focus.
+pub fn handle_spin(state: &mut MaskedInputState, event: &crossterm::event::Event) -> TextOutcome {
+    if !state.focus.get() {
+        return TextOutcome::Continue;
+    }
+    let delta = match event {
+        ct_event!(keycode press Up) => 1,
+        ct_event!(keycode press Down) => -1,
+        _ => return TextOutcome::Continue,
+    };
+    if spin_section(state, delta) {
+        TextOutcome::TextChanged
+    } else {
+        TextOutcome::Unchanged
+    }
+}
+
+/// Up/Down spin the date field at the cursor.
+///
+/// Only if the widget has the focus.
+pub fn handle_date_spin(
+    state: &mut DateInputState,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    if !state.widget.focus.get() {
+        return TextOutcome::Continue;
+    }
+    let delta = match event {
+        ct_event!(keycode press Up) => 1,
+        ct_event!(keycode press Down) => -1,
+        _ => return TextOutcome::Continue,
+    };
+    if spin_date(state, delta) {
+        TextOutcome::TextChanged
+    } else {
+        TextOutcome::Unchanged
+    }
+}
+
+/// Step sizes for the mouse wheel.
+///
+/// The unit is given by the date field at the cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WheelSteps {
+    step: i64,
+    shift_step: i64,
+}
+
+impl Default for WheelSteps {
+    fn default() -> Self {
+        Self {
+            step: 1,
+            shift_step: 10,
+        }
+    }
+}
+
+impl WheelSteps {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Step for the mouse wheel. Default is 1.
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Step with Shift held. Default is 10.
+    pub fn shift_step(mut self, step: i64) -> Self {
+        self.shift_step = step;
+        self
+    }
+}
+
+/// The mouse wheel spins the date field at the cursor.
+///
+/// Only if the mouse is over the widget.
+pub fn handle_date_wheel(
+    state: &mut DateInputState,
+    steps: &WheelSteps,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    let area = state.widget.area;
+    let over = |x: u16, y: u16| area.contains(Position::new(x, y));
+    let delta = match event {
+        ct_event!(scroll up for x,y) if over(*x, *y) => steps.step,
+        ct_event!(scroll down for x,y) if over(*x, *y) => -steps.step,
+        ct_event!(scroll SHIFT up for x,y) if over(*x, *y) => steps.shift_step,
+        ct_event!(scroll SHIFT down for x,y) if over(*x, *y) => -steps.shift_step,
+        _ => return TextOutcome::Continue,
+    };
+    if spin_date(state, delta) {
+        TextOutcome::TextChanged
+    } else {
+        TextOutcome::Unchanged
+    }
+}
+
+/// Section consists of digit tokens only.
+fn is_digit_section(state: &MaskedInputState, range: Range<upos_type>) -> bool {
+    // one token per position, separators are escaped.
+    let mask = state.mask();
+    let mut tokens = Vec::new();
+    let mut it = mask.graphemes(true);
+    while let Some(t) = it.next() {
+        if t == "\\" {
+            it.next();
+            tokens.push(false);
+        } else {
+            tokens.push(t == "0" || t == "9");
+        }
+    }
+    !range.is_empty()
+        && (range.start..range.end).all(|pos| tokens.get(pos as usize).copied().unwrap_or(false))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DateField {
+    Day,
+    Month,
+    Year,
+}
+
+/// Find the date field at the text position.
+///
+/// Follows the mask generation of DateInputState.
+fn date_field_at(pattern: &str, locale: chrono::Locale, pos: upos_type) -> Option<DateField> {
+    let items = StrftimeItems::new_with_locale(pattern, locale)
+        .parse()
+        .ok()?;
+
+    let mut start = 0;
+    for item in items.iter() {
+        let (width, field) = match item {
+            Item::Literal(s) | Item::Space(s) => (s.graphemes(true).count() as upos_type, None),
+            Item::OwnedLiteral(s) | Item::OwnedSpace(s) => {
+                (s.graphemes(true).count() as upos_type, None)
+            }
+            Item::Numeric(v, _) => match v {
+                Numeric::Year => (4, Some(DateField::Year)),
+                Numeric::IsoYear => (4, None),
+                Numeric::YearMod100 => (2, Some(DateField::Year)),
+                Numeric::Month => (2, Some(DateField::Month)),
+                Numeric::Day => (2, Some(DateField::Day)),
+                Numeric::YearDiv100
+                | Numeric::IsoYearDiv100
+                | Numeric::IsoYearMod100
+                | Numeric::WeekFromSun
+                | Numeric::WeekFromMon
+                | Numeric::IsoWeek
+                | Numeric::Hour
+                | Numeric::Hour12
+                | Numeric::Minute
+                | Numeric::Second => (2, None),
+                Numeric::NumDaysFromSun | Numeric::WeekdayFromMon => (1, None),
+                Numeric::Ordinal => (3, None),
+                _ => return None,
+            },
+            Item::Fixed(v) => match v {
+                Fixed::ShortMonthName | Fixed::ShortWeekdayName => (3, None),
+                Fixed::LongMonthName => (9, None),
+                Fixed::LongWeekdayName => (8, None),
+                Fixed::LowerAmPm | Fixed::UpperAmPm => (2, None),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        if pos >= start && pos < start + width {
+            return field;
+        }
+        start += width;
+    }
+    None
+}
+
+fn add_days(date: NaiveDate, delta: i64) -> Option<NaiveDate> {
+    if delta >= 0 {
+        date.checked_add_days(Days::new(delta as u64))
+    } else {
+        date.checked_sub_days(Days::new(delta.unsigned_abs()))
+    }
+}
+
+fn add_months(date: NaiveDate, delta: i64) -> Option<NaiveDate> {
+    let months = Months::new(u32::try_from(delta.unsigned_abs()).ok()?);
+    if delta >= 0 {
+        date.checked_add_months(months)
+    } else {
+        date.checked_sub_months(months)
+    }
+}
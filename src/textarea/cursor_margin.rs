@@ -0,0 +1,122 @@
+//!
+//! Keep the cursor of a TextArea in view.
+//!
+//! The TextArea only scrolls when the cursor moves. If the area
+//! shrinks, e.g. when the terminal is resized, the cursor can end
+//! up below the visible rows until the next key press.
+//!
+//! [CursorMargin] checks the cursor after rendering and scrolls
+//! if the cursor moved or the area got smaller. With a margin > 0
+//! it keeps that many rows of context above and below the cursor,
+//! like scrolloff in vim. Scrolling with the mouse wheel is left
+//! alone as long as the cursor doesn't move.
+//!
+//! Only a focused TextArea is scrolled.
+//!
+//! ```rust ignore
+//! // instead of TextArea::new().render(area, buf, &mut state.text)
+//! state.margin.render(TextArea::new(), area, buf, &mut state.text);
+//! ```
+//!
+use crate::text::{upos_type, TextPosition};
+use crate::textarea::{TextArea, TextAreaState};
+use rat_focus::HasFocus;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::widgets::StatefulWidget;
+use std::cmp::{max, min};
+
+/// Scrolls the TextArea to keep the cursor with a margin visible.
+///
+/// Remembers the cursor and the size of the last render.
+#[derive(Debug, Default, Clone)]
+pub struct CursorMargin {
+    margin: u16,
+    cursor: Option<TextPosition>,
+    size: Size,
+}
+
+impl CursorMargin {
+    /// New with a margin of 0 rows.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Rows of context above and below the cursor.
+    ///
+    /// The margin is reduced if the area is too small.
+    pub fn cursor_margin(mut self, margin: u16) -> Self {
+        self.margin = margin;
+        self
+    }
+
+    /// Rows of context above and below the cursor.
+    pub fn margin(&self) -> u16 {
+        self.margin
+    }
+
+    /// Set the rows of context above and below the cursor.
+    pub fn set_margin(&mut self, margin: u16) {
+        self.margin = margin;
+    }
+
+    /// Render the TextArea and render again if the offset
+    /// had to change.
+    pub fn render(
+        &mut self,
+        widget: TextArea<'_>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TextAreaState,
+    ) {
+        widget.clone().render(area, buf, state);
+        if self.update(state) {
+            widget.render(area, buf, state);
+        }
+    }
+
+    /// Check the cursor after rendering the TextArea.
+    ///
+    /// Scrolls if the cursor moved or the area got smaller
+    /// since the last call. Returns true if the offset changed,
+    /// the TextArea must be rendered again then.
+    pub fn update(&mut self, state: &mut TextAreaState) -> bool {
+        let cursor = state.cursor();
+        let size = state.inner.as_size();
+
+        let moved = self.cursor != Some(cursor);
+        let shrunk = size.width < self.size.width || size.height < self.size.height;
+        self.cursor = Some(cursor);
+        self.size = size;
+
+        if !state.is_focused() {
+            return false;
+        }
+        if !(moved || shrunk || state.focus.gained()) {
+            return false;
+        }
+        if size.width == 0 || size.height == 0 {
+            return false;
+        }
+
+        let old_offset = state.offset();
+        state.scroll_cursor_to_visible();
+
+        let height = size.height as upos_type;
+        let margin = min(self.margin as upos_type, height.saturating_sub(1) / 2);
+        let (_, oy) = state.offset();
+        let oy = oy as upos_type;
+        let max_offset = state.len_lines().saturating_sub(height);
+
+        let new_oy = if cursor.y < oy + margin {
+            cursor.y.saturating_sub(margin)
+        } else if cursor.y + margin >= oy + height {
+            min(cursor.y + margin + 1 - height, max(oy, max_offset))
+        } else {
+            oy
+        };
+        state.set_vertical_offset(new_oy as usize);
+
+        state.offset() != old_offset
+    }
+}
@@ -0,0 +1,160 @@
+//!
+//! Goto line/column and cursor reporting for the TextArea.
+//!
+//! For a status line like "Ln 12, Col 34" there is
+//! [cursor_line_col] with the column in graphemes and
+//! [cursor_display_col] with tabs expanded. [handle_events]
+//! reports [CursorOutcome::CursorMoved] when the cursor moved,
+//! so the status line only needs an update then.
+//!
+//! ```rust ignore
+//! match goto::handle_events(&mut state.text, focus, event) {
+//!     CursorOutcome::CursorMoved | CursorOutcome::TextChanged => {
+//!         let (line, col) = goto::cursor_line_col(&state.text);
+//!         state.status = format!("Ln {}, Col {}", line + 1, col + 1);
+//!         Outcome::Changed
+//!     }
+//!     r => r.into(),
+//! }
+//! ```
+//!
+//! [set_cursor_line_col] and [goto_line] clamp to the text.
+//! All lines and columns are 0-based.
+//!
+use crate::text::{upos_type, TextPosition};
+use crate::textarea::TextAreaState;
+use rat_event::{ConsumedEvent, Outcome};
+use rat_text::event::TextOutcome;
+use std::cmp::min;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Result of [handle_events].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CursorOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// The cursor moved, the text didn't change.
+    CursorMoved,
+    /// Text content has changed. The cursor may have moved too.
+    TextChanged,
+}
+
+impl ConsumedEvent for CursorOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != CursorOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for CursorOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            CursorOutcome::Changed
+        } else {
+            CursorOutcome::Unchanged
+        }
+    }
+}
+
+impl From<TextOutcome> for CursorOutcome {
+    fn from(value: TextOutcome) -> Self {
+        match value {
+            TextOutcome::Continue => CursorOutcome::Continue,
+            TextOutcome::Unchanged => CursorOutcome::Unchanged,
+            TextOutcome::Changed => CursorOutcome::Changed,
+            TextOutcome::TextChanged => CursorOutcome::TextChanged,
+        }
+    }
+}
+
+impl From<CursorOutcome> for Outcome {
+    fn from(value: CursorOutcome) -> Self {
+        match value {
+            CursorOutcome::Continue => Outcome::Continue,
+            CursorOutcome::Unchanged => Outcome::Unchanged,
+            CursorOutcome::Changed => Outcome::Changed,
+            CursorOutcome::CursorMoved => Outcome::Changed,
+            CursorOutcome::TextChanged => Outcome::Changed,
+        }
+    }
+}
+
+/// Cursor as (line, column). The column counts graphemes.
+pub fn cursor_line_col(state: &TextAreaState) -> (upos_type, upos_type) {
+    let cursor = state.cursor();
+    (cursor.y, cursor.x)
+}
+
+/// Display column of the cursor. Tabs are expanded with
+/// the tab width of the TextArea.
+pub fn cursor_display_col(state: &TextAreaState) -> upos_type {
+    let cursor = state.cursor();
+    let tabs = state.tab_width().max(1) as upos_type;
+    let line = state.line_at(cursor.y);
+
+    let mut col = 0;
+    for g in line.graphemes(true).take(cursor.x as usize) {
+        if g == "\t" {
+            col += tabs - col % tabs;
+        } else {
+            col += unicode_display_width::width(g) as upos_type;
+        }
+    }
+    col
+}
+
+/// Set the cursor to line and column. Both are clamped to the
+/// text. Scrolls the cursor into view.
+///
+/// Returns true if the cursor or the offset changed.
+pub fn set_cursor_line_col(
+    state: &mut TextAreaState,
+    line: upos_type,
+    col: upos_type,
+    extend_selection: bool,
+) -> bool {
+    let line = min(line, state.len_lines().saturating_sub(1));
+    let col = min(col, state.line_width(line));
+    let c = state.set_cursor(TextPosition::new(col, line), extend_selection);
+    let s = state.scroll_cursor_to_visible();
+    c || s
+}
+
+/// Cursor to the start of the line and scroll the line to
+/// the middle of the view. The line is clamped to the text.
+///
+/// Returns true if the cursor or the offset changed.
+pub fn goto_line(state: &mut TextAreaState, line: upos_type) -> bool {
+    let line = min(line, state.len_lines().saturating_sub(1));
+    let c = state.set_cursor(TextPosition::new(0, line), false);
+    let half = state.inner.height as upos_type / 2;
+    let s = state.set_vertical_offset(line.saturating_sub(half) as usize);
+    let h = state.set_horizontal_offset(0);
+    c || s || h
+}
+
+/// Handle all events of the TextArea and report cursor
+/// movement as [CursorOutcome::CursorMoved].
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut TextAreaState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> CursorOutcome {
+    let cursor = state.cursor();
+    let r: CursorOutcome = crate::textarea::handle_events(state, focus, event).into();
+    if r == CursorOutcome::Changed && state.cursor() != cursor {
+        CursorOutcome::CursorMoved
+    } else {
+        r
+    }
+}
@@ -0,0 +1,291 @@
+//!
+//! Marks in a gutter column next to the TextArea.
+//!
+//! Breakpoints, diagnostics, the current execution line ...
+//! [LineMarksState] maps line numbers to a [Mark], [LineMarks]
+//! renders the one cell wide column and clicks in this column
+//! report the line with [MarkOutcome::Clicked].
+//!
+//! Works like [LineNumbers](crate::line_number::LineNumbers),
+//! it's rendered beside the TextArea.
+//!
+//! ```rust ignore
+//! let l = Layout::horizontal([
+//!     Constraint::Length(state.marks.width()),
+//!     Constraint::Fill(1),
+//! ]).split(area);
+//!
+//! TextArea::new().render(l[1], buf, &mut state.text);
+//! LineMarks::new()
+//!     .start(state.text.vertical_offset() as upos_type)
+//!     .end(state.text.len_lines())
+//!     .render(l[0], buf, &mut state.marks);
+//!
+//! // events
+//! match marks::handle_events(&mut state.marks, event) {
+//!     MarkOutcome::Clicked(line) => state.marks.toggle(line, Mark::new("●", Color::Red)),
+//!     r => r.into(),
+//! }
+//! ```
+//!
+use crate::_private::NonExhaustive;
+use crate::event::{ConsumedEvent, Outcome, TextOutcome};
+use crate::text::upos_type;
+use rat_event::ct_event;
+use rat_reloc::{relocate_area, RelocatableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+use ratatui::widgets::StatefulWidget;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+
+/// Mark for one line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mark {
+    /// One cell wide symbol.
+    pub symbol: Cow<'static, str>,
+    /// Style for the symbol.
+    pub style: Style,
+}
+
+impl Mark {
+    pub fn new(symbol: impl Into<Cow<'static, str>>, style: impl Into<Style>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            style: style.into(),
+        }
+    }
+}
+
+/// Renders the mark column.
+#[derive(Debug, Default, Clone)]
+pub struct LineMarks {
+    start: upos_type,
+    end: Option<upos_type>,
+    style: Style,
+}
+
+/// State & event handling.
+#[derive(Debug, Clone)]
+pub struct LineMarksState {
+    /// Area of the mark column.
+    /// __read only__ renewed with each render.
+    pub area: Rect,
+    /// First rendered line.
+    /// __read only__ renewed with each render.
+    pub start: upos_type,
+    /// End of the text, if known.
+    /// __read only__ renewed with each render.
+    pub end: Option<upos_type>,
+    /// Marks by line.
+    /// __read+write__
+    pub marks: BTreeMap<usize, Mark>,
+    /// Reserve the column even if there are no marks.
+    /// __read+write__
+    pub reserve: bool,
+
+    pub non_exhaustive: NonExhaustive,
+}
+
+/// Result of event handling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MarkOutcome {
+    /// The given event has not been used at all.
+    Continue,
+    /// The event has been recognized, but the result was nil.
+    /// Further processing for this event may stop.
+    Unchanged,
+    /// The event has been recognized and there is some change
+    /// due to it.
+    /// Further processing for this event may stop.
+    /// Rendering the ui is advised.
+    Changed,
+    /// The mark column of this line has been clicked.
+    Clicked(usize),
+}
+
+impl ConsumedEvent for MarkOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != MarkOutcome::Continue
+    }
+}
+
+// Useful for converting most navigation/edit results.
+impl From<bool> for MarkOutcome {
+    fn from(value: bool) -> Self {
+        if value {
+            MarkOutcome::Changed
+        } else {
+            MarkOutcome::Unchanged
+        }
+    }
+}
+
+impl From<Outcome> for MarkOutcome {
+    fn from(value: Outcome) -> Self {
+        match value {
+            Outcome::Continue => MarkOutcome::Continue,
+            Outcome::Unchanged => MarkOutcome::Unchanged,
+            Outcome::Changed => MarkOutcome::Changed,
+        }
+    }
+}
+
+impl From<MarkOutcome> for Outcome {
+    fn from(value: MarkOutcome) -> Self {
+        match value {
+            MarkOutcome::Continue => Outcome::Continue,
+            MarkOutcome::Unchanged => Outcome::Unchanged,
+            MarkOutcome::Changed => Outcome::Changed,
+            MarkOutcome::Clicked(_) => Outcome::Changed,
+        }
+    }
+}
+
+impl From<MarkOutcome> for TextOutcome {
+    fn from(value: MarkOutcome) -> Self {
+        match value {
+            MarkOutcome::Continue => TextOutcome::Continue,
+            MarkOutcome::Unchanged => TextOutcome::Unchanged,
+            MarkOutcome::Changed => TextOutcome::Changed,
+            MarkOutcome::Clicked(_) => TextOutcome::Changed,
+        }
+    }
+}
+
+impl LineMarks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// First line to render. Use the vertical offset of the TextArea.
+    pub fn start(mut self, start: upos_type) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// End of the text. No marks are rendered or clicked after it.
+    pub fn end(mut self, end: upos_type) -> Self {
+        self.end = Some(end);
+        self
+    }
+
+    /// Base style for the column.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl StatefulWidget for LineMarks {
+    type State = LineMarksState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.area = area;
+        state.start = self.start;
+        state.end = self.end;
+
+        buf.set_style(area, self.style);
+        for y in 0..area.height {
+            let line = self.start as usize + y as usize;
+            if self.end.is_some_and(|v| line >= v as usize) {
+                break;
+            }
+            if let Some(mark) = state.marks.get(&line) {
+                buf.set_stringn(
+                    area.x,
+                    area.y + y,
+                    mark.symbol.as_ref(),
+                    area.width as usize,
+                    self.style.patch(mark.style),
+                );
+            }
+        }
+    }
+}
+
+impl Default for LineMarksState {
+    fn default() -> Self {
+        Self {
+            area: Default::default(),
+            start: 0,
+            end: None,
+            marks: Default::default(),
+            reserve: false,
+            non_exhaustive: NonExhaustive,
+        }
+    }
+}
+
+impl RelocatableState for LineMarksState {
+    fn relocate(&mut self, shift: (i16, i16), clip: Rect) {
+        self.area = relocate_area(self.area, shift, clip);
+    }
+}
+
+impl LineMarksState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Width of the mark column.
+    ///
+    /// 1 if there are marks or the column is reserved, otherwise 0.
+    pub fn width(&self) -> u16 {
+        if self.reserve || !self.marks.is_empty() {
+            1
+        } else {
+            0
+        }
+    }
+
+    /// Set all marks.
+    pub fn set_line_marks(&mut self, marks: BTreeMap<usize, Mark>) {
+        self.marks = marks;
+    }
+
+    /// Set the mark for a line.
+    pub fn set_mark(&mut self, line: usize, mark: Mark) {
+        self.marks.insert(line, mark);
+    }
+
+    /// Remove the mark of a line.
+    pub fn clear_mark(&mut self, line: usize) -> Option<Mark> {
+        self.marks.remove(&line)
+    }
+
+    /// Set or remove the mark. Returns true if it was set.
+    pub fn toggle(&mut self, line: usize, mark: Mark) -> bool {
+        if self.marks.remove(&line).is_some() {
+            false
+        } else {
+            self.marks.insert(line, mark);
+            true
+        }
+    }
+
+    /// Line at the screen position, if it's in the mark column.
+    pub fn line_at(&self, pos: (u16, u16)) -> Option<usize> {
+        if !self.area.contains(pos.into()) {
+            return None;
+        }
+        let line = self.start as usize + (pos.1 - self.area.y) as usize;
+        if self.end.is_some_and(|v| line >= v as usize) {
+            None
+        } else {
+            Some(line)
+        }
+    }
+}
+
+/// Report clicks in the mark column.
+pub fn handle_events(state: &mut LineMarksState, event: &crossterm::event::Event) -> MarkOutcome {
+    match event {
+        ct_event!(mouse down Left for x, y) => match state.line_at((*x, *y)) {
+            Some(line) => MarkOutcome::Clicked(line),
+            None => MarkOutcome::Continue,
+        },
+        _ => MarkOutcome::Continue,
+    }
+}
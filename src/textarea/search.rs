@@ -0,0 +1,158 @@
+//!
+//! Find text in the TextArea.
+//!
+//! [TextSearch] keeps the matches of a search text.
+//! [TextSearch::search_next] and [TextSearch::search_prev] select
+//! the next/previous match, center it and report when the search
+//! wrapped around the end of the text.
+//!
+//! ```rust ignore
+//! state.search.set_needle("fn ");
+//! state.search.update(&state.text);
+//!
+//! match state.search.search_next(&mut state.text) {
+//!     SearchResult::NotFound => state.status = "not found".into(),
+//!     SearchResult::Found(_) => {}
+//!     SearchResult::Wrapped(_) => state.status = "wrapped to top".into(),
+//! }
+//! if let Some(n) = state.search.current_match_index() {
+//!     state.count = format!("{} of {}", n + 1, state.search.search_match_count());
+//! }
+//! ```
+//!
+use crate::text::{upos_type, TextPosition, TextRange};
+use crate::textarea::TextAreaState;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Result of [TextSearch::search_next] and [TextSearch::search_prev].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchResult {
+    /// There is no match.
+    NotFound,
+    /// Selected the match with this index.
+    Found(usize),
+    /// Wrapped around the end/start of the text and
+    /// selected the match with this index.
+    Wrapped(usize),
+}
+
+/// Search state.
+#[derive(Debug, Default, Clone)]
+pub struct TextSearch {
+    /// Search text.
+    needle: String,
+    /// All matches in text order.
+    /// __read only__ renewed with each update.
+    pub matches: Vec<TextRange>,
+    /// Current match.
+    /// __read only__
+    pub current: Option<usize>,
+}
+
+impl TextSearch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Search text. Call [update](TextSearch::update) afterwards.
+    ///
+    /// Matches don't span lines, the search text should not
+    /// contain line breaks.
+    pub fn set_needle(&mut self, needle: impl Into<String>) {
+        self.needle = needle.into();
+    }
+
+    /// Search text.
+    pub fn needle(&self) -> &str {
+        &self.needle
+    }
+
+    /// Find all matches. Call after the text or the search text changed.
+    pub fn update(&mut self, text: &TextAreaState) {
+        self.matches.clear();
+        self.current = None;
+        if self.needle.is_empty() {
+            return;
+        }
+
+        for row in 0..text.len_lines() {
+            let line = text.line_at(row);
+            for (byte, m) in line.match_indices(self.needle.as_str()) {
+                let x0 = line[..byte].graphemes(true).count() as upos_type;
+                let x1 = x0 + m.graphemes(true).count() as upos_type;
+                self.matches
+                    .push(TextRange::new(TextPosition::new(x0, row), (x1, row)));
+            }
+        }
+    }
+
+    /// Number of matches.
+    pub fn search_match_count(&self) -> usize {
+        self.matches.len()
+    }
+
+    /// Index of the current match, if any.
+    pub fn current_match_index(&self) -> Option<usize> {
+        self.current
+    }
+
+    /// Current match.
+    pub fn current_match(&self) -> Option<TextRange> {
+        self.matches.get(self.current?).copied()
+    }
+
+    /// Select the first match after the cursor.
+    /// Wraps to the first match of the text.
+    pub fn search_next(&mut self, text: &mut TextAreaState) -> SearchResult {
+        if self.matches.is_empty() {
+            self.current = None;
+            return SearchResult::NotFound;
+        }
+        let cursor = text.cursor();
+        let r = match self.matches.iter().position(|m| m.start >= cursor) {
+            Some(idx) => SearchResult::Found(idx),
+            None => SearchResult::Wrapped(0),
+        };
+        self.select(r, text)
+    }
+
+    /// Select the last match before the selection/cursor.
+    /// Wraps to the last match of the text.
+    pub fn search_prev(&mut self, text: &mut TextAreaState) -> SearchResult {
+        if self.matches.is_empty() {
+            self.current = None;
+            return SearchResult::NotFound;
+        }
+        let start = text.selection().start;
+        let r = match self.matches.iter().rposition(|m| m.start < start) {
+            Some(idx) => SearchResult::Found(idx),
+            None => SearchResult::Wrapped(self.matches.len() - 1),
+        };
+        self.select(r, text)
+    }
+
+    fn select(&mut self, r: SearchResult, text: &mut TextAreaState) -> SearchResult {
+        let (SearchResult::Found(idx) | SearchResult::Wrapped(idx)) = r else {
+            return r;
+        };
+        let m = self.matches[idx];
+        self.current = Some(idx);
+        text.set_selection(m.start, m.end);
+        center_range(text, m);
+        r
+    }
+}
+
+/// Scroll the range to the middle of the page,
+/// and make it horizontally visible.
+fn center_range(text: &mut TextAreaState, range: TextRange) -> bool {
+    let page = text.vscroll.page_len();
+    let v = text
+        .vscroll
+        .set_offset((range.start.y as usize).saturating_sub(page / 2));
+    let end = range.end.x.max(range.start.x + 1);
+    let h = text
+        .hscroll
+        .scroll_to_range(range.start.x as usize..end as usize);
+    v || h
+}
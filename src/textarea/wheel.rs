@@ -0,0 +1,86 @@
+//!
+//! Mouse wheel scrolling for the TextArea.
+//!
+//! The wheel scrolls vertically, Shift+wheel horizontally,
+//! anywhere in the widget area including the block and the
+//! scrollbars. Terminals that report a horizontal wheel get
+//! that too. The cursor is not moved.
+//!
+//! Everything else goes through ScrollAreaState like View and
+//! Clipper, so the scroll step and the scrollbars behave the same.
+//!
+//! ```rust ignore
+//! // instead of textarea::handle_events(&mut state.text, focus, event)
+//! let r = wheel::handle_events(&mut state.text, focus, event);
+//! ```
+//!
+use crate::event::TextOutcome;
+use crate::textarea::TextAreaState;
+use rat_event::{ct_event, flow, HandleEvent, MouseOnly};
+use rat_scrolled::event::ScrollOutcome;
+use rat_scrolled::ScrollAreaState;
+use ratatui::layout::Position;
+
+/// Scroll with the mouse wheel.
+///
+/// Returns TextOutcome::Changed if the offset changed,
+/// Continue outside the area.
+pub fn handle_wheel_events(
+    state: &mut TextAreaState,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    let area = state.area;
+    let over = |x: u16, y: u16| area.contains(Position::new(x, y));
+    let r = match event {
+        ct_event!(scroll SHIFT down for x,y) | ct_event!(scroll right for x,y) if over(*x, *y) => {
+            let delta = state.hscroll.scroll_by();
+            state.scroll_right(delta)
+        }
+        ct_event!(scroll SHIFT up for x,y) | ct_event!(scroll left for x,y) if over(*x, *y) => {
+            let delta = state.hscroll.scroll_by();
+            state.scroll_left(delta)
+        }
+        _ => {
+            let mut sas = ScrollAreaState::new()
+                .area(area)
+                .h_scroll(&mut state.hscroll)
+                .v_scroll(&mut state.vscroll);
+            match sas.handle(event, MouseOnly) {
+                ScrollOutcome::Up(v) => state.scroll_up(v),
+                ScrollOutcome::Down(v) => state.scroll_down(v),
+                ScrollOutcome::Left(v) => state.scroll_left(v),
+                ScrollOutcome::Right(v) => state.scroll_right(v),
+                ScrollOutcome::VPos(v) => state.set_vertical_offset(v),
+                ScrollOutcome::HPos(v) => state.set_horizontal_offset(v),
+                ScrollOutcome::Continue => return TextOutcome::Continue,
+                _ => false,
+            }
+        }
+    };
+    if r {
+        TextOutcome::Changed
+    } else {
+        TextOutcome::Unchanged
+    }
+}
+
+/// Handle all events with the wheel scrolling.
+/// Text events are only processed if focus is true.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut TextAreaState,
+    focus: bool,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    flow!(handle_wheel_events(state, event));
+    crate::textarea::handle_events(state, focus, event)
+}
+
+/// Handle only mouse-events with the wheel scrolling.
+pub fn handle_mouse_events(
+    state: &mut TextAreaState,
+    event: &crossterm::event::Event,
+) -> TextOutcome {
+    flow!(handle_wheel_events(state, event));
+    crate::textarea::handle_mouse_events(state, event)
+}
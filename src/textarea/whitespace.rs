@@ -0,0 +1,161 @@
+//!
+//! Visible whitespace for the TextArea.
+//!
+//! [ShowWhitespace] renders spaces as "·" and tabs as "→" with
+//! a separate style, and can mark trailing spaces/tabs at the end
+//! of a line with a warning style. Render it after the TextArea.
+//!
+//! ```rust ignore
+//! TextArea::new().render(area, buf, &mut state.text);
+//! ShowWhitespace::new()
+//!     .highlight_trailing(true)
+//!     .render(buf, &state.text);
+//! ```
+//!
+//! Selected whitespace keeps the selection style, only the
+//! symbol changes.
+//!
+use crate::text::upos_type;
+use crate::textarea::TextAreaState;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Style, Stylize};
+use std::cmp::min;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Renders the whitespace markers.
+#[derive(Debug, Clone)]
+pub struct ShowWhitespace {
+    show_whitespace: bool,
+    highlight_trailing: bool,
+    style: Option<Style>,
+    trailing_style: Option<Style>,
+    space: &'static str,
+    tab: &'static str,
+}
+
+impl Default for ShowWhitespace {
+    fn default() -> Self {
+        Self {
+            show_whitespace: true,
+            highlight_trailing: false,
+            style: None,
+            trailing_style: None,
+            space: "·",
+            tab: "→",
+        }
+    }
+}
+
+impl ShowWhitespace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show spaces and tabs. Default is true.
+    pub fn show_whitespace(mut self, show: bool) -> Self {
+        self.show_whitespace = show;
+        self
+    }
+
+    /// Mark trailing spaces and tabs. Default is false.
+    pub fn highlight_trailing(mut self, highlight: bool) -> Self {
+        self.highlight_trailing = highlight;
+        self
+    }
+
+    /// Style for the whitespace symbols.
+    ///
+    /// __Default__
+    /// The style of the cell, dimmed.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Style for trailing whitespace.
+    ///
+    /// __Default__
+    /// Red background.
+    pub fn trailing_style(mut self, style: Style) -> Self {
+        self.trailing_style = Some(style);
+        self
+    }
+
+    /// Symbols for space and tab.
+    ///
+    /// __Default__
+    /// "·" and "→"
+    pub fn symbols(mut self, space: &'static str, tab: &'static str) -> Self {
+        self.space = space;
+        self.tab = tab;
+        self
+    }
+
+    /// Render the markers over the visible part of the TextArea.
+    pub fn render(&self, buf: &mut Buffer, state: &TextAreaState) {
+        if !self.show_whitespace && !self.highlight_trailing {
+            return;
+        }
+        let inner = state.inner;
+        if inner.is_empty() {
+            return;
+        }
+
+        let trailing_style = self.trailing_style.unwrap_or_else(|| Style::new().on_red());
+        let selection = state.selection();
+
+        let (ox, oy) = state.offset();
+        let rows = oy as upos_type
+            ..min(
+                oy as upos_type + inner.height as upos_type,
+                state.len_lines(),
+            );
+        let trailing = rows
+            .clone()
+            .map(|row| trailing_start(state, row))
+            .collect::<Vec<_>>();
+
+        for g in state.glyphs(rows.clone(), ox as u16, inner.width) {
+            if g.screen_width() == 0 {
+                continue;
+            }
+            let symbol = match state.str_slice_byte(g.text_bytes()).as_ref() {
+                " " => self.space,
+                "\t" => self.tab,
+                _ => continue,
+            };
+
+            let pos = g.pos();
+            let is_trailing =
+                self.highlight_trailing && trailing[(pos.y - rows.start) as usize] <= pos.x;
+            if !self.show_whitespace && !is_trailing {
+                continue;
+            }
+
+            let (sx, sy) = g.screen_pos();
+            let selected = selection.contains_pos(pos);
+            for d in 0..g.screen_width() {
+                let Some(cell) = buf.cell_mut((inner.x + sx + d, inner.y + sy)) else {
+                    continue;
+                };
+                if self.show_whitespace && d == 0 {
+                    cell.set_symbol(symbol);
+                    if !selected {
+                        let style = self.style.unwrap_or_else(|| cell.style().dim());
+                        cell.set_style(style);
+                    }
+                }
+                if is_trailing && !selected {
+                    cell.set_style(trailing_style);
+                }
+            }
+        }
+    }
+}
+
+/// First column of the trailing spaces/tabs.
+fn trailing_start(state: &TextAreaState, row: upos_type) -> upos_type {
+    let line = state.line_at(row);
+    let line = line.trim_end_matches(['\n', '\r']);
+    line.trim_end_matches([' ', '\t']).graphemes(true).count() as upos_type
+}
@@ -2,11 +2,64 @@
 //! Small helpers.
 //!
 use ratatui::buffer::Buffer;
-use ratatui::layout::{Rect, Size};
+use ratatui::layout::{Position, Rect, Size};
 use ratatui::prelude::{BlockExt, Widget};
-use ratatui::style::{Style, Stylize};
+use ratatui::style::{Color, Modifier, Style, Stylize};
 use ratatui::widgets::{Block, Padding};
+use std::cell::Cell;
+use std::cmp::min;
 use std::{fmt, mem};
+use unicode_width::UnicodeWidthStr;
+
+/// Crate-wide render mode.
+///
+/// Affects the fallback styles and some widgets add
+/// extra markers to distinguish selection and focus.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RenderMode {
+    /// Render as styled.
+    #[default]
+    Normal,
+    /// Fallback styles use black/white pairs.
+    HighContrast,
+    /// Fallback styles use only attributes. Selection and focus
+    /// are additionally shown with ascii markers.
+    NoColor,
+}
+
+thread_local! {
+    static RENDER_MODE: Cell<RenderMode> = const { Cell::new(RenderMode::Normal) };
+}
+
+/// Set the render mode.
+///
+/// This is a thread-local setting, set it before rendering.
+pub fn set_render_mode(mode: RenderMode) {
+    RENDER_MODE.with(|v| v.set(mode));
+}
+
+/// Current render mode.
+pub fn render_mode() -> RenderMode {
+    RENDER_MODE.with(|v| v.get())
+}
+
+/// Is this a light color? Used to choose a black/white pair.
+fn is_light(color: Color) -> bool {
+    match color {
+        Color::White
+        | Color::Gray
+        | Color::LightRed
+        | Color::LightGreen
+        | Color::LightYellow
+        | Color::LightBlue
+        | Color::LightMagenta
+        | Color::LightCyan
+        | Color::Yellow => true,
+        Color::Rgb(r, g, b) => 299 * r as u32 + 587 * g as u32 + 114 * b as u32 > 128_000,
+        Color::Indexed(i) => matches!(i, 7 | 9..=15) || i >= 244,
+        _ => false,
+    }
+}
 
 /// Union the areas, but regard only non-empty ones.
 ///
@@ -28,7 +81,26 @@ pub fn union_non_empty(area1: Rect, area2: Rect) -> Rect {
 /// This is not the same as setting Style::reversed().
 /// The latter sends special controls to the terminal,
 /// the former just swaps.
+///
+/// With [RenderMode::HighContrast] this returns a black/white
+/// pair opposite to the background, with [RenderMode::NoColor]
+/// the colors are removed and the style is reversed and bold.
 pub fn revert_style(mut style: Style) -> Style {
+    match render_mode() {
+        RenderMode::Normal => {}
+        RenderMode::HighContrast => {
+            return if style.bg.map(is_light).unwrap_or(false) {
+                style.white().on_black()
+            } else {
+                style.black().on_white()
+            };
+        }
+        RenderMode::NoColor => {
+            style.fg = None;
+            style.bg = None;
+            return style.add_modifier(Modifier::REVERSED | Modifier::BOLD);
+        }
+    }
     if style.fg.is_some() || style.bg.is_some() {
         mem::swap(&mut style.fg, &mut style.bg);
         style
@@ -38,7 +110,15 @@ pub fn revert_style(mut style: Style) -> Style {
 }
 
 /// Fallback for select style.
-pub fn fallback_select_style(style: Style) -> Style {
+///
+/// With [RenderMode::NoColor] the colors are removed and
+/// the style is underlined and bold.
+pub fn fallback_select_style(mut style: Style) -> Style {
+    if render_mode() == RenderMode::NoColor {
+        style.fg = None;
+        style.bg = None;
+        return style.add_modifier(Modifier::UNDERLINED | Modifier::BOLD);
+    }
     if style.fg.is_some() || style.bg.is_some() {
         style
     } else {
@@ -46,6 +126,39 @@ pub fn fallback_select_style(style: Style) -> Style {
     }
 }
 
+/// Patch `over` on top of `base`.
+///
+/// Only the attributes set in `over` change, everything else
+/// comes from `base`.
+pub fn compose(base: Style, over: Style) -> Style {
+    base.patch(over)
+}
+
+/// Fallback chain for styles.
+///
+/// Patches the given styles in order on top of `base` and skips
+/// the ones that are not set. Later styles win for the attributes
+/// they set.
+///
+/// The widgets use the order
+///
+/// `base < block < state-specific < focus`
+///
+/// * base: the style of the widget.
+/// * block: the style of the block. Applied by rendering the block
+///   after the base style.
+/// * state-specific: select, hover, armed ...
+/// * focus: the focus style, if the widget has the focus.
+///
+/// Fallbacks derived from the base style, like [revert_style],
+/// stand in for a missing focus style.
+pub fn fallback(styles: &[Option<Style>], base: Style) -> Style {
+    styles
+        .iter()
+        .flatten()
+        .fold(base, |style, over| compose(style, *over))
+}
+
 /// Reset an area of the buffer.
 pub fn reset_buf_area(area: Rect, buf: &mut Buffer) {
     for y in area.top()..area.bottom() {
@@ -72,6 +185,127 @@ pub fn fill_buf_area(buf: &mut Buffer, area: Rect, symbol: &str, style: impl Int
     }
 }
 
+/// Copy a part of a source buffer to the target buffer.
+///
+/// This is what View and Clipper use to show the scrolled part
+/// of their temp buffer.
+///
+/// * src: The source buffer. Its area is in the same coordinates
+///   as the offset, it doesn't need to start at (0,0).
+/// * offset: Position in the coordinates of the source buffer that
+///   is shown at the top-left corner of the target area. The
+///   source buffer may start right/below of the offset, then the
+///   copy starts further right/below in the target area.
+/// * tgt_area: Target area in buf.
+/// * buf: Target buffer.
+///
+/// Only the part of src that fits into tgt_area is copied,
+/// nothing else is touched.
+///
+/// Wide glyphs cut in half by the left or right edge are replaced
+/// with spaces, so no half glyph is left in the target.
+pub fn copy_buffer(src: &Buffer, offset: Position, tgt_area: Rect, buf: &mut Buffer) {
+    let src_area = src.area;
+    let tgt_area = tgt_area.intersection(buf.area);
+
+    // extra offset due to buffer starts right of offset.
+    let off_x0 = src_area.x.saturating_sub(offset.x);
+    let off_y0 = src_area.y.saturating_sub(offset.y);
+    // cut source buffer due to start left of offset.
+    let cut_x0 = offset.x.saturating_sub(src_area.x);
+    let cut_y0 = offset.y.saturating_sub(src_area.y);
+
+    // length to copy
+    let len_src = src_area.width.saturating_sub(cut_x0);
+    let len_tgt = tgt_area.width.saturating_sub(off_x0);
+    let len = min(len_src, len_tgt) as usize;
+
+    // area height to copy
+    let height_src = src_area.height.saturating_sub(cut_y0);
+    let height_tgt = tgt_area.height.saturating_sub(off_y0);
+    let height = min(height_src, height_tgt);
+
+    if len == 0 {
+        return;
+    }
+
+    for y in 0..height {
+        let src_row = src.index_of(src_area.x, src_area.y + cut_y0 + y);
+        let src_0 = src_row + cut_x0 as usize;
+        let tgt_0 = buf.index_of(tgt_area.x + off_x0, tgt_area.y + off_y0 + y);
+
+        let src_cells = &src.content[src_0..src_0 + len];
+        let tgt_cells = &mut buf.content[tgt_0..tgt_0 + len];
+        tgt_cells.clone_from_slice(src_cells);
+
+        // left edge: a wide glyph starting left of the cut
+        // covers the first cells.
+        let mut x = src_row;
+        let mut covered = 0;
+        while x < src_0 {
+            let w = src.content[x].symbol().width().max(1);
+            covered = (x + w).saturating_sub(src_0);
+            x += w;
+        }
+        for cell in tgt_cells.iter_mut().take(covered) {
+            cell.set_symbol(" ");
+        }
+
+        // right edge: a wide glyph that doesn't fit completely.
+        let mut x = covered;
+        while x < len {
+            let w = tgt_cells[x].symbol().width().max(1);
+            if x + w > len {
+                for cell in tgt_cells[x..].iter_mut() {
+                    cell.set_symbol(" ");
+                }
+            }
+            x += w;
+        }
+    }
+}
+
+/// Text of an area of the buffer, one string per row.
+///
+/// Wide glyphs count once, glyphs cut at the left edge become
+/// spaces, glyphs cut at the right edge are dropped.
+/// Trailing whitespace is trimmed. Rows outside the buffer are empty.
+pub(crate) fn buffer_text(buf: &Buffer, area: Rect) -> Vec<String> {
+    let mut text = Vec::with_capacity(area.height as usize);
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        if y >= buf.area.top() && y < buf.area.bottom() {
+            let right = min(area.right(), buf.area.right());
+            let mut x = buf.area.x;
+            while x < right {
+                let Some(cell) = buf.cell((x, y)) else {
+                    break;
+                };
+                let w = cell.symbol().width().max(1) as u16;
+                if x >= area.x {
+                    if x + w <= area.right() {
+                        line.push_str(cell.symbol());
+                    }
+                } else if x + w > area.x {
+                    line.extend((area.x..x + w).map(|_| ' '));
+                }
+                x += w;
+            }
+        }
+        line.truncate(line.trim_end().len());
+        text.push(line);
+    }
+    text
+}
+
+/// Hash for [buffer_text].
+pub(crate) fn text_hash(text: &[String]) -> u64 {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    hasher.finish()
+}
+
 pub fn rect_dbg(area: Rect) -> String {
     use fmt::Write;
     let mut buf = String::new();
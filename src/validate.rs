@@ -0,0 +1,167 @@
+//!
+//! Validate all fields of a form.
+//!
+//! [validate_form] checks all fields, marks the invalid ones
+//! and returns the FocusFlag of the first invalid field.
+//! The input widgets render themselves with their invalid style,
+//! [render_invalid] can add a common error style on top.
+//!
+//! ```rust ignore
+//! // on submit
+//! let mut fields: [&mut dyn Validatable; 3] =
+//!     [&mut state.name, &mut state.amount, &mut state.date];
+//! if let Some(flag) = validate_form(&mut fields, &focus) {
+//!     focus.focus_flag(flag);
+//!     return Ok(Control::Changed);
+//! }
+//! ```
+//!
+use crate::date_input::DateInputState;
+use crate::focus::flag_by_name;
+use crate::number_input::NumberInputState;
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use rat_focus::{Focus, FocusFlag};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Style;
+
+/// A field that can be checked for a valid value.
+pub trait Validatable {
+    /// The current value is valid.
+    fn is_valid(&self) -> bool;
+
+    /// Render as invalid.
+    fn set_invalid(&mut self, invalid: bool);
+
+    /// Rendered as invalid.
+    fn is_invalid(&self) -> bool;
+
+    /// Name of the FocusFlag.
+    ///
+    /// The field is found by name in the Focus. This works
+    /// for widgets using a different version of rat-focus too.
+    fn focus_name(&self) -> &str;
+
+    /// Area of the last render.
+    fn area(&self) -> Rect;
+}
+
+/// Validate all fields and mark the invalid ones.
+///
+/// Returns the FocusFlag of the first invalid field, if it
+/// can be found in the Focus.
+pub fn validate_form(fields: &mut [&mut dyn Validatable], focus: &Focus) -> Option<FocusFlag> {
+    let mut first = None;
+    for field in fields.iter_mut() {
+        let valid = field.is_valid();
+        field.set_invalid(!valid);
+        if !valid && first.is_none() {
+            first = flag_by_name(focus, field.focus_name());
+        }
+    }
+    first
+}
+
+/// Render the error style over all invalid fields.
+///
+/// Call after rendering the fields.
+pub fn render_invalid(fields: &[&dyn Validatable], style: Style, buf: &mut Buffer) {
+    for field in fields {
+        if field.is_invalid() {
+            buf.set_style(field.area().intersection(buf.area), style);
+        }
+    }
+}
+
+/// Has no format of its own. Valid unless it is marked invalid.
+impl Validatable for TextInputState {
+    fn is_valid(&self) -> bool {
+        !self.invalid
+    }
+
+    fn set_invalid(&mut self, invalid: bool) {
+        self.invalid = invalid;
+    }
+
+    fn is_invalid(&self) -> bool {
+        self.invalid
+    }
+
+    fn focus_name(&self) -> &str {
+        self.focus.name()
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+/// The mask only restricts the input. Valid unless it is marked invalid.
+impl Validatable for MaskedInputState {
+    fn is_valid(&self) -> bool {
+        !self.invalid
+    }
+
+    fn set_invalid(&mut self, invalid: bool) {
+        self.invalid = invalid;
+    }
+
+    fn is_invalid(&self) -> bool {
+        self.invalid
+    }
+
+    fn focus_name(&self) -> &str {
+        self.focus.name()
+    }
+
+    fn area(&self) -> Rect {
+        self.area
+    }
+}
+
+/// Valid if empty or a number.
+impl Validatable for NumberInputState {
+    fn is_valid(&self) -> bool {
+        self.value_opt::<f64>().is_ok()
+    }
+
+    fn set_invalid(&mut self, invalid: bool) {
+        self.widget.invalid = invalid;
+    }
+
+    fn is_invalid(&self) -> bool {
+        self.widget.invalid
+    }
+
+    fn focus_name(&self) -> &str {
+        self.widget.focus.name()
+    }
+
+    fn area(&self) -> Rect {
+        self.widget.area
+    }
+}
+
+/// Valid if empty or a date.
+impl Validatable for DateInputState {
+    fn is_valid(&self) -> bool {
+        self.is_empty() || self.value().is_ok()
+    }
+
+    fn set_invalid(&mut self, invalid: bool) {
+        self.widget.invalid = invalid;
+    }
+
+    fn is_invalid(&self) -> bool {
+        self.widget.invalid
+    }
+
+    fn focus_name(&self) -> &str {
+        self.widget.focus.name()
+    }
+
+    fn area(&self) -> Rect {
+        self.widget.area
+    }
+}
@@ -0,0 +1,298 @@
+//!
+//! Form level undo/redo.
+//!
+//! [ValueHistory] records committed field changes as
+//! (flag, old value, new value). The values are kept as strings,
+//! the widgets convert with [ValueStr].
+//!
+//! Undo/redo don't touch the widgets, they return the flag and the
+//! value to restore, and the application sets it.
+//!
+//! ```rust ignore
+//! // after the focus handling
+//! state.history.track(&state.name.focus, &state.name);
+//! state.history.track(&state.date.focus, &state.date);
+//!
+//! match state.history.handle(event, Regular) {
+//!     HistoryOutcome::Restore(flag, value) => {
+//!         if flag == state.name.focus {
+//!             state.name.set_value_str(&value);
+//!         } else if flag == state.date.focus {
+//!             state.date.set_value_str(&value);
+//!         }
+//!         Outcome::Changed
+//!     }
+//!     r => r.into(),
+//! }
+//! ```
+//!
+use crate::checkbox::CheckboxState;
+use crate::choice::ChoiceState;
+use crate::date_input::DateInputState;
+use crate::number_input::NumberInputState;
+use crate::text_input::TextInputState;
+use crate::text_input_mask::MaskedInputState;
+use rat_event::{ct_event, ConsumedEvent, HandleEvent, Outcome, Regular};
+use rat_focus::FocusFlag;
+use std::str::FromStr;
+
+/// Access the value of a widget as string.
+pub trait ValueStr {
+    /// Current value.
+    fn value_str(&self) -> String;
+
+    /// Set the value. Returns false if the string is not
+    /// a valid value for this widget.
+    fn set_value_str(&mut self, value: &str) -> bool;
+}
+
+/// One committed change.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValueChange {
+    pub flag: FocusFlag,
+    pub old: String,
+    pub new: String,
+}
+
+/// Undo/redo stack of committed field changes.
+#[derive(Debug, Clone)]
+pub struct ValueHistory {
+    undo: Vec<ValueChange>,
+    redo: Vec<ValueChange>,
+    limit: usize,
+    // value when the field gained the focus.
+    pending: Vec<(FocusFlag, String)>,
+}
+
+/// Result of event handling.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HistoryOutcome {
+    /// The given event was not handled at all.
+    Continue,
+    /// The event was handled, nothing to undo/redo.
+    Unchanged,
+    /// Set the value of the widget with this flag.
+    Restore(FocusFlag, String),
+}
+
+impl ConsumedEvent for HistoryOutcome {
+    fn is_consumed(&self) -> bool {
+        *self != HistoryOutcome::Continue
+    }
+}
+
+impl From<HistoryOutcome> for Outcome {
+    fn from(value: HistoryOutcome) -> Self {
+        match value {
+            HistoryOutcome::Continue => Outcome::Continue,
+            HistoryOutcome::Unchanged => Outcome::Unchanged,
+            HistoryOutcome::Restore(_, _) => Outcome::Changed,
+        }
+    }
+}
+
+impl Default for ValueHistory {
+    fn default() -> Self {
+        Self {
+            undo: Default::default(),
+            redo: Default::default(),
+            limit: 100,
+            pending: Default::default(),
+        }
+    }
+}
+
+impl ValueHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum number of changes kept.
+    ///
+    /// __Default__
+    /// 100
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Record a committed change.
+    ///
+    /// Does nothing if old and new are equal. Clears the redo stack.
+    pub fn push(&mut self, flag: FocusFlag, old: impl Into<String>, new: impl Into<String>) {
+        let old = old.into();
+        let new = new.into();
+        if old == new {
+            return;
+        }
+        self.redo.clear();
+        self.undo.push(ValueChange { flag, old, new });
+        if self.undo.len() > self.limit {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Record changes with the focus.
+    ///
+    /// Remembers the value when the widget gains the focus and
+    /// records a change when it loses the focus.
+    /// Call it for every field after the focus handling.
+    pub fn track(&mut self, flag: &FocusFlag, state: &impl ValueStr) {
+        if flag.gained() {
+            self.pending.retain(|(f, _)| f != flag);
+            self.pending.push((flag.clone(), state.value_str()));
+        }
+        if flag.lost() {
+            if let Some(idx) = self.pending.iter().position(|(f, _)| f == flag) {
+                let (flag, old) = self.pending.remove(idx);
+                self.push(flag, old, state.value_str());
+            }
+        }
+    }
+
+    /// Undo the last change.
+    ///
+    /// Returns the flag and the old value.
+    pub fn undo(&mut self) -> Option<(FocusFlag, String)> {
+        let change = self.undo.pop()?;
+        let r = (change.flag.clone(), change.old.clone());
+        self.redo.push(change);
+        Some(r)
+    }
+
+    /// Redo the last undone change.
+    ///
+    /// Returns the flag and the new value.
+    pub fn redo(&mut self) -> Option<(FocusFlag, String)> {
+        let change = self.redo.pop()?;
+        let r = (change.flag.clone(), change.new.clone());
+        self.undo.push(change);
+        Some(r)
+    }
+
+    /// Anything to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Anything to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forget everything.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.pending.clear();
+    }
+}
+
+impl HandleEvent<crossterm::event::Event, Regular, HistoryOutcome> for ValueHistory {
+    fn handle(&mut self, event: &crossterm::event::Event, _qualifier: Regular) -> HistoryOutcome {
+        let r = match event {
+            ct_event!(key press CONTROL-'z') => self.undo(),
+            ct_event!(key press CONTROL_SHIFT-'Z') => self.redo(),
+            _ => return HistoryOutcome::Continue,
+        };
+        match r {
+            Some((flag, value)) => HistoryOutcome::Restore(flag, value),
+            None => HistoryOutcome::Unchanged,
+        }
+    }
+}
+
+/// Handle Ctrl+Z/Ctrl+Shift+Z.
+///
+/// Call it before the widgets, the text widgets use
+/// Ctrl+Z for their own undo.
+pub fn handle_events(
+    history: &mut ValueHistory,
+    event: &crossterm::event::Event,
+) -> HistoryOutcome {
+    history.handle(event, Regular)
+}
+
+impl ValueStr for TextInputState {
+    fn value_str(&self) -> String {
+        self.text().to_string()
+    }
+
+    fn set_value_str(&mut self, value: &str) -> bool {
+        self.set_text(value);
+        true
+    }
+}
+
+impl ValueStr for MaskedInputState {
+    fn value_str(&self) -> String {
+        self.text().to_string()
+    }
+
+    fn set_value_str(&mut self, value: &str) -> bool {
+        self.set_text(value);
+        true
+    }
+}
+
+impl ValueStr for DateInputState {
+    fn value_str(&self) -> String {
+        self.widget.value_str()
+    }
+
+    fn set_value_str(&mut self, value: &str) -> bool {
+        self.widget.set_value_str(value)
+    }
+}
+
+impl ValueStr for NumberInputState {
+    fn value_str(&self) -> String {
+        self.widget.value_str()
+    }
+
+    fn set_value_str(&mut self, value: &str) -> bool {
+        self.widget.set_value_str(value)
+    }
+}
+
+impl ValueStr for CheckboxState {
+    fn value_str(&self) -> String {
+        self.value().to_string()
+    }
+
+    fn set_value_str(&mut self, value: &str) -> bool {
+        match value.parse::<bool>() {
+            Ok(v) => {
+                self.set_value(v);
+                true
+            }
+            Err(_) => false,
+        }
+    }
+}
+
+/// The empty string is no selection.
+impl<T> ValueStr for ChoiceState<T>
+where
+    T: PartialEq + ToString + FromStr,
+{
+    fn value_str(&self) -> String {
+        self.value_opt_ref()
+            .map(|v| v.to_string())
+            .unwrap_or_default()
+    }
+
+    fn set_value_str(&mut self, value: &str) -> bool {
+        if value.is_empty() {
+            self.clear_selection();
+            return true;
+        }
+        match value.parse::<T>() {
+            Ok(v) => {
+                self.set_value(&v);
+                self.value_opt_ref() == Some(&v)
+            }
+            Err(_) => false,
+        }
+    }
+}
@@ -49,16 +49,27 @@
 
 mod view_style;
 
-use std::cmp::{max, min};
+use std::cmp::max;
 pub use view_style::*;
 
+use crate::empty::EmptyRender;
 use crate::event::ScrollOutcome;
+use crate::render_timing::RenderTimer;
+use crate::scroll_indicator::{
+    scroll_fraction, ScrollIndicator, ScrollIndicatorOverlay, ScrollIndicatorState,
+    ScrollIndicatorStyle,
+};
+use crate::scroll_step::ScrollStep;
+use crate::scroll_to::{ensure_area_visible, ScrollTo};
+use crate::util::{buffer_text, copy_buffer, text_hash};
 use rat_event::{HandleEvent, MouseOnly, Outcome, Regular};
 use rat_reloc::RelocatableState;
 use rat_scrolled::{Scroll, ScrollArea, ScrollAreaState, ScrollState};
 use ratatui::buffer::Buffer;
 use ratatui::layout::{Position, Rect, Size};
 use ratatui::prelude::{StatefulWidget, Widget};
+use ratatui::style::Style;
+use ratatui::text::Text;
 use ratatui::widgets::Block;
 
 /// Configure the view.
@@ -70,6 +81,11 @@ pub struct View<'a> {
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
     vscroll: Option<Scroll<'a>>,
+
+    indicator: Option<ScrollIndicator>,
+    indicator_style: Option<ScrollIndicatorStyle>,
+    scroll_step: Option<ScrollStep>,
+    empty: Option<EmptyRender<'a>>,
 }
 
 /// Render to the temp buffer.
@@ -93,6 +109,9 @@ pub struct ViewBuffer<'a> {
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
     vscroll: Option<Scroll<'a>>,
+    indicator: Option<ScrollIndicatorOverlay>,
+    empty: Option<EmptyRender<'a>>,
+    _timer: RenderTimer,
 }
 
 /// Clips and copies the temp buffer to the frame buffer.
@@ -105,6 +124,9 @@ pub struct ViewWidget<'a> {
     block: Option<Block<'a>>,
     hscroll: Option<Scroll<'a>>,
     vscroll: Option<Scroll<'a>>,
+    indicator: Option<ScrollIndicatorOverlay>,
+    empty: Option<EmptyRender<'a>>,
+    _timer: RenderTimer,
 }
 
 /// View state.
@@ -127,9 +149,14 @@ pub struct ViewState {
     /// Vertical scroll
     /// __read+write__
     pub vscroll: ScrollState,
+    /// Scroll position overlay for the vertical scroll.
+    /// __read only__
+    pub indicator: ScrollIndicatorState,
 
     /// For the buffer to survive render()
     buffer: Option<Buffer>,
+    /// Offset of the last render.
+    offset: Position,
 }
 
 impl<'a> View<'a> {
@@ -176,6 +203,37 @@ impl<'a> View<'a> {
         self
     }
 
+    /// How far the mouse wheel scrolls.
+    ///
+    /// __Default__
+    /// 1/10 of the page.
+    pub fn scroll_step(mut self, step: ScrollStep) -> Self {
+        self.scroll_step = Some(step);
+        self
+    }
+
+    /// Render something if the layout is empty.
+    ///
+    /// Called with the inner area after the block and the
+    /// scrollbars.
+    pub fn empty_render(mut self, render: impl Fn(Rect, &mut Buffer) + 'a) -> Self {
+        self.empty = Some(EmptyRender::new(render));
+        self
+    }
+
+    /// Text shown in the middle if the layout is empty.
+    pub fn empty_text(mut self, text: impl Into<Text<'a>>, style: Style) -> Self {
+        self.empty = Some(EmptyRender::text(text, style));
+        self
+    }
+
+    /// Show the vertical scroll position in the bottom-right
+    /// corner while scrolling.
+    pub fn indicator(mut self, indicator: ScrollIndicator) -> Self {
+        self.indicator = Some(indicator);
+        self
+    }
+
     /// Combined style.
     pub fn styles(mut self, styles: ViewStyle) -> Self {
         if styles.block.is_some() {
@@ -185,6 +243,9 @@ impl<'a> View<'a> {
             self.hscroll = self.hscroll.map(|v| v.styles(styles.clone()));
             self.vscroll = self.vscroll.map(|v| v.styles(styles.clone()));
         }
+        if styles.indicator.is_some() {
+            self.indicator_style = styles.indicator;
+        }
         self
     }
 
@@ -204,6 +265,7 @@ impl<'a> View<'a> {
 
     /// Calculates the layout and creates a temporary buffer.
     pub fn into_buffer(self, area: Rect, state: &mut ViewState) -> ViewBuffer<'a> {
+        let timer = RenderTimer::start("View", "");
         state.area = area;
         state.layout = self.layout;
 
@@ -234,6 +296,10 @@ impl<'a> View<'a> {
         state
             .vscroll
             .set_max_offset(max_y.saturating_sub(state.widget_area.height) as usize);
+        if let Some(step) = self.scroll_step {
+            step.apply(&mut state.hscroll);
+            step.apply(&mut state.vscroll);
+        }
 
         // offset is in layout coordinates.
         // internal buffer starts at (view.x,view.y)
@@ -257,6 +323,20 @@ impl<'a> View<'a> {
             block: self.block,
             hscroll: self.hscroll,
             vscroll: self.vscroll,
+            indicator: self.indicator.map(|v| {
+                let overlay = ScrollIndicatorOverlay::new(v);
+                if let Some(styles) = self.indicator_style {
+                    overlay.styles(styles)
+                } else {
+                    overlay
+                }
+            }),
+            empty: if state.layout.is_empty() {
+                self.empty
+            } else {
+                None
+            },
+            _timer: timer,
         }
     }
 }
@@ -356,8 +436,11 @@ impl<'a> ViewBuffer<'a> {
             block: self.block,
             hscroll: self.hscroll,
             vscroll: self.vscroll,
+            indicator: self.indicator,
+            empty: self.empty,
             offset: self.offset,
             buffer: self.buffer,
+            _timer: self._timer,
         }
     }
 }
@@ -380,53 +463,19 @@ impl StatefulWidget for ViewWidget<'_> {
                     .v_scroll(&mut state.vscroll),
             );
 
-        let src_area = self.buffer.area;
-        let tgt_area = state.widget_area;
-        let offset = self.offset;
-
-        // extra offset due to buffer starts right of offset.
-        let off_x0 = src_area.x.saturating_sub(offset.x);
-        let off_y0 = src_area.y.saturating_sub(offset.y);
-        // cut source buffer due to start left of offset.
-        let cut_x0 = offset.x.saturating_sub(src_area.x);
-        let cut_y0 = offset.y.saturating_sub(src_area.y);
-
-        // length to copy
-        let len_src = src_area.width.saturating_sub(cut_x0);
-        let len_tgt = tgt_area.width.saturating_sub(off_x0);
-        let len = min(len_src, len_tgt);
-
-        // area height to copy
-        let height_src = src_area.height.saturating_sub(cut_y0);
-        let height_tgt = tgt_area.height.saturating_sub(off_y0);
-        let height = min(height_src, height_tgt);
-
-        // ** slow version **
-        // for y in 0..height {
-        //     for x in 0..len {
-        //         let src_pos = Position::new(src_area.x + cut_x0 + x, src_area.y + cut_y0 + y);
-        //         let src_cell = self.buffer.cell(src_pos).expect("src-cell");
-        //
-        //         let tgt_pos = Position::new(tgt_area.x + off_x0 + x, tgt_area.y + off_y0 + y);
-        //         let tgt_cell = buf.cell_mut(tgt_pos).expect("tgt_cell");
-        //
-        //         *tgt_cell = src_cell.clone();
-        //     }
-        // }
-
-        for y in 0..height {
-            let src_0 = self
-                .buffer
-                .index_of(src_area.x + cut_x0, src_area.y + cut_y0 + y);
-            let tgt_0 = buf.index_of(tgt_area.x + off_x0, tgt_area.y + off_y0 + y);
-
-            let src = &self.buffer.content[src_0..src_0 + len as usize];
-            let tgt = &mut buf.content[tgt_0..tgt_0 + len as usize];
-            tgt.clone_from_slice(src);
+        if let Some(empty) = &self.empty {
+            empty.render(state.widget_area, buf);
+        }
+
+        copy_buffer(&self.buffer, self.offset, state.widget_area, buf);
+
+        if let Some(indicator) = &self.indicator {
+            indicator.render(state.widget_area, buf, &state.vscroll, &mut state.indicator);
         }
 
         // keep buffer
         state.buffer = Some(self.buffer);
+        state.offset = self.offset;
     }
 }
 
@@ -440,6 +489,40 @@ impl ViewState {
         self.hscroll.scroll_to_pos(area.x as usize);
         self.vscroll.scroll_to_pos(area.y as usize);
     }
+
+    /// Plain text of the visible region of the last render,
+    /// one string per row.
+    ///
+    /// Trailing whitespace is trimmed. Styles and anything
+    /// outside the view buffer (block, scrollbars) are not included.
+    pub fn visible_text(&self) -> Vec<String> {
+        let Some(buffer) = &self.buffer else {
+            return Vec::default();
+        };
+        let visible = Rect::new(
+            self.offset.x,
+            self.offset.y,
+            self.widget_area.width,
+            self.widget_area.height,
+        );
+        buffer_text(buffer, visible)
+    }
+
+    /// Hash of the [visible_text](ViewState::visible_text).
+    ///
+    /// Can be used to detect that nothing visible changed.
+    pub fn visible_text_hash(&self) -> u64 {
+        text_hash(&self.visible_text())
+    }
+}
+
+/// Coordinates of the view buffer.
+impl ScrollTo for ViewState {
+    type Range = Rect;
+
+    fn ensure_visible(&mut self, range: Rect) -> bool {
+        ensure_area_visible(&mut self.hscroll, &mut self.vscroll, range)
+    }
 }
 
 impl ViewState {
@@ -457,6 +540,11 @@ impl ViewState {
         self.vscroll.page_len()
     }
 
+    /// Vertical scroll position as a fraction 0.0..=1.0.
+    pub fn vertical_fraction(&self) -> f64 {
+        scroll_fraction(&self.vscroll)
+    }
+
     pub fn horizontal_offset(&self) -> usize {
         self.hscroll.offset()
     }
@@ -471,6 +559,11 @@ impl ViewState {
         self.hscroll.page_len()
     }
 
+    /// Horizontal scroll position as a fraction 0.0..=1.0.
+    pub fn horizontal_fraction(&self) -> f64 {
+        scroll_fraction(&self.hscroll)
+    }
+
     pub fn horizontal_scroll_to(&mut self, pos: usize) -> bool {
         self.hscroll.scroll_to_pos(pos)
     }
@@ -519,3 +612,20 @@ impl HandleEvent<crossterm::event::Event, MouseOnly, Outcome> for ViewState {
         }
     }
 }
+
+/// Handle all events.
+/// The view itself doesn't take the focus, focus is only
+/// here for consistency with the other widgets.
+/// Mouse events are processed if they are in range.
+pub fn handle_events(
+    state: &mut ViewState,
+    _focus: bool,
+    event: &crossterm::event::Event,
+) -> Outcome {
+    HandleEvent::handle(state, event, Regular)
+}
+
+/// Handle only mouse-events.
+pub fn handle_mouse_events(state: &mut ViewState, event: &crossterm::event::Event) -> Outcome {
+    HandleEvent::handle(state, event, MouseOnly)
+}
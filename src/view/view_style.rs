@@ -1,4 +1,5 @@
 use crate::_private::NonExhaustive;
+use crate::scroll_indicator::ScrollIndicatorStyle;
 use rat_scrolled::ScrollStyle;
 use ratatui::widgets::Block;
 
@@ -7,6 +8,8 @@ use ratatui::widgets::Block;
 pub struct ViewStyle {
     pub block: Option<Block<'static>>,
     pub scroll: Option<ScrollStyle>,
+    /// Scroll position overlay.
+    pub indicator: Option<ScrollIndicatorStyle>,
 
     pub non_exhaustive: NonExhaustive,
 }
@@ -16,6 +19,7 @@ impl Default for ViewStyle {
         Self {
             block: None,
             scroll: None,
+            indicator: None,
             non_exhaustive: NonExhaustive,
         }
     }
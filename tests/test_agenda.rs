@@ -0,0 +1,140 @@
+use chrono::{Datelike, NaiveDate};
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::calendar::{Agenda, AgendaState, Month, MonthState, SelectedDate};
+use rat_widget::event::{CalOutcome, HandleEvent, Regular};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("date")
+}
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+}
+
+#[test]
+fn test_rows() {
+    let mut state = AgendaState::new();
+    state.top = date(2024, 5, 30);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 4));
+    Agenda::new()
+        .format("%d.%m.")
+        .day(|d| Line::from(format!("day {}", d.day())))
+        .render(buf.area, &mut buf, &mut state);
+
+    assert_eq!(
+        state.dates,
+        vec![
+            date(2024, 5, 30),
+            date(2024, 5, 31),
+            date(2024, 6, 1),
+            date(2024, 6, 2)
+        ]
+    );
+    assert_eq!(row(&buf, 2).trim_end(), "01.06. day 1");
+}
+
+#[test]
+fn test_entries() {
+    let mut state = AgendaState::new();
+    state.top = date(2024, 5, 1);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 3));
+    Agenda::new()
+        .entries(|d| d.weekday().number_from_monday() == 1)
+        .render(buf.area, &mut buf, &mut state);
+
+    assert_eq!(
+        state.dates,
+        vec![date(2024, 5, 6), date(2024, 5, 13), date(2024, 5, 20)]
+    );
+
+    state.focus.set(true);
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        CalOutcome::Day(date(2024, 5, 6))
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Down), Regular),
+        CalOutcome::Day(date(2024, 5, 13))
+    );
+}
+
+#[test]
+fn test_scroll_selected() {
+    let mut state = AgendaState::new();
+    state.today = date(2024, 5, 15);
+    state.top = date(2024, 5, 1);
+    state.focus.set(true);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 5));
+    Agenda::new().render(buf.area, &mut buf, &mut state);
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Home), Regular),
+        CalOutcome::Day(date(2024, 5, 15))
+    );
+    assert_eq!(state.top, date(2024, 5, 15));
+
+    assert_eq!(
+        state.handle(&key(KeyCode::PageDown), Regular),
+        CalOutcome::Day(date(2024, 5, 19))
+    );
+    for _ in 0..3 {
+        state.handle(&key(KeyCode::Down), Regular);
+    }
+    assert_eq!(state.selected(), Some(date(2024, 5, 22)));
+    assert_eq!(state.top, date(2024, 5, 18));
+
+    for _ in 0..30 {
+        state.handle(&key(KeyCode::Up), Regular);
+    }
+    assert_eq!(state.selected(), Some(date(2024, 4, 22)));
+    assert_eq!(state.top, date(2024, 4, 22));
+}
+
+#[test]
+fn test_shared_selection() {
+    let shared = SelectedDate::new();
+
+    let mut month = MonthState::new();
+    month.shared_date = shared.clone();
+    let mut agenda = AgendaState::new();
+    agenda.selection = shared.clone();
+    agenda.top = date(2024, 1, 1);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 8));
+    let area = buf.area;
+    Month::new()
+        .date(date(2024, 5, 1))
+        .day_selection()
+        .render(area, &mut buf, &mut month);
+    month.select_day(Some(9));
+    assert_eq!(shared.get(), Some(date(2024, 5, 10)));
+
+    // agenda scrolls to the selection.
+    Agenda::new().render(area, &mut buf, &mut agenda);
+    assert_eq!(agenda.dates.last(), Some(&date(2024, 5, 10)));
+
+    agenda.focus.set(true);
+    agenda.handle(&key(KeyCode::Down), Regular);
+    assert_eq!(shared.get(), Some(date(2024, 5, 11)));
+
+    Month::new()
+        .date(date(2024, 5, 1))
+        .day_selection()
+        .render(area, &mut buf, &mut month);
+    assert_eq!(month.selected_day_as_date(), Some(date(2024, 5, 11)));
+}
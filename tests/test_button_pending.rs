@@ -0,0 +1,58 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_widget::button::{Button, ButtonOutcome, ButtonState};
+use rat_widget::event::{HandleEvent, Regular};
+use rat_widget::focus::HasFocus;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn test_pending() {
+    let mut state = ButtonState::new();
+    state.focus.set(true);
+
+    assert!(state.set_pending(true));
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        ButtonOutcome::Unchanged
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc), Regular),
+        ButtonOutcome::Continue
+    );
+    assert!(state.is_focused());
+
+    assert!(state.tick());
+    assert_eq!(state.pending_frame, 1);
+
+    assert!(state.set_pending(false));
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter), Regular),
+        ButtonOutcome::Pressed
+    );
+}
+
+#[test]
+fn test_pending_render() {
+    let area = Rect::new(0, 0, 3, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = ButtonState::new();
+    state.set_pending(true);
+
+    let pending = Style::new().fg(Color::Red);
+    Button::new("ok")
+        .spinner(&["*"])
+        .pending_style(pending)
+        .render(area, &mut buf, &mut state);
+
+    // the spinner doesn't overwrite the label.
+    assert_eq!(buf.cell((0, 0)).expect("cell").symbol(), "*");
+    assert_eq!(buf.cell((0, 0)).expect("cell").fg, Color::Red);
+    assert_eq!(buf.cell((1, 0)).expect("cell").symbol(), "o");
+    assert_eq!(buf.cell((2, 0)).expect("cell").symbol(), "k");
+}
@@ -0,0 +1,266 @@
+use chrono::NaiveDate;
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_widget::calendar::{Month, MonthState, SelectionMove};
+use rat_widget::event::{CalOutcome, HandleEvent, MouseOnly, Regular};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("date")
+}
+
+fn pos(area: Rect) -> (u16, u16) {
+    (area.x, area.y)
+}
+
+#[test]
+fn test_hover() {
+    let mut state = MonthState::new();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 8));
+    Month::new().date(date(2024, 5, 1)).day_selection().render(
+        Rect::new(0, 0, 30, 8),
+        &mut buf,
+        &mut state,
+    );
+
+    let (x, y) = pos(state.area_days[9]);
+    let r = state.handle(&mouse(MouseEventKind::Moved, x, y), MouseOnly);
+    assert_eq!(r, CalOutcome::Changed);
+    assert_eq!(state.hover_date, Some(date(2024, 5, 10)));
+
+    let r = state.handle(&mouse(MouseEventKind::Moved, x + 1, y), MouseOnly);
+    assert_eq!(r, CalOutcome::Continue);
+
+    let r = state.handle(&mouse(MouseEventKind::Moved, 29, 7), MouseOnly);
+    assert_eq!(r, CalOutcome::Changed);
+    assert_eq!(state.hover_date, None);
+}
+
+#[test]
+fn test_drag_range() {
+    let mut state = MonthState::new();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 8));
+    Month::new()
+        .date(date(2024, 5, 1))
+        .range_selection()
+        .render(Rect::new(0, 0, 30, 8), &mut buf, &mut state);
+
+    let (x, y) = pos(state.area_days[4]);
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Changed);
+
+    let (x, y) = pos(state.area_days[1]);
+    let r = state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Changed);
+    assert_eq!(
+        state.preview_range(),
+        Some((date(2024, 5, 2), date(2024, 5, 5)))
+    );
+    assert_eq!(state.selected_range(), None);
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Up(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Range(date(2024, 5, 2), date(2024, 5, 5)));
+    assert_eq!(state.preview_range(), None);
+    assert_eq!(
+        state.selected_range(),
+        Some((date(2024, 5, 2), date(2024, 5, 5)))
+    );
+}
+
+#[test]
+fn test_drag_range_months() {
+    let mut months = [MonthState::new(), MonthState::new()];
+    let mut buf = Buffer::empty(Rect::new(0, 0, 60, 8));
+    Month::new()
+        .date(date(2024, 5, 1))
+        .range_selection()
+        .render(Rect::new(0, 0, 30, 8), &mut buf, &mut months[0]);
+    Month::new()
+        .date(date(2024, 6, 1))
+        .range_selection()
+        .render(Rect::new(30, 0, 30, 8), &mut buf, &mut months[1]);
+
+    let (x, y) = pos(months[0].area_days[29]);
+    let r = months.as_mut_slice().handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        Regular,
+    );
+    assert_eq!(r, CalOutcome::Changed);
+
+    let (x, y) = pos(months[1].area_days[2]);
+    let r = months.as_mut_slice().handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), x, y),
+        Regular,
+    );
+    assert_eq!(r, CalOutcome::Changed);
+    assert_eq!(
+        months[0].preview_range(),
+        Some((date(2024, 5, 30), date(2024, 6, 3)))
+    );
+    assert_eq!(months[0].preview_range(), months[1].preview_range());
+
+    let r = months
+        .as_mut_slice()
+        .handle(&mouse(MouseEventKind::Up(MouseButton::Left), x, y), Regular);
+    assert_eq!(r, CalOutcome::Range(date(2024, 5, 30), date(2024, 6, 3)));
+    assert_eq!(
+        months[1].selected_range(),
+        Some((date(2024, 5, 30), date(2024, 6, 3)))
+    );
+}
+
+#[test]
+fn test_move_selection() {
+    let mut state = MonthState::new();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 8));
+    Month::new()
+        .date(date(2024, 5, 1))
+        .day_selection()
+        .week_selection()
+        .render(Rect::new(0, 0, 30, 8), &mut buf, &mut state);
+
+    assert_eq!(state.selected_date(), None);
+    assert!(state.select_date(Some(date(2024, 5, 10))));
+    assert_eq!(state.selected_date(), Some(date(2024, 5, 10)));
+
+    let r = state.move_selection(SelectionMove::NextDay(7));
+    assert_eq!(r, CalOutcome::Day(date(2024, 5, 17)));
+    assert_eq!(state.selected_date(), Some(date(2024, 5, 17)));
+
+    let r = state.move_selection(SelectionMove::PrevWeek(1));
+    assert_eq!(r, CalOutcome::Week(date(2024, 5, 6)));
+    assert_eq!(state.selected_date(), Some(date(2024, 5, 6)));
+    assert_eq!(state.selected_day(), None);
+
+    state.select_date(Some(date(2024, 5, 30)));
+    let r = state.move_selection(SelectionMove::NextDay(7));
+    assert_eq!(r, CalOutcome::Continue);
+    assert_eq!(state.selected_date(), Some(date(2024, 5, 30)));
+}
+
+#[test]
+fn test_week_drag() {
+    let mut state = MonthState::new();
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 8));
+    Month::new().date(date(2024, 5, 1)).week_selection().render(
+        Rect::new(0, 0, 30, 8),
+        &mut buf,
+        &mut state,
+    );
+
+    // 2024-05 starts on a Wednesday, week 1 is Mon 6th.
+    let (x, y) = pos(state.area_weeks[1]);
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Week(date(2024, 5, 6)));
+    assert_eq!(state.selected_week, Some(1));
+
+    let (x, y) = pos(state.area_weeks[3]);
+    let r = state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Changed);
+    assert_eq!(state.selected_week, None);
+    assert_eq!(
+        state.preview_range(),
+        Some((date(2024, 5, 6), date(2024, 5, 26)))
+    );
+
+    let r = state.handle(
+        &mouse(MouseEventKind::Up(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Range(date(2024, 5, 6), date(2024, 5, 26)));
+    assert_eq!(
+        state.selected_range(),
+        Some((date(2024, 5, 6), date(2024, 5, 26)))
+    );
+
+    // click without drag keeps the week.
+    let (x, y) = pos(state.area_weeks[2]);
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    let r = state.handle(
+        &mouse(MouseEventKind::Up(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Unchanged);
+    assert_eq!(state.selected_week, Some(2));
+    assert_eq!(state.selected_range(), None);
+}
+
+#[test]
+fn test_adjacent_days() {
+    let mut state = MonthState::new();
+    let area = Rect::new(0, 0, 30, 8);
+    let mut buf = Buffer::empty(area);
+    // 2024-05 starts on a Wednesday and ends on a Friday.
+    Month::new()
+        .date(date(2024, 5, 1))
+        .day_selection()
+        .show_adjacent_days(true)
+        .adjacent_selection()
+        .render(area, &mut buf, &mut state);
+
+    let dates = state
+        .area_adjacent
+        .iter()
+        .map(|(d, _)| *d)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        dates,
+        vec![
+            date(2024, 4, 29),
+            date(2024, 4, 30),
+            date(2024, 6, 1),
+            date(2024, 6, 2)
+        ]
+    );
+    let (x, y) = pos(state.area_adjacent[0].1);
+    assert_eq!(buf.cell((x + 1, y)).expect("cell").symbol(), "9");
+
+    let (x, y) = pos(state.area_adjacent[3].1);
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Adjacent(date(2024, 6, 2)));
+    assert_eq!(state.selected_day, None);
+
+    // shown but not selectable.
+    Month::new()
+        .date(date(2024, 5, 1))
+        .day_selection()
+        .show_adjacent_days(true)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.area_adjacent.len(), 4);
+    let r = state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), x, y),
+        MouseOnly,
+    );
+    assert_eq!(r, CalOutcome::Continue);
+}
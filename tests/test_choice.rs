@@ -0,0 +1,323 @@
+use rat_widget::choice::{Choice, ChoiceState};
+use rat_widget::popup::{Placement, PlacementStrategy};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, StatefulWidget};
+
+fn render(state: &mut ChoiceState, area: Rect, buf: &mut Buffer) {
+    let (widget, popup) = Choice::new()
+        .auto_items((0..10).map(|v| format!("item {}", v)))
+        .popup_len(3)
+        .popup_block(Block::bordered())
+        .into_widgets();
+    widget.render(area, buf, state);
+    popup.render(area, buf, state);
+}
+
+#[test]
+fn test_open_scrolls_to_last() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let area = Rect::new(0, 0, 15, 1);
+    let mut state = ChoiceState::default();
+
+    render(&mut state, area, &mut buf);
+    state.select(Some(9));
+    state.set_popup_active(true);
+    render(&mut state, area, &mut buf);
+
+    assert_eq!(state.page_len(), 3);
+    assert_eq!(state.offset(), 7);
+    assert_eq!(state.item_areas.len(), 3);
+}
+
+#[test]
+fn test_open_centers_selection() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let area = Rect::new(0, 0, 15, 1);
+    let mut state = ChoiceState::default();
+
+    render(&mut state, area, &mut buf);
+    state.select(Some(5));
+    state.set_popup_active(true);
+    render(&mut state, area, &mut buf);
+
+    assert_eq!(state.offset(), 4);
+}
+
+#[test]
+fn test_open_short_popup() {
+    // only 2 rows below, 3 rows above.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 6));
+    let area = Rect::new(0, 3, 15, 1);
+    let mut state = ChoiceState::default();
+
+    render(&mut state, area, &mut buf);
+    state.select(Some(9));
+    state.set_popup_active(true);
+    render(&mut state, area, &mut buf);
+
+    assert!(state.page_len() >= 1);
+    let offset = state.offset();
+    assert!(offset <= 9 && 9 < offset + state.page_len());
+}
+
+#[test]
+fn test_scroll_away_from_selection() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let area = Rect::new(0, 0, 15, 1);
+    let mut state = ChoiceState::default();
+
+    render(&mut state, area, &mut buf);
+    state.select(Some(9));
+    state.set_popup_active(true);
+    render(&mut state, area, &mut buf);
+    assert_eq!(state.offset(), 7);
+
+    // e.g. dragging the scrollbar.
+    state.set_offset(0);
+    render(&mut state, area, &mut buf);
+    assert_eq!(state.offset(), 0);
+    assert_eq!(state.selected(), Some(9));
+}
+
+#[test]
+fn test_display_placeholder() {
+    let area = Rect::new(0, 0, 15, 1);
+    let mut state = ChoiceState::<u32>::default();
+
+    let widget = || {
+        Choice::new()
+            .item(1, "Germany (DE)")
+            .item(2, "Austria (AT)")
+            .display(|_, item| Line::from(item.to_string()[9..11].to_string()))
+            .placeholder("--")
+            .into_widgets()
+            .0
+    };
+
+    let mut buf = Buffer::empty(area);
+    widget().render(area, &mut buf, &mut state);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("-"));
+    assert_eq!(buf.cell((2, 0)).map(|v| v.symbol()), Some(" "));
+
+    state.select(Some(0));
+    let mut buf = Buffer::empty(area);
+    widget().render(area, &mut buf, &mut state);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("D"));
+    assert_eq!(buf.cell((1, 0)).map(|v| v.symbol()), Some("E"));
+    assert_eq!(buf.cell((2, 0)).map(|v| v.symbol()), Some(" "));
+}
+
+#[test]
+fn test_closed_span_styles() {
+    let area = Rect::new(0, 0, 15, 1);
+    let mut state = ChoiceState::<u32>::default();
+
+    let widget = || {
+        Choice::new()
+            .item(
+                1,
+                Line::from(vec![
+                    Span::styled("*", Style::new().fg(Color::Green)),
+                    Span::styled(" ok", Style::new().fg(Color::Yellow)),
+                ]),
+            )
+            .style(Style::new().fg(Color::White).bg(Color::Blue))
+            .focus_style(Style::new().fg(Color::Black).bg(Color::Cyan))
+            .into_widgets()
+            .0
+    };
+
+    let mut buf = Buffer::empty(area);
+    widget().render(area, &mut buf, &mut state);
+    state.select(Some(0));
+    widget().render(area, &mut buf, &mut state);
+    let cell = buf.cell((0, 0)).expect("cell");
+    assert_eq!(cell.fg, Color::Green);
+    assert_eq!(cell.bg, Color::Blue);
+    let cell = buf.cell((1, 0)).expect("cell");
+    assert_eq!(cell.fg, Color::Yellow);
+    assert_eq!(cell.bg, Color::Blue);
+
+    state.focus.set(true);
+    let mut buf = Buffer::empty(area);
+    widget().render(area, &mut buf, &mut state);
+    let cell = buf.cell((0, 0)).expect("cell");
+    assert_eq!(cell.symbol(), "*");
+    assert_eq!(cell.fg, Color::Green);
+    assert_eq!(cell.bg, Color::Cyan);
+    let cell = buf.cell((2, 0)).expect("cell");
+    assert_eq!(cell.fg, Color::Yellow);
+    assert_eq!(cell.bg, Color::Cyan);
+    // rest of the item area
+    let cell = buf.cell((5, 0)).expect("cell");
+    assert_eq!(cell.fg, Color::Black);
+    assert_eq!(cell.bg, Color::Cyan);
+}
+
+#[test]
+fn test_flip_shrink() {
+    // 8 rows above, 3 rows below.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 12));
+    let area = Rect::new(0, 8, 15, 1);
+    let mut state = ChoiceState::default();
+
+    let render = |state: &mut ChoiceState, buf: &mut Buffer| {
+        let (widget, popup) = Choice::new()
+            .auto_items((0..10).map(|v| format!("item {}", v)))
+            .popup_len(10)
+            .popup_block(Block::bordered())
+            .popup_placement(Placement::BelowOrAbove)
+            .popup_strategy(PlacementStrategy::FlipShrink { min_len: 3 })
+            .into_widgets();
+        widget.render(area, buf, state);
+        popup.render(area, buf, state);
+    };
+
+    render(&mut state, &mut buf);
+    state.set_popup_active(true);
+    render(&mut state, &mut buf);
+
+    // flipped above and shrunk to 8 rows incl. border.
+    assert_eq!(state.popup.area, Rect::new(0, 0, 15, 8));
+    assert_eq!(state.page_len(), 6);
+}
+
+#[test]
+fn test_popup_anchor() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 20));
+    let area = Rect::new(2, 2, 20, 3);
+    let mut state = ChoiceState::default();
+
+    let render = |state: &mut ChoiceState, buf: &mut Buffer| {
+        let (widget, popup) = Choice::new()
+            .auto_items((0..10).map(|v| format!("item {}", v)))
+            .popup_len(3)
+            .popup_placement(Placement::Below)
+            .popup_anchor(Rect::new(1, 1, 10, 1))
+            .into_widgets();
+        widget.render(area, buf, state);
+        popup.render(area, buf, state);
+    };
+
+    render(&mut state, &mut buf);
+    state.set_popup_active(true);
+    render(&mut state, &mut buf);
+
+    assert_eq!(state.popup.area, Rect::new(3, 4, 10, 3));
+}
+
+#[test]
+fn test_item_nav() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let area = Rect::new(0, 0, 15, 1);
+
+    let choice = || {
+        Choice::new()
+            .item("a", "The Abyss")
+            .item("b", "The Birds")
+            .item("c", "The Conversation")
+    };
+
+    // default: first char.
+    let mut state = ChoiceState::default();
+    let (widget, _) = choice().into_widgets();
+    widget.render(area, &mut buf, &mut state);
+    assert!(state.select_by_char('T'));
+    assert_eq!(state.selected(), Some(1));
+    assert!(!state.select_by_char('b'));
+
+    // override
+    let mut state = ChoiceState::default();
+    let (widget, _) = choice()
+        .item_nav("b", 'b')
+        .item_nav("x", 'x')
+        .into_widgets();
+    widget.render(area, &mut buf, &mut state);
+    assert_eq!(state.nav_char[1], vec!['b']);
+    assert!(state.select_by_char('B'));
+    assert_eq!(state.selected(), Some(1));
+
+    // any char
+    let mut state = ChoiceState::default();
+    let (widget, _) = choice().nav_any_char(true).into_widgets();
+    widget.render(area, &mut buf, &mut state);
+    assert!(state.select_by_char('c'));
+    assert_eq!(state.selected(), Some(2));
+    assert!(state.select_by_char('y'));
+    assert_eq!(state.selected(), Some(0));
+}
+
+#[test]
+fn test_popup_clamped_to_boundary() {
+    // boundary with 2 rows above and 7 rows below the widget,
+    // shorter than the item count.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 30));
+    let boundary = Rect::new(0, 10, 20, 10);
+
+    let render = |area: Rect, state: &mut ChoiceState, buf: &mut Buffer| {
+        let (widget, popup) = Choice::new()
+            .auto_items((0..20).map(|v| format!("item {}", v)))
+            .popup_len(20)
+            .popup_block(Block::bordered())
+            .popup_boundary(boundary)
+            .into_widgets();
+        widget.render(area, buf, state);
+        popup.render(area, buf, state);
+    };
+
+    let area = Rect::new(0, 12, 15, 1);
+    let mut state = ChoiceState::default();
+    render(area, &mut state, &mut buf);
+    state.select(Some(19));
+    state.set_popup_active(true);
+    render(area, &mut state, &mut buf);
+
+    assert_eq!(state.popup.area, Rect::new(0, 13, 15, 7));
+    assert_eq!(state.page_len(), 5);
+    assert_eq!(state.offset(), 15);
+
+    // 6 rows above, 3 rows below.
+    let area = Rect::new(0, 16, 15, 1);
+    let mut state = ChoiceState::default();
+    render(area, &mut state, &mut buf);
+    state.select(Some(0));
+    state.set_popup_active(true);
+    render(area, &mut state, &mut buf);
+
+    assert_eq!(state.popup.area, Rect::new(0, 10, 15, 6));
+    assert_eq!(state.page_len(), 4);
+    assert_eq!(state.offset(), 0);
+}
+
+#[test]
+fn test_popup_no_space() {
+    // fixed placement below, widget at the bottom of the boundary.
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 30));
+    let boundary = Rect::new(0, 10, 20, 10);
+
+    let render = |area: Rect, state: &mut ChoiceState, buf: &mut Buffer| {
+        let (widget, popup) = Choice::new()
+            .auto_items((0..20).map(|v| format!("item {}", v)))
+            .popup_len(20)
+            .popup_block(Block::bordered())
+            .popup_placement(Placement::Below)
+            .popup_boundary(boundary)
+            .into_widgets();
+        widget.render(area, buf, state);
+        popup.render(area, buf, state);
+    };
+
+    let area = Rect::new(0, 19, 15, 1);
+    let mut state = ChoiceState::default();
+    render(area, &mut state, &mut buf);
+    state.select(Some(0));
+    state.set_popup_active(true);
+    render(area, &mut state, &mut buf);
+
+    assert_eq!(state.popup.area.height, 3);
+    assert_eq!(state.page_len(), 1);
+    assert_eq!(state.item_areas.len(), 1);
+}
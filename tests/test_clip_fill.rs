@@ -0,0 +1,42 @@
+use rat_widget::text_input::clip_fill::ClipFill;
+use rat_widget::text_input::{TextInput, TextInputState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn line(buf: &Buffer) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, 0)).expect("cell").symbol())
+        .collect()
+}
+
+fn render(state: &mut TextInputState) -> Buffer {
+    let area = Rect::new(0, 0, 6, 1);
+    let mut buf = Buffer::empty(area);
+    TextInput::new().render(area, &mut buf, state);
+    ClipFill::new().render_text_input(&mut buf, state);
+    buf
+}
+
+#[test]
+fn test_clip_fill() {
+    let mut state = TextInputState::new();
+    state.set_text("漢字ab漢字");
+    render(&mut state);
+
+    state.set_offset(0);
+    assert_eq!(line(&render(&mut state)), "漢 字 ab");
+
+    state.set_offset(1);
+    assert_eq!(line(&render(&mut state)), " 字 ab ");
+}
+
+#[test]
+fn test_literal() {
+    let mut state = TextInputState::new();
+    state.set_text("∃漢字漢");
+    render(&mut state);
+
+    state.set_offset(0);
+    assert_eq!(line(&render(&mut state)), "∃漢 字  ");
+}
@@ -0,0 +1,46 @@
+use rat_widget::checkbox::{Checkbox, CheckboxState};
+use rat_widget::clipper::{Clipper, ClipperState};
+use rat_widget::layout::GenericLayout;
+use ratatui::layout::Rect;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn test_render_lazy() {
+    let mut state = ClipperState::<usize>::default();
+    let mut l = GenericLayout::new();
+    l.add(0, Rect::new(0, 0, 10, 1), None, Rect::default());
+    l.add(1, Rect::new(0, 50, 10, 1), None, Rect::default());
+    state.set_layout(Rc::new(l));
+
+    let mut c0 = CheckboxState::default();
+    let mut c1 = CheckboxState {
+        area: Rect::new(1, 1, 10, 1),
+        ..Default::default()
+    };
+
+    let calls = Cell::new(0);
+    let mut clip_buf = Clipper::new().into_buffer(Rect::new(0, 0, 20, 10), &mut state);
+    assert!(clip_buf.render(
+        0,
+        || {
+            calls.set(calls.get() + 1);
+            Checkbox::new()
+        },
+        &mut c0
+    ));
+    assert!(clip_buf
+        .render2(
+            1,
+            || {
+                calls.set(calls.get() + 1);
+                (Checkbox::new(), ())
+            },
+            &mut c1
+        )
+        .is_none());
+
+    assert_eq!(calls.get(), 1);
+    assert_eq!(c0.area, Rect::new(0, 0, 10, 1));
+    assert_eq!(c1.area, Rect::default());
+}
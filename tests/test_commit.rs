@@ -0,0 +1,60 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::date_input::DateInputState;
+use rat_widget::event::{handle_commit, CommitOutcome, Outcome, TextOutcome};
+use rat_widget::text_input::TextInputState;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_commit_enter() {
+    let mut state = TextInputState::new();
+    state.focus.set(true);
+
+    let r = handle_commit(&mut state, &key(KeyCode::Char('x')));
+    assert_eq!(r, CommitOutcome::TextChanged);
+    assert_eq!(state.text(), "x");
+
+    let r = handle_commit(&mut state, &key(KeyCode::Enter));
+    assert_eq!(r, CommitOutcome::Committed);
+
+    // without focus
+    state.focus.set(false);
+    let r = handle_commit(&mut state, &key(KeyCode::Enter));
+    assert_eq!(r, CommitOutcome::Continue);
+}
+
+#[test]
+fn test_commit_lost() {
+    let mut state = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    state.widget.focus.set(false);
+    state.widget.focus.set_lost(true);
+
+    let r = handle_commit(&mut state, &key(KeyCode::Char('1')));
+    assert_eq!(r, CommitOutcome::Committed);
+
+    state.widget.focus.set_lost(false);
+    let r = handle_commit(&mut state, &key(KeyCode::Char('1')));
+    assert_eq!(r, CommitOutcome::Continue);
+}
+
+#[test]
+fn test_commit_outcome() {
+    assert_eq!(
+        CommitOutcome::from(TextOutcome::TextChanged),
+        CommitOutcome::TextChanged
+    );
+    assert_eq!(Outcome::from(CommitOutcome::Committed), Outcome::Changed);
+    assert_eq!(CommitOutcome::from(true), CommitOutcome::Changed);
+    for o in [Outcome::Continue, Outcome::Unchanged, Outcome::Changed] {
+        assert_eq!(Outcome::from(CommitOutcome::from(o)), o);
+    }
+}
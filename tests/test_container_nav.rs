@@ -0,0 +1,53 @@
+use rat_widget::button::ButtonState;
+use rat_widget::focus::{
+    next_container, prev_container, ContainerFlag, Focus, FocusBuilder, HasFocus,
+};
+use ratatui::layout::Rect;
+
+// a | [b c] | [d] e
+fn build(w: &[ButtonState; 5]) -> Focus {
+    let mut fb = FocusBuilder::default();
+    fb.widget(&w[0]);
+    let tag = fb.start(Some(ContainerFlag::named("c1")), Rect::default(), 0);
+    fb.widget(&w[1]).widget(&w[2]);
+    fb.end(tag);
+    let tag = fb.start(Some(ContainerFlag::named("c2")), Rect::default(), 0);
+    fb.widget(&w[3]);
+    fb.end(tag);
+    fb.widget(&w[4]);
+    fb.build()
+}
+
+#[test]
+fn test_next_container() {
+    let w = ["a", "b", "c", "d", "e"].map(ButtonState::named);
+    let focus = build(&w);
+
+    focus.focus(&w[0]);
+    assert!(next_container(&focus));
+    assert!(w[1].is_focused());
+    focus.focus(&w[2]);
+    assert!(next_container(&focus));
+    assert!(w[3].is_focused());
+    assert!(next_container(&focus));
+    assert!(w[4].is_focused());
+    assert!(next_container(&focus));
+    assert!(w[0].is_focused());
+}
+
+#[test]
+fn test_prev_container() {
+    let w = ["a", "b", "c", "d", "e"].map(ButtonState::named);
+    let focus = build(&w);
+
+    focus.focus(&w[4]);
+    assert!(prev_container(&focus));
+    assert!(w[3].is_focused());
+    assert!(prev_container(&focus));
+    assert!(w[1].is_focused());
+    focus.focus(&w[2]);
+    assert!(prev_container(&focus));
+    assert!(w[0].is_focused());
+    assert!(prev_container(&focus));
+    assert!(w[4].is_focused());
+}
@@ -0,0 +1,48 @@
+use rat_widget::util::copy_buffer;
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Style;
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect()
+}
+
+#[test]
+fn test_copy_offset() {
+    let mut src = Buffer::empty(Rect::new(0, 0, 10, 2));
+    src.set_string(0, 0, "0123456789", Style::default());
+    src.set_string(0, 1, "abcdefghij", Style::default());
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+    copy_buffer(&src, Position::new(2, 1), Rect::new(1, 0, 4, 2), &mut buf);
+    assert_eq!(row(&buf, 0), " cdef ");
+    assert_eq!(row(&buf, 1), "      ");
+
+    // source starts right of the offset
+    let mut src = Buffer::empty(Rect::new(3, 0, 3, 1));
+    src.set_string(3, 0, "xyz", Style::default());
+    let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+    copy_buffer(&src, Position::new(1, 0), Rect::new(0, 0, 6, 1), &mut buf);
+    assert_eq!(row(&buf, 0), "  xyz ");
+}
+
+#[test]
+fn test_copy_wide() {
+    let mut src = Buffer::empty(Rect::new(0, 0, 8, 1));
+    src.set_string(0, 0, "漢字ab", Style::default());
+
+    // left edge cuts 漢
+    let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+    copy_buffer(&src, Position::new(1, 0), Rect::new(0, 0, 5, 1), &mut buf);
+    assert_eq!(buf.cell((0, 0)).expect("cell").symbol(), " ");
+    assert_eq!(buf.cell((1, 0)).expect("cell").symbol(), "字");
+    assert_eq!(buf.cell((3, 0)).expect("cell").symbol(), "a");
+
+    // right edge cuts 字
+    let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+    copy_buffer(&src, Position::new(0, 0), Rect::new(0, 0, 3, 1), &mut buf);
+    assert_eq!(buf.cell((0, 0)).expect("cell").symbol(), "漢");
+    assert_eq!(buf.cell((2, 0)).expect("cell").symbol(), " ");
+}
@@ -0,0 +1,45 @@
+use rat_widget::text::ctrl_glyph::CtrlGlyph;
+use rat_widget::text_input::{TextInput, TextInputState};
+use rat_widget::textarea::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect()
+}
+
+#[test]
+fn test_text_area() {
+    let area = Rect::new(0, 0, 6, 2);
+    let mut buf = Buffer::empty(area);
+    let mut state = TextAreaState::new();
+    state.set_text("a\x07b\n\u{FFFD}c");
+    TextArea::new().render(area, &mut buf, &mut state);
+    assert_eq!(line(&buf, 0), "a\u{FFFD}b   ");
+
+    CtrlGlyph::new().render_text_area(&mut buf, &state);
+    assert_eq!(line(&buf, 0), "a·b   ");
+    assert_eq!(line(&buf, 1), "\u{FFFD}c    ");
+
+    let mut buf = Buffer::empty(area);
+    state.set_show_ctrl(true);
+    TextArea::new().render(area, &mut buf, &mut state);
+    CtrlGlyph::new().render_text_area(&mut buf, &state);
+    assert_eq!(line(&buf, 0), "a\u{2407}b\u{2424}  ");
+}
+
+#[test]
+fn test_text_input() {
+    let area = Rect::new(0, 0, 5, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = TextInputState::new();
+    state.set_text("a\x1bb");
+    TextInput::new().render(area, &mut buf, &mut state);
+    CtrlGlyph::new()
+        .replacement("?")
+        .render_text_input(&mut buf, &state);
+    assert_eq!(line(&buf, 0), "a?b  ");
+}
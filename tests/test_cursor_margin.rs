@@ -0,0 +1,104 @@
+use rat_widget::textarea::cursor_margin::CursorMargin;
+use rat_widget::textarea::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+
+fn state() -> TextAreaState {
+    let mut state = TextAreaState::new();
+    let text = (0..20).map(|n| format!("line {}\n", n)).collect::<String>();
+    state.set_text(text);
+    state.focus.set(true);
+    state
+}
+
+fn cursor_visible(state: &TextAreaState) -> bool {
+    let (_, oy) = state.offset();
+    let y = state.cursor().y as usize;
+    y >= oy && y < oy + state.inner.height as usize
+}
+
+#[test]
+fn test_shrink() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let mut state = state();
+    let mut margin = CursorMargin::new();
+
+    margin.render(
+        TextArea::new(),
+        Rect::new(0, 0, 20, 10),
+        &mut buf,
+        &mut state,
+    );
+    state.set_cursor((0, 8), false);
+    margin.render(
+        TextArea::new(),
+        Rect::new(0, 0, 20, 10),
+        &mut buf,
+        &mut state,
+    );
+    assert_eq!(state.offset(), (0, 0));
+
+    // the cursor is below the new area.
+    margin.render(
+        TextArea::new(),
+        Rect::new(0, 0, 20, 5),
+        &mut buf,
+        &mut state,
+    );
+    assert!(cursor_visible(&state));
+    assert_eq!(state.offset(), (0, 4));
+    assert_eq!(buf.cell((0, 4)).expect("cell").symbol(), "l");
+    assert_eq!(buf.cell((5, 4)).expect("cell").symbol(), "8");
+}
+
+#[test]
+fn test_margin() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let mut state = state();
+    let mut margin = CursorMargin::new().cursor_margin(2);
+    let area = Rect::new(0, 0, 20, 10);
+
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    state.move_down(7, false);
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    assert_eq!(state.offset(), (0, 0));
+
+    // two rows below the cursor.
+    state.move_down(1, false);
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    assert_eq!(state.offset(), (0, 1));
+
+    state.move_up(7, false);
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    assert_eq!(state.offset(), (0, 0));
+
+    // the last line doesn't scroll beyond the text.
+    state.move_to_end(false);
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    assert!(cursor_visible(&state));
+    assert_eq!(state.offset(), (0, 11));
+}
+
+#[test]
+fn test_keep_scroll() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let mut state = state();
+    let mut margin = CursorMargin::new().cursor_margin(2);
+    let area = Rect::new(0, 0, 20, 10);
+
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    // wheel scrolling doesn't move the cursor.
+    state.scroll_down(5);
+    margin.render(TextArea::new(), area, &mut buf, &mut state);
+    assert_eq!(state.offset(), (0, 5));
+
+    // not focused.
+    state.focus.set(false);
+    margin.render(
+        TextArea::new(),
+        Rect::new(0, 0, 20, 3),
+        &mut buf,
+        &mut state,
+    );
+    assert_eq!(state.offset(), (0, 5));
+}
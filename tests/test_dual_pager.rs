@@ -0,0 +1,61 @@
+use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
+use rat_widget::pager::{DualPager, DualPagerState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Flex, Rect};
+use ratatui::widgets::{Block, Padding};
+use std::rc::Rc;
+
+fn render(area: Rect, state: &mut DualPagerState<usize>) {
+    let mut buf = Buffer::empty(area);
+    let pager = DualPager::new().column_ratio_from(state);
+    _ = pager.into_buffer(area, &mut buf, state);
+}
+
+#[test]
+fn test_move_split() {
+    let area = Rect::new(0, 0, 40, 10);
+    let mut state = DualPagerState::<usize>::new();
+    render(area, &mut state);
+    assert_eq!(state.nav.widget_areas[0].width, 20);
+    assert_eq!(state.nav.widget_areas[1].width, 20);
+
+    assert!(state.move_split(5));
+    assert_eq!(state.column_ratio, Some((25, 15)));
+    render(area, &mut state);
+    assert_eq!(state.nav.widget_areas[0].width, 25);
+    assert_eq!(state.nav.widget_areas[1].width, 15);
+
+    // clamped to the minimum width.
+    assert!(state.move_split(-100));
+    assert_eq!(state.column_ratio, Some((10, 30)));
+    render(area, &mut state);
+    assert!(!state.move_split(-1));
+}
+
+#[test]
+fn test_layout_wide_column() {
+    let area = Rect::new(0, 0, 40, 10);
+    let mut buf = Buffer::empty(area);
+
+    let pager = DualPager::new().column_ratio(1, 3).layout_column(1);
+    let page = pager.layout_size(area);
+    assert_eq!(page.width, 30);
+
+    let mut form = LayoutForm::<usize>::new().flex(Flex::Legacy);
+    form.widget(0, FormLabel::None, FormWidget::StretchX(5, 1));
+    form.page_break();
+    form.widget(1, FormLabel::None, FormWidget::StretchX(5, 1));
+    let mut state = DualPagerState::new();
+    state.set_layout(Rc::new(form.paged(page, Padding::default())));
+
+    let mut pager = pager.into_buffer(area, &mut buf, &mut state);
+    assert!(pager.render_widget(1, Block::bordered));
+    // the wide right column gets the full width.
+    assert_eq!(pager.locate_widget(1), Some(Rect::new(11, 1, 28, 1)));
+    // clipped in the narrow left column.
+    assert_eq!(pager.locate_widget(0), Some(Rect::new(1, 1, 9, 1)));
+    drop(pager);
+
+    assert_eq!(buf.cell((11, 1)).expect("cell").symbol(), "┌");
+    assert_eq!(buf.cell((38, 1)).expect("cell").symbol(), "┐");
+}
@@ -0,0 +1,152 @@
+use rat_widget::clipper::{Clipper, ClipperState};
+use rat_widget::empty::EmptyRender;
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::scrolled::Scroll;
+use rat_widget::table::empty::empty_message;
+use rat_widget::table::selection::RowSelection as TableRowSelection;
+use rat_widget::table::textdata::{Cell as TableCell, Row};
+use rat_widget::table::{Table, TableState};
+use rat_widget::view::{View, ViewState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::{Block, StatefulWidget};
+use std::cell::Cell;
+
+#[test]
+fn test_list_empty_render() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+
+    let called = Cell::new(Rect::default());
+    List::<RowSelection>::default()
+        .block(Block::bordered())
+        .scroll(Scroll::new())
+        .empty_message("not shown")
+        .empty_render(|area, _buf| called.set(area))
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(called.get(), state.inner);
+    assert_eq!(buf.cell((1, 2)).map(|v| v.symbol()), Some(" "));
+    // no scrollbar for empty content.
+    assert_eq!(state.inner, Rect::new(1, 1, 8, 3));
+}
+
+#[test]
+fn test_list_empty_not_called() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+
+    let called = Cell::new(false);
+    List::<RowSelection>::default()
+        .item("a")
+        .empty_render(|_, _| called.set(true))
+        .render(area, &mut buf, &mut state);
+
+    assert!(!called.get());
+}
+
+#[test]
+fn test_list_empty_text() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+
+    List::<RowSelection>::default()
+        .empty_text("No data", Style::new().fg(Color::Red))
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(buf.cell((1, 2)).map(|v| v.symbol()), Some("N"));
+    assert_eq!(buf.cell((1, 2)).map(|v| v.fg), Some(Color::Red));
+}
+
+#[test]
+fn test_view_empty_text() {
+    let area = Rect::new(0, 0, 12, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ViewState::default();
+
+    View::new()
+        .block(Block::bordered())
+        .empty_text("Empty", Style::default())
+        .into_buffer(area, &mut state)
+        .into_widget()
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(state.widget_area, Rect::new(1, 1, 10, 3));
+    assert_eq!(buf.cell((3, 2)).map(|v| v.symbol()), Some("E"));
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("┌"));
+}
+
+#[test]
+fn test_view_not_empty() {
+    let area = Rect::new(0, 0, 12, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ViewState::default();
+
+    let called = Cell::new(false);
+    View::new()
+        .layout(Rect::new(0, 0, 10, 3))
+        .empty_render(|_, _| called.set(true))
+        .into_buffer(area, &mut state)
+        .into_widget()
+        .render(area, &mut buf, &mut state);
+
+    assert!(!called.get());
+}
+
+#[test]
+fn test_clipper_empty_render() {
+    let area = Rect::new(0, 0, 12, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ClipperState::<usize>::default();
+
+    let called = Cell::new(Rect::default());
+    Clipper::new()
+        .block(Block::bordered())
+        .empty_render(|area, _buf| called.set(area))
+        .into_buffer(area, &mut state)
+        .into_widget()
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(called.get(), state.widget_area);
+    assert_eq!(called.get(), Rect::new(1, 1, 10, 3));
+}
+
+#[test]
+fn test_empty_text_clipped() {
+    let area = Rect::new(0, 0, 5, 1);
+    let mut buf = Buffer::empty(area);
+
+    EmptyRender::text("one\ntwo\nthree", Style::default()).render(area, &mut buf);
+
+    assert_eq!(buf.cell((1, 0)).map(|v| v.symbol()), Some("o"));
+}
+
+#[test]
+fn test_table_empty_message() {
+    let area = Rect::new(0, 0, 12, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = TableState::<TableRowSelection>::default();
+
+    let style = Style::new().fg(Color::Red);
+    Table::<TableRowSelection>::new()
+        .rows(Vec::<Row<'_>>::new())
+        .header(Row::new([TableCell::from("Name")]))
+        .widths([Constraint::Length(10)])
+        .style(style)
+        .render(area, &mut buf, &mut state);
+    assert!(empty_message("Empty", style, &state, &mut buf));
+
+    // header stays, message centered in the rows below.
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("N"));
+    assert_eq!(buf.cell((3, 2)).map(|v| v.symbol()), Some("E"));
+    assert_eq!(buf.cell((3, 2)).map(|v| v.fg), Some(Color::Red));
+    assert!(buf
+        .cell((3, 2))
+        .map(|v| v.modifier.contains(Modifier::DIM))
+        .unwrap_or(false));
+}
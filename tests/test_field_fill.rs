@@ -0,0 +1,73 @@
+use rat_widget::number_input::{NumberInput, NumberInputState};
+use rat_widget::text_input::field_fill::{EmptyFill, FieldFill};
+use rat_widget::text_input::{TextInput, TextInputState};
+use rat_widget::text_input_mask::{MaskedInput, MaskedInputState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Modifier;
+use ratatui::widgets::StatefulWidget;
+
+fn line(buf: &Buffer) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, 0)).expect("cell").symbol())
+        .collect()
+}
+
+#[test]
+fn test_text_input() {
+    let area = Rect::new(0, 0, 8, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = TextInputState::new();
+    state.set_text("abc");
+    TextInput::new().render(area, &mut buf, &mut state);
+    EmptyFill::new().render_text_input(&mut buf, &state);
+    assert_eq!(line(&buf), "abc_____");
+    assert_eq!(state.cursor(), 0);
+
+    let mut buf = Buffer::empty(area);
+    TextInput::new().render(area, &mut buf, &mut state);
+    EmptyFill::new()
+        .field_fill(FieldFill::Char('.'))
+        .render_text_input(&mut buf, &state);
+    assert_eq!(line(&buf), "abc.....");
+
+    let mut buf = Buffer::empty(area);
+    TextInput::new().render(area, &mut buf, &mut state);
+    EmptyFill::new()
+        .field_fill(FieldFill::Underline)
+        .render_text_input(&mut buf, &state);
+    assert_eq!(line(&buf), "abc     ");
+    assert!(!buf[(2, 0)].modifier.contains(Modifier::UNDERLINED));
+    assert!(buf[(3, 0)].modifier.contains(Modifier::UNDERLINED));
+}
+
+#[test]
+fn test_masked_input() {
+    let area = Rect::new(0, 0, 12, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = MaskedInputState::new()
+        .with_mask("99.99.9999")
+        .expect("mask");
+    MaskedInput::new().render(area, &mut buf, &mut state);
+    EmptyFill::new().render_masked_input(&mut buf, &state);
+    assert_eq!(line(&buf), "__.__.____  ");
+
+    state.set_text("1 .05.    ");
+    let mut buf = Buffer::empty(area);
+    MaskedInput::new().render(area, &mut buf, &mut state);
+    EmptyFill::new().render_masked_input(&mut buf, &state);
+    assert_eq!(line(&buf), "1_.05.____  ");
+}
+
+#[test]
+fn test_number_input() {
+    let area = Rect::new(0, 0, 10, 1);
+    let mut buf = Buffer::empty(area);
+    let mut state = NumberInputState::new()
+        .with_pattern("####0.00")
+        .expect("pattern");
+    state.set_value(12.5).expect("value");
+    NumberInput::new().render(area, &mut buf, &mut state);
+    EmptyFill::new().render_masked_input(&mut buf, &state.widget);
+    assert_eq!(line(&buf), "___12.50  ");
+}
@@ -0,0 +1,130 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::event::{Dialog, HandleEvent};
+use rat_widget::focus::FocusFlag;
+use rat_widget::form::{FieldPalette, FieldPaletteState, PaletteOutcome};
+use rat_widget::layout::{FormLabel, FormWidget, GenericLayout, LayoutForm};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::widgets::{Padding, StatefulWidget};
+use std::rc::Rc;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+}
+
+#[test]
+fn test_palette() {
+    let name = FocusFlag::named("name");
+    let street = FocusFlag::named("street");
+    let city = FocusFlag::named("city");
+    let unnamed = FocusFlag::named("unnamed");
+
+    let mut layout = LayoutForm::<FocusFlag>::new();
+    layout.widget(name.clone(), FormLabel::Str("Name"), FormWidget::Width(15));
+    layout.widget(unnamed.clone(), FormLabel::Width(5), FormWidget::Width(15));
+    layout.widget(
+        street.clone(),
+        FormLabel::Str("Street"),
+        FormWidget::Width(15),
+    );
+    layout.widget(
+        city.clone(),
+        FormLabel::String("City".into()),
+        FormWidget::Width(15),
+    );
+    let layout = Rc::new(layout.paged(Size::new(30, 2), Padding::default()));
+    let street_page = layout.page_of(street.clone()).expect("page");
+    let city_page = layout.page_of(city.clone()).expect("page");
+    assert!(street_page > 0);
+
+    let mut state = FieldPaletteState::new();
+    state.set_layout(layout.clone());
+
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('x'), KeyModifiers::NONE), Dialog),
+        PaletteOutcome::Continue
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Char('g'), KeyModifiers::CONTROL), Dialog),
+        PaletteOutcome::Changed
+    );
+    assert!(state.is_active());
+
+    let labels = state
+        .entries
+        .iter()
+        .map(|v| (v.label.as_str(), v.page))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        labels,
+        vec![("Name", 0), ("Street", street_page), ("City", city_page)]
+    );
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 40, 12));
+    FieldPalette::new().render(buf.area, &mut buf, &mut state);
+    assert!(row(&buf, 1).starts_with("│> "));
+    assert!(row(&buf, 3).starts_with("│Street"));
+    assert!(row(&buf, 3).ends_with(&format!("{}│", street_page + 1)));
+
+    // filter
+    state.handle(&key(KeyCode::Char('T'), KeyModifiers::SHIFT), Dialog);
+    assert_eq!(state.filter, "T");
+    assert_eq!(state.matches, vec![1, 2]);
+    state.handle(&key(KeyCode::Down, KeyModifiers::NONE), Dialog);
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter, KeyModifiers::NONE), Dialog),
+        PaletteOutcome::Selected(city.clone(), city_page)
+    );
+    assert!(!state.is_active());
+}
+
+#[test]
+fn test_palette_modal() {
+    let mut state = FieldPaletteState::new();
+    state.open();
+    assert!(state.entries.is_empty());
+    assert_eq!(
+        state.handle(&key(KeyCode::F(5), KeyModifiers::NONE), Dialog),
+        PaletteOutcome::Unchanged
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Enter, KeyModifiers::NONE), Dialog),
+        PaletteOutcome::Unchanged
+    );
+    assert_eq!(
+        state.handle(&key(KeyCode::Esc, KeyModifiers::NONE), Dialog),
+        PaletteOutcome::Changed
+    );
+    assert!(!state.is_active());
+}
+
+#[test]
+fn test_palette_unpaged() {
+    let name = FocusFlag::named("name");
+
+    let mut layout = GenericLayout::<FocusFlag>::new();
+    layout.add(
+        name.clone(),
+        Rect::new(6, 3, 15, 1),
+        Some("Name".into()),
+        Rect::new(0, 3, 5, 1),
+    );
+    assert_eq!(layout.page_of(name.clone()), Some(0));
+
+    let mut state = FieldPaletteState::new();
+    state.set_layout(Rc::new(layout));
+    state.open();
+    assert_eq!(state.entries.len(), 1);
+    assert_eq!(state.entries[0].page, 0);
+}
@@ -0,0 +1,62 @@
+use rat_widget::button::ButtonState;
+use rat_widget::focus::{Focus, FocusBuilder, FocusMemory, HasFocus};
+
+fn build(w0: &ButtonState, w1: &ButtonState) -> Focus {
+    let mut fb = FocusBuilder::default();
+    fb.widget(w0).widget(w1);
+    fb.build()
+}
+
+#[test]
+fn test_restore() {
+    let a = ButtonState::named("a");
+    let b = ButtonState::named("b");
+
+    let focus = build(&a, &b);
+    focus.focus(&b);
+
+    let mut mem = FocusMemory::new();
+    mem.save(&focus);
+    focus.focus(&a);
+
+    assert!(mem.restore(&focus));
+    assert!(b.is_focused());
+    assert!(!mem.is_saved());
+}
+
+#[test]
+fn test_restore_by_name() {
+    let a = ButtonState::named("a");
+    let b = ButtonState::named("b");
+
+    let focus = build(&a, &b);
+    focus.focus(&b);
+
+    let mut mem = FocusMemory::new();
+    mem.save(&focus);
+
+    // state recreated while the dialog was open.
+    let b = ButtonState::named("b");
+    let focus = build(&a, &b);
+
+    assert!(mem.restore(&focus));
+    assert!(b.is_focused());
+}
+
+#[test]
+fn test_restore_fallback() {
+    let a = ButtonState::named("a");
+    let b = ButtonState::named("b");
+
+    let focus = build(&a, &b);
+    focus.focus(&b);
+
+    let mut mem = FocusMemory::new();
+    mem.save(&focus);
+
+    let c = ButtonState::named("c");
+    let focus = build(&c, &a);
+
+    assert!(!mem.restore(&focus));
+    assert!(c.is_focused());
+}
@@ -0,0 +1,90 @@
+use rat_widget::button::ButtonState;
+use rat_widget::checkbox::CheckboxState;
+use rat_widget::focus::{flag_by_name, focus_name, Focus, FocusBuilder, FocusFlag, HasFocus};
+use rat_widget::layout::GenericLayout;
+use rat_widget::pager::SinglePagerState;
+use ratatui::layout::{Rect, Size};
+use std::rc::Rc;
+
+struct State {
+    pager: SinglePagerState<FocusFlag>,
+    name: ButtonState,
+    street: ButtonState,
+    active: CheckboxState,
+}
+
+impl State {
+    // all states are new, but have the same names.
+    fn new() -> Self {
+        let mut state = Self {
+            pager: SinglePagerState::new(),
+            name: ButtonState::named("name"),
+            street: ButtonState::named("street"),
+            active: CheckboxState::named("active"),
+        };
+
+        // one field per page.
+        let mut l = GenericLayout::<FocusFlag>::new();
+        l.set_page_size(Size::new(10, 1));
+        l.set_page_count(3);
+        for (i, f) in [
+            state.name.focus(),
+            state.street.focus(),
+            state.active.focus(),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            l.add(f, Rect::new(0, i as u16, 10, 1), None, Rect::default());
+        }
+        state.pager.set_layout(Rc::new(l));
+        state
+    }
+
+    fn focus(&self) -> Focus {
+        let mut fb = FocusBuilder::default();
+        fb.widget(&self.name)
+            .widget(&self.street)
+            .widget(&self.active);
+        fb.build()
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let state = State::new();
+    let focus = state.focus();
+    focus.focus(&state.active);
+    let saved = focus.focused_name();
+    assert_eq!(saved.as_deref(), Some("active"));
+
+    // rebuild
+    let state = State::new();
+    let focus = state.focus();
+    focus.first();
+    assert!(state.name.is_focused());
+
+    assert!(focus_name(&focus, saved.as_deref().expect("name")));
+    assert!(state.active.is_focused());
+    assert!(!state.name.is_focused());
+
+    // show the page of the field.
+    let flag = focus.focused().expect("focused");
+    assert_eq!(state.pager.layout().page_of(flag), Some(2));
+}
+
+#[test]
+fn test_missing_name() {
+    let state = State::new();
+    let focus = state.focus();
+    focus.focus(&state.street);
+
+    assert!(!focus_name(&focus, "city"));
+    assert!(!focus_name(&focus, ""));
+    assert!(state.street.is_focused());
+    assert!(flag_by_name(&focus, "city").is_none());
+    assert_eq!(
+        flag_by_name(&focus, "name").map(|v| v.name().to_string()),
+        Some("name".to_string())
+    );
+}
@@ -0,0 +1,99 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_event::{HandleEvent, Regular};
+use rat_widget::button::ButtonState;
+use rat_widget::focus::{ContainerFlag, Focus, FocusBuilder, FocusContainer, FocusTrap, HasFocus};
+
+struct Modal {
+    container: ContainerFlag,
+    ok: ButtonState,
+    cancel: ButtonState,
+}
+
+impl FocusContainer for Modal {
+    fn build(&self, builder: &mut FocusBuilder) {
+        builder.widget(&self.ok).widget(&self.cancel);
+    }
+
+    fn container(&self) -> Option<ContainerFlag> {
+        Some(self.container.clone())
+    }
+}
+
+struct App {
+    a: ButtonState,
+    b: ButtonState,
+    modal: Modal,
+}
+
+fn app_focus(app: &App) -> Focus {
+    let mut fb = FocusBuilder::default();
+    fb.widget(&app.a).widget(&app.b);
+    fb.build()
+}
+
+fn tab() -> Event {
+    Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE))
+}
+
+fn back_tab() -> Event {
+    Event::Key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT))
+}
+
+fn app() -> App {
+    App {
+        a: ButtonState::named("a"),
+        b: ButtonState::named("b"),
+        modal: Modal {
+            container: ContainerFlag::named("modal"),
+            ok: ButtonState::named("ok"),
+            cancel: ButtonState::named("cancel"),
+        },
+    }
+}
+
+#[test]
+fn test_trap() {
+    let app = app();
+    let mut trap = FocusTrap::new();
+
+    let focus = app_focus(&app);
+    focus.focus(&app.b);
+
+    trap.activate(&focus, &app.modal);
+    assert!(trap.is_active());
+    assert!(app.modal.ok.is_focused());
+    assert!(!app.b.is_focused());
+
+    for i in 0..6 {
+        let mut focus = trap.build(&app.modal, || app_focus(&app));
+        let event = if i % 3 == 0 { back_tab() } else { tab() };
+        focus.handle(&event, Regular);
+
+        assert!(!app.a.is_focused());
+        assert!(!app.b.is_focused());
+        assert!(app.modal.ok.is_focused() || app.modal.cancel.is_focused());
+    }
+}
+
+#[test]
+fn test_release() {
+    let app = app();
+    let mut trap = FocusTrap::new();
+
+    let focus = app_focus(&app);
+    focus.focus(&app.b);
+    trap.activate(&focus, &app.modal);
+    assert_eq!(trap.previous(), Some(app.b.focus()));
+
+    trap.deactivate();
+    assert!(!trap.is_active());
+
+    let mut focus = trap.build(&app.modal, || app_focus(&app));
+    assert!(app.b.is_focused());
+
+    // only restored once.
+    focus.handle(&tab(), Regular);
+    let focus = trap.build(&app.modal, || app_focus(&app));
+    assert!(app.a.is_focused());
+    assert!(focus.focused().is_some());
+}
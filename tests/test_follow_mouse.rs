@@ -0,0 +1,84 @@
+use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_widget::button::ButtonState;
+use rat_widget::choice::ChoiceState;
+use rat_widget::focus::{FocusBuilder, FocusFollowsMouse, HasFocus};
+use ratatui::layout::Rect;
+use std::time::{Duration, Instant};
+
+fn moved(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Moved,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_follow() {
+    let mut w = ["a", "b"].map(ButtonState::named);
+    w[0].area = Rect::new(0, 0, 10, 1);
+    w[1].area = Rect::new(0, 2, 10, 1);
+
+    let mut fb = FocusBuilder::default();
+    fb.widget(&w[0]).widget(&w[1]);
+    let focus = fb.build();
+    focus.first();
+
+    let mut fm = FocusFollowsMouse::new();
+    assert!(fm.handle(&focus, &moved(1, 2), None));
+    assert!(w[1].is_focused());
+    assert!(!fm.handle(&focus, &moved(2, 2), None));
+
+    // nothing here
+    assert!(!fm.handle(&focus, &moved(1, 1), None));
+    assert!(w[1].is_focused());
+}
+
+#[test]
+fn test_dwell() {
+    let mut w = ["a", "b"].map(ButtonState::named);
+    w[0].area = Rect::new(0, 0, 10, 1);
+    w[1].area = Rect::new(0, 2, 10, 1);
+
+    let mut fb = FocusBuilder::default();
+    fb.widget(&w[0]).widget(&w[1]);
+    let focus = fb.build();
+    focus.first();
+
+    let delay = Some(Duration::from_millis(300));
+    let t0 = Instant::now();
+    let mut fm = FocusFollowsMouse::new();
+    assert!(!fm.handle_at(&focus, &moved(1, 2), delay, t0));
+    assert!(w[0].is_focused());
+    assert!(!fm.expire_at(&focus, t0 + Duration::from_millis(100)));
+    assert!(fm.expire_at(&focus, t0 + Duration::from_millis(300)));
+    assert!(w[1].is_focused());
+}
+
+#[test]
+fn test_popup() {
+    let mut c = ChoiceState::<usize>::named("c");
+    c.area = Rect::new(0, 0, 10, 1);
+    c.popup.area = Rect::new(0, 1, 10, 5);
+    let mut b = ButtonState::named("b");
+    b.area = Rect::new(0, 3, 10, 1);
+
+    let mut fb = FocusBuilder::default();
+    fb.widget(&c).widget(&b);
+    let focus = fb.build();
+    focus.first();
+
+    // the popup covers the button
+    let mut fm = FocusFollowsMouse::new();
+    assert!(!fm.handle(&focus, &moved(1, 3), None));
+    assert!(c.is_focused());
+
+    // next to the popup, but the popup is still open.
+    b.area = Rect::new(20, 3, 10, 1);
+    let mut fb = FocusBuilder::default();
+    fb.widget(&c).widget(&b);
+    let focus = fb.build();
+    assert!(!fm.handle(&focus, &moved(21, 3), None));
+    assert!(c.is_focused());
+}
@@ -0,0 +1,26 @@
+use rat_widget::layout::{GenericLayout, HitPart};
+use ratatui::layout::{Position, Rect, Size};
+
+#[test]
+fn test_widget_at() {
+    let mut l = GenericLayout::new();
+    l.set_page_size(Size::new(20, 10));
+    l.set_page_count(2);
+    l.add(1, Rect::new(8, 1, 10, 1), None, Rect::new(0, 1, 7, 1));
+    l.add(2, Rect::new(8, 12, 10, 1), None, Rect::new(0, 12, 7, 1));
+
+    assert_eq!(
+        l.widget_at(0, Position::new(9, 1)),
+        Some((1, HitPart::Widget))
+    );
+    assert_eq!(
+        l.widget_at(0, Position::new(2, 1)),
+        Some((1, HitPart::Label))
+    );
+    assert_eq!(l.widget_at(0, Position::new(2, 2)), None);
+    assert_eq!(
+        l.widget_at(1, Position::new(2, 2)),
+        Some((2, HitPart::Label))
+    );
+    assert_eq!(l.widget_at(1, Position::new(2, 1)), None);
+}
@@ -0,0 +1,36 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::event::{handle_named, Outcome, Regular};
+use rat_widget::focus::FocusFlag;
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_handle_named() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+    state.focus = FocusFlag::named("fruits");
+    List::new(["a", "b", "c"]).render(area, &mut buf, &mut state);
+
+    // not focused
+    let (r, name) = handle_named(&mut state, &key(KeyCode::Down), Regular);
+    assert_eq!(r, Outcome::Continue);
+    assert_eq!(name, None);
+
+    state.focus.set(true);
+    let (r, name) = handle_named(&mut state, &key(KeyCode::Down), Regular);
+    assert_eq!(r, Outcome::Changed);
+    assert_eq!(name.as_deref(), Some("fruits"));
+}
@@ -0,0 +1,103 @@
+use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_widget::event::HoverOutcome;
+use rat_widget::list::{handle_hover, List, ListState};
+use rat_widget::table::hover::{handle_events, TableHover};
+use rat_widget::table::selection::RowSelection;
+use rat_widget::table::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn moved(column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Moved,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_list_hover() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<rat_widget::list::selection::RowSelection>::default();
+
+    // off
+    List::new(["a", "b", "c"]).render(area, &mut buf, &mut state);
+    assert_eq!(
+        handle_hover(&mut state, &moved(1, 1)),
+        HoverOutcome::Continue
+    );
+
+    List::new(["a", "b", "c"])
+        .hover_highlight(true)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(
+        handle_hover(&mut state, &moved(1, 1)),
+        HoverOutcome::Hovered(1)
+    );
+    assert_eq!(
+        handle_hover(&mut state, &moved(2, 1)),
+        HoverOutcome::Continue
+    );
+    assert_eq!(state.selected(), None);
+
+    let mut buf = Buffer::empty(area);
+    List::new(["a", "b", "c"])
+        .hover_highlight(true)
+        .render(area, &mut buf, &mut state);
+    assert!(buf[(0, 1)].modifier.contains(Modifier::UNDERLINED));
+    assert!(!buf[(0, 0)].modifier.contains(Modifier::UNDERLINED));
+
+    assert_eq!(
+        handle_hover(&mut state, &moved(1, 4)),
+        HoverOutcome::Changed
+    );
+    assert_eq!(state.hovered, None);
+}
+
+struct Data;
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        3
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(10)]
+    }
+
+    fn render_cell(&self, _: &TableContext, _: usize, row: usize, area: Rect, buf: &mut Buffer) {
+        buf.set_string(area.x, area.y, format!("{}", row), Style::default());
+    }
+}
+
+#[test]
+fn test_table_hover() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = TableState::<RowSelection>::default();
+    let mut hover = TableHover::new().style(Style::new().add_modifier(Modifier::ITALIC));
+
+    Table::new().data(Data).render(area, &mut buf, &mut state);
+    assert_eq!(
+        handle_events(&mut hover, &state, &moved(1, 2)),
+        HoverOutcome::Hovered(2)
+    );
+    hover.render(&state, &mut buf);
+    assert!(buf[(0, 2)].modifier.contains(Modifier::ITALIC));
+
+    // selected rows are not highlighted.
+    state.select(Some(2));
+    let mut buf = Buffer::empty(area);
+    Table::new().data(Data).render(area, &mut buf, &mut state);
+    hover.render(&state, &mut buf);
+    assert!(!buf[(0, 2)].modifier.contains(Modifier::ITALIC));
+
+    assert_eq!(
+        handle_events(&mut hover, &state, &moved(1, 4)),
+        HoverOutcome::Changed
+    );
+}
@@ -0,0 +1,34 @@
+use rat_widget::paragraph::Paragraph;
+use rat_widget::slider::Slider;
+use ratatui::layout::Direction;
+use ratatui::widgets::Block;
+
+#[test]
+fn test_paragraph() {
+    let p = Paragraph::new("one\nthree\n");
+    assert_eq!(p.width(), 5);
+    assert_eq!(p.height(), 2);
+
+    let p = Paragraph::new("one\nthree").block(Block::bordered());
+    assert_eq!(p.width(), 7);
+    assert_eq!(p.height(), 4);
+}
+
+#[test]
+fn test_slider() {
+    let s = Slider::<u8>::new().lower_bound("0").upper_bound("100");
+    assert_eq!(s.width(), 1 + 3 + 3);
+    assert_eq!(s.height(), 1);
+
+    let s = Slider::<u8>::new()
+        .direction(Direction::Vertical)
+        .lower_bound("0")
+        .upper_bound("100")
+        .block(Block::bordered());
+    assert_eq!(s.width(), 3 + 2);
+    assert_eq!(s.height(), 1 + 1 + 1 + 2);
+
+    let s = Slider::<u8>::new().horizontal_knob("<>\n<>");
+    assert_eq!(s.width(), 2);
+    assert_eq!(s.height(), 2);
+}
@@ -0,0 +1,67 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_widget::event::{ChordOutcome, KeyChords};
+use std::time::{Duration, Instant};
+
+fn key(c: char) -> KeyEvent {
+    KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE)
+}
+
+fn chords() -> KeyChords<&'static str> {
+    KeyChords::new()
+        .timeout(Duration::from_millis(500))
+        .chord([key('g'), key('g')], "top")
+        .chord([key(' '), key('x'), key('s')], "save")
+}
+
+#[test]
+fn test_chord() {
+    let mut c = chords();
+    let t = Instant::now();
+
+    assert_eq!(c.handle_at(&Event::Key(key('g')), t), ChordOutcome::Pending);
+    assert!(c.is_pending());
+    assert_eq!(
+        c.handle_at(&Event::Key(key('g')), t + Duration::from_millis(100)),
+        ChordOutcome::Chord("top")
+    );
+    assert!(!c.is_pending());
+
+    assert_eq!(
+        c.handle_at(&Event::Key(key('a')), t),
+        ChordOutcome::Continue
+    );
+}
+
+#[test]
+fn test_mismatch_replay() {
+    let mut c = chords();
+    let t = Instant::now();
+
+    assert_eq!(c.handle_at(&Event::Key(key(' ')), t), ChordOutcome::Pending);
+    assert_eq!(c.handle_at(&Event::Key(key('x')), t), ChordOutcome::Pending);
+    assert_eq!(
+        c.handle_at(&Event::Key(key('q')), t),
+        ChordOutcome::Replay(vec![key(' '), key('x'), key('q')])
+    );
+    assert!(!c.is_pending());
+}
+
+#[test]
+fn test_timeout() {
+    let mut c = chords();
+    let t = Instant::now();
+
+    assert_eq!(c.handle_at(&Event::Key(key('g')), t), ChordOutcome::Pending);
+    assert_eq!(
+        c.handle_at(&Event::Key(key('g')), t + Duration::from_millis(600)),
+        ChordOutcome::Replay(vec![key('g'), key('g')])
+    );
+
+    assert_eq!(c.handle_at(&Event::Key(key('g')), t), ChordOutcome::Pending);
+    assert_eq!(c.expire_at(t + Duration::from_millis(100)), None);
+    assert_eq!(
+        c.expire_at(t + Duration::from_millis(600)),
+        Some(vec![key('g')])
+    );
+    assert!(!c.is_pending());
+}
@@ -0,0 +1,29 @@
+use rat_widget::layout::{layout_dialog2, DialogItem};
+use ratatui::layout::{Constraint, Flex, Rect};
+use ratatui::widgets::Padding;
+
+#[test]
+fn test_two_groups() {
+    let l = layout_dialog2(
+        Rect::new(0, 0, 40, 10),
+        Padding::new(1, 1, 1, 1),
+        [Constraint::Length(6)],
+        Flex::Start,
+        [Constraint::Length(4), Constraint::Length(8)],
+        Flex::End,
+        1,
+    );
+
+    assert_eq!(l.widget_for(DialogItem::Buttons), Rect::new(1, 8, 38, 1));
+    assert_eq!(l.widget_for(DialogItem::Button(0)), Rect::new(1, 8, 6, 1));
+    assert_eq!(l.widget_for(DialogItem::Button(1)), Rect::new(26, 8, 4, 1));
+    assert_eq!(l.widget_for(DialogItem::Button(2)), Rect::new(31, 8, 8, 1));
+    assert_eq!(
+        l.widget_for(DialogItem::LeftButtons),
+        Rect::new(1, 8, 19, 1)
+    );
+    assert_eq!(
+        l.widget_for(DialogItem::RightButtons),
+        Rect::new(21, 8, 18, 1)
+    );
+}
@@ -1,4 +1,4 @@
-use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
+use rat_widget::layout::{FormLabel, FormWidget, LabelPosition, LayoutForm};
 use ratatui::layout::{Rect, Size};
 use ratatui::widgets::{Block, Padding};
 
@@ -128,3 +128,53 @@ fn test_overflow2() {
     let l = layout.paged(Size::new(100, u16::MAX), Padding::new(0, 0, 1, 1));
     dbg!(l);
 }
+
+fn label_form(position: LabelPosition) -> LayoutForm<i32> {
+    let mut layout = LayoutForm::<i32>::new().label_position(position);
+    layout.widget(1, FormLabel::Str("Name"), FormWidget::Width(15));
+    layout.widget(2, FormLabel::Width(5), FormWidget::Size(10, 2));
+    layout.widget(3, FormLabel::None, FormWidget::Width(15));
+    layout
+}
+
+#[test]
+fn test_label_above() {
+    let g = label_form(LabelPosition::Above).endless(20, Padding::default());
+
+    assert_eq!(g.label(0), Rect::new(0, 0, 5, 1));
+    assert_eq!(g.widget(0), Rect::new(0, 1, 15, 1));
+    assert_eq!(g.label(1), Rect::new(0, 2, 5, 1));
+    assert_eq!(g.widget(1), Rect::new(0, 3, 10, 2));
+    assert_eq!(g.label(2), Rect::default());
+    assert_eq!(g.widget(2), Rect::new(0, 5, 15, 1));
+
+    // focus order doesn't depend on the labels.
+    assert_eq!(
+        (0..g.widget_len())
+            .map(|i| g.widget_key(i))
+            .collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+}
+
+#[test]
+fn test_label_auto() {
+    let g = label_form(LabelPosition::Auto(30)).endless(40, Padding::default());
+    assert_eq!(g.label(0), Rect::new(0, 0, 5, 1));
+    assert_eq!(g.widget(0), Rect::new(6, 0, 15, 1));
+
+    let g = label_form(LabelPosition::Auto(30)).endless(20, Padding::default());
+    assert_eq!(g.label(0), Rect::new(0, 0, 5, 1));
+    assert_eq!(g.widget(0), Rect::new(0, 1, 15, 1));
+}
+
+#[test]
+fn test_label_above_break() {
+    let g = label_form(LabelPosition::Above).paged(Size::new(20, 5), Padding::default());
+
+    assert_eq!(g.page_of(1), Some(0));
+    assert_eq!(g.page_of(2), Some(1));
+    assert_eq!(g.label(1), Rect::new(0, 5, 5, 1));
+    assert_eq!(g.widget(1), Rect::new(0, 6, 10, 2));
+    assert_eq!(g.page_of(3), Some(1));
+}
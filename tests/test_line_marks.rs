@@ -0,0 +1,75 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_widget::event::{Outcome, TextOutcome};
+use rat_widget::textarea::marks::{handle_events, LineMarks, LineMarksState, Mark, MarkOutcome};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::StatefulWidget;
+use std::collections::BTreeMap;
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_render() {
+    let mut state = LineMarksState::new();
+    assert_eq!(state.width(), 0);
+    state.reserve = true;
+    assert_eq!(state.width(), 1);
+
+    let mut marks = BTreeMap::new();
+    marks.insert(3, Mark::new("●", Color::Red));
+    marks.insert(5, Mark::new("▶", Color::Blue));
+    marks.insert(9, Mark::new("●", Color::Red));
+    state.set_line_marks(marks);
+
+    let area = Rect::new(0, 0, 1, 5);
+    let mut buf = Buffer::empty(area);
+    LineMarks::new()
+        .start(2)
+        .end(8)
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(buf[(0, 0)].symbol(), " ");
+    assert_eq!(buf[(0, 1)].symbol(), "●");
+    assert_eq!(buf[(0, 1)].fg, Color::Red);
+    assert_eq!(buf[(0, 3)].symbol(), "▶");
+    assert_eq!(buf[(0, 3)].fg, Color::Blue);
+}
+
+#[test]
+fn test_click() {
+    let mut state = LineMarksState::new();
+    let area = Rect::new(0, 1, 1, 5);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 5, 6));
+    LineMarks::new()
+        .start(10)
+        .end(13)
+        .render(area, &mut buf, &mut state);
+
+    let r = handle_events(&mut state, &click(0, 2));
+    assert_eq!(r, MarkOutcome::Clicked(11));
+    assert_eq!(TextOutcome::from(r), TextOutcome::Changed);
+    assert_eq!(Outcome::from(r), Outcome::Changed);
+
+    // after the end of the text.
+    assert_eq!(
+        handle_events(&mut state, &click(0, 4)),
+        MarkOutcome::Continue
+    );
+    // outside the column.
+    assert_eq!(
+        handle_events(&mut state, &click(1, 2)),
+        MarkOutcome::Continue
+    );
+
+    assert!(state.toggle(11, Mark::new("●", Color::Red)));
+    assert!(!state.toggle(11, Mark::new("●", Color::Red)));
+    assert!(state.marks.is_empty());
+}
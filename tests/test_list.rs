@@ -0,0 +1,266 @@
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::reloc::RelocatableState;
+use rat_widget::scroll_to::ScrollTo;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+#[test]
+fn test_row_area() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+
+    List::new(["a", "b", "c"]).render(area, &mut buf, &mut state);
+
+    assert_eq!(state.row_area(0), Some(Rect::new(0, 0, 10, 1)));
+    assert_eq!(state.row_area(2), Some(Rect::new(0, 2, 10, 1)));
+    assert_eq!(state.row_area(3), None);
+
+    assert_eq!(state.index_at((3, 1)), Some(1));
+    assert_eq!(state.index_at((3, 4)), None);
+    assert_eq!(state.index_at((10, 1)), None);
+
+    state.relocate((2, 2), Rect::new(0, 0, 20, 20));
+    assert_eq!(state.row_area(1), Some(Rect::new(2, 3, 10, 1)));
+    assert_eq!(state.index_at((3, 3)), Some(1));
+}
+
+#[test]
+fn test_empty_message() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+
+    List::<RowSelection>::default()
+        .empty_message("No data")
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(buf.cell((1, 2)).map(|v| v.symbol()), Some("N"));
+    assert_eq!(buf.cell((1, 1)).map(|v| v.symbol()), Some(" "));
+}
+
+#[test]
+fn test_separator_header() {
+    let area = Rect::new(0, 0, 10, 4);
+    let mut state = ListState::<RowSelection>::default();
+
+    let list = || {
+        List::<RowSelection>::default()
+            .header("A")
+            .item("a0")
+            .item("a1")
+            .separator()
+            .item("a2")
+            .header("B")
+            .item("b0")
+            .item("b1")
+    };
+
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert_eq!(state.rows(), 5);
+    assert_eq!(state.row_of_item(0), 1);
+    assert_eq!(state.row_of_item(2), 4);
+    assert_eq!(state.item_of_row(3), None);
+    assert_eq!(buf.cell((0, 3)).map(|v| v.symbol()), Some("─"));
+
+    // clicks on headers do nothing
+    assert_eq!(state.index_at((1, 0)), None);
+    assert_eq!(state.index_at((1, 1)), Some(0));
+
+    // navigation skips headers and separators.
+    state.select(Some(1));
+    state.move_down(1);
+    assert_eq!(state.selected(), Some(2));
+    state.move_down(1);
+    assert_eq!(state.selected(), Some(3));
+    assert_eq!(state.offset(), 3);
+}
+
+#[test]
+fn test_sticky_header() {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut state = ListState::<RowSelection>::default();
+
+    let list = || {
+        List::<RowSelection>::default()
+            .sticky_headers(true)
+            .header("A")
+            .items(["a0", "a1", "a2", "a3", "a4"])
+    };
+
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(!state.sticky_header);
+
+    state.set_offset(2);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(state.sticky_header);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("A"));
+    assert_eq!(buf.cell((1, 0)).map(|v| v.symbol()), Some(" "));
+    assert_eq!(state.index_at((1, 0)), None);
+    assert_eq!(state.index_at((1, 1)), Some(2));
+}
+
+#[test]
+fn test_group_headers() {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut state = ListState::<RowSelection>::default();
+    let names = ["Anna", "Arne", "Axel", "Bea", "Bert", "Carl"];
+
+    let list = || {
+        List::<RowSelection>::new(names).group_headers(|i| {
+            let c = names[i].chars().next()?;
+            if i == 0 || !names[i - 1].starts_with(c) {
+                Some(c.to_string().into())
+            } else {
+                None
+            }
+        })
+    };
+
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert_eq!(state.row_items.len(), 9);
+    assert_eq!(state.item_rows, vec![1, 2, 3, 5, 6, 8]);
+    assert!(!state.sticky_header);
+
+    // inside group A
+    state.set_offset(2);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(state.sticky_header);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("A"));
+
+    // header B scrolled to the top.
+    state.set_offset(4);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(!state.sticky_header);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("B"));
+
+    state.set_offset(6);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(state.sticky_header);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("B"));
+    assert_eq!(state.index_at((1, 1)), None);
+    assert_eq!(state.index_at((1, 2)), Some(5));
+}
+
+#[test]
+fn test_group_headers_scroll_up() {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut state = ListState::<RowSelection>::default();
+    let names = ["Anna", "Arne", "Axel", "Bea", "Bert", "Carl"];
+
+    // headers before the items.
+    let list = || {
+        List::<RowSelection>::default()
+            .group_headers(|i| {
+                let c = names[i].chars().next()?;
+                if i == 0 || !names[i - 1].starts_with(c) {
+                    Some(c.to_string().into())
+                } else {
+                    None
+                }
+            })
+            .items(names)
+    };
+
+    state.set_offset(6);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert_eq!(state.item_rows, vec![1, 2, 3, 5, 6, 8]);
+
+    // "Arne" in the middle of group A.
+    state.select(Some(1));
+    assert!(state.scroll_to_selected());
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(state.sticky_header);
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some("A"));
+    assert_eq!(buf.cell((0, 1)).map(|v| v.symbol()), Some("A"));
+    assert_eq!(buf.cell((1, 1)).map(|v| v.symbol()), Some("r"));
+    assert_eq!(state.row_area(0), None);
+    assert_eq!(state.row_area(1), Some(Rect::new(0, 1, 10, 1)));
+
+    // "Anna" is covered, scroll once more.
+    state.select(Some(0));
+    assert!(state.scroll_to_selected());
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(!state.sticky_header);
+    assert_eq!(buf.cell((1, 1)).map(|v| v.symbol()), Some("n"));
+
+    // ensure_visible keeps the row above for the header.
+    state.set_offset(6);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(state.ensure_visible(2..3));
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert!(state.sticky_header);
+    assert_eq!(state.row_area(2), Some(Rect::new(0, 1, 10, 1)));
+    assert_eq!(buf.cell((1, 1)).map(|v| v.symbol()), Some("x"));
+}
+
+#[test]
+fn test_load_more() {
+    use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+    use rat_widget::list::{handle_load_more, ListOutcome};
+
+    let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    let area = Rect::new(0, 0, 10, 3);
+    let mut state = ListState::<RowSelection>::default();
+    let mut items = vec!["a0", "a1", "a2", "a3"];
+
+    let mut buf = Buffer::empty(area);
+    List::new(items.clone())
+        .has_more(true)
+        .render(area, &mut buf, &mut state);
+    assert!(!state.load_more());
+
+    state.select(Some(1));
+    let r = handle_load_more(&mut state, true, &key(KeyCode::Down));
+    assert_eq!(r, ListOutcome::Changed);
+    let r = handle_load_more(&mut state, true, &key(KeyCode::Down));
+    assert_eq!(r, ListOutcome::LoadMore);
+    assert_eq!(state.selected(), Some(3));
+    // only once.
+    let r = handle_load_more(&mut state, true, &key(KeyCode::Down));
+    assert_eq!(r, ListOutcome::Unchanged);
+    assert_eq!(state.selected(), Some(3));
+
+    state.set_offset(2);
+    let mut buf = Buffer::empty(area);
+    List::new(items.clone())
+        .has_more(true)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(buf.cell((0, 2)).map(|v| v.symbol()), Some("l"));
+    assert_eq!(state.index_at((0, 2)), None);
+
+    // more items rearm it.
+    items.extend(["a4", "a5"]);
+    let mut buf = Buffer::empty(area);
+    List::new(items.clone())
+        .has_more(true)
+        .render(area, &mut buf, &mut state);
+    let r = handle_load_more(&mut state, true, &key(KeyCode::Up));
+    assert_eq!(r, ListOutcome::Changed);
+    assert!(!state.at_end());
+    state.move_to(5);
+    assert!(state.load_more());
+    assert!(!state.load_more());
+
+    // no more items.
+    let mut buf = Buffer::empty(area);
+    List::new(items.clone())
+        .has_more(false)
+        .render(area, &mut buf, &mut state);
+    assert!(!state.at_end());
+    assert_eq!(state.row_items.len(), 0);
+}
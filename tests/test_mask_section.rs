@@ -0,0 +1,182 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_widget::date_input::DateInputState;
+use rat_widget::event::TextOutcome;
+use rat_widget::text_input_mask::section::{
+    current_section, handle_date_spin, handle_date_wheel, handle_spin, spin_date, spin_section,
+    WheelSteps,
+};
+use rat_widget::text_input_mask::MaskedInputState;
+use ratatui::layout::Rect;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn wheel(kind: MouseEventKind, x: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: x,
+        row: 0,
+        modifiers,
+    })
+}
+
+#[test]
+fn test_current_section() {
+    let mut state = MaskedInputState::new();
+    state.set_mask("99\\/99\\/9999").expect("mask");
+    state.set_text("24/12/2024");
+
+    state.set_cursor(0, false);
+    assert_eq!(current_section(&state), Some(0..2));
+    // right behind the section.
+    state.set_cursor(2, false);
+    assert_eq!(current_section(&state), Some(0..2));
+    state.set_cursor(3, false);
+    assert_eq!(current_section(&state), Some(3..5));
+    state.set_cursor(7, false);
+    assert_eq!(current_section(&state), Some(6..10));
+}
+
+#[test]
+fn test_spin_section() {
+    let mut state = MaskedInputState::new();
+    state.set_mask("99\\/99\\/9999").expect("mask");
+    state.set_text("24/12/2024");
+
+    state.set_cursor(4, false);
+    assert!(spin_section(&mut state, 1));
+    assert_eq!(state.text(), "24/13/2024");
+    assert_eq!(state.cursor(), 4);
+
+    // wraps within the section.
+    state.set_text("24/99/2024");
+    state.set_cursor(4, false);
+    assert!(spin_section(&mut state, 1));
+    assert_eq!(state.text(), "24/00/2024");
+    assert!(spin_section(&mut state, -1));
+    assert_eq!(state.text(), "24/99/2024");
+
+    // empty counts as 0.
+    state.set_text("  /  /    ");
+    state.set_cursor(0, false);
+    assert!(spin_section(&mut state, 1));
+    assert_eq!(state.text(), "01/  /    ");
+}
+
+#[test]
+fn test_spin_not_digits() {
+    let mut state = MaskedInputState::new();
+    state.set_mask("ll-99").expect("mask");
+    state.set_text("ab-12");
+    state.set_cursor(1, false);
+    assert!(!spin_section(&mut state, 1));
+    assert_eq!(state.text(), "ab-12");
+}
+
+#[test]
+fn test_spin_date() {
+    let mut state = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    state.widget.set_text("31.01.2024");
+
+    // day carries into the month.
+    state.widget.set_cursor(0, false);
+    assert!(spin_date(&mut state, 1));
+    assert_eq!(state.widget.text(), "01.02.2024");
+    assert_eq!(state.widget.cursor(), 0);
+
+    // month carries into the year.
+    state.widget.set_text("15.12.2024");
+    state.widget.set_cursor(4, false);
+    assert!(spin_date(&mut state, 1));
+    assert_eq!(state.widget.text(), "15.01.2025");
+
+    // year
+    state.widget.set_cursor(8, false);
+    assert!(spin_date(&mut state, -1));
+    assert_eq!(state.widget.text(), "15.01.2024");
+
+    // invalid date spins the section.
+    state.widget.set_text("31.02.2024");
+    state.widget.set_cursor(0, false);
+    assert!(spin_date(&mut state, 1));
+    assert_eq!(state.widget.text(), "32.02.2024");
+}
+
+#[test]
+fn test_handle_spin() {
+    let mut state = MaskedInputState::new();
+    state.set_mask("99\\:99").expect("mask");
+    state.set_text("10:30");
+    state.set_cursor(0, false);
+
+    assert_eq!(
+        handle_spin(&mut state, &key(KeyCode::Up)),
+        TextOutcome::Continue
+    );
+
+    state.focus.set(true);
+    assert_eq!(
+        handle_spin(&mut state, &key(KeyCode::Up)),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(state.text(), "11:30");
+    assert_eq!(
+        handle_spin(&mut state, &key(KeyCode::Down)),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(state.text(), "10:30");
+    assert_eq!(
+        handle_spin(&mut state, &key(KeyCode::Left)),
+        TextOutcome::Continue
+    );
+
+    let mut date = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    date.widget.set_text("28.02.2023");
+    date.widget.focus.set(true);
+    date.widget.set_cursor(0, false);
+    assert_eq!(
+        handle_date_spin(&mut date, &key(KeyCode::Up)),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(date.widget.text(), "01.03.2023");
+}
+
+#[test]
+fn test_date_wheel() {
+    let mut date = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    date.widget.area = Rect::new(0, 0, 10, 1);
+    date.widget.set_text("28.02.2023");
+    let steps = WheelSteps::new().step(1).shift_step(7);
+
+    // the month at the cursor gives the unit.
+    date.widget.set_cursor(4, false);
+    let up = wheel(MouseEventKind::ScrollUp, 2, KeyModifiers::NONE);
+    assert_eq!(
+        handle_date_wheel(&mut date, &steps, &up),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(date.widget.text(), "28.03.2023");
+
+    // larger step with Shift.
+    let down = wheel(MouseEventKind::ScrollDown, 2, KeyModifiers::SHIFT);
+    assert_eq!(
+        handle_date_wheel(&mut date, &steps, &down),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(date.widget.text(), "28.08.2022");
+
+    // not over the widget.
+    let outside = wheel(MouseEventKind::ScrollUp, 12, KeyModifiers::NONE);
+    assert_eq!(
+        handle_date_wheel(&mut date, &steps, &outside),
+        TextOutcome::Continue
+    );
+    assert_eq!(date.widget.text(), "28.08.2022");
+}
@@ -0,0 +1,38 @@
+use rat_widget::text_input_mask::MaskedInputState;
+
+fn date_input() -> MaskedInputState {
+    let mut state = MaskedInputState::new();
+    state.set_mask(r"##\/##\/####").expect("mask");
+    state.set_text("12/05/2024");
+    state
+}
+
+#[test]
+fn test_delete_across_literal() {
+    let mut state = date_input();
+    // '#' sections are numeric and align right.
+    state.set_selection(1, 4);
+    assert!(state.delete_next_char());
+    assert_eq!(state.text(), " 1/ 5/2024");
+
+    let mut state = date_input();
+    state.set_selection(1, 4);
+    assert!(state.delete_prev_char());
+    assert_eq!(state.text(), " 1/ 5/2024");
+}
+
+#[test]
+fn test_delete_all_sections() {
+    let mut state = date_input();
+    state.select_all();
+    assert!(state.delete_prev_char());
+    assert_eq!(state.text(), "  /  /    ");
+}
+
+#[test]
+fn test_delete_section_tail() {
+    let mut state = date_input();
+    state.set_selection(4, 8);
+    assert!(state.delete_next_char());
+    assert_eq!(state.text(), "12/ 0/  24");
+}
@@ -0,0 +1,87 @@
+use rat_widget::choice::{Choice, ChoiceState};
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::scroll_indicator::MoreIndicator;
+use rat_widget::scrolled::ScrollState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, StatefulWidget};
+
+fn symbol(buf: &Buffer, x: u16, y: u16) -> &str {
+    buf.cell((x, y)).expect("cell").symbol()
+}
+
+#[test]
+fn test_marks() {
+    let area = Rect::new(0, 0, 5, 3);
+    let mut scroll = ScrollState::new();
+    scroll.set_max_offset(4);
+
+    let mut buf = Buffer::empty(area);
+    MoreIndicator::new().render(area, &mut buf, &scroll);
+    assert_eq!(symbol(&buf, 4, 0), " ");
+    assert_eq!(symbol(&buf, 4, 2), "▼");
+
+    scroll.set_offset(2);
+    let mut buf = Buffer::empty(area);
+    MoreIndicator::new().render(area, &mut buf, &scroll);
+    assert_eq!(symbol(&buf, 4, 0), "▲");
+    assert_eq!(symbol(&buf, 4, 2), "▼");
+
+    scroll.set_offset(4);
+    let mut buf = Buffer::empty(area);
+    MoreIndicator::new()
+        .glyphs("^", "v")
+        .render(area, &mut buf, &scroll);
+    assert_eq!(symbol(&buf, 4, 0), "^");
+    assert_eq!(symbol(&buf, 4, 2), " ");
+}
+
+#[test]
+fn test_choice_popup() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 20));
+    let area = Rect::new(0, 0, 15, 1);
+    let mut state = ChoiceState::default();
+
+    let render = |state: &mut ChoiceState, buf: &mut Buffer| {
+        let (widget, popup) = Choice::new()
+            .auto_items((0..10).map(|v| format!("item {}", v)))
+            .popup_len(3)
+            .popup_block(Block::bordered())
+            .more_indicator(true)
+            .into_widgets();
+        widget.render(area, buf, state);
+        popup.render(area, buf, state);
+    };
+
+    render(&mut state, &mut buf);
+    state.select(Some(5));
+    state.set_popup_active(true);
+    render(&mut state, &mut buf);
+
+    let popup = state.popup.area;
+    let x = state.popup.widget_area.right() - 1;
+    assert_eq!(symbol(&buf, x, popup.top()), "▲");
+    assert_eq!(symbol(&buf, x, popup.bottom() - 1), "▼");
+}
+
+#[test]
+fn test_list() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+
+    List::new((0..20).map(|v| format!("{}", v)))
+        .more_indicator(true)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(symbol(&buf, 9, 0), " ");
+    assert_eq!(symbol(&buf, 9, 4), "▼");
+
+    state.scroll.set_offset(15);
+    let mut buf = Buffer::empty(area);
+    List::new((0..20).map(|v| format!("{}", v)))
+        .more_indicator(true)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(symbol(&buf, 9, 0), "▲");
+    assert_eq!(symbol(&buf, 9, 4), " ");
+}
@@ -0,0 +1,69 @@
+use rat_widget::msgdialog::{MsgDialog, MsgDialogState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Modifier, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, StatefulWidget};
+
+#[test]
+fn test_rich_text() {
+    let area = Rect::new(0, 0, 20, 8);
+    let mut buf = Buffer::empty(area);
+    let mut state = MsgDialogState::new();
+
+    state.append("one\ntwo");
+    let name = String::from("file");
+    state.append_line(Line::from(vec![
+        Span::from("x "),
+        Span::from(name.as_str()).bold(),
+    ]));
+    drop(name);
+    assert_eq!(state.message.borrow().lines.len(), 3);
+
+    MsgDialog::new()
+        .block(Block::bordered())
+        .render(area, &mut buf, &mut state);
+
+    // centered
+    assert_eq!(buf.cell((6, 3)).map(|v| v.symbol()), Some("x"));
+    assert_eq!(buf.cell((8, 3)).map(|v| v.symbol()), Some("f"));
+    assert!(buf
+        .cell((8, 3))
+        .map(|v| v.modifier.contains(Modifier::BOLD))
+        .unwrap_or(false));
+}
+
+#[test]
+fn test_append_lines() {
+    let area = Rect::new(0, 0, 20, 10);
+    let mut buf = Buffer::empty(area);
+    let mut state = MsgDialogState::new();
+    state.set_max_lines(Some(10));
+
+    state.append_lines(&["0", "1", "2", "3"]);
+    MsgDialog::new().render(area, &mut buf, &mut state);
+    let page = state.message.borrow().lines.len();
+    assert_eq!(page, 4);
+
+    // follows the bottom.
+    let lines = (4..20).map(|v| v.to_string()).collect::<Vec<_>>();
+    let lines = lines.iter().map(|v| v.as_str()).collect::<Vec<_>>();
+    state.append_lines(&lines);
+    MsgDialog::new().render(area, &mut buf, &mut state);
+
+    let message = state.message.borrow().clone();
+    assert_eq!(message.lines.len(), 10);
+    assert_eq!(message.lines[0].to_string(), "10");
+    assert_eq!(message.lines[9].to_string(), "19");
+
+    let text = (0..area.height)
+        .map(|y| {
+            (0..area.width)
+                .map(|x| buf.cell((x, y)).expect("cell").symbol())
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(text.contains("19"));
+    assert!(!text.contains("10 "));
+}
@@ -0,0 +1,50 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::number_input::rounding::{apply, handle_commit, round, Rounding};
+use rat_widget::number_input::NumberInputState;
+
+#[test]
+fn test_round() {
+    assert_eq!(round(2.675, 2, Rounding::HalfUp), 2.68);
+    assert_eq!(round(2.665, 2, Rounding::HalfEven), 2.66);
+    assert_eq!(round(2.675, 2, Rounding::HalfEven), 2.68);
+    assert_eq!(round(2.6751, 2, Rounding::HalfEven), 2.68);
+    assert_eq!(round(-2.675, 2, Rounding::HalfUp), -2.68);
+    assert_eq!(round(2.679, 2, Rounding::Truncate), 2.67);
+    assert_eq!(round(0.29, 2, Rounding::Truncate), 0.29);
+    assert_eq!(round(0.5, 0, Rounding::HalfEven), 0.0);
+    assert_eq!(round(1.5, 0, Rounding::HalfEven), 2.0);
+}
+
+#[test]
+fn test_apply() {
+    let mut state = NumberInputState::new()
+        .with_pattern("####0.00")
+        .expect("pattern");
+
+    state.widget.set_text("    1.5");
+    // padded with zeros.
+    assert!(apply(&mut state, 2, Rounding::HalfUp));
+    assert_eq!(state.value::<f64>().expect("value"), 1.5);
+    assert_eq!(state.widget.text().trim(), "1.50");
+
+    state.widget.set_text("");
+    assert!(!apply(&mut state, 2, Rounding::HalfUp));
+}
+
+#[test]
+fn test_commit() {
+    let mut state = NumberInputState::new()
+        .with_pattern("####0.000")
+        .expect("pattern");
+    state.set_value(2.675).expect("value");
+    state.widget.focus.set(true);
+
+    let enter = Event::Key(KeyEvent {
+        code: KeyCode::Enter,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    });
+    handle_commit(&mut state, &enter, 2, Rounding::HalfEven);
+    assert_eq!(state.value::<f64>().expect("value"), 2.68);
+}
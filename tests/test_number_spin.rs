@@ -0,0 +1,103 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_widget::event::TextOutcome;
+use rat_widget::number_input::spin::{handle_events, handle_mouse_events, NumberSpin};
+use rat_widget::number_input::NumberInputState;
+use ratatui::layout::Rect;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+fn wheel(kind: MouseEventKind, x: u16, modifiers: KeyModifiers) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: x,
+        row: 0,
+        modifiers,
+    })
+}
+
+fn state() -> NumberInputState {
+    let mut state = NumberInputState::new()
+        .with_pattern("####0.00")
+        .expect("pattern");
+    state.widget.area = Rect::new(0, 0, 8, 1);
+    state
+}
+
+#[test]
+fn test_spin() {
+    let mut state = state();
+    let spin = NumberSpin::new().step(0.5).range(0.0, 2.0);
+
+    // empty counts as 0.
+    assert!(spin.spin(&mut state, 0.5));
+    assert_eq!(state.value::<f64>().expect("value"), 0.5);
+
+    // clamped to the range.
+    assert!(spin.spin(&mut state, 5.0));
+    assert_eq!(state.value::<f64>().expect("value"), 2.0);
+    assert!(!spin.spin(&mut state, 1.0));
+    assert!(spin.spin(&mut state, -5.0));
+    assert_eq!(state.value::<f64>().expect("value"), 0.0);
+
+    // doesn't fit the pattern.
+    let spin = NumberSpin::new();
+    state.set_value(99999.0).expect("value");
+    let text = state.widget.text().to_string();
+    assert!(!spin.spin(&mut state, 1.0));
+    assert_eq!(state.widget.text(), text);
+}
+
+#[test]
+fn test_handle_keys() {
+    let mut state = state();
+    let spin = NumberSpin::new().step(1.0).shift_step(10.0);
+    state.set_value(5.0).expect("value");
+
+    assert_eq!(
+        handle_events(&mut state, &spin, &key(KeyCode::Up, KeyModifiers::NONE)),
+        TextOutcome::Continue
+    );
+
+    state.widget.focus.set(true);
+    assert_eq!(
+        handle_events(&mut state, &spin, &key(KeyCode::Up, KeyModifiers::NONE)),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(state.value::<f64>().expect("value"), 6.0);
+    assert_eq!(
+        handle_events(&mut state, &spin, &key(KeyCode::Down, KeyModifiers::SHIFT)),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(state.value::<f64>().expect("value"), -4.0);
+}
+
+#[test]
+fn test_handle_wheel() {
+    let mut state = state();
+    let spin = NumberSpin::new().step(1.0).shift_step(10.0);
+    state.set_value(5.0).expect("value");
+
+    // no focus needed, only over the widget.
+    let up = wheel(MouseEventKind::ScrollUp, 2, KeyModifiers::NONE);
+    assert_eq!(
+        handle_mouse_events(&mut state, &spin, &up),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(state.value::<f64>().expect("value"), 6.0);
+
+    let up = wheel(MouseEventKind::ScrollUp, 2, KeyModifiers::SHIFT);
+    assert_eq!(
+        handle_mouse_events(&mut state, &spin, &up),
+        TextOutcome::TextChanged
+    );
+    assert_eq!(state.value::<f64>().expect("value"), 16.0);
+
+    let outside = wheel(MouseEventKind::ScrollDown, 9, KeyModifiers::NONE);
+    assert_eq!(
+        handle_mouse_events(&mut state, &spin, &outside),
+        TextOutcome::Continue
+    );
+    assert_eq!(state.value::<f64>().expect("value"), 16.0);
+}
@@ -0,0 +1,50 @@
+use chrono::NaiveDate;
+use rat_widget::button::ButtonOutcome;
+use rat_widget::event::{CalOutcome, ConsumedEvent, MenuOutcome, Outcome, PagerOutcome};
+use rat_widget::rect_adjust::RectAdjustOutcome;
+
+#[test]
+fn test_consumed() {
+    assert!(!PagerOutcome::Continue.is_consumed());
+    assert!(PagerOutcome::Page(1).is_consumed());
+    assert!(!CalOutcome::Continue.is_consumed());
+    assert!(CalOutcome::Unchanged.is_consumed());
+    assert!(!ButtonOutcome::Continue.is_consumed());
+    assert!(ButtonOutcome::Pressed.is_consumed());
+    assert!(!MenuOutcome::Continue.is_consumed());
+}
+
+#[test]
+fn test_from() {
+    assert_eq!(PagerOutcome::from(true), PagerOutcome::Changed);
+    assert_eq!(CalOutcome::from(false), CalOutcome::Unchanged);
+    assert_eq!(ButtonOutcome::from(true), ButtonOutcome::Changed);
+    assert_eq!(ButtonOutcome::from(false), ButtonOutcome::Unchanged);
+    assert_eq!(RectAdjustOutcome::from(true), RectAdjustOutcome::Changed);
+
+    for o in [Outcome::Continue, Outcome::Unchanged, Outcome::Changed] {
+        assert_eq!(Outcome::from(PagerOutcome::from(o)), o);
+        assert_eq!(Outcome::from(CalOutcome::from(o)), o);
+        assert_eq!(Outcome::from(ButtonOutcome::from(o)), o);
+        assert_eq!(Outcome::from(MenuOutcome::from(o)), o);
+        assert_eq!(Outcome::from(RectAdjustOutcome::from(o)), o);
+    }
+
+    let day = NaiveDate::from_ymd_opt(2024, 1, 1).expect("date");
+    assert_eq!(Outcome::from(CalOutcome::Day(day)), Outcome::Changed);
+    assert_eq!(Outcome::from(PagerOutcome::Page(2)), Outcome::Changed);
+    assert_eq!(Outcome::from(ButtonOutcome::Pressed), Outcome::Changed);
+}
+
+#[test]
+fn test_chain() {
+    let r: Outcome = PagerOutcome::Continue.into();
+    let r = r.or_else(|| CalOutcome::Continue.into());
+    let r = r.or_else(|| ButtonOutcome::Pressed.into());
+    let r = r.or_else(|| panic!("consumed"));
+    assert_eq!(r, Outcome::Changed);
+
+    let r = ButtonOutcome::Continue.or_else(|| Outcome::Unchanged.into());
+    assert_eq!(r, ButtonOutcome::Unchanged);
+    assert!(ButtonOutcome::Pressed > ButtonOutcome::Changed);
+}
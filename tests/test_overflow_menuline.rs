@@ -0,0 +1,138 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_widget::event::MenuOutcome;
+use rat_widget::menu::overflow_menuline::{
+    handle_events, handle_popup_events, OverflowMenuLine, OverflowMenuLineState,
+};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn render(buf: &mut Buffer, width: u16, state: &mut OverflowMenuLineState) {
+    let (line, popup) = OverflowMenuLine::new()
+        .title("Sample")
+        .item_parsed("_File")
+        .item_parsed("_Edit")
+        .item_parsed("_Help")
+        .into_widgets();
+    let area = Rect::new(0, 9, width, 1);
+    line.render(area, buf, state);
+    popup.render(area, buf, state);
+}
+
+fn line(buf: &Buffer, width: u16) -> String {
+    (0..width)
+        .map(|x| buf.cell((x, 9)).expect("cell").symbol())
+        .collect()
+}
+
+#[test]
+fn test_fits() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = OverflowMenuLineState::new();
+
+    render(&mut buf, 30, &mut state);
+    assert_eq!(line(&buf, 22), "Sample File Edit Help ");
+    assert_eq!(state.first_hidden, None);
+    assert_eq!(state.area_more, Rect::default());
+}
+
+#[test]
+fn test_truncate_title() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = OverflowMenuLineState::new();
+
+    render(&mut buf, 18, &mut state);
+    assert_eq!(line(&buf, 18), "Sa… File Edit Help");
+    assert_eq!(state.first_hidden, None);
+}
+
+#[test]
+fn test_overflow() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = OverflowMenuLineState::new();
+
+    render(&mut buf, 14, &mut state);
+    assert_eq!(line(&buf, 14), "S… File Edit »");
+    assert_eq!(state.first_hidden, Some(2));
+    assert_eq!(state.line.item_areas[2], Rect::default());
+    assert_eq!(state.area_more, Rect::new(13, 9, 1, 1));
+}
+
+#[test]
+fn test_keys() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = OverflowMenuLineState::new();
+    render(&mut buf, 14, &mut state);
+
+    state.select(Some(1));
+    assert!(!state.popup_active());
+
+    let r = handle_events(&mut state, true, &key(KeyCode::Right));
+    assert_eq!(r, MenuOutcome::Selected(2));
+    assert!(state.popup_active());
+    assert_eq!(state.popup.selected(), Some(0));
+
+    render(&mut buf, 14, &mut state);
+    let r = handle_popup_events(&mut state, true, &key(KeyCode::Enter));
+    assert_eq!(r, MenuOutcome::Activated(2));
+
+    let r = handle_events(&mut state, true, &key(KeyCode::Left));
+    assert_eq!(r, MenuOutcome::Selected(1));
+    assert!(!state.popup_active());
+}
+
+#[test]
+fn test_mouse() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = OverflowMenuLineState::new();
+    render(&mut buf, 14, &mut state);
+
+    let r = handle_events(&mut state, true, &click(13, 9));
+    assert_eq!(r, MenuOutcome::Changed);
+    assert!(state.popup_active());
+
+    render(&mut buf, 14, &mut state);
+    let r = handle_popup_events(&mut state, true, &click(13, 9));
+    assert_eq!(r, MenuOutcome::Changed);
+    assert!(!state.popup_active());
+
+    let r = handle_events(&mut state, true, &click(3, 9));
+    assert_eq!(r, MenuOutcome::Selected(0));
+}
+
+#[test]
+fn test_wide_marker() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = OverflowMenuLineState::new();
+
+    let (bar, popup) = OverflowMenuLine::new()
+        .title("Sample")
+        .item_parsed("_File")
+        .item_parsed("_Edit")
+        .item_parsed("_Help")
+        .overflow("more")
+        .into_widgets();
+    let area = Rect::new(0, 9, 14, 1);
+    bar.render(area, &mut buf, &mut state);
+    popup.render(area, &mut buf, &mut state);
+
+    assert_eq!(line(&buf, 14), "S… File   more");
+    assert_eq!(state.first_hidden, Some(1));
+    assert_eq!(state.area_more, Rect::new(10, 9, 4, 1));
+    assert!(state.line.item_areas[0].right() < state.area_more.left());
+}
@@ -0,0 +1,82 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::button::ButtonState;
+use rat_widget::event::Outcome;
+use rat_widget::focus::{FocusBuilder, FocusFlag, HasFocus};
+use rat_widget::layout::GenericLayout;
+use rat_widget::pager::{handle_page_focus, SinglePagerState};
+use ratatui::layout::{Rect, Size};
+use std::rc::Rc;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_page_focus() {
+    let w = ["a", "b", "c", "d"].map(ButtonState::named);
+
+    // two widgets per page
+    let mut l = GenericLayout::<FocusFlag>::new();
+    l.set_page_size(Size::new(10, 2));
+    l.set_page_count(2);
+    for (i, w) in w.iter().enumerate() {
+        l.add(
+            w.focus(),
+            Rect::new(0, i as u16, 10, 1),
+            None,
+            Rect::default(),
+        );
+    }
+    let mut pager = SinglePagerState::new();
+    pager.set_layout(Rc::new(l));
+    let layout = pager.layout();
+
+    let mut fb = FocusBuilder::default();
+    for w in w.iter() {
+        fb.widget(w);
+    }
+    let focus = fb.build();
+
+    let tab = key(KeyCode::Tab, KeyModifiers::NONE);
+    let backtab = key(KeyCode::BackTab, KeyModifiers::SHIFT);
+
+    focus.focus(&w[1]);
+    let mut shown = None;
+    let r = handle_page_focus(&tab, &focus, &layout, |v| shown = layout.page_of(v));
+    assert_eq!(r, Outcome::Changed);
+    assert!(w[2].is_focused());
+    assert_eq!(shown, Some(1));
+
+    // wraps around
+    focus.focus(&w[3]);
+    let r = handle_page_focus(&tab, &focus, &layout, |v| shown = layout.page_of(v));
+    assert_eq!(r, Outcome::Changed);
+    assert!(w[0].is_focused());
+    assert_eq!(shown, Some(0));
+
+    let r = handle_page_focus(&backtab, &focus, &layout, |v| shown = layout.page_of(v));
+    assert_eq!(r, Outcome::Changed);
+    assert!(w[3].is_focused());
+    assert_eq!(shown, Some(1));
+}
+
+#[test]
+fn test_page_focus_outside() {
+    let w = ["a", "b"].map(ButtonState::named);
+    let mut l = GenericLayout::<FocusFlag>::new();
+    l.add(w[0].focus(), Rect::new(0, 0, 10, 1), None, Rect::default());
+
+    let mut fb = FocusBuilder::default();
+    fb.widget(&w[0]).widget(&w[1]);
+    let focus = fb.build();
+
+    focus.focus(&w[1]);
+    let tab = key(KeyCode::Tab, KeyModifiers::NONE);
+    let r = handle_page_focus(&tab, &focus, &l, |_| {});
+    assert_eq!(r, Outcome::Continue);
+}
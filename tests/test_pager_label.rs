@@ -0,0 +1,48 @@
+use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
+use rat_widget::pager::{SinglePager, SinglePagerState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::Padding;
+use std::cell::Cell;
+use std::rc::Rc;
+
+#[test]
+fn test_render_label_with() {
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buf = Buffer::empty(area);
+
+    let pager = SinglePager::new()
+        .label_style(Style::new().bold())
+        .label_alignment(Alignment::Right);
+    let page = pager.layout_size(area);
+
+    let mut form = LayoutForm::<usize>::new();
+    for i in 0..10 {
+        form.widget(i, FormLabel::Width(5), FormWidget::Size(5, 1));
+    }
+    let mut state = SinglePagerState::new();
+    state.set_layout(Rc::new(form.paged(page, Padding::default())));
+    assert!(state.layout().page_count() > 1);
+
+    let calls = Cell::new(0);
+    let mut pager = pager.into_buffer(area, &mut buf, &mut state);
+    for i in 0..10 {
+        pager.render_label_with(i, || {
+            calls.set(calls.get() + 1);
+            Line::from(format!("{}", i))
+        });
+    }
+    drop(pager);
+
+    // only the first page.
+    assert!(calls.get() > 0 && calls.get() < 10);
+
+    let label = state.layout().label(0);
+    let cell = buf
+        .cell((label.x + label.width - 1, label.y + 1))
+        .expect("cell");
+    assert_eq!(cell.symbol(), "0");
+    assert!(cell.modifier.contains(Modifier::BOLD));
+}
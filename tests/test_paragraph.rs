@@ -0,0 +1,127 @@
+use rat_widget::paragraph::{Paragraph, ParagraphState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{StatefulWidget, Wrap};
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect()
+}
+
+#[test]
+fn test_wrap_marker() {
+    let area = Rect::new(0, 0, 7, 4);
+    let mut buf = Buffer::empty(area);
+    let mut state = ParagraphState::new();
+    Paragraph::new("aaa bbb ccc\nddd")
+        .wrap(Wrap { trim: true })
+        .wrap_marker(Some('↩'))
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(state.lines, 4);
+    assert_eq!(row(&buf, 0), "aaa   ↩");
+    assert_eq!(row(&buf, 1), "bbb   ↩");
+    assert_eq!(row(&buf, 2), "ccc    ");
+    assert_eq!(row(&buf, 3), "ddd    ");
+}
+
+#[test]
+fn test_wrap_marker_scrolled() {
+    let area = Rect::new(0, 0, 7, 2);
+    let mut buf = Buffer::empty(area);
+    let mut state = ParagraphState::new();
+    state.vscroll.set_max_offset(10);
+    state.set_line_offset(1);
+    Paragraph::new("aaa bbb ccc ddd eee")
+        .wrap(Wrap { trim: true })
+        .wrap_marker(Some('↩'))
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(row(&buf, 0), "bbb   ↩");
+    assert_eq!(row(&buf, 1), "ccc   ↩");
+}
+
+#[test]
+fn test_scroll_to_line() {
+    let area = Rect::new(0, 0, 6, 3);
+    let mut buf = Buffer::empty(area);
+    let mut state = ParagraphState::new();
+    let text = "aaa bbb\nccc\nddd eee fff\nggg";
+    Paragraph::new(text)
+        .wrap(Wrap { trim: true })
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.line_count(), 7);
+
+    assert!(state.scroll_to_line(2));
+    assert_eq!(state.line_offset(), 3);
+
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(text)
+        .wrap(Wrap { trim: true })
+        .render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "ddd   ");
+    assert_eq!(row(&buf, 2), "fff   ");
+
+    // offset in the middle of a wrapped line.
+    state.set_line_offset(4);
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(text)
+        .wrap(Wrap { trim: true })
+        .render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "eee   ");
+    assert_eq!(row(&buf, 2), "ggg   ");
+}
+
+#[test]
+fn test_layout_cache() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut state = ParagraphState::new();
+
+    let text = (0..10_000)
+        .map(|v| format!("line {} with some words to wrap", v))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(text.as_str())
+        .wrap(Wrap { trim: true })
+        .text_id(1)
+        .render(area, &mut buf, &mut state);
+    let lines = state.line_count();
+    assert!(lines > 10_000);
+
+    state.set_line_offset(lines - 1);
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(text.as_str())
+        .wrap(Wrap { trim: true })
+        .text_id(1)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "wrap      ");
+
+    // same id, the cached layout is used.
+    let other = vec!["x"; 10_000].join("\n");
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(other.as_str())
+        .wrap(Wrap { trim: true })
+        .text_id(1)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.line_count(), lines);
+
+    // new id
+    state.set_line_offset(0);
+    let mut buf = Buffer::empty(area);
+    Paragraph::new(other.as_str())
+        .wrap(Wrap { trim: true })
+        .text_id(2)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.line_count(), 10_000);
+    assert_eq!(row(&buf, 0), "x         ");
+
+    // without id the text is hashed.
+    let mut buf = Buffer::empty(area);
+    Paragraph::new("short")
+        .wrap(Wrap { trim: true })
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.line_count(), 1);
+    assert_eq!(row(&buf, 0), "short     ");
+}
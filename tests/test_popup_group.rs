@@ -0,0 +1,29 @@
+use rat_widget::choice::ChoiceState;
+use rat_widget::popup::PopupGroup;
+
+#[test]
+fn test_single_open() {
+    let mut c1 = ChoiceState::<usize>::default();
+    let mut c2 = ChoiceState::<usize>::default();
+    let mut group = PopupGroup::new();
+
+    c1.set_popup_active(true);
+    assert!(!group.update(&mut [&mut c1.popup, &mut c2.popup]));
+    assert!(c1.popup_active());
+    assert_eq!(group.active, Some(0));
+
+    c2.set_popup_active(true);
+    assert!(group.update(&mut [&mut c1.popup, &mut c2.popup]));
+    assert!(!c1.popup_active());
+    assert!(c2.popup_active());
+    assert_eq!(group.active, Some(1));
+
+    c1.set_popup_active(true);
+    assert!(group.update(&mut [&mut c1.popup, &mut c2.popup]));
+    assert!(c1.popup_active());
+    assert!(!c2.popup_active());
+
+    assert!(group.close_all(&mut [&mut c1.popup, &mut c2.popup]));
+    assert!(!c1.popup_active());
+    assert_eq!(group.active, None);
+}
@@ -0,0 +1,44 @@
+use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_widget::prelude::*;
+use rat_widget::view;
+use ratatui::layout::Rect;
+
+fn wheel_down(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::ScrollDown,
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_named() {
+    let slider = SliderState::<u8>::named("volume");
+    assert_eq!(slider.focus.name(), "volume");
+    let slider = SliderState::<f32>::named("balance");
+    assert_eq!(slider.focus.name(), "balance");
+}
+
+#[test]
+fn test_popup_active() {
+    let mut choice = ChoiceState::<usize>::named("choice");
+    assert!(!choice.popup_active());
+    choice.set_popup_active(true);
+    assert!(choice.popup_active());
+}
+
+#[test]
+fn test_view_events() {
+    let mut state = ViewState::new();
+    state.widget_area = Rect::new(0, 0, 10, 10);
+    state.vscroll.set_page_len(10);
+    state.vscroll.set_max_offset(20);
+
+    let r = view::handle_mouse_events(&mut state, &wheel_down(20, 20));
+    assert_eq!(r, Outcome::Continue);
+
+    let r = view::handle_events(&mut state, true, &wheel_down(5, 5));
+    assert_eq!(r, Outcome::Changed);
+    assert!(state.vscroll.offset() > 0);
+}
@@ -0,0 +1,122 @@
+use chrono::NaiveDate;
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
+use rat_widget::calendar::{quick_select, PickerView, QuickSelect, QuickSelectState};
+use rat_widget::event::CalOutcome;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn click(area: Rect) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: area.x,
+        row: area.y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).expect("date")
+}
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf[(x, y)].symbol())
+        .collect::<String>()
+}
+
+fn render(state: &mut QuickSelectState) -> Buffer {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 21, 5));
+    QuickSelect::new().render(buf.area, &mut buf, state);
+    buf
+}
+
+#[test]
+fn test_header() {
+    let mut state = QuickSelectState::new();
+    state.set_date(date(2024, 3, 15));
+    assert_eq!(state.date(), date(2024, 3, 1));
+
+    let buf = render(&mut state);
+    assert_eq!(row(&buf, 0), "‹    March 2024     ›");
+    assert_eq!(state.inner, Rect::new(0, 1, 21, 4));
+
+    let event = click(state.area_next);
+    assert_eq!(
+        quick_select::handle_mouse_events(&mut state, &event),
+        CalOutcome::Changed
+    );
+    assert_eq!(state.date(), date(2024, 4, 1));
+}
+
+#[test]
+fn test_month_grid() {
+    let mut state = QuickSelectState::new();
+    state.set_date(date(2024, 3, 1));
+    render(&mut state);
+
+    let event = click(state.area_month);
+    assert_eq!(
+        quick_select::handle_mouse_events(&mut state, &event),
+        CalOutcome::Changed
+    );
+    assert_eq!(state.view(), PickerView::Month);
+    assert_eq!(state.cursor, 2);
+
+    let buf = render(&mut state);
+    assert_eq!(row(&buf, 1).trim_end(), " Jan    Feb    Mar");
+    assert_eq!(row(&buf, 4).trim_end(), " Oct    Nov    Dec");
+
+    quick_select::handle_events(&mut state, true, &key(KeyCode::Down, KeyModifiers::NONE));
+    assert_eq!(state.cursor, 5);
+    assert_eq!(
+        quick_select::handle_events(&mut state, true, &key(KeyCode::Enter, KeyModifiers::NONE)),
+        CalOutcome::Changed
+    );
+    assert_eq!(state.view(), PickerView::Day);
+    assert_eq!(state.date(), date(2024, 6, 1));
+}
+
+#[test]
+fn test_year_grid() {
+    let mut state = QuickSelectState::new();
+    state.set_date(date(2024, 3, 1));
+    render(&mut state);
+
+    quick_select::handle_events(&mut state, true, &key(KeyCode::Up, KeyModifiers::CONTROL));
+    quick_select::handle_events(&mut state, true, &key(KeyCode::Up, KeyModifiers::CONTROL));
+    assert_eq!(state.view(), PickerView::Year);
+    assert_eq!(state.year_start, 2016);
+    assert_eq!(state.cursor, 8);
+
+    quick_select::handle_events(
+        &mut state,
+        true,
+        &key(KeyCode::PageDown, KeyModifiers::NONE),
+    );
+    assert_eq!(state.year_start, 2028);
+
+    let buf = render(&mut state);
+    assert_eq!(row(&buf, 1).trim_end(), " 2028   2029   2030");
+
+    let event = click(state.area_items[1]);
+    quick_select::handle_mouse_events(&mut state, &event);
+    assert_eq!(state.view(), PickerView::Month);
+    assert_eq!(state.date(), date(2029, 3, 1));
+
+    quick_select::handle_events(&mut state, true, &key(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(state.view(), PickerView::Day);
+    assert_eq!(state.date(), date(2029, 3, 1));
+}
@@ -0,0 +1,112 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::event::{HandleEvent, Readline, Regular, TextOutcome};
+use rat_widget::text::clipboard::LocalClipboard;
+use rat_widget::text_input::TextInputState;
+use rat_widget::text_input_mask::MaskedInputState;
+
+fn key(c: char, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code: KeyCode::Char(c),
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+fn ctrl(c: char) -> Event {
+    key(c, KeyModifiers::CONTROL)
+}
+
+fn alt(c: char) -> Event {
+    key(c, KeyModifiers::ALT)
+}
+
+fn text_state(text: &str) -> TextInputState {
+    let mut state = TextInputState::new();
+    state.set_clipboard(Some(LocalClipboard::new()));
+    state.set_text(text);
+    state.focus.set(true);
+    state
+}
+
+#[test]
+fn test_motion() {
+    let mut state = text_state("hello big world");
+    state.set_cursor(5, false);
+
+    state.handle(&ctrl('a'), Readline);
+    assert_eq!(state.cursor(), 0);
+    assert!(!state.has_selection());
+    state.handle(&ctrl('e'), Readline);
+    assert_eq!(state.cursor(), 15);
+    state.handle(&alt('b'), Readline);
+    assert_eq!(state.cursor(), 10);
+    state.set_cursor(0, false);
+    state.handle(&alt('f'), Readline);
+    assert_ne!(state.cursor(), 0);
+}
+
+#[test]
+fn test_kill_yank() {
+    let mut state = text_state("hello big world");
+    state.set_cursor(9, false);
+
+    let r = state.handle(&ctrl('k'), Readline);
+    assert_eq!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "hello big");
+
+    state.handle(&ctrl('a'), Readline);
+    state.handle(&ctrl('y'), Readline);
+    assert_eq!(state.text(), " worldhello big");
+
+    state.set_cursor(6, false);
+    state.handle(&ctrl('u'), Readline);
+    assert_eq!(state.text(), "hello big");
+    assert_eq!(state.cursor(), 0);
+
+    state.handle(&ctrl('e'), Readline);
+    state.handle(&ctrl('w'), Readline);
+    assert_eq!(state.text(), "hello ");
+    state.handle(&ctrl('y'), Readline);
+    assert_eq!(state.text(), "hello big");
+}
+
+#[test]
+fn test_regular_unchanged() {
+    let mut state = text_state("hello");
+    state.set_cursor(2, false);
+    // regular keeps select-all
+    state.handle(&ctrl('a'), Regular);
+    assert!(state.has_selection());
+    // everything else falls through to regular
+    state.set_cursor(5, false);
+    state.handle(&key('!', KeyModifiers::NONE), Readline);
+    assert_eq!(state.text(), "hello!");
+}
+
+#[test]
+fn test_not_focused() {
+    let mut state = text_state("hello");
+    state.focus.set(false);
+    let r = state.handle(&ctrl('k'), Readline);
+    assert_eq!(r, TextOutcome::Continue);
+    assert_eq!(state.text(), "hello");
+}
+
+#[test]
+fn test_masked() {
+    let mut state = MaskedInputState::new();
+    state.set_mask(r"##\.##\.####").expect("mask");
+    state.set_clipboard(Some(LocalClipboard::new()));
+    state.set_text("12.05.2024");
+    state.focus.set(true);
+
+    state.handle(&ctrl('a'), Readline);
+    assert_eq!(state.cursor(), 0);
+    state.handle(&alt('f'), Readline);
+    assert_ne!(state.cursor(), 0);
+
+    state.set_cursor(3, false);
+    state.handle(&ctrl('k'), Readline);
+    assert_eq!(state.text(), "12.  .    ");
+}
@@ -0,0 +1,64 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::rect_adjust::{handle_events, RectAdjust, RectAdjustOutcome, RectAdjustState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_move_resize() {
+    let mut state = RectAdjustState::new().with_boundary(Rect::new(0, 0, 20, 10));
+
+    let r = handle_events(&mut state, &key(KeyCode::Left, KeyModifiers::NONE));
+    assert_eq!(r, RectAdjustOutcome::Continue);
+
+    state.start(Rect::new(2, 2, 5, 3));
+    let r = handle_events(&mut state, &key(KeyCode::Right, KeyModifiers::NONE));
+    assert_eq!(r, RectAdjustOutcome::Changed);
+    assert_eq!(state.rect, Rect::new(3, 2, 5, 3));
+
+    handle_events(&mut state, &key(KeyCode::Down, KeyModifiers::SHIFT));
+    assert_eq!(state.rect, Rect::new(3, 2, 5, 4));
+
+    // clamped to the boundary
+    for _ in 0..20 {
+        handle_events(&mut state, &key(KeyCode::Right, KeyModifiers::NONE));
+    }
+    assert_eq!(state.rect, Rect::new(15, 2, 5, 4));
+    let r = handle_events(&mut state, &key(KeyCode::Right, KeyModifiers::SHIFT));
+    assert_eq!(r, RectAdjustOutcome::Unchanged);
+
+    // min size
+    for _ in 0..10 {
+        handle_events(&mut state, &key(KeyCode::Up, KeyModifiers::SHIFT));
+    }
+    assert_eq!(state.rect, Rect::new(15, 2, 5, 1));
+
+    let r = handle_events(&mut state, &key(KeyCode::Enter, KeyModifiers::NONE));
+    assert_eq!(r, RectAdjustOutcome::Confirm(Rect::new(15, 2, 5, 1)));
+    assert!(!state.is_active());
+}
+
+#[test]
+fn test_cancel() {
+    let mut state = RectAdjustState::new();
+    state.start(Rect::new(2, 2, 5, 3));
+    handle_events(&mut state, &key(KeyCode::Left, KeyModifiers::NONE));
+    assert_eq!(state.rect, Rect::new(1, 2, 5, 3));
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 10, 10));
+    RectAdjust::new().render(buf.area, &mut buf, &mut state);
+    assert_eq!(buf.cell((1, 2)).map(|v| v.symbol()), Some("┌"));
+
+    let r = handle_events(&mut state, &key(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(r, RectAdjustOutcome::Cancel(Rect::new(2, 2, 5, 3)));
+    assert_eq!(state.rect, Rect::new(2, 2, 5, 3));
+}
@@ -0,0 +1,58 @@
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::util::{revert_style, set_render_mode, RenderMode};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::StatefulWidget;
+
+#[test]
+fn test_revert_style() {
+    let style = Style::new().fg(Color::Black).bg(Color::LightBlue);
+
+    set_render_mode(RenderMode::Normal);
+    assert_eq!(
+        revert_style(style),
+        Style::new().fg(Color::LightBlue).bg(Color::Black)
+    );
+
+    set_render_mode(RenderMode::HighContrast);
+    assert_eq!(
+        revert_style(style),
+        Style::new().fg(Color::White).bg(Color::Black)
+    );
+    assert_eq!(
+        revert_style(Style::new().fg(Color::Gray).bg(Color::Blue)),
+        Style::new().fg(Color::Black).bg(Color::White)
+    );
+
+    set_render_mode(RenderMode::NoColor);
+    assert_eq!(
+        revert_style(style),
+        Style::new().add_modifier(Modifier::REVERSED | Modifier::BOLD)
+    );
+
+    set_render_mode(RenderMode::Normal);
+}
+
+#[test]
+fn test_list_marker() {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut state = ListState::<RowSelection>::default();
+    state.select(Some(1));
+
+    let mut buf = Buffer::empty(area);
+    List::new(["a", "b", "c"]).render(area, &mut buf, &mut state);
+    assert_eq!(buf.cell((0, 1)).map(|v| v.symbol()), Some("b"));
+
+    set_render_mode(RenderMode::NoColor);
+    let mut buf = Buffer::empty(area);
+    List::new(["a", "b", "c"]).render(area, &mut buf, &mut state);
+    set_render_mode(RenderMode::Normal);
+
+    assert_eq!(buf.cell((0, 0)).map(|v| v.symbol()), Some(" "));
+    assert_eq!(buf.cell((2, 0)).map(|v| v.symbol()), Some("a"));
+    assert_eq!(buf.cell((0, 1)).map(|v| v.symbol()), Some(">"));
+    assert_eq!(buf.cell((2, 1)).map(|v| v.symbol()), Some("b"));
+    assert_eq!(state.row_area(1), Some(Rect::new(0, 1, 10, 1)));
+}
@@ -0,0 +1,32 @@
+use rat_widget::render_timing::{render_report, reset_render_report, timed};
+use rat_widget::view::{View, ViewState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+#[test]
+fn test_timed() {
+    reset_render_report();
+
+    let r = timed("Table", "orders", || 42);
+    assert_eq!(r, 42);
+
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ViewState::default();
+    let view = View::new().layout(area).into_buffer(area, &mut state);
+    view.into_widget().render(area, &mut buf, &mut state);
+
+    let report = render_report();
+    if cfg!(feature = "render-timing") {
+        assert_eq!(report.len(), 2);
+        assert_eq!(report[0].widget, "Table");
+        assert_eq!(report[0].name, "orders");
+        assert_eq!(report[1].widget, "View");
+    } else {
+        assert!(report.is_empty());
+    }
+
+    reset_render_report();
+    assert!(render_report().is_empty());
+}
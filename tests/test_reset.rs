@@ -0,0 +1,65 @@
+use rat_widget::reset::{reset_form, reset_to_mask, TextReset};
+use rat_widget::text_input::TextInputState;
+use rat_widget::text_input_mask::MaskedInputState;
+use rat_widget::textarea::TextAreaState;
+
+#[test]
+fn test_text_input() {
+    let mut state = TextInputState::new();
+    TextReset::set_value(&mut state, "hello");
+    assert_eq!(state.text(), "hello");
+    assert_eq!(state.cursor(), 0);
+
+    state.set_selection(1, 4);
+    state.set_offset(2);
+    assert!(state.reset());
+    assert!(TextReset::is_empty(&state));
+    assert_eq!(state.cursor(), 0);
+    assert!(!state.has_selection());
+    assert_eq!(state.offset(), 0);
+    assert!(!state.reset());
+}
+
+#[test]
+fn test_masked_input() {
+    let mut state = MaskedInputState::new();
+    state.set_mask("##.##").expect("mask");
+    let empty = state.text().to_string();
+
+    TextReset::set_value(&mut state, "12.34");
+    assert_eq!(state.text(), "12.34");
+    assert!(!TextReset::is_empty(&state));
+
+    state.set_cursor(3, false);
+    assert!(reset_to_mask(&mut state));
+    assert_eq!(state.text(), empty);
+    assert!(TextReset::is_empty(&state));
+    assert_eq!(state.offset(), 0);
+    assert!(!state.reset());
+}
+
+#[test]
+fn test_textarea() {
+    let mut state = TextAreaState::new();
+    TextReset::set_value(&mut state, "one\ntwo\nthree");
+    assert_eq!(state.len_lines(), 3);
+
+    state.set_cursor((2, 2), false);
+    state.vscroll.set_offset(1);
+    assert!(state.reset());
+    assert!(TextReset::is_empty(&state));
+    assert_eq!(state.cursor(), (0, 0).into());
+    assert!(!state.has_selection());
+    assert_eq!(state.vscroll.offset(), 0);
+}
+
+#[test]
+fn test_reset_form() {
+    let mut name = TextInputState::new();
+    let mut notes = TextAreaState::new();
+    assert!(!reset_form(&mut [&mut name, &mut notes]));
+
+    TextReset::set_value(&mut notes, "notes");
+    assert!(reset_form(&mut [&mut name, &mut notes]));
+    assert!(TextReset::is_empty(&notes));
+}
@@ -0,0 +1,90 @@
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::scroll_indicator::{
+    scroll_fraction, ScrollIndicator, ScrollIndicatorOverlay, ScrollIndicatorState,
+};
+use rat_widget::scrolled::ScrollState;
+use rat_widget::view::ViewState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn row(buf: &Buffer, y: u16) -> String {
+    (buf.area.left()..buf.area.right())
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect()
+}
+
+#[test]
+fn test_label() {
+    let mut scroll = ScrollState::default();
+    scroll.set_max_offset(180);
+    scroll.set_page_len(20);
+    scroll.set_offset(81);
+
+    let overlay = ScrollIndicatorOverlay::new(ScrollIndicator::Percent);
+    assert_eq!(overlay.label(&scroll), "45%");
+    let overlay = ScrollIndicatorOverlay::new(ScrollIndicator::Lines);
+    assert_eq!(overlay.label(&scroll), "82/200");
+}
+
+#[test]
+fn test_fade() {
+    let mut scroll = ScrollState::default();
+    scroll.set_max_offset(10);
+    let mut state = ScrollIndicatorState::new();
+
+    // first render only records.
+    assert!(!state.update(&scroll, 2));
+    scroll.set_offset(1);
+    assert!(state.update(&scroll, 2));
+    assert!(state.update(&scroll, 2));
+    assert!(!state.update(&scroll, 2));
+    scroll.set_offset(2);
+    assert!(state.update(&scroll, 2));
+}
+
+#[test]
+fn test_list() {
+    let area = Rect::new(0, 0, 12, 4);
+    let list = || {
+        List::<RowSelection>::new((0..20).map(|v| format!("item {}", v)))
+            .indicator(ScrollIndicator::Lines)
+    };
+    let mut state = ListState::<RowSelection>::default();
+
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 3), "item 3      ");
+
+    state.scroll.set_offset(5);
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 0), "item 5      ");
+    assert_eq!(row(&buf, 3), "item 8 6/20 ");
+
+    for _ in 0..10 {
+        list().render(area, &mut buf, &mut state);
+    }
+    let mut buf = Buffer::empty(area);
+    list().render(area, &mut buf, &mut state);
+    assert_eq!(row(&buf, 3), "item 8      ");
+}
+
+#[test]
+fn test_fraction() {
+    let mut scroll = ScrollState::default();
+    assert_eq!(scroll_fraction(&scroll), 1.0);
+    scroll.set_max_offset(200);
+    assert_eq!(scroll_fraction(&scroll), 0.0);
+    scroll.set_offset(50);
+    assert_eq!(scroll_fraction(&scroll), 0.25);
+    scroll.set_offset(200);
+    assert_eq!(scroll_fraction(&scroll), 1.0);
+
+    let mut state = ViewState::new();
+    state.vscroll.set_max_offset(10);
+    state.vscroll.set_offset(5);
+    assert_eq!(state.vertical_fraction(), 0.5);
+    assert_eq!(state.horizontal_fraction(), 1.0);
+}
@@ -0,0 +1,51 @@
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::paragraph::{Paragraph, ParagraphState};
+use rat_widget::scroll_step::ScrollStep;
+use rat_widget::scrolled::ScrollState;
+use rat_widget::view::{View, ViewState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+#[test]
+fn test_apply() {
+    let mut scroll = ScrollState::new();
+    scroll.set_page_len(20);
+    // default 1/10 of the page
+    assert_eq!(scroll.scroll_by(), 2);
+
+    ScrollStep::Lines(3).apply(&mut scroll);
+    assert_eq!(scroll.scroll_by(), 3);
+    ScrollStep::Page.apply(&mut scroll);
+    assert_eq!(scroll.scroll_by(), 20);
+    ScrollStep::Lines(0).apply(&mut scroll);
+    assert_eq!(scroll.scroll_by(), 1);
+}
+
+#[test]
+fn test_widgets() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+
+    let mut state = ListState::<RowSelection>::default();
+    List::new((0..50).map(|v| v.to_string()))
+        .scroll_step(ScrollStep::Lines(3))
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.scroll.scroll_by(), 3);
+
+    let mut state = ParagraphState::default();
+    Paragraph::new("a\nb\nc\nd\ne\nf\ng")
+        .scroll_step(ScrollStep::Page)
+        .render(area, &mut buf, &mut state);
+    assert_eq!(state.vscroll.scroll_by(), 5);
+
+    let mut state = ViewState::default();
+    let view = View::new()
+        .layout(Rect::new(0, 0, 10, 50))
+        .scroll_step(ScrollStep::Page)
+        .into_buffer(area, &mut state);
+    view.into_widget().render(area, &mut buf, &mut state);
+    assert_eq!(state.vscroll.scroll_by(), 5);
+    assert_eq!(state.hscroll.scroll_by(), 10);
+}
@@ -0,0 +1,60 @@
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::scroll_to::ScrollTo;
+use rat_widget::text::{TextPosition, TextRange};
+use rat_widget::textarea::TextAreaState;
+use rat_widget::view::ViewState;
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+#[test]
+fn test_list() {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut state = ListState::<RowSelection>::default();
+    let mut buf = Buffer::empty(area);
+    List::<RowSelection>::default()
+        .header("A")
+        .items(["a0", "a1", "a2", "a3", "a4", "a5"])
+        .render(area, &mut buf, &mut state);
+
+    assert!(!state.ensure_visible(0..1));
+    // item rows are shifted by the header.
+    assert!(state.ensure_visible(3..5));
+    assert_eq!(state.offset(), 3);
+    assert!(!state.ensure_visible(4..4));
+    assert!(state.ensure_visible(0..0));
+    assert_eq!(state.offset(), 1);
+}
+
+#[test]
+fn test_view() {
+    let mut state = ViewState::new();
+    state.vscroll.set_page_len(10);
+    state.vscroll.set_max_offset(100);
+    state.hscroll.set_page_len(20);
+    state.hscroll.set_max_offset(100);
+
+    assert!(!state.ensure_visible(Rect::new(5, 5, 10, 2)));
+    assert!(state.ensure_visible(Rect::new(30, 15, 10, 2)));
+    assert_eq!(state.hscroll.offset(), 20);
+    assert_eq!(state.vscroll.offset(), 7);
+    // bigger than the page shows the start.
+    assert!(state.ensure_visible(Rect::new(0, 40, 5, 20)));
+    assert_eq!(state.vscroll.offset(), 40);
+    assert_eq!(state.hscroll.offset(), 0);
+}
+
+#[test]
+fn test_textarea() {
+    let mut state = TextAreaState::new();
+    state.vscroll.set_page_len(5);
+    state.vscroll.set_max_offset(100);
+    state.hscroll.set_page_len(10);
+    state.hscroll.set_max_offset(100);
+
+    let r = TextRange::new(TextPosition::new(12, 20), TextPosition::new(15, 20));
+    assert!(state.ensure_visible(r));
+    assert_eq!(state.vscroll.offset(), 16);
+    assert_eq!(state.hscroll.offset(), 5);
+}
@@ -0,0 +1,64 @@
+use rat_widget::date_input::DateInputState;
+use rat_widget::number_input::NumberInputState;
+use rat_widget::text_input::select_on_focus::{SelectBehavior, SelectOnFocus};
+use rat_widget::text_input::TextInputState;
+
+fn text() -> TextInputState {
+    let mut state = TextInputState::new();
+    state.set_text("hello");
+    state.set_cursor(2, false);
+    state.focus.set(true);
+    state
+}
+
+#[test]
+fn test_text_input() {
+    let mut state = text();
+    let select = SelectOnFocus::new().select_on_focus(SelectBehavior::SelectAll);
+
+    // not gained
+    assert!(!select.apply_text_input(&mut state));
+    assert_eq!(state.selection(), 2..2);
+
+    state.focus.set_gained(true);
+    assert!(select.apply_text_input(&mut state));
+    assert_eq!(state.selection(), 0..5);
+
+    let mut state = text();
+    state.focus.set_gained(true);
+    let select = SelectOnFocus::new().select_on_focus(SelectBehavior::CursorEnd);
+    assert!(select.apply_text_input(&mut state));
+    assert_eq!(state.cursor(), 5);
+    assert_eq!(state.selection(), 5..5);
+
+    let mut state = text();
+    state.focus.set_gained(true);
+    assert!(!SelectOnFocus::new().apply_text_input(&mut state));
+    assert_eq!(state.cursor(), 2);
+}
+
+#[test]
+fn test_masked_input() {
+    let mut state = DateInputState::new()
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+    state.widget.set_cursor(4, false);
+    state.widget.focus.set(true);
+    state.widget.focus.set_gained(true);
+
+    // right-aligned day section
+    assert!(SelectOnFocus::new().apply_masked_input(&mut state.widget));
+    assert_eq!(state.widget.cursor(), 2);
+
+    let select = SelectOnFocus::new().select_on_focus(SelectBehavior::SelectAll);
+    assert!(select.apply_masked_input(&mut state.widget));
+    assert_eq!(state.widget.selection(), 0..10);
+
+    let mut state = NumberInputState::new()
+        .with_pattern("####0.00")
+        .expect("pattern");
+    state.widget.focus.set(true);
+    state.widget.focus.set_gained(true);
+    SelectOnFocus::new().apply_masked_input(&mut state.widget);
+    assert_eq!(state.widget.cursor(), 5);
+}
@@ -0,0 +1,81 @@
+use rat_widget::textarea::whitespace::ShowWhitespace;
+use rat_widget::textarea::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect()
+}
+
+fn render(state: &mut TextAreaState, show: ShowWhitespace) -> Buffer {
+    let area = Rect::new(0, 0, 12, 2);
+    let mut buf = Buffer::empty(area);
+    TextArea::new().render(area, &mut buf, state);
+    show.render(&mut buf, state);
+    buf
+}
+
+#[test]
+fn test_whitespace() {
+    let mut state = TextAreaState::new();
+    state.set_tab_width(4);
+    state.set_text("a b\tc\nd  ");
+
+    let buf = render(&mut state, ShowWhitespace::new());
+    // the tab is padded to the tab stop.
+    assert_eq!(line(&buf, 0), "a·b→c       ");
+    assert_eq!(line(&buf, 1), "d··         ");
+    assert!(buf
+        .cell((1, 0))
+        .expect("cell")
+        .modifier
+        .contains(Modifier::DIM));
+    assert!(!buf
+        .cell((0, 0))
+        .expect("cell")
+        .modifier
+        .contains(Modifier::DIM));
+}
+
+#[test]
+fn test_trailing() {
+    let mut state = TextAreaState::new();
+    state.set_text("a b\nd  ");
+
+    let trailing = Style::new().bg(Color::Red);
+    let buf = render(
+        &mut state,
+        ShowWhitespace::new()
+            .show_whitespace(false)
+            .highlight_trailing(true)
+            .trailing_style(trailing),
+    );
+    assert_eq!(line(&buf, 1), "d           ");
+    // inner whitespace is not trailing.
+    assert_eq!(buf.cell((1, 0)).expect("cell").bg, Color::Reset);
+    assert_eq!(buf.cell((1, 1)).expect("cell").bg, Color::Red);
+    assert_eq!(buf.cell((2, 1)).expect("cell").bg, Color::Red);
+    assert_eq!(buf.cell((3, 1)).expect("cell").bg, Color::Reset);
+}
+
+#[test]
+fn test_selection() {
+    let mut state = TextAreaState::new();
+    state.set_text("a b");
+    state.set_selection((0, 0), (3, 0));
+
+    let area = Rect::new(0, 0, 12, 1);
+    let mut buf = Buffer::empty(area);
+    TextArea::new()
+        .select_style(Style::new().bg(Color::Blue))
+        .render(area, &mut buf, &mut state);
+    ShowWhitespace::new().render(&mut buf, &state);
+
+    // symbol changes, the selection style stays.
+    assert_eq!(buf.cell((1, 0)).expect("cell").symbol(), "·");
+    assert_eq!(buf.cell((1, 0)).expect("cell").bg, Color::Blue);
+}
@@ -0,0 +1,53 @@
+use rat_widget::layout::{FormLabel, FormWidget, LayoutForm};
+use rat_widget::pager::{SinglePager, SinglePagerState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Rect, Size};
+use ratatui::widgets::{Block, Padding};
+use std::rc::Rc;
+
+fn state(page: Size) -> SinglePagerState<usize> {
+    let mut form = LayoutForm::<usize>::new();
+    let tag = form.start(Some(Block::bordered()));
+    for i in 0..10 {
+        form.widget(i, FormLabel::None, FormWidget::Size(5, 1));
+    }
+    form.end(tag);
+
+    let mut state = SinglePagerState::new();
+    state.set_layout(Rc::new(form.paged(page, Padding::default())));
+    state
+}
+
+fn symbol(buf: &Buffer, x: u16, y: u16) -> &str {
+    buf.cell((x, y)).expect("cell").symbol()
+}
+
+#[test]
+fn test_render_block() {
+    let area = Rect::new(0, 0, 20, 8);
+    let mut buf = Buffer::empty(area);
+
+    let pager = SinglePager::new();
+    let page = pager.layout_size(area);
+    let mut state = state(page);
+    assert!(state.layout().page_count() > 1);
+
+    let inner = pager.inner(area);
+    let mut pager = pager.into_buffer(area, &mut buf, &mut state);
+    pager.render_block();
+    drop(pager);
+
+    // block is closed at the page break.
+    assert_eq!(symbol(&buf, inner.x, inner.y), "┌");
+    assert_eq!(symbol(&buf, inner.x, inner.y + 1), "│");
+    assert_eq!(symbol(&buf, inner.x, inner.y + 5), "└");
+
+    // and starts again on the next page.
+    state.next_page();
+    let mut buf = Buffer::empty(area);
+    let mut pager = SinglePager::new().into_buffer(area, &mut buf, &mut state);
+    pager.render_block();
+    drop(pager);
+    assert_eq!(symbol(&buf, inner.x, inner.y), "┌");
+    assert_eq!(symbol(&buf, inner.x, inner.y + 1), "│");
+}
@@ -0,0 +1,183 @@
+use rat_widget::button::{Button, ButtonState};
+use rat_widget::choice::{Choice, ChoiceState};
+use rat_widget::list::selection::RowSelection;
+use rat_widget::list::{List, ListState};
+use rat_widget::pager::{PageNavigation, PageNavigationState};
+use rat_widget::table::selection::RowSelection as TableRowSelection;
+use rat_widget::table::styles::precedence;
+use rat_widget::table::textdata::Row;
+use rat_widget::table::{Table, TableState, TableStyle};
+use rat_widget::util::{compose, fallback};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::widgets::{Block, StatefulWidget};
+
+// base
+fn base() -> Style {
+    Style::new().red()
+}
+
+// block, sets only the background.
+fn block() -> Block<'static> {
+    Block::bordered().style(Style::new().on_blue())
+}
+
+fn assert_cell(buf: &Buffer, pos: (u16, u16), fg: Color, bg: Color) {
+    let cell = &buf[pos];
+    assert_eq!((cell.fg, cell.bg), (fg, bg), "cell {:?}", pos);
+}
+
+#[test]
+fn test_compose() {
+    assert_eq!(
+        compose(Style::new().red().on_blue(), Style::new().on_green()),
+        Style::new().red().on_green()
+    );
+    assert_eq!(
+        fallback(
+            &[
+                None,
+                Some(Style::new().bold()),
+                Some(Style::new().on_green())
+            ],
+            base()
+        ),
+        Style::new().red().bold().on_green()
+    );
+    assert_eq!(fallback(&[None, None], base()), base());
+}
+
+#[test]
+fn test_button() {
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    let mut state = ButtonState::new();
+
+    let button = || {
+        Button::new("ok")
+            .style(base())
+            .block(block())
+            .focus_style(Style::new().on_green())
+    };
+
+    button().render(area, &mut buf, &mut state);
+    // base < block
+    assert_cell(&buf, (0, 0), Color::Red, Color::Blue);
+    assert_cell(&buf, (4, 1), Color::Red, Color::Blue);
+
+    state.focus.set(true);
+    button().render(area, &mut buf, &mut state);
+    // block < focus
+    assert_cell(&buf, (0, 0), Color::Red, Color::Blue);
+    assert_cell(&buf, (4, 1), Color::Red, Color::Green);
+}
+
+#[test]
+fn test_choice() {
+    let area = Rect::new(0, 0, 15, 3);
+    let mut buf = Buffer::empty(area);
+    let mut state = ChoiceState::default();
+
+    let render = |state: &mut ChoiceState, buf: &mut Buffer| {
+        let (widget, _popup) = Choice::new()
+            .auto_items(["a", "b"])
+            .style(base())
+            .block(block())
+            .focus_style(Style::new().on_green())
+            .into_widgets();
+        widget.render(area, buf, state);
+    };
+
+    render(&mut state, &mut buf);
+    assert_cell(&buf, (0, 0), Color::Red, Color::Blue);
+    assert_cell(&buf, (2, 1), Color::Red, Color::Blue);
+
+    state.focus.set(true);
+    render(&mut state, &mut buf);
+    assert_cell(&buf, (2, 1), Color::Red, Color::Green);
+}
+
+#[test]
+fn test_list() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = ListState::<RowSelection>::default();
+    state.select(Some(0));
+
+    let list = || {
+        List::new(["a", "b", "c"])
+            .style(base())
+            .block(block())
+            .select_style(Style::new().bold())
+            .focus_style(Style::new().on_green())
+    };
+
+    list().render(area, &mut buf, &mut state);
+    // base < block < select
+    assert_cell(&buf, (1, 2), Color::Red, Color::Blue);
+    assert_cell(&buf, (1, 1), Color::Red, Color::Blue);
+    assert!(buf[(1, 1)].modifier.contains(Modifier::BOLD));
+
+    state.focus.set(true);
+    list().render(area, &mut buf, &mut state);
+    // select < focus
+    assert_cell(&buf, (1, 1), Color::Red, Color::Green);
+    assert!(buf[(1, 1)].modifier.contains(Modifier::BOLD));
+    assert_cell(&buf, (1, 2), Color::Red, Color::Blue);
+}
+
+#[test]
+fn test_page_navigation() {
+    let area = Rect::new(0, 0, 20, 3);
+    let mut buf = Buffer::empty(area);
+    let mut state = PageNavigationState::new();
+    state.set_page_count(2);
+
+    PageNavigation::new()
+        .style(base())
+        .block(block())
+        .nav_style(Style::new().on_green())
+        .render(area, &mut buf, &mut state);
+
+    assert_cell(&buf, (10, 1), Color::Red, Color::Blue);
+    let nav = state.next_area;
+    assert_cell(&buf, (nav.x + 1, nav.y), Color::Red, Color::Green);
+}
+
+#[test]
+fn test_table() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = TableState::<TableRowSelection>::default();
+    state.select(Some(0));
+
+    let table = || {
+        let styles = TableStyle {
+            style: base(),
+            select_row: Some(Style::new().bold()),
+            focus_style: Some(Style::new().on_green()),
+            show_row_focus: true,
+            ..Default::default()
+        };
+        Table::new()
+            .rows([Row::new(["a"]), Row::new(["b"]), Row::new(["c"])])
+            .widths([Constraint::Length(5)])
+            .block(Block::bordered())
+            .styles(precedence(styles, Style::new().on_blue()))
+    };
+
+    table().render(area, &mut buf, &mut state);
+    // base < block < select
+    assert_cell(&buf, (0, 0), Color::Red, Color::Blue);
+    assert_cell(&buf, (1, 2), Color::Red, Color::Blue);
+    assert_cell(&buf, (1, 1), Color::Red, Color::Blue);
+    assert!(buf[(1, 1)].modifier.contains(Modifier::BOLD));
+
+    state.focus.set(true);
+    table().render(area, &mut buf, &mut state);
+    // select < focus
+    assert_cell(&buf, (1, 1), Color::Red, Color::Green);
+    assert!(buf[(1, 1)].modifier.contains(Modifier::BOLD));
+    assert_cell(&buf, (1, 2), Color::Red, Color::Blue);
+}
@@ -0,0 +1,116 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_widget::event::Outcome;
+use rat_widget::table::column_nav::{
+    handle_column_selection, handle_events, ColumnNav, ColumnSelection,
+};
+use rat_widget::table::selection::{CellSelection, RowSelection};
+use rat_widget::table::textdata::Row;
+use rat_widget::table::{Table, TableSelection, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn render<Selection: TableSelection + Default>(
+    area: Rect,
+    state: &mut TableState<Selection>,
+) -> Buffer {
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .rows([
+            Row::new(["a1", "b1", "c1", "d1"]),
+            Row::new(["a2", "b2", "c2", "d2"]),
+        ])
+        .widths([Constraint::Length(4); 4])
+        .column_spacing(0)
+        .layout_width(16)
+        .select_column_style(Some(Style::new().bg(Color::Green)))
+        .render(area, &mut buf, state);
+    buf
+}
+
+#[test]
+fn test_cell_selection() {
+    let area = Rect::new(0, 0, 16, 3);
+    let mut state = TableState::<CellSelection>::default();
+    render(area, &mut state);
+    state.select_cell(Some((0, 1)));
+
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Right)),
+        Outcome::Changed
+    );
+    assert_eq!(state.selection.selected_column(), Some(1));
+    // the row stays.
+    assert_eq!(state.selection.selected(), Some((1, 1)));
+
+    handle_events(&mut state, true, &key(KeyCode::End));
+    assert_eq!(state.selection.selected_column(), Some(3));
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Right)),
+        Outcome::Unchanged
+    );
+    handle_events(&mut state, true, &key(KeyCode::Home));
+    assert_eq!(state.selection.selected_column(), Some(0));
+
+    // no focus
+    assert_eq!(
+        handle_events(&mut state, false, &key(KeyCode::Right)),
+        Outcome::Continue
+    );
+}
+
+#[test]
+fn test_row_selection() {
+    let area = Rect::new(0, 0, 16, 3);
+    let mut state = TableState::<RowSelection>::default();
+    render(area, &mut state);
+    state.select(Some(0));
+
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Right)),
+        Outcome::Continue
+    );
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::End)),
+        Outcome::Continue
+    );
+    assert_eq!(state.selection.selected_column(), None);
+    assert_eq!(state.selected(), Some(0));
+}
+
+#[test]
+fn test_column_selection() {
+    // only 2 of 4 columns visible.
+    let area = Rect::new(0, 0, 8, 3);
+    let mut state = TableState::<ColumnSelection>::default();
+    render(area, &mut state);
+
+    assert_eq!(
+        handle_column_selection(&mut state, true, &key(KeyCode::Right)),
+        Outcome::Changed
+    );
+    assert_eq!(state.selection.selected_column(), Some(0));
+    let buf = render(area, &mut state);
+    assert_eq!(buf[(0, 0)].bg, Color::Green);
+    assert_eq!(buf[(0, 1)].bg, Color::Green);
+    assert_eq!(buf[(4, 0)].bg, Color::Reset);
+
+    // scrolled into view.
+    handle_column_selection(&mut state, true, &key(KeyCode::End));
+    assert_eq!(state.selection.selected_column(), Some(3));
+    assert!(state.x_offset() > 0);
+    let buf = render(area, &mut state);
+    assert_eq!(buf[(4, 0)].symbol(), "d");
+    assert_eq!(buf[(4, 0)].bg, Color::Green);
+
+    // rows still scroll.
+    assert_eq!(
+        handle_column_selection(&mut state, true, &key(KeyCode::Down)),
+        Outcome::Unchanged
+    );
+}
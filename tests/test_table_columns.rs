@@ -0,0 +1,150 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_widget::table::columns::{
+    handle_events, ColumnsOutcome, OrderedColumns, TableColumnsState,
+};
+use rat_widget::table::export::TableExport;
+use rat_widget::table::selection::RowSelection;
+use rat_widget::table::textdata::Row;
+use rat_widget::table::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::StatefulWidget;
+
+const DATA: [[&str; 3]; 2] = [["a1", "b1", "c1"], ["a2", "b2", "c2"]];
+
+struct Data;
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        DATA.len()
+    }
+
+    fn header(&self) -> Option<Row<'a>> {
+        Some(Row::new(["A", "B", "C"]))
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![
+            Constraint::Length(2),
+            Constraint::Length(3),
+            Constraint::Length(4),
+        ]
+    }
+
+    fn render_cell(
+        &self,
+        _: &TableContext,
+        column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        buf.set_string(area.x, area.y, DATA[row][column], Style::default());
+    }
+}
+
+fn render(columns: &TableColumnsState, table: &mut TableState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 12, 4);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .column_spacing(1)
+        .data(OrderedColumns::new(Data, columns))
+        .render(area, &mut buf, table);
+    buf
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn key(code: KeyCode, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent::new(code, modifiers))
+}
+
+#[test]
+fn test_order() {
+    let mut columns = TableColumnsState::new();
+    columns.set_columns(3);
+    assert_eq!(columns.column_order(), &[0, 1, 2]);
+
+    assert!(!columns.set_column_order(vec![0, 0, 1]));
+    assert!(!columns.set_column_order(vec![0, 3, 1]));
+    assert!(columns.set_column_order(vec![2, 0, 1]));
+    columns.set_column_hidden(0, true);
+    assert_eq!(columns.visible_columns(), vec![2, 1]);
+    assert_eq!(columns.data_column(1), Some(1));
+    assert_eq!(columns.display_column(0), None);
+
+    let mut table = TableState::default();
+    let buf = render(&columns, &mut table);
+    assert_eq!(line(&buf, 0), "C    B");
+    assert_eq!(line(&buf, 1), "c1   b1");
+    assert_eq!(table.columns, 2);
+
+    let export = TableExport::new()
+        .columns(columns.visible_columns())
+        .export(
+            &table,
+            rat_widget::table::export::ExportRows::All,
+            |c, r| DATA[r][c],
+        );
+    assert_eq!(export, "c1\tb1\nc2\tb2\n");
+
+    // unchanged column count keeps the order.
+    columns.set_columns(3);
+    assert_eq!(columns.column_order(), &[2, 0, 1]);
+}
+
+#[test]
+fn test_keys() {
+    let mut columns = TableColumnsState::new();
+    columns.set_columns(3);
+
+    let r = handle_events(&mut columns, true, &key(KeyCode::Left, KeyModifiers::NONE));
+    assert_eq!(r, ColumnsOutcome::Continue);
+    let r = handle_events(
+        &mut columns,
+        true,
+        &key(KeyCode::Char('o'), KeyModifiers::CONTROL),
+    );
+    assert_eq!(r, ColumnsOutcome::Changed);
+    assert!(columns.active);
+
+    let r = handle_events(&mut columns, true, &key(KeyCode::Right, KeyModifiers::NONE));
+    assert_eq!(r, ColumnsOutcome::OrderChanged);
+    assert_eq!(columns.column_order(), &[1, 0, 2]);
+    assert_eq!(columns.selected_column(), Some(0));
+
+    let r = handle_events(&mut columns, true, &key(KeyCode::Left, KeyModifiers::SHIFT));
+    assert_eq!(r, ColumnsOutcome::Changed);
+    assert_eq!(columns.selected_column(), Some(1));
+
+    let r = handle_events(
+        &mut columns,
+        true,
+        &key(KeyCode::Char(' '), KeyModifiers::NONE),
+    );
+    assert_eq!(r, ColumnsOutcome::OrderChanged);
+    assert!(columns.is_hidden(1));
+
+    // hidden columns are shown in column mode.
+    let mut table = TableState::default();
+    let buf = render(&columns, &mut table);
+    assert_eq!(line(&buf, 0), "B   A  C");
+    assert!(buf[(0, 0)].modifier.contains(Modifier::CROSSED_OUT));
+    assert!(buf[(0, 0)].modifier.contains(Modifier::REVERSED));
+
+    let r = handle_events(&mut columns, true, &key(KeyCode::F(2), KeyModifiers::NONE));
+    assert_eq!(r, ColumnsOutcome::Unchanged);
+    let r = handle_events(&mut columns, true, &key(KeyCode::Esc, KeyModifiers::NONE));
+    assert_eq!(r, ColumnsOutcome::Changed);
+    assert!(!columns.active);
+
+    let buf = render(&columns, &mut table);
+    assert_eq!(line(&buf, 0), "A  C");
+}
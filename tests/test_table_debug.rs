@@ -0,0 +1,75 @@
+use rat_widget::table::debug::DebugData;
+use rat_widget::table::selection::RowSelection;
+use rat_widget::table::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::StatefulWidget;
+use std::cell::Cell;
+
+struct BadData {
+    rows: Cell<usize>,
+}
+
+impl<'a> TableData<'a> for BadData {
+    fn rows(&self) -> usize {
+        // grows with every call.
+        let rows = self.rows.get();
+        self.rows.set(rows + 1);
+        rows
+    }
+
+    fn row_height(&self, _row: usize) -> u16 {
+        0
+    }
+
+    fn render_cell(
+        &self,
+        _ctx: &TableContext,
+        _column: usize,
+        row: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        // one cell too wide.
+        buf.set_string(
+            area.x,
+            area.y,
+            format!("{}xxxxxxxxxx", row),
+            Style::default(),
+        );
+    }
+}
+
+#[test]
+fn test_rows_stable() {
+    let data = DebugData::new(BadData { rows: Cell::new(3) });
+    assert_eq!(data.rows(), 3);
+    assert_eq!(data.rows(), 3);
+    assert_eq!(data.row_height(0), 1);
+    assert_eq!(data.row_height(5), 1);
+
+    let data = DebugData::new(BadData { rows: Cell::new(3) }).debug_data(false);
+    assert_eq!(data.rows(), 3);
+    assert_eq!(data.rows(), 4);
+    assert_eq!(data.row_height(0), 0);
+}
+
+#[test]
+fn test_render_clipped() {
+    let area = Rect::new(0, 0, 10, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = TableState::<RowSelection>::default();
+
+    Table::new()
+        .data(DebugData::new(BadData { rows: Cell::new(3) }))
+        .widths([Constraint::Length(3), Constraint::Length(3)])
+        .column_spacing(1)
+        .render(area, &mut buf, &mut state);
+
+    assert_eq!(state.rows, 3);
+    let line = (0..10)
+        .map(|x| buf.cell((x, 0)).expect("cell").symbol())
+        .collect::<String>();
+    assert_eq!(line, "0xx 0xx   ");
+}
@@ -0,0 +1,75 @@
+use rat_widget::table::columns::TableColumnsState;
+use rat_widget::table::export::{ExportFormat, ExportRows, TableExport};
+use rat_widget::table::selection::RowSetSelection;
+use rat_widget::table::TableState;
+
+fn state() -> TableState<RowSetSelection> {
+    TableState::<RowSetSelection> {
+        rows: 3,
+        columns: 2,
+        ..Default::default()
+    }
+}
+
+const DATA: [[&str; 2]; 3] = [["a", "1"], ["b,c", "2"], ["d\"e", "3\t4"]];
+
+#[test]
+fn test_tsv() {
+    let state = state();
+    let txt = TableExport::new().header_text(["name", "value"]).export(
+        &state,
+        ExportRows::All,
+        |c, r| DATA[r][c],
+    );
+    assert_eq!(txt, "name\tvalue\na\t1\nb,c\t2\nd\"e\t3 4\n");
+}
+
+#[test]
+fn test_csv() {
+    let state = state();
+    let txt =
+        TableExport::new()
+            .format(ExportFormat::Csv)
+            .export(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(txt, "a,1\n\"b,c\",2\n\"d\"\"e\",3\t4\n");
+}
+
+#[test]
+fn test_selected_columns() {
+    let mut state = state();
+    state.selection.add(2);
+    let txt = TableExport::new()
+        .columns([1, 0])
+        .export(&state, ExportRows::Selected, |c, r| DATA[r][c]);
+    assert_eq!(txt, "3 4\td\"e\n");
+}
+
+#[test]
+fn test_visible_columns() {
+    let state = state();
+    let mut columns = TableColumnsState::new();
+    columns.set_columns(2);
+    columns.set_column_order(vec![1, 0]);
+    let txt = TableExport::from_columns(&columns)
+        .header_text(["name", "value"])
+        .export(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(txt, "value\tname\n1\ta\n2\tb,c\n3 4\td\"e\n");
+
+    columns.set_column_hidden(0, true);
+    let txt =
+        TableExport::from_columns(&columns).export(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(txt, "1\n2\n3 4\n");
+}
+
+#[test]
+fn test_before_render() {
+    let state = TableState::<RowSetSelection>::default();
+    let txt = TableExport::new().export(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(txt, "");
+
+    let txt = TableExport::new()
+        .rows(2)
+        .columns([0])
+        .export(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(txt, "a\nb,c\n");
+}
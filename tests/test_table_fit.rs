@@ -0,0 +1,50 @@
+use rat_widget::table::export::ExportRows;
+use rat_widget::table::fit::FitColumns;
+use rat_widget::table::selection::RowSelection;
+use rat_widget::table::TableState;
+use ratatui::layout::{Constraint, Rect};
+
+const DATA: [[&str; 2]; 4] = [
+    ["a", "1"],
+    ["Überlänge", "22"],
+    ["日本", "3\n4444"],
+    ["a very long name that gets clamped", "5"],
+];
+
+fn state() -> TableState<RowSelection> {
+    TableState::<RowSelection> {
+        rows: 4,
+        columns: 2,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_fit_all() {
+    let state = state();
+    let widths = FitColumns::new()
+        .max_width(12)
+        .fit(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(widths, vec![Constraint::Length(12), Constraint::Length(4)]);
+}
+
+#[test]
+fn test_fit_header() {
+    let state = state();
+    let widths =
+        FitColumns::new()
+            .header_text(["Name", "Number"])
+            .fit(&state, ExportRows::All, |c, r| DATA[r][c]);
+    assert_eq!(widths, vec![Constraint::Length(34), Constraint::Length(6)]);
+}
+
+#[test]
+fn test_fit_visible() {
+    let mut state = state();
+    // as if rendered with two visible rows.
+    state.row_areas = vec![Rect::new(0, 0, 10, 1), Rect::new(0, 1, 10, 1)];
+    let widths = FitColumns::new()
+        .min_width(3)
+        .fit(&state, ExportRows::Visible, |c, r| DATA[r][c]);
+    assert_eq!(widths, vec![Constraint::Length(9), Constraint::Length(3)]);
+}
@@ -0,0 +1,168 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_widget::table::group::{
+    handle_events, GroupOutcome, GroupRow, GroupedData, TableGroupState, TableGroups,
+};
+use rat_widget::table::selection::RowSelection;
+use rat_widget::table::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::StatefulWidget;
+
+// (day, amount)
+const DATA: [(usize, u32); 5] = [(1, 10), (1, 20), (2, 5), (3, 1), (3, 2)];
+
+struct Data;
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        DATA.len()
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(10)]
+    }
+
+    fn render_cell(&self, _: &TableContext, _: usize, row: usize, area: Rect, buf: &mut Buffer) {
+        buf.set_string(area.x, area.y, format!("{}", DATA[row].1), Style::default());
+    }
+}
+
+struct Days;
+
+impl TableGroups for Days {
+    fn group(&self, row: usize) -> usize {
+        DATA[row].0
+    }
+
+    fn render_header(
+        &self,
+        _: &TableContext,
+        group: usize,
+        _: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        buf.set_string(area.x, area.y, format!("day {}", group), Style::default());
+    }
+
+    fn has_footer(&self, group: usize) -> bool {
+        group == 1
+    }
+
+    fn render_footer(
+        &self,
+        _: &TableContext,
+        group: usize,
+        _: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let sum: u32 = DATA.iter().filter(|v| v.0 == group).map(|v| v.1).sum();
+        buf.set_string(area.x, area.y, format!("sum {}", sum), Style::default());
+    }
+}
+
+fn render(groups: &TableGroupState, table: &mut TableState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 10, 10);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .data(GroupedData::new(Data, Days, groups))
+        .render(area, &mut buf, table);
+    buf
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn test_rows() {
+    let mut groups = TableGroupState::new();
+    groups.update(DATA.len(), &Days);
+    assert_eq!(
+        groups.rows,
+        vec![
+            GroupRow::Header(1),
+            GroupRow::Data(0),
+            GroupRow::Data(1),
+            GroupRow::Footer(1),
+            GroupRow::Header(2),
+            GroupRow::Data(2),
+            GroupRow::Header(3),
+            GroupRow::Data(3),
+            GroupRow::Data(4),
+        ]
+    );
+    assert_eq!(groups.display_row(3), Some(7));
+    assert_eq!(groups.data_row(5), Some(2));
+    assert_eq!(groups.data_row(4), None);
+
+    let mut table = TableState::default();
+    let buf = render(&groups, &mut table);
+    assert_eq!(line(&buf, 0), "day 1");
+    assert_eq!(line(&buf, 1), "10");
+    assert_eq!(line(&buf, 3), "sum 30");
+
+    groups.collapse(1);
+    assert_eq!(groups.rows.len(), 7);
+    assert_eq!(groups.row(1), Some(GroupRow::Footer(1)));
+    assert_eq!(groups.display_row(0), None);
+}
+
+#[test]
+fn test_keys() {
+    let mut groups = TableGroupState::new();
+    groups.update(DATA.len(), &Days);
+    let mut table = TableState::default();
+    render(&groups, &mut table);
+
+    table.select(Some(4));
+    let r = handle_events(&mut groups, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, GroupOutcome::Collapsed(2));
+    assert!(groups.is_collapsed(2));
+    assert_eq!(groups.row(5), Some(GroupRow::Header(3)));
+
+    let r = handle_events(&mut groups, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, GroupOutcome::Unchanged);
+    let r = handle_events(&mut groups, &mut table, true, &key(KeyCode::Right));
+    assert_eq!(r, GroupOutcome::Expanded(2));
+
+    // not on a header.
+    table.select(Some(5));
+    let r = handle_events(&mut groups, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, GroupOutcome::Continue);
+}
+
+#[test]
+fn test_click() {
+    let mut groups = TableGroupState::new();
+    groups.update(DATA.len(), &Days);
+    let mut table = TableState::default();
+    render(&groups, &mut table);
+
+    let y = table.row_areas[6].y;
+    let event = Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: 1,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    });
+    let r = handle_events(&mut groups, &mut table, false, &event);
+    assert_eq!(r, GroupOutcome::Collapsed(3));
+    assert_eq!(table.selected(), Some(6));
+
+    let buf = render(&groups, &mut table);
+    assert_eq!(line(&buf, 6), "day 3");
+    assert_eq!(line(&buf, 7), "");
+}
@@ -0,0 +1,169 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_widget::table::selection::RowSelection;
+use rat_widget::table::tree::{handle_events, TableTree, TableTreeState, TreeData, TreeOutcome};
+use rat_widget::table::{Table, TableContext, TableData, TableState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::{Constraint, Rect};
+use ratatui::style::Style;
+use ratatui::widgets::StatefulWidget;
+
+// (level, name)
+const DATA: [(usize, &str); 6] = [
+    (0, "src"),
+    (1, "table"),
+    (2, "tree.rs"),
+    (1, "lib.rs"),
+    (0, "tests"),
+    (0, "README"),
+];
+
+struct Data;
+
+impl<'a> TableData<'a> for Data {
+    fn rows(&self) -> usize {
+        DATA.len()
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        vec![Constraint::Length(10)]
+    }
+
+    fn render_cell(&self, _: &TableContext, _: usize, row: usize, area: Rect, buf: &mut Buffer) {
+        let (level, name) = DATA[row];
+        buf.set_string(area.x + level as u16, area.y, name, Style::default());
+    }
+}
+
+struct Levels;
+
+impl TableTree for Levels {
+    fn level(&self, row: usize) -> usize {
+        DATA[row].0
+    }
+}
+
+fn render(tree: &TableTreeState, table: &mut TableState<RowSelection>) -> Buffer {
+    let area = Rect::new(0, 0, 10, 10);
+    let mut buf = Buffer::empty(area);
+    Table::new()
+        .data(TreeData::new(Data, tree))
+        .render(area, &mut buf, table);
+    buf
+}
+
+fn line(buf: &Buffer, y: u16) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, y)).expect("cell").symbol())
+        .collect::<String>()
+        .trim_end()
+        .to_string()
+}
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+#[test]
+fn test_rows() {
+    let mut tree = TableTreeState::new();
+    tree.update(DATA.len(), &Levels);
+    assert_eq!(tree.rows, vec![0, 1, 2, 3, 4, 5]);
+    assert!(tree.has_children(0));
+    assert!(!tree.has_children(3));
+    assert!(!tree.has_children(4));
+    assert_eq!(tree.parent(2), Some(1));
+    assert_eq!(tree.parent(3), Some(0));
+    assert_eq!(tree.parent(4), None);
+
+    let mut table = TableState::default();
+    assert!(tree.collapse_all(&mut table));
+    assert_eq!(tree.rows, vec![0, 4, 5]);
+    let buf = render(&tree, &mut table);
+    assert_eq!(line(&buf, 1), "tests");
+
+    assert!(tree.expand(0));
+    assert_eq!(tree.rows, vec![0, 1, 3, 4, 5]);
+    assert!(tree.expand_all(&mut table));
+    assert_eq!(tree.rows.len(), 6);
+    assert!(!tree.expand_all(&mut table));
+}
+
+#[test]
+fn test_all_keeps_selection() {
+    let mut tree = TableTreeState::new();
+    tree.update(DATA.len(), &Levels);
+    let mut table = TableState::default();
+    render(&tree, &mut table);
+
+    // "tests" moves up.
+    table.select(Some(4));
+    assert!(tree.collapse_all(&mut table));
+    assert_eq!(table.selected(), Some(1));
+    assert!(tree.expand_all(&mut table));
+    assert_eq!(table.selected(), Some(4));
+
+    // "tree.rs" is hidden, its top-level ancestor "src" is selected.
+    table.select(Some(2));
+    assert!(tree.collapse_all(&mut table));
+    assert_eq!(table.selected(), Some(0));
+
+    // "README" after the last row.
+    table.select(Some(2));
+    assert!(tree.expand_all(&mut table));
+    assert_eq!(table.selected(), Some(5));
+}
+
+#[test]
+fn test_subtree() {
+    let mut tree = TableTreeState::new();
+    tree.update(DATA.len(), &Levels);
+
+    assert_eq!(tree.toggle_subtree(0), TreeOutcome::Collapsed(0));
+    assert!(tree.is_collapsed(0));
+    assert!(tree.is_collapsed(1));
+    assert_eq!(tree.rows, vec![0, 4, 5]);
+
+    assert_eq!(tree.toggle_subtree(0), TreeOutcome::Expanded(0));
+    assert!(tree.collapsed.is_empty());
+    assert_eq!(tree.toggle_subtree(5), TreeOutcome::Unchanged);
+}
+
+#[test]
+fn test_keys() {
+    let mut tree = TableTreeState::new();
+    tree.update(DATA.len(), &Levels);
+    let mut table = TableState::default();
+    render(&tree, &mut table);
+
+    // leaf: jump to the parent.
+    table.select(Some(2));
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, TreeOutcome::Changed);
+    assert_eq!(table.selected(), Some(1));
+
+    // expanded: collapse.
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, TreeOutcome::Collapsed(1));
+    assert_eq!(tree.rows, vec![0, 1, 3, 4, 5]);
+
+    // collapsed: jump to the parent.
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, TreeOutcome::Changed);
+    assert_eq!(table.selected(), Some(0));
+
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Char('*')));
+    assert_eq!(r, TreeOutcome::Collapsed(0));
+    assert_eq!(tree.rows, vec![0, 4, 5]);
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Char('*')));
+    assert_eq!(r, TreeOutcome::Expanded(0));
+    assert_eq!(tree.rows.len(), 6);
+
+    // top-level leaf.
+    table.select(Some(5));
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Left));
+    assert_eq!(r, TreeOutcome::Continue);
+    let r = handle_events(&mut tree, &mut table, true, &key(KeyCode::Right));
+    assert_eq!(r, TreeOutcome::Continue);
+    let r = handle_events(&mut tree, &mut table, false, &key(KeyCode::Char('*')));
+    assert_eq!(r, TreeOutcome::Continue);
+}
@@ -0,0 +1,39 @@
+use rat_widget::text_input::overflow::OverflowIndicator;
+use rat_widget::text_input::{TextInput, TextInputState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn line(buf: &Buffer) -> String {
+    (0..buf.area.width)
+        .map(|x| buf.cell((x, 0)).expect("cell").symbol())
+        .collect()
+}
+
+fn render(state: &mut TextInputState) -> Buffer {
+    let area = Rect::new(0, 0, 5, 1);
+    let mut buf = Buffer::empty(area);
+    TextInput::new().render(area, &mut buf, state);
+    OverflowIndicator::new()
+        .glyphs("<", ">")
+        .render_text_input(&mut buf, state);
+    buf
+}
+
+#[test]
+fn test_overflow() {
+    let mut state = TextInputState::new();
+    state.set_text("abcdefghij");
+    state.set_offset(0);
+    assert_eq!(line(&render(&mut state)), "abcd>");
+
+    state.set_offset(3);
+    assert_eq!(line(&render(&mut state)), "<efg>");
+
+    state.set_offset(5);
+    assert_eq!(line(&render(&mut state)), "<ghij");
+
+    state.set_text("abc");
+    state.set_offset(0);
+    assert_eq!(line(&render(&mut state)), "abc  ");
+}
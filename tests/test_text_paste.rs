@@ -0,0 +1,58 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::event::TextOutcome;
+use rat_widget::text::clipboard::LocalClipboard;
+use rat_widget::text_input::paste::{handle_paste, PastePolicy};
+use rat_widget::text_input::TextInputState;
+
+fn ctrl_v() -> Event {
+    Event::Key(KeyEvent {
+        code: KeyCode::Char('v'),
+        modifiers: KeyModifiers::CONTROL,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_policy() {
+    let text = "one\r\ntwo\nthree\rfour\r\n";
+    assert_eq!(
+        PastePolicy::ReplaceWithSpace.apply(text),
+        "one two three four"
+    );
+    assert_eq!(PastePolicy::StripNewlines.apply(text), "onetwothreefour");
+    assert_eq!(PastePolicy::FirstLineOnly.apply(text), "one");
+    assert_eq!(PastePolicy::FirstLineOnly.apply(""), "");
+}
+
+#[test]
+fn test_paste_event() {
+    let mut state = TextInputState::new();
+    state.set_text("<>");
+    state.set_cursor(1, false);
+
+    // not focused
+    let paste = Event::Paste("a\r\nb".into());
+    let r = handle_paste(&mut state, &paste, PastePolicy::ReplaceWithSpace);
+    assert_eq!(r, TextOutcome::Continue);
+
+    state.focus.set(true);
+    let r = handle_paste(&mut state, &paste, PastePolicy::ReplaceWithSpace);
+    assert_eq!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "<a b>");
+}
+
+#[test]
+fn test_paste_clip() {
+    let mut state = TextInputState::new();
+    state.focus.set(true);
+    state.set_clipboard(Some(LocalClipboard::new()));
+    _ = state
+        .clipboard()
+        .expect("clip")
+        .set_string("first\r\nsecond\r\n");
+
+    let r = handle_paste(&mut state, &ctrl_v(), PastePolicy::FirstLineOnly);
+    assert_eq!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "first");
+}
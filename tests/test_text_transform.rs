@@ -0,0 +1,60 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::event::TextOutcome;
+use rat_widget::text_input::transform::{apply, handle_commit, lower, trim, upper};
+use rat_widget::text_input::TextInputState;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent {
+        code,
+        modifiers: KeyModifiers::NONE,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_presets() {
+    assert_eq!(upper("at"), "AT");
+    assert_eq!(lower("Mail@Example.COM"), "mail@example.com");
+    assert_eq!(trim("  x y "), "x y");
+}
+
+#[test]
+fn test_apply() {
+    let mut state = TextInputState::new();
+    state.set_text("  abc  ");
+    state.set_cursor(7, false);
+
+    assert!(apply(&mut state, trim));
+    assert_eq!(state.text(), "abc");
+    assert_eq!(state.cursor(), 3);
+
+    assert!(!apply(&mut state, trim));
+}
+
+#[test]
+fn test_commit_enter() {
+    let mut state = TextInputState::new();
+    state.set_text("de");
+    state.focus.set(true);
+
+    // not consumed.
+    let r = handle_commit(&mut state, &key(KeyCode::Char('x')), upper);
+    assert_eq!(r, TextOutcome::Continue);
+    assert_eq!(state.text(), "de");
+
+    let r = handle_commit(&mut state, &key(KeyCode::Enter), upper);
+    assert_eq!(r, TextOutcome::Continue);
+    assert_eq!(state.text(), "DE");
+}
+
+#[test]
+fn test_commit_lost() {
+    let mut state = TextInputState::new();
+    state.set_text("de");
+    state.focus.set_lost(true);
+
+    let r = handle_commit(&mut state, &key(KeyCode::Tab), upper);
+    assert_eq!(r, TextOutcome::TextChanged);
+    assert_eq!(state.text(), "DE");
+}
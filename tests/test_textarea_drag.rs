@@ -0,0 +1,77 @@
+use crossterm::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+use rat_widget::event::{HandleEvent, MouseOnly};
+use rat_widget::textarea::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn mouse(kind: MouseEventKind, column: u16, row: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column,
+        row,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+#[test]
+fn test_drag_scrolls_down() {
+    let area = Rect::new(0, 0, 20, 5);
+    let mut buf = Buffer::empty(area);
+    let mut state = TextAreaState::new();
+    state.set_text(
+        (0..20)
+            .map(|v| format!("line {}", v))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    TextArea::new().render(area, &mut buf, &mut state);
+
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 0, 0),
+        MouseOnly,
+    );
+    assert_eq!(state.vertical_offset(), 0);
+
+    // each drag event past the bottom edge advances the offset.
+    state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), 2, 5),
+        MouseOnly,
+    );
+    assert_eq!(state.vertical_offset(), 1);
+    TextArea::new().render(area, &mut buf, &mut state);
+    state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), 2, 5),
+        MouseOnly,
+    );
+    assert_eq!(state.vertical_offset(), 2);
+
+    let sel = state.selection();
+    assert_eq!(sel.start.y, 0);
+    assert_eq!(sel.end.y, 6);
+}
+
+#[test]
+fn test_drag_scrolls_up() {
+    let area = Rect::new(0, 1, 20, 5);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 7));
+    let mut state = TextAreaState::new();
+    state.set_text(
+        (0..20)
+            .map(|v| format!("line {}", v))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+    state.set_vertical_offset(10);
+    TextArea::new().render(area, &mut buf, &mut state);
+
+    state.handle(
+        &mouse(MouseEventKind::Down(MouseButton::Left), 0, 3),
+        MouseOnly,
+    );
+    state.handle(
+        &mouse(MouseEventKind::Drag(MouseButton::Left), 0, 0),
+        MouseOnly,
+    );
+    assert_eq!(state.vertical_offset(), 9);
+}
@@ -0,0 +1,75 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
+use rat_widget::text::TextPosition;
+use rat_widget::textarea::goto::{self, CursorOutcome};
+use rat_widget::textarea::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn state() -> TextAreaState {
+    let mut state = TextAreaState::new();
+    let text = (0..40).map(|n| format!("line {}\n", n)).collect::<String>();
+    state.set_text(text);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+    TextArea::new().render(buf.area, &mut buf, &mut state);
+    state
+}
+
+#[test]
+fn test_set_cursor_line_col() {
+    let mut state = state();
+    assert!(goto::set_cursor_line_col(&mut state, 3, 2, false));
+    assert_eq!(goto::cursor_line_col(&state), (3, 2));
+
+    goto::set_cursor_line_col(&mut state, 3, 100, false);
+    assert_eq!(state.cursor(), TextPosition::new(6, 3));
+
+    goto::set_cursor_line_col(&mut state, 100, 3, false);
+    assert_eq!(state.cursor(), TextPosition::new(0, 40));
+    assert!(state.offset().1 > 30);
+}
+
+#[test]
+fn test_goto_line() {
+    let mut state = state();
+    assert!(goto::goto_line(&mut state, 20));
+    assert_eq!(state.cursor(), TextPosition::new(0, 20));
+    assert_eq!(state.offset(), (0, 15));
+
+    goto::goto_line(&mut state, 2);
+    assert_eq!(state.offset(), (0, 0));
+}
+
+#[test]
+fn test_display_col() {
+    let mut state = TextAreaState::new();
+    state.set_text("a\tb漢c");
+    state.set_tab_width(4);
+    state.set_cursor((5, 0), false);
+    assert_eq!(goto::cursor_line_col(&state), (0, 5));
+    assert_eq!(goto::cursor_display_col(&state), 8);
+
+    state.set_cursor((2, 0), false);
+    assert_eq!(goto::cursor_display_col(&state), 4);
+}
+
+#[test]
+fn test_cursor_moved() {
+    let mut state = state();
+    assert_eq!(
+        goto::handle_events(&mut state, true, &key(KeyCode::Down)),
+        CursorOutcome::CursorMoved
+    );
+    assert_eq!(
+        goto::handle_events(&mut state, true, &key(KeyCode::Char('x'))),
+        CursorOutcome::TextChanged
+    );
+    assert_eq!(
+        goto::handle_events(&mut state, false, &key(KeyCode::Down)),
+        CursorOutcome::Continue
+    );
+}
@@ -0,0 +1,76 @@
+use rat_widget::text::TextRange;
+use rat_widget::textarea::search::{SearchResult, TextSearch};
+use rat_widget::textarea::TextAreaState;
+
+fn text() -> TextAreaState {
+    let mut state = TextAreaState::new();
+    let mut s = String::new();
+    for i in 0..30 {
+        if i % 10 == 3 {
+            s.push_str(&format!("line {} find me\n", i));
+        } else {
+            s.push_str(&format!("line {}\n", i));
+        }
+    }
+    state.set_text(s);
+    state.vscroll.set_page_len(10);
+    state.vscroll.set_max_offset(21);
+    state.hscroll.set_page_len(40);
+    state.hscroll.set_max_offset(0);
+    state
+}
+
+#[test]
+fn test_matches() {
+    let state = text();
+    let mut search = TextSearch::new();
+    search.set_needle("find");
+    search.update(&state);
+
+    assert_eq!(search.search_match_count(), 3);
+    assert_eq!(search.current_match_index(), None);
+    assert_eq!(search.matches[0], TextRange::new((7, 3), (11, 3)));
+    assert_eq!(search.matches[2], TextRange::new((8, 23), (12, 23)));
+
+    search.set_needle("");
+    search.update(&state);
+    assert_eq!(search.search_match_count(), 0);
+}
+
+#[test]
+fn test_search_next() {
+    let mut state = text();
+    let mut search = TextSearch::new();
+    search.set_needle("find");
+    search.update(&state);
+
+    assert_eq!(search.search_next(&mut state), SearchResult::Found(0));
+    assert_eq!(search.search_next(&mut state), SearchResult::Found(1));
+    assert_eq!(state.selection(), TextRange::new((8, 13), (12, 13)));
+    // centered
+    assert_eq!(state.vscroll.offset(), 8);
+    assert_eq!(search.current_match_index(), Some(1));
+
+    assert_eq!(search.search_next(&mut state), SearchResult::Found(2));
+    assert_eq!(search.search_next(&mut state), SearchResult::Wrapped(0));
+    assert_eq!(state.vscroll.offset(), 0);
+}
+
+#[test]
+fn test_search_prev() {
+    let mut state = text();
+    let mut search = TextSearch::new();
+    search.set_needle("find");
+    search.update(&state);
+
+    assert_eq!(search.search_prev(&mut state), SearchResult::Wrapped(2));
+    assert_eq!(state.selection(), TextRange::new((8, 23), (12, 23)));
+    assert_eq!(search.search_prev(&mut state), SearchResult::Found(1));
+    assert_eq!(search.search_prev(&mut state), SearchResult::Found(0));
+    assert_eq!(search.search_prev(&mut state), SearchResult::Wrapped(2));
+
+    search.set_needle("nothing");
+    search.update(&state);
+    assert_eq!(search.search_prev(&mut state), SearchResult::NotFound);
+    assert_eq!(search.current_match_index(), None);
+}
@@ -0,0 +1,79 @@
+use crossterm::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+use rat_widget::event::TextOutcome;
+use rat_widget::text::TextPosition;
+use rat_widget::textarea::wheel;
+use rat_widget::textarea::{TextArea, TextAreaState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::{Block, StatefulWidget};
+
+fn scroll(kind: MouseEventKind, modifiers: KeyModifiers, x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind,
+        column: x,
+        row: y,
+        modifiers,
+    })
+}
+
+fn state() -> TextAreaState {
+    let mut state = TextAreaState::new();
+    let text = (0..20)
+        .map(|n| format!("line {} {}\n", n, "x".repeat(40)))
+        .collect::<String>();
+    state.set_text(text);
+    state.set_cursor((2, 1), false);
+
+    let mut buf = Buffer::empty(Rect::new(0, 0, 20, 10));
+    TextArea::new()
+        .block(Block::bordered())
+        .render(buf.area, &mut buf, &mut state);
+    state
+}
+
+#[test]
+fn test_vertical() {
+    let mut state = state();
+
+    // on the border
+    let r = wheel::handle_mouse_events(
+        &mut state,
+        &scroll(MouseEventKind::ScrollDown, KeyModifiers::NONE, 0, 5),
+    );
+    assert_eq!(r, TextOutcome::Changed);
+    assert!(state.offset().1 > 0);
+    assert_eq!(state.cursor(), TextPosition::new(2, 1));
+
+    let r = wheel::handle_mouse_events(
+        &mut state,
+        &scroll(MouseEventKind::ScrollDown, KeyModifiers::NONE, 30, 5),
+    );
+    assert_eq!(r, TextOutcome::Continue);
+}
+
+#[test]
+fn test_horizontal() {
+    let mut state = state();
+
+    let r = wheel::handle_mouse_events(
+        &mut state,
+        &scroll(MouseEventKind::ScrollDown, KeyModifiers::SHIFT, 5, 5),
+    );
+    assert_eq!(r, TextOutcome::Changed);
+    assert!(state.offset().0 > 0);
+    assert_eq!(state.offset().1, 0);
+    assert_eq!(state.cursor(), TextPosition::new(2, 1));
+
+    let r = wheel::handle_mouse_events(
+        &mut state,
+        &scroll(MouseEventKind::ScrollLeft, KeyModifiers::NONE, 5, 5),
+    );
+    assert_eq!(r, TextOutcome::Changed);
+    assert_eq!(state.offset().0, 0);
+
+    let r = wheel::handle_mouse_events(
+        &mut state,
+        &scroll(MouseEventKind::ScrollUp, KeyModifiers::SHIFT, 30, 5),
+    );
+    assert_eq!(r, TextOutcome::Continue);
+}
@@ -0,0 +1,67 @@
+use rat_widget::button::ButtonState;
+use rat_widget::date_input::DateInputState;
+use rat_widget::focus::{FocusBuilder, HasFocus};
+use rat_widget::number_input::NumberInputState;
+use rat_widget::text_input::TextInputState;
+use rat_widget::validate::{render_invalid, validate_form, Validatable};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Style};
+
+#[test]
+fn test_validate_form() {
+    let mut name = TextInputState::named("name");
+    let mut amount = NumberInputState::named("amount")
+        .with_pattern("####0.00")
+        .expect("pattern");
+    let mut date = DateInputState::named("date")
+        .with_pattern("%d.%m.%Y")
+        .expect("pattern");
+
+    // the text widgets use their own version of rat-focus,
+    // these stand in with the same names.
+    let stand_in = ["name", "amount", "date"].map(ButtonState::named);
+    let mut fb = FocusBuilder::default();
+    for w in stand_in.iter() {
+        fb.widget(w);
+    }
+    let focus = fb.build();
+
+    {
+        let mut fields: [&mut dyn Validatable; 3] = [&mut name, &mut amount, &mut date];
+        assert!(validate_form(&mut fields, &focus).is_none());
+    }
+
+    date.widget.set_text("31.02.2024");
+    amount.widget.set_text("   1x.00");
+    {
+        let mut fields: [&mut dyn Validatable; 3] = [&mut name, &mut amount, &mut date];
+        let first = validate_form(&mut fields, &focus).expect("invalid");
+        assert_eq!(first.name(), "amount");
+        focus.focus_flag(first);
+    }
+    assert!(!name.is_invalid());
+    assert!(amount.is_invalid());
+    assert!(date.is_invalid());
+    assert!(stand_in[1].is_focused());
+
+    // the app marks a text invalid.
+    name.invalid = true;
+    amount.widget.set_text("   12.00");
+    {
+        let mut fields: [&mut dyn Validatable; 3] = [&mut name, &mut amount, &mut date];
+        let first = validate_form(&mut fields, &focus).expect("invalid");
+        assert_eq!(first.name(), "name");
+    }
+    assert!(!amount.is_invalid());
+
+    name.area = Rect::new(0, 0, 5, 1);
+    date.widget.area = Rect::new(0, 1, 5, 1);
+    amount.widget.area = Rect::new(0, 2, 5, 1);
+    let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+    let error = Style::new().bg(Color::Red);
+    render_invalid(&[&name, &amount, &date], error, &mut buf);
+    assert_eq!(buf[(0, 0)].bg, Color::Red);
+    assert_eq!(buf[(4, 1)].bg, Color::Red);
+    assert_eq!(buf[(0, 2)].bg, Color::Reset);
+}
@@ -0,0 +1,101 @@
+use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers};
+use rat_widget::checkbox::CheckboxState;
+use rat_widget::choice::ChoiceState;
+use rat_widget::event::{HandleEvent, Regular};
+use rat_widget::focus::FocusFlag;
+use rat_widget::text_input::TextInputState;
+use rat_widget::value_history::{HistoryOutcome, ValueHistory, ValueStr};
+
+fn key(c: char, modifiers: KeyModifiers) -> Event {
+    Event::Key(KeyEvent {
+        code: KeyCode::Char(c),
+        modifiers,
+        kind: KeyEventKind::Press,
+        state: KeyEventState::NONE,
+    })
+}
+
+#[test]
+fn test_undo_redo() {
+    let a = FocusFlag::named("a");
+    let b = FocusFlag::named("b");
+    let mut history = ValueHistory::new();
+
+    history.push(a.clone(), "1", "2");
+    history.push(b.clone(), "x", "y");
+    // no change
+    history.push(b.clone(), "y", "y");
+
+    assert_eq!(history.undo(), Some((b.clone(), "x".to_string())));
+    assert_eq!(history.undo(), Some((a.clone(), "1".to_string())));
+    assert_eq!(history.undo(), None);
+    assert!(history.can_redo());
+    assert_eq!(history.redo(), Some((a.clone(), "2".to_string())));
+
+    // new change clears redo
+    history.push(a.clone(), "2", "3");
+    assert!(!history.can_redo());
+}
+
+#[test]
+fn test_limit() {
+    let a = FocusFlag::named("a");
+    let mut history = ValueHistory::new().with_limit(2);
+    history.push(a.clone(), "1", "2");
+    history.push(a.clone(), "2", "3");
+    history.push(a.clone(), "3", "4");
+    assert_eq!(history.undo(), Some((a.clone(), "3".to_string())));
+    assert_eq!(history.undo(), Some((a.clone(), "2".to_string())));
+    assert_eq!(history.undo(), None);
+}
+
+#[test]
+fn test_track() {
+    let mut check = CheckboxState::new();
+    let mut history = ValueHistory::new();
+
+    check.focus.set_gained(true);
+    history.track(&check.focus, &check);
+    check.focus.set_gained(false);
+    check.set_value(true);
+    check.focus.set_lost(true);
+    history.track(&check.focus, &check);
+
+    let r = history.handle(&key('z', KeyModifiers::CONTROL), Regular);
+    assert_eq!(
+        r,
+        HistoryOutcome::Restore(check.focus.clone(), "false".into())
+    );
+    let r = history.handle(
+        &key('Z', KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+        Regular,
+    );
+    assert_eq!(
+        r,
+        HistoryOutcome::Restore(check.focus.clone(), "true".into())
+    );
+    let r = history.handle(
+        &key('Z', KeyModifiers::CONTROL | KeyModifiers::SHIFT),
+        Regular,
+    );
+    assert_eq!(r, HistoryOutcome::Unchanged);
+    let r = history.handle(&key('x', KeyModifiers::NONE), Regular);
+    assert_eq!(r, HistoryOutcome::Continue);
+}
+
+#[test]
+fn test_value_str() {
+    let mut text = TextInputState::new();
+    assert!(text.set_value_str("abc"));
+    assert_eq!(text.value_str(), "abc");
+
+    let mut choice = ChoiceState::<u32>::default();
+    choice.keys = vec![1, 2, 3];
+    assert_eq!(choice.value_str(), "");
+    assert!(choice.set_value_str("2"));
+    assert_eq!(choice.value_str(), "2");
+    assert!(!choice.set_value_str("7"));
+    assert!(!choice.set_value_str("x"));
+    assert!(choice.set_value_str(""));
+    assert_eq!(choice.value_opt(), None);
+}
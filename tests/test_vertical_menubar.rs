@@ -0,0 +1,115 @@
+use crossterm::event::{
+    Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use rat_widget::event::MenuOutcome;
+use rat_widget::menu::vertical_menubar::{handle_events, VerticalMenubar};
+use rat_widget::menu::{MenubarState, StaticMenu};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+static MENU: StaticMenu = StaticMenu {
+    menu: &[
+        ("_File", &["_Open", "_Save"]),
+        ("_Edit", &["_Copy", "_Paste"]),
+        ("_Help", &[]),
+    ],
+};
+
+fn key(code: KeyCode) -> Event {
+    Event::Key(KeyEvent::new(code, KeyModifiers::NONE))
+}
+
+fn click(x: u16, y: u16) -> Event {
+    Event::Mouse(MouseEvent {
+        kind: MouseEventKind::Down(MouseButton::Left),
+        column: x,
+        row: y,
+        modifiers: KeyModifiers::NONE,
+    })
+}
+
+fn render(buf: &mut Buffer, state: &mut MenubarState) {
+    let (column, popup) = VerticalMenubar::new(&MENU).into_widgets();
+    let area = Rect::new(0, 0, 6, 10);
+    column.render(area, buf, state);
+    popup.render(area, buf, state);
+}
+
+#[test]
+fn test_layout() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = MenubarState::new();
+
+    assert_eq!(VerticalMenubar::new(&MENU).width(), 4);
+
+    render(&mut buf, &mut state);
+    assert_eq!(
+        state.bar.item_areas,
+        vec![
+            Rect::new(0, 0, 6, 1),
+            Rect::new(0, 1, 6, 1),
+            Rect::new(0, 2, 6, 1),
+        ]
+    );
+    assert_eq!(buf.cell((0, 1)).expect("cell").symbol(), "E");
+}
+
+#[test]
+fn test_keys() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = MenubarState::new();
+    render(&mut buf, &mut state);
+
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Down)),
+        MenuOutcome::Selected(0)
+    );
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Down)),
+        MenuOutcome::Selected(1)
+    );
+    assert!(!state.popup_active());
+
+    // open to the right of the title row.
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Right)),
+        MenuOutcome::Changed
+    );
+    assert!(state.popup_active());
+    render(&mut buf, &mut state);
+    assert!(state.popup.popup.area.x >= 6);
+    assert_eq!(state.popup.popup.area.y, 1);
+
+    // moving keeps the submenu open.
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Up)),
+        MenuOutcome::Selected(0)
+    );
+    assert!(state.popup_active());
+
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Left)),
+        MenuOutcome::Changed
+    );
+    assert!(!state.popup_active());
+
+    assert_eq!(
+        handle_events(&mut state, true, &key(KeyCode::Enter)),
+        MenuOutcome::Activated(0)
+    );
+    assert!(state.popup_active());
+}
+
+#[test]
+fn test_mouse() {
+    let mut buf = Buffer::empty(Rect::new(0, 0, 30, 10));
+    let mut state = MenubarState::new();
+    render(&mut buf, &mut state);
+
+    assert_eq!(
+        handle_events(&mut state, false, &click(1, 2)),
+        MenuOutcome::Selected(2)
+    );
+    assert!(state.popup_active());
+}
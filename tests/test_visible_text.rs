@@ -0,0 +1,64 @@
+use rat_widget::clipper::{Clipper, ClipperState};
+use rat_widget::layout::GenericLayout;
+use rat_widget::view::{View, ViewState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::text::Span;
+use ratatui::widgets::StatefulWidget;
+use std::rc::Rc;
+
+fn render_view(state: &mut ViewState) {
+    let area = Rect::new(0, 0, 6, 2);
+    let mut buf = Buffer::empty(area);
+    let mut view = View::new()
+        .layout(Rect::new(0, 0, 20, 4))
+        .into_buffer(area, state);
+    view.render_widget(Span::from("ab日本cd  "), Rect::new(0, 0, 20, 1));
+    view.render_widget(Span::from("x"), Rect::new(3, 1, 20, 1));
+    view.into_widget().render(area, &mut buf, state);
+}
+
+#[test]
+fn test_view() {
+    let mut state = ViewState::new();
+    assert!(state.visible_text().is_empty());
+
+    render_view(&mut state);
+    assert_eq!(state.visible_text(), vec!["ab日本", "   x"]);
+    let hash = state.visible_text_hash();
+
+    render_view(&mut state);
+    assert_eq!(state.visible_text_hash(), hash);
+
+    // cut in the middle of a wide glyph.
+    state.hscroll.set_offset(3);
+    render_view(&mut state);
+    assert_eq!(state.visible_text(), vec![" 本cd", "x"]);
+    assert_ne!(state.visible_text_hash(), hash);
+
+    state.hscroll.set_offset(1);
+    render_view(&mut state);
+    assert_eq!(state.visible_text(), vec!["b日本c", "  x"]);
+
+    state.hscroll.set_offset(2);
+    render_view(&mut state);
+    assert_eq!(state.visible_text(), vec!["日本cd", " x"]);
+}
+
+#[test]
+fn test_clipper() {
+    let mut state = ClipperState::<usize>::default();
+    let mut l = GenericLayout::new();
+    l.add(0, Rect::new(0, 0, 10, 1), None, Rect::default());
+    l.add(1, Rect::new(0, 5, 10, 1), None, Rect::default());
+    state.set_layout(Rc::new(l));
+
+    let area = Rect::new(0, 0, 10, 3);
+    let mut buf = Buffer::empty(area);
+    let mut clip = Clipper::new().into_buffer(area, &mut state);
+    clip.render_widget(0, || Span::from("first"));
+    clip.render_widget(1, || Span::from("second"));
+    clip.into_widget().render(area, &mut buf, &mut state);
+
+    assert_eq!(state.visible_text(), vec!["first", "", ""]);
+}